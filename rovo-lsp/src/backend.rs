@@ -1,11 +1,12 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use tokio::sync::RwLock;
 use tower_lsp::jsonrpc::Result;
 use tower_lsp::lsp_types::*;
 use tower_lsp::{Client, LanguageServer};
 
-use crate::handlers;
+use crate::{handlers, utils};
 
 /// LSP backend implementation for Rovo language server
 pub struct Backend {
@@ -13,6 +14,12 @@ pub struct Backend {
     client: Client,
     /// In-memory cache of document contents
     document_map: Arc<RwLock<HashMap<String, String>>>,
+    /// Whether debug messages are sent to the client (`"verbose": true` init option)
+    verbose: AtomicBool,
+    /// Operation IDs declared by each cached document, for workspace-wide duplicate checks
+    operation_ids: RwLock<HashMap<Url, Vec<handlers::OperationIdSite>>>,
+    /// Documents that currently have cross-file duplicate operation ID warnings
+    duplicate_id_documents: RwLock<HashSet<String>>,
 }
 
 impl Backend {
@@ -20,6 +27,19 @@ impl Backend {
         Self {
             client,
             document_map: Arc::new(RwLock::new(HashMap::new())),
+            verbose: AtomicBool::new(false),
+            operation_ids: RwLock::new(HashMap::new()),
+            duplicate_id_documents: RwLock::new(HashSet::new()),
+        }
+    }
+
+    /// Send a debug message to the client's log when verbose logging is enabled
+    ///
+    /// Messages go through `window/logMessage` rather than stderr/stdout, so they
+    /// never interfere with the stdio transport.
+    async fn log_debug(&self, message: impl FnOnce() -> String) {
+        if self.verbose.load(Ordering::Relaxed) {
+            self.client.log_message(MessageType::LOG, message()).await;
         }
     }
 
@@ -33,19 +53,77 @@ impl Backend {
             .await
             .insert(uri.clone(), content.clone());
 
-        // Run diagnostics
-        let diagnostics = handlers::text_document_did_change(&content, params.uri.clone());
+        // Re-index this document's operation IDs and check them against the others
+        let mut duplicate_ids = {
+            let mut operation_ids = self.operation_ids.write().await;
+            operation_ids.insert(
+                params.uri.clone(),
+                handlers::collect_operation_ids(&content),
+            );
+            handlers::indexed_duplicate_operation_id_diagnostics(&operation_ids)
+        };
+        self.refresh_duplicate_ids(&mut duplicate_ids, &uri).await;
+
+        // Run diagnostics over the whole document, even after an incremental
+        // change: some checks (like the secured GET hint) read route
+        // registrations elsewhere in the file, so the changed region isn't enough
+        let mut diagnostics = handlers::text_document_did_change(&content, params.uri.clone());
+        diagnostics.extend(duplicate_ids.remove(&params.uri).unwrap_or_default());
 
         // Publish diagnostics
         self.client
             .publish_diagnostics(params.uri, diagnostics, Some(params.version))
             .await;
     }
+
+    /// Republish diagnostics for other documents whose cross-file duplicate
+    /// operation ID warnings appeared or went away, skipping `changed`
+    async fn refresh_duplicate_ids(
+        &self,
+        duplicate_ids: &mut HashMap<Url, Vec<Diagnostic>>,
+        changed: &str,
+    ) {
+        // Remember which documents have warnings so they can be cleared later
+        let current: HashSet<String> = duplicate_ids
+            .iter()
+            .filter(|(_, diags)| !diags.is_empty())
+            .map(|(uri, _)| uri.to_string())
+            .collect();
+        let previous = std::mem::replace(
+            &mut *self.duplicate_id_documents.write().await,
+            current.clone(),
+        );
+
+        for key in current.union(&previous) {
+            if key == changed {
+                continue;
+            }
+            let (Ok(other_uri), Some(text)) = (
+                Url::parse(key),
+                self.document_map.read().await.get(key).cloned(),
+            ) else {
+                continue;
+            };
+            let mut diagnostics = handlers::text_document_did_change(&text, other_uri.clone());
+            diagnostics.extend(duplicate_ids.remove(&other_uri).unwrap_or_default());
+            self.client
+                .publish_diagnostics(other_uri, diagnostics, None)
+                .await;
+        }
+    }
 }
 
 #[tower_lsp::async_trait]
 impl LanguageServer for Backend {
-    async fn initialize(&self, _: InitializeParams) -> Result<InitializeResult> {
+    async fn initialize(&self, params: InitializeParams) -> Result<InitializeResult> {
+        let verbose = params
+            .initialization_options
+            .as_ref()
+            .and_then(|options| options.get("verbose"))
+            .and_then(|value| value.as_bool())
+            .unwrap_or(false);
+        self.verbose.store(verbose, Ordering::Relaxed);
+
         Ok(InitializeResult {
             server_info: Some(ServerInfo {
                 name: "rovo-lsp".to_string(),
@@ -53,16 +131,33 @@ impl LanguageServer for Backend {
             }),
             capabilities: ServerCapabilities {
                 text_document_sync: Some(TextDocumentSyncCapability::Kind(
-                    TextDocumentSyncKind::FULL,
+                    TextDocumentSyncKind::INCREMENTAL,
                 )),
                 completion_provider: Some(CompletionOptions {
                     trigger_characters: Some(vec!["@".to_string()]),
                     ..Default::default()
                 }),
                 hover_provider: Some(HoverProviderCapability::Simple(true)),
+                signature_help_provider: Some(SignatureHelpOptions {
+                    trigger_characters: Some(vec![" ".to_string()]),
+                    retrigger_characters: None,
+                    work_done_progress_options: WorkDoneProgressOptions::default(),
+                }),
                 code_action_provider: Some(CodeActionProviderCapability::Simple(true)),
                 definition_provider: Some(OneOf::Left(true)),
+                type_definition_provider: Some(TypeDefinitionProviderCapability::Simple(true)),
                 references_provider: Some(OneOf::Left(true)),
+                document_symbol_provider: Some(OneOf::Left(true)),
+                folding_range_provider: Some(FoldingRangeProviderCapability::Simple(true)),
+                selection_range_provider: Some(SelectionRangeProviderCapability::Simple(true)),
+                inlay_hint_provider: Some(OneOf::Left(true)),
+                code_lens_provider: Some(CodeLensOptions {
+                    resolve_provider: Some(true),
+                }),
+                execute_command_provider: Some(ExecuteCommandOptions {
+                    commands: vec![handlers::SHOW_LOCATION_COMMAND.to_string()],
+                    work_done_progress_options: WorkDoneProgressOptions::default(),
+                }),
                 rename_provider: Some(OneOf::Left(true)),
                 semantic_tokens_provider: Some(
                     SemanticTokensServerCapabilities::SemanticTokensOptions(
@@ -79,7 +174,7 @@ impl LanguageServer for Backend {
                                 token_modifiers: vec![SemanticTokenModifier::DOCUMENTATION],
                             },
                             full: Some(SemanticTokensFullOptions::Bool(true)),
-                            range: None,
+                            range: Some(true),
                             ..Default::default()
                         },
                     ),
@@ -109,16 +204,56 @@ impl LanguageServer for Backend {
         .await;
     }
 
+    async fn did_close(&self, params: DidCloseTextDocumentParams) {
+        let uri = params.text_document.uri;
+        self.document_map.write().await.remove(uri.as_str());
+
+        // Its operation IDs no longer clash with the remaining documents
+        let mut duplicate_ids = {
+            let mut operation_ids = self.operation_ids.write().await;
+            operation_ids.remove(&uri);
+            handlers::indexed_duplicate_operation_id_diagnostics(&operation_ids)
+        };
+        self.refresh_duplicate_ids(&mut duplicate_ids, uri.as_str())
+            .await;
+
+        self.client.publish_diagnostics(uri, Vec::new(), None).await;
+    }
+
     async fn did_change(&self, params: DidChangeTextDocumentParams) {
-        if let Some(change) = params.content_changes.first() {
-            self.on_change(TextDocumentItem {
-                uri: params.text_document.uri.clone(),
-                text: change.text.clone(),
-                version: params.text_document.version,
-                language_id: "rust".to_string(),
+        if params.content_changes.is_empty() {
+            return;
+        }
+
+        // Changes are ranges relative to the document after the previous change
+        let text = {
+            let mut document_map = self.document_map.write().await;
+            document_map
+                .get_mut(params.text_document.uri.as_str())
+                .map(|text| {
+                    utils::apply_content_changes(text, &params.content_changes);
+                    text.clone()
+                })
+        };
+        // Without the open document, range changes can't be applied to anything
+        let Some(text) = text else {
+            self.log_debug(|| {
+                format!(
+                    "[ROVO] Ignoring change to {}, which isn't open",
+                    params.text_document.uri
+                )
             })
             .await;
-        }
+            return;
+        };
+
+        self.on_change(TextDocumentItem {
+            uri: params.text_document.uri,
+            text,
+            version: params.text_document.version,
+            language_id: "rust".to_string(),
+        })
+        .await;
     }
 
     async fn completion(&self, params: CompletionParams) -> Result<Option<CompletionResponse>> {
@@ -155,6 +290,25 @@ impl LanguageServer for Backend {
         Ok(handlers::text_document_hover(&content, position))
     }
 
+    async fn signature_help(&self, params: SignatureHelpParams) -> Result<Option<SignatureHelp>> {
+        let uri = params
+            .text_document_position_params
+            .text_document
+            .uri
+            .to_string();
+        let position = params.text_document_position_params.position;
+
+        let content = {
+            let document_map = self.document_map.read().await;
+            match document_map.get(&uri) {
+                Some(content) => content.clone(),
+                None => return Ok(None),
+            }
+        };
+
+        Ok(handlers::signature_help(&content, position))
+    }
+
     async fn code_action(&self, params: CodeActionParams) -> Result<Option<CodeActionResponse>> {
         let uri = params.text_document.uri.to_string();
 
@@ -207,120 +361,138 @@ impl LanguageServer for Backend {
             }
         };
 
-        let line_idx = position.line as usize;
-        let lines: Vec<&str> = content.lines().collect();
+        Ok(handlers::goto_definition(
+            &content,
+            position,
+            params.text_document_position_params.text_document.uri,
+        ))
+    }
 
-        if line_idx >= lines.len() {
-            return Ok(None);
-        }
+    async fn goto_type_definition(
+        &self,
+        params: request::GotoTypeDefinitionParams,
+    ) -> Result<Option<request::GotoTypeDefinitionResponse>> {
+        let uri = params
+            .text_document_position_params
+            .text_document
+            .uri
+            .to_string();
 
-        // Only work near #[rovo]
-        if !crate::parser::is_near_rovo_attribute(&content, line_idx) {
-            return Ok(None);
-        }
+        let content = {
+            let document_map = self.document_map.read().await;
+            match document_map.get(&uri) {
+                Some(content) => content.clone(),
+                None => return Ok(None),
+            }
+        };
 
-        // Check for path parameter first (binding or body usage -> doc definition)
-        if let Some(location) = handlers::goto_path_param_definition(
+        Ok(handlers::type_definition(
             &content,
-            position,
-            params
-                .text_document_position_params
-                .text_document
-                .uri
-                .clone(),
-        ) {
-            return Ok(Some(GotoDefinitionResponse::Scalar(location)));
+            params.text_document_position_params.position,
+            params.text_document_position_params.text_document.uri,
+        )
+        .map(request::GotoTypeDefinitionResponse::Scalar))
+    }
+
+    async fn document_symbol(
+        &self,
+        params: DocumentSymbolParams,
+    ) -> Result<Option<DocumentSymbolResponse>> {
+        let uri = params.text_document.uri.to_string();
+
+        let content = {
+            let document_map = self.document_map.read().await;
+            match document_map.get(&uri) {
+                Some(content) => content.clone(),
+                None => return Ok(None),
+            }
+        };
+
+        let symbols = handlers::document_symbol(&content);
+        if symbols.is_empty() {
+            Ok(None)
+        } else {
+            Ok(Some(DocumentSymbolResponse::Nested(symbols)))
         }
+    }
+
+    async fn folding_range(&self, params: FoldingRangeParams) -> Result<Option<Vec<FoldingRange>>> {
+        let uri = params.text_document.uri.to_string();
 
-        let line = lines[line_idx];
-        let char_idx =
-            match crate::utils::utf16_pos_to_byte_index(line, position.character as usize) {
-                Some(idx) => idx,
+        let content = {
+            let document_map = self.document_map.read().await;
+            match document_map.get(&uri) {
+                Some(content) => content.clone(),
                 None => return Ok(None),
-            };
+            }
+        };
 
-        // Check if cursor is on a type
-        if let Some((response_type, _, _)) =
-            crate::type_resolver::get_type_at_position(line, char_idx)
-        {
-            if let Some(type_name) =
-                crate::type_resolver::extract_type_from_response(&response_type)
-            {
-                if let Some(def_line) =
-                    crate::type_resolver::find_type_definition(&content, &type_name)
-                {
-                    // Find the exact position of the type name in the definition line
-                    // Strip comments to avoid matching inside comments
-                    let def_line_text = lines.get(def_line).unwrap_or(&"");
-                    let def_col = lines
-                        .get(def_line)
-                        .and_then(|l| {
-                            // Remove line comments
-                            let code_part = l.split("//").next().unwrap_or(l);
-
-                            // Search for type name with word boundary
-                            // Look for it after struct/enum/type keyword
-                            if let Some(struct_pos) = code_part.find("struct") {
-                                let after_struct = &code_part[struct_pos + 6..];
-                                after_struct.trim_start().find(&type_name).map(|p| {
-                                    struct_pos
-                                        + 6
-                                        + (after_struct.len() - after_struct.trim_start().len())
-                                        + p
-                                })
-                            } else if let Some(enum_pos) = code_part.find("enum") {
-                                let after_enum = &code_part[enum_pos + 4..];
-                                after_enum.trim_start().find(&type_name).map(|p| {
-                                    enum_pos
-                                        + 4
-                                        + (after_enum.len() - after_enum.trim_start().len())
-                                        + p
-                                })
-                            } else if let Some(type_pos) = code_part.find("type") {
-                                let after_type = &code_part[type_pos + 4..];
-                                after_type.trim_start().find(&type_name).map(|p| {
-                                    type_pos
-                                        + 4
-                                        + (after_type.len() - after_type.trim_start().len())
-                                        + p
-                                })
-                            } else {
-                                // Fallback to simple find in code part
-                                code_part.find(&type_name)
-                            }
-                        })
-                        .unwrap_or(0);
-
-                    // Convert byte offsets to UTF-16 columns for LSP positions
-                    let start_char = crate::utils::byte_index_to_utf16_col(def_line_text, def_col);
-                    let end_char = crate::utils::byte_index_to_utf16_col(
-                        def_line_text,
-                        def_col + type_name.len(),
-                    );
-
-                    let location = Location {
-                        uri: params
-                            .text_document_position_params
-                            .text_document
-                            .uri
-                            .clone(),
-                        range: Range {
-                            start: Position {
-                                line: def_line as u32,
-                                character: start_char as u32,
-                            },
-                            end: Position {
-                                line: def_line as u32,
-                                character: end_char as u32,
-                            },
-                        },
-                    };
+        Ok(Some(handlers::get_folding_ranges(&content)))
+    }
+
+    async fn selection_range(
+        &self,
+        params: SelectionRangeParams,
+    ) -> Result<Option<Vec<SelectionRange>>> {
+        let uri = params.text_document.uri.to_string();
+
+        let content = {
+            let document_map = self.document_map.read().await;
+            match document_map.get(&uri) {
+                Some(content) => content.clone(),
+                None => return Ok(None),
+            }
+        };
 
-                    return Ok(Some(GotoDefinitionResponse::Scalar(location)));
-                }
+        Ok(Some(handlers::get_selection_ranges(
+            &content,
+            &params.positions,
+        )))
+    }
+
+    async fn inlay_hint(&self, params: InlayHintParams) -> Result<Option<Vec<InlayHint>>> {
+        let uri = params.text_document.uri.to_string();
+
+        let content = {
+            let document_map = self.document_map.read().await;
+            match document_map.get(&uri) {
+                Some(content) => content.clone(),
+                None => return Ok(None),
             }
-        }
+        };
+
+        Ok(Some(handlers::get_inlay_hints(&content, params.range)))
+    }
 
+    async fn code_lens(&self, params: CodeLensParams) -> Result<Option<Vec<CodeLens>>> {
+        let uri = params.text_document.uri.to_string();
+
+        let content = {
+            let document_map = self.document_map.read().await;
+            match document_map.get(&uri) {
+                Some(content) => content.clone(),
+                None => return Ok(None),
+            }
+        };
+
+        Ok(Some(handlers::get_code_lenses(
+            &content,
+            &params.text_document.uri,
+        )))
+    }
+
+    async fn code_lens_resolve(&self, params: CodeLens) -> Result<CodeLens> {
+        Ok(handlers::resolve_code_lens(params))
+    }
+
+    async fn execute_command(
+        &self,
+        params: ExecuteCommandParams,
+    ) -> Result<Option<serde_json::Value>> {
+        if let Some(show) = handlers::show_location_params(&params) {
+            // Clients without window/showDocument support just ignore the lens click
+            let _ = self.client.show_document(show).await;
+        }
         Ok(None)
     }
 
@@ -412,6 +584,50 @@ impl LanguageServer for Backend {
             }
         };
 
-        Ok(handlers::semantic_tokens_full(&content))
+        self.log_debug(|| {
+            format!(
+                "[ROVO] semantic_tokens_full called, content length: {}",
+                content.len()
+            )
+        })
+        .await;
+
+        let result = handlers::semantic_tokens_full(&content);
+
+        self.log_debug(|| {
+            let count = match &result {
+                Some(SemanticTokensResult::Tokens(tokens)) => tokens.data.len(),
+                _ => 0,
+            };
+            format!("[ROVO] Found {} semantic tokens", count)
+        })
+        .await;
+
+        Ok(result)
+    }
+
+    async fn semantic_tokens_range(
+        &self,
+        params: SemanticTokensRangeParams,
+    ) -> Result<Option<SemanticTokensRangeResult>> {
+        let uri = params.text_document.uri.to_string();
+
+        let content = {
+            let document_map = self.document_map.read().await;
+            match document_map.get(&uri) {
+                Some(content) => content.clone(),
+                None => return Ok(None),
+            }
+        };
+
+        self.log_debug(|| {
+            format!(
+                "[ROVO] semantic_tokens_range called for lines {}-{}",
+                params.range.start.line, params.range.end.line
+            )
+        })
+        .await;
+
+        Ok(handlers::semantic_tokens_range(&content, params.range))
     }
 }
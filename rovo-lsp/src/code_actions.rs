@@ -27,17 +27,18 @@ pub fn get_code_actions(content: &str, range: Range, uri: Url) -> Vec<CodeAction
     // Check if we're inside or near (including above) a function with #[rovo]
     let (is_near_rovo, rovo_line, _fn_line) = find_rovo_function_context(content, start_line);
 
-    // Check if we're in a struct and offer JsonSchema derive
-    if let Some(struct_info) = find_struct_context(content, start_line) {
-        if !struct_info.has_jsonschema {
-            actions.push(create_add_jsonschema_action(
-                content,
-                struct_info.derive_line,
-                struct_info.struct_line,
-                struct_info.has_derive,
-                uri.clone(),
-            ));
-        }
+    // Check if we're on a response whose type lacks JsonSchema, or in a struct,
+    // and offer JsonSchema derive
+    let struct_info = find_response_type_struct(content, start_line)
+        .or_else(|| find_struct_context(content, start_line).filter(|info| !info.has_jsonschema));
+    if let Some(struct_info) = struct_info {
+        actions.push(create_add_jsonschema_action(
+            content,
+            struct_info.derive_line,
+            struct_info.struct_line,
+            struct_info.has_derive,
+            uri.clone(),
+        ));
         // Don't return - might also be in a function
     }
 
@@ -219,12 +220,44 @@ pub fn get_code_actions(content: &str, range: Range, uri: Url) -> Vec<CodeAction
         ));
     }
 
+    // Action 9: Extract the example under the cursor into a const
+    if let Some(action) = create_extract_example_action(
+        content,
+        start_line,
+        &filtered_annotations,
+        doc_start_line,
+        insert_line,
+        uri.clone(),
+    ) {
+        actions.push(action);
+    }
+
+    // Action 10: Move a loose metadata annotation under the cursor into # Metadata
+    if let Some(action) = create_move_to_metadata_action(
+        content,
+        start_line,
+        &filtered_annotations,
+        doc_start_line,
+        insert_line,
+        uri.clone(),
+    ) {
+        actions.push(action);
+    }
+
+    // Action 11: Reorder sections into canonical order
+    if let Some(action) =
+        create_reorder_sections_action(content, doc_start_line, insert_line, uri.clone())
+    {
+        actions.push(action);
+    }
+
     actions
 }
 
 /// Get code actions to fix diagnostics
 ///
-/// Provides quick fixes for issues like invalid status codes.
+/// Provides quick fixes for issues like invalid status codes, missing
+/// blank lines after section headers and annotations missing their `@`.
 ///
 /// # Arguments
 /// * `content` - The document content
@@ -240,15 +273,25 @@ pub fn get_diagnostic_code_actions(
 ) -> Vec<CodeActionOrCommand> {
     let mut actions = Vec::new();
 
-    // Check if this is an invalid status code error
-    if diagnostic.message.contains("Invalid HTTP status") {
+    // Check if this is an invalid or duplicate status code
+    if diagnostic.message.contains("Invalid HTTP status")
+        || diagnostic.message.starts_with("Duplicate status code")
+    {
         // Extract the invalid status code from the diagnostic
         let line = diagnostic.range.start.line as usize;
         let lines: Vec<&str> = content.lines().collect();
 
         if line < lines.len() {
+            // The status under the diagnostic, which a duplicate shouldn't be changed to
+            let current = lines[line].get(
+                diagnostic.range.start.character as usize..diagnostic.range.end.character as usize,
+            );
+
             // Suggest common valid status codes
-            for status in [200, 201, 400, 404, 500] {
+            for status in [200, 201, 400, 404, 500]
+                .into_iter()
+                .filter(|status| current != Some(status.to_string().as_str()))
+            {
                 actions.push(create_fix_status_code_action(
                     format!("Change to {}", status).as_str(),
                     status,
@@ -259,9 +302,98 @@ pub fn get_diagnostic_code_actions(
         }
     }
 
+    if diagnostic
+        .message
+        .starts_with("Missing blank line after section header")
+    {
+        let line = diagnostic.range.start.line as usize;
+        if let Some(header) = content.lines().nth(line) {
+            actions.push(create_insert_blank_doc_line_action(
+                header,
+                line + 1,
+                diagnostic,
+                uri.clone(),
+            ));
+        }
+    }
+
+    if diagnostic
+        .message
+        .contains("looks like an annotation missing its '@'")
+    {
+        actions.push(create_insert_at_sign_action(diagnostic, uri));
+    }
+
     actions
 }
 
+/// Quick fix prepending `@` to an annotation written without it
+fn create_insert_at_sign_action(diagnostic: &Diagnostic, uri: Url) -> CodeActionOrCommand {
+    let position = diagnostic.range.start;
+
+    let mut changes = std::collections::HashMap::new();
+    changes.insert(
+        uri,
+        vec![TextEdit {
+            range: Range {
+                start: position,
+                end: position,
+            },
+            new_text: "@".to_string(),
+        }],
+    );
+
+    CodeActionOrCommand::CodeAction(CodeAction {
+        title: "Add missing '@'".to_string(),
+        kind: Some(CodeActionKind::QUICKFIX),
+        diagnostics: Some(vec![diagnostic.clone()]),
+        edit: Some(WorkspaceEdit {
+            changes: Some(changes),
+            ..Default::default()
+        }),
+        is_preferred: Some(true),
+        ..Default::default()
+    })
+}
+
+/// Quick fix inserting a blank `///` line (indented like `header`) before `insert_line`
+fn create_insert_blank_doc_line_action(
+    header: &str,
+    insert_line: usize,
+    diagnostic: &Diagnostic,
+    uri: Url,
+) -> CodeActionOrCommand {
+    let indent = &header[..header.len() - header.trim_start().len()];
+    let position = Position {
+        line: insert_line as u32,
+        character: 0,
+    };
+
+    let mut changes = std::collections::HashMap::new();
+    changes.insert(
+        uri,
+        vec![TextEdit {
+            range: Range {
+                start: position,
+                end: position,
+            },
+            new_text: format!("{}///\n", indent),
+        }],
+    );
+
+    CodeActionOrCommand::CodeAction(CodeAction {
+        title: "Insert blank line after header".to_string(),
+        kind: Some(CodeActionKind::QUICKFIX),
+        diagnostics: Some(vec![diagnostic.clone()]),
+        edit: Some(WorkspaceEdit {
+            changes: Some(changes),
+            ..Default::default()
+        }),
+        is_preferred: Some(true),
+        ..Default::default()
+    })
+}
+
 fn create_fix_status_code_action(
     title: &str,
     new_status: u16,
@@ -586,6 +718,17 @@ fn find_struct_context(content: &str, current_line: usize) -> Option<StructConte
     })
 }
 
+/// Find the definition of the response type on `line` if it lacks a JsonSchema derive
+fn find_response_type_struct(content: &str, line: usize) -> Option<StructContext> {
+    let response_type = parse_annotations(content)
+        .into_iter()
+        .find(|ann| ann.line == line && ann.kind == AnnotationKind::Response)?
+        .response_type?;
+    let type_name = crate::type_resolver::extract_type_from_response(&response_type)?;
+    let definition = crate::type_resolver::find_missing_derive(content, &type_name, "JsonSchema")?;
+    find_struct_context(content, definition)
+}
+
 /// Create action to add JsonSchema to a struct
 fn create_add_jsonschema_action(
     content: &str,
@@ -824,6 +967,9 @@ fn find_section(
     (true, Some(section_start), Some(last_content_line))
 }
 
+/// Canonical order of the documentation sections
+const SECTION_ORDER: [&str; 4] = ["Path Parameters", "Responses", "Examples", "Metadata"];
+
 /// Find the correct insertion point for a new section based on desired order:
 /// # Responses -> # Examples -> # Metadata
 /// Note: Respects @rovo-ignore - insertions will happen before @rovo-ignore if present
@@ -838,8 +984,7 @@ fn find_section_insertion_point(
     // Respect @rovo-ignore boundary
     let effective_end = find_effective_doc_end(content, doc_start, doc_end);
 
-    // Define section order
-    let section_order = ["Path Parameters", "Responses", "Examples", "Metadata"];
+    let section_order = SECTION_ORDER;
     let Some(target_index) = section_order.iter().position(|&s| s == section_name) else {
         return effective_end;
     };
@@ -889,6 +1034,98 @@ fn find_section_insertion_point(
     effective_end
 }
 
+/// Create an action that rewrites the doc sections into canonical order
+///
+/// Each section keeps its content, including any non-standard sections that follow
+/// it. Returns `None` if the sections are already in order.
+fn create_reorder_sections_action(
+    content: &str,
+    doc_start: usize,
+    doc_end: usize,
+    uri: Url,
+) -> Option<CodeActionOrCommand> {
+    let lines: Vec<&str> = content.lines().collect();
+    let effective_end = find_effective_doc_end(content, doc_start, doc_end);
+
+    // (order_index, header_line, last_content_line), in document order
+    let mut sections: Vec<(usize, usize, usize)> = SECTION_ORDER
+        .iter()
+        .enumerate()
+        .filter_map(|(order_idx, name)| {
+            match find_section(content, name, doc_start, effective_end) {
+                (true, Some(start), Some(last)) => Some((order_idx, start, last)),
+                _ => None,
+            }
+        })
+        .collect();
+    sections.sort_by_key(|&(_, start, _)| start);
+
+    if sections.windows(2).all(|pair| pair[0].0 < pair[1].0) {
+        return None;
+    }
+
+    let region_start = sections.first()?.1;
+    let region_end = sections.last()?.2;
+
+    // Each block runs up to the next section header, minus trailing blank lines
+    let mut blocks: Vec<(usize, Vec<&str>)> = sections
+        .iter()
+        .enumerate()
+        .map(|(idx, &(order_idx, start, last))| {
+            let end = sections.get(idx + 1).map_or(last + 1, |next| next.1);
+            let mut block: Vec<&str> = lines[start..end].to_vec();
+            while block.last().is_some_and(|line| line.trim() == "///") {
+                block.pop();
+            }
+            (order_idx, block)
+        })
+        .collect();
+    blocks.sort_by_key(|(order_idx, _)| *order_idx);
+
+    let indent = {
+        let header = lines[region_start];
+        &header[..header.len() - header.trim_start().len()]
+    };
+    let new_text = blocks
+        .iter()
+        .map(|(_, block)| {
+            block
+                .iter()
+                .map(|line| format!("{}\n", line))
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join(&format!("{}///\n", indent));
+
+    let mut changes = std::collections::HashMap::new();
+    changes.insert(
+        uri,
+        vec![TextEdit {
+            range: Range {
+                start: Position {
+                    line: region_start as u32,
+                    character: 0,
+                },
+                end: Position {
+                    line: region_end as u32 + 1,
+                    character: 0,
+                },
+            },
+            new_text,
+        }],
+    );
+
+    Some(CodeActionOrCommand::CodeAction(CodeAction {
+        title: "Reorder doc sections".to_string(),
+        kind: Some(CodeActionKind::REFACTOR_REWRITE),
+        edit: Some(WorkspaceEdit {
+            changes: Some(changes),
+            ..Default::default()
+        }),
+        ..Default::default()
+    }))
+}
+
 /// Create a smart action that adds entries to Responses or Examples sections
 /// If section exists, appends to the end. If not, creates the section.
 fn create_smart_section_action(
@@ -1065,6 +1302,57 @@ fn find_metadata_insertion_point(
     metadata_end
 }
 
+/// Build the edit that inserts `annotation` into the Metadata section
+/// If the section exists, the annotation goes to its ordered position; if not,
+/// the section is created in canonical order.
+fn metadata_insertion_edit(
+    content: &str,
+    annotation: &str,
+    has_metadata: bool,
+    doc_start: usize,
+    doc_end: usize,
+) -> TextEdit {
+    let (insert_line, new_text) = match find_section(content, "Metadata", doc_start, doc_end) {
+        (true, Some(metadata_start), Some(metadata_end)) if has_metadata => {
+            // Metadata section exists - find where to insert based on annotation type
+            let insert_line =
+                find_metadata_insertion_point(content, annotation, metadata_start, metadata_end);
+            (insert_line, format!("/// {}\n", annotation))
+        }
+        _ => {
+            // Metadata section doesn't exist - create it
+            // Find the correct insertion point to maintain order
+            let insert_line = find_section_insertion_point(content, "Metadata", doc_start, doc_end);
+
+            let lines: Vec<&str> = content.lines().collect();
+            let (needs_blank_line, needs_suffix_blank) =
+                check_blank_line_requirements(&lines, insert_line);
+
+            let new_text = match (needs_blank_line, needs_suffix_blank) {
+                (true, true) => format!("///\n/// # Metadata\n///\n/// {}\n///\n", annotation),
+                (true, false) => format!("///\n/// # Metadata\n///\n/// {}\n", annotation),
+                (false, true) => format!("/// # Metadata\n///\n/// {}\n///\n", annotation),
+                (false, false) => format!("/// # Metadata\n///\n/// {}\n", annotation),
+            };
+            (insert_line, new_text)
+        }
+    };
+
+    TextEdit {
+        range: Range {
+            start: Position {
+                line: insert_line as u32,
+                character: 0,
+            },
+            end: Position {
+                line: insert_line as u32,
+                character: 0,
+            },
+        },
+        new_text,
+    }
+}
+
 /// Create a smart action for metadata annotations (@tag, @security, @id, @hidden)
 /// If Metadata section exists, appends to it. If not, creates the section.
 fn create_smart_metadata_action(
@@ -1077,70 +1365,16 @@ fn create_smart_metadata_action(
     uri: Url,
 ) -> CodeActionOrCommand {
     let mut changes = std::collections::HashMap::new();
-
-    if has_metadata {
-        // Metadata section exists - find where to insert based on annotation type
-        let (_, Some(metadata_start), Some(metadata_end)) =
-            find_section(content, "Metadata", doc_start, doc_end)
-        else {
-            // Fallback - shouldn't happen if has_metadata is true
-            return create_smart_section_action(
-                content, title, "Metadata", annotation, doc_start, doc_end, uri,
-            );
-        };
-
-        let insert_line =
-            find_metadata_insertion_point(content, annotation, metadata_start, metadata_end);
-
-        changes.insert(
-            uri.clone(),
-            vec![TextEdit {
-                range: Range {
-                    start: Position {
-                        line: insert_line as u32,
-                        character: 0,
-                    },
-                    end: Position {
-                        line: insert_line as u32,
-                        character: 0,
-                    },
-                },
-                new_text: format!("/// {}\n", annotation),
-            }],
-        );
-    } else {
-        // Metadata section doesn't exist - create it
-        // Find the correct insertion point to maintain order
-        let insert_line = find_section_insertion_point(content, "Metadata", doc_start, doc_end);
-
-        let lines: Vec<&str> = content.lines().collect();
-        let (needs_blank_line, needs_suffix_blank) =
-            check_blank_line_requirements(&lines, insert_line);
-
-        let new_text = match (needs_blank_line, needs_suffix_blank) {
-            (true, true) => format!("///\n/// # Metadata\n///\n/// {}\n///\n", annotation),
-            (true, false) => format!("///\n/// # Metadata\n///\n/// {}\n", annotation),
-            (false, true) => format!("/// # Metadata\n///\n/// {}\n///\n", annotation),
-            (false, false) => format!("/// # Metadata\n///\n/// {}\n", annotation),
-        };
-
-        changes.insert(
-            uri.clone(),
-            vec![TextEdit {
-                range: Range {
-                    start: Position {
-                        line: insert_line as u32,
-                        character: 0,
-                    },
-                    end: Position {
-                        line: insert_line as u32,
-                        character: 0,
-                    },
-                },
-                new_text,
-            }],
-        );
-    }
+    changes.insert(
+        uri,
+        vec![metadata_insertion_edit(
+            content,
+            annotation,
+            has_metadata,
+            doc_start,
+            doc_end,
+        )],
+    );
 
     CodeActionOrCommand::CodeAction(CodeAction {
         title: title.to_string(),
@@ -1158,6 +1392,78 @@ fn create_smart_metadata_action(
     })
 }
 
+/// Create an action that moves a loose metadata annotation on `current_line`
+/// (one written outside `# Metadata`) into the Metadata section
+fn create_move_to_metadata_action(
+    content: &str,
+    current_line: usize,
+    annotations: &[&crate::parser::Annotation],
+    doc_start: usize,
+    doc_end: usize,
+    uri: Url,
+) -> Option<CodeActionOrCommand> {
+    let annotation = annotations.iter().find(|ann| {
+        ann.line == current_line
+            && matches!(
+                ann.kind,
+                AnnotationKind::Tag
+                    | AnnotationKind::Security
+                    | AnnotationKind::SecurityAnd
+                    | AnnotationKind::Id
+                    | AnnotationKind::Hidden
+            )
+    })?;
+
+    let (has_metadata, metadata_start, metadata_end) =
+        find_section(content, "Metadata", doc_start, doc_end);
+    if let (Some(start), Some(end)) = (metadata_start, metadata_end) {
+        if (start..=end).contains(&annotation.line) {
+            return None;
+        }
+    }
+
+    let text = content
+        .lines()
+        .nth(annotation.line)?
+        .trim()
+        .trim_start_matches("///")
+        .trim();
+    let keyword = text.split_whitespace().next()?;
+
+    let removal = TextEdit {
+        range: Range {
+            start: Position {
+                line: annotation.line as u32,
+                character: 0,
+            },
+            end: Position {
+                line: annotation.line as u32 + 1,
+                character: 0,
+            },
+        },
+        new_text: String::new(),
+    };
+
+    let mut changes = std::collections::HashMap::new();
+    changes.insert(
+        uri,
+        vec![
+            metadata_insertion_edit(content, text, has_metadata, doc_start, doc_end),
+            removal,
+        ],
+    );
+
+    Some(CodeActionOrCommand::CodeAction(CodeAction {
+        title: format!("Move {} into # Metadata", keyword),
+        kind: Some(CodeActionKind::REFACTOR_REWRITE),
+        edit: Some(WorkspaceEdit {
+            changes: Some(changes),
+            ..Default::default()
+        }),
+        ..Default::default()
+    }))
+}
+
 /// Create action to add common REST responses
 fn create_smart_rest_responses_action(
     content: &str,
@@ -1258,6 +1564,147 @@ fn create_smart_rest_responses_action(
     })
 }
 
+/// Create action to move an inline example into a `const` above the function
+///
+/// The const is typed with the body type of the matching response (e.g. `User`
+/// for `Json<User>`), and the example entry is replaced with a reference to it.
+fn create_extract_example_action(
+    content: &str,
+    current_line: usize,
+    annotations: &[&crate::parser::Annotation],
+    doc_start: usize,
+    doc_end: usize,
+    uri: Url,
+) -> Option<CodeActionOrCommand> {
+    let lines: Vec<&str> = content.lines().collect();
+
+    // Collect the doc lines of this block the same way the parser does
+    let doc_lines: Vec<(usize, &str)> = (doc_start..doc_end)
+        .filter_map(|i| {
+            let line = lines.get(i)?.trim();
+            line.starts_with("///").then_some((i, line))
+        })
+        .collect();
+
+    // Find the example entry covering the current line
+    let (example, last_line) = annotations
+        .iter()
+        .filter(|ann| ann.kind == AnnotationKind::Example && ann.line <= current_line)
+        .find_map(|ann| {
+            let pos = doc_lines.iter().position(|(i, _)| *i == ann.line)?;
+            let (_, consumed) = crate::parser::parse_multiline_example(&doc_lines[pos..])?;
+            let last_line = doc_lines[pos + consumed - 1].0;
+            (current_line <= last_line).then_some((*ann, last_line))
+        })?;
+
+    let status = example.status?;
+    let value = example.example_value.as_deref()?.trim();
+
+    // Nothing to extract if the example already is a plain path like EXAMPLE_200
+    if value.is_empty()
+        || value
+            .chars()
+            .all(|c| c.is_alphanumeric() || c == '_' || c == ':')
+    {
+        return None;
+    }
+
+    // Resolve the body type from the response with the same status code
+    let response_type = annotations
+        .iter()
+        .find(|ann| ann.kind == AnnotationKind::Response && ann.status == Some(status))
+        .and_then(|ann| ann.response_type.as_deref())?;
+    let body_type = response_type
+        .strip_prefix("Json<")
+        .and_then(|inner| inner.strip_suffix('>'))
+        .unwrap_or(response_type)
+        .trim();
+
+    // Prefer EXAMPLE_<CODE>, falling back to a function-specific name on conflicts
+    let mut const_name = format!("EXAMPLE_{}", status);
+    if content.contains(&format!("const {}:", const_name)) {
+        let fn_name = lines
+            .iter()
+            .skip(doc_end)
+            .find_map(|line| {
+                let after_fn = line.split("fn ").nth(1)?;
+                let name: String = after_fn
+                    .chars()
+                    .take_while(|c| c.is_alphanumeric() || *c == '_')
+                    .collect();
+                (!name.is_empty()).then_some(name)
+            })
+            .unwrap_or_default();
+        const_name = format!("{}_EXAMPLE_{}", fn_name.to_uppercase(), status);
+    }
+
+    // Re-indent multi-line examples, which the parser stores trimmed
+    let mut depth = 0i32;
+    let const_value = value
+        .lines()
+        .map(|line| {
+            let closes = line.starts_with(['}', ']', ')']);
+            let indent = "    ".repeat((depth - i32::from(closes)).max(0) as usize);
+            let (braces, brackets, parens) = crate::parser::count_delimiters(line);
+            depth += braces + brackets + parens;
+            format!("{}{}", indent, line)
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    let const_text = format!("const {}: {} = {};\n\n", const_name, body_type, const_value);
+
+    let indent: String = lines
+        .get(example.line)?
+        .chars()
+        .take_while(|c| c.is_whitespace())
+        .collect();
+    let replacement = format!("{}/// {}: {}", indent, status, const_name);
+    let last_line_len = lines.get(last_line).map_or(0, |l| l.encode_utf16().count());
+
+    let mut changes = std::collections::HashMap::new();
+    changes.insert(
+        uri,
+        vec![
+            TextEdit {
+                range: Range {
+                    start: Position {
+                        line: doc_start as u32,
+                        character: 0,
+                    },
+                    end: Position {
+                        line: doc_start as u32,
+                        character: 0,
+                    },
+                },
+                new_text: const_text,
+            },
+            TextEdit {
+                range: Range {
+                    start: Position {
+                        line: example.line as u32,
+                        character: 0,
+                    },
+                    end: Position {
+                        line: last_line as u32,
+                        character: last_line_len as u32,
+                    },
+                },
+                new_text: replacement,
+            },
+        ],
+    );
+
+    Some(CodeActionOrCommand::CodeAction(CodeAction {
+        title: "Extract example to const".to_string(),
+        kind: Some(CodeActionKind::REFACTOR_EXTRACT),
+        edit: Some(WorkspaceEdit {
+            changes: Some(changes),
+            ..Default::default()
+        }),
+        ..Default::default()
+    }))
+}
+
 /// Get path parameters from function signature that are not yet documented
 fn get_undocumented_path_params(content: &str, rovo_line: usize) -> Vec<String> {
     let lines: Vec<&str> = content.lines().collect();
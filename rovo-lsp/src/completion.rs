@@ -32,6 +32,8 @@ pub enum CompletionItemKind {
     Keyword,
     /// A code snippet
     Snippet,
+    /// A type defined in the document
+    Class,
 }
 
 /// Get completion suggestions at the given position
@@ -83,6 +85,83 @@ pub fn get_completions(content: &str, position: Position) -> Vec<CompletionItem>
         }
     }
 
+    if after_doc.starts_with("@tag ") {
+        let parts: Vec<&str> = after_doc.split_whitespace().collect();
+        if parts.len() == 1 {
+            return get_tag_completions(content, position.line, "");
+        } else if parts.len() == 2 && !prefix.ends_with(char::is_whitespace) {
+            return get_tag_completions(content, position.line, parts[1]);
+        }
+    }
+
+    if after_doc.starts_with("@security-and ") {
+        // Complete the scheme currently being typed; every word after the keyword is one
+        if prefix.ends_with(char::is_whitespace) {
+            return get_security_scheme_completions("");
+        }
+        let current = after_doc.split_whitespace().last().unwrap_or_default();
+        return get_security_scheme_completions(current);
+    }
+
+    if after_doc.starts_with("@content-type ") {
+        // @content-type STATUS TYPE - complete the type once the status is written
+        let parts: Vec<&str> = after_doc.split_whitespace().collect();
+        if parts.len() == 2 && prefix.ends_with(char::is_whitespace) {
+            return get_content_type_completions("");
+        } else if parts.len() == 3 && !prefix.ends_with(char::is_whitespace) {
+            return get_content_type_completions(parts[2]);
+        }
+    }
+
+    if after_doc.starts_with("@produces ") {
+        // @produces TYPE
+        let parts: Vec<&str> = after_doc.split_whitespace().collect();
+        if parts.len() == 1 {
+            return get_content_type_completions("");
+        } else if parts.len() == 2 && !prefix.ends_with(char::is_whitespace) {
+            return get_content_type_completions(parts[1]);
+        }
+    }
+
+    if after_doc.starts_with("@ratelimit ") {
+        // @ratelimit COUNT per-WINDOW - complete the window once the count is written
+        let parts: Vec<&str> = after_doc.split_whitespace().collect();
+        if parts.len() == 2 && prefix.ends_with(char::is_whitespace) {
+            return get_ratelimit_window_completions("");
+        } else if parts.len() == 3 && !prefix.ends_with(char::is_whitespace) {
+            return get_ratelimit_window_completions(parts[2]);
+        }
+    }
+
+    if after_doc.starts_with("@link ") {
+        // @link STATUS OPERATION_ID ... - complete the operation ID once the status is written
+        let parts: Vec<&str> = after_doc.split_whitespace().collect();
+        if parts.len() == 2 && prefix.ends_with(char::is_whitespace) {
+            return get_operation_id_completions(content, "");
+        } else if parts.len() == 3 && !prefix.ends_with(char::is_whitespace) {
+            return get_operation_id_completions(content, parts[2]);
+        }
+    }
+
+    if after_doc.starts_with("@errors ") {
+        let parts: Vec<&str> = after_doc.split_whitespace().collect();
+        if parts.len() == 1 {
+            return get_error_set_completions("");
+        } else if parts.len() == 2 {
+            return get_error_set_completions(parts[1]);
+        }
+    }
+
+    // After a response or example status, complete the types defined in the document
+    if matches!(
+        context,
+        SectionContext::ResponsesSection | SectionContext::ExamplesSection
+    ) {
+        if let Some((typed, in_generic)) = response_type_prefix(after_doc) {
+            return get_type_completions(content, typed, in_generic);
+        }
+    }
+
     // Context-aware completions based on current section
     match context {
         SectionContext::PathParametersSection => {
@@ -134,7 +213,7 @@ pub fn get_completions(content: &str, position: Position) -> Vec<CompletionItem>
 
 /// Context about which section we're currently in
 #[derive(Debug, Clone, PartialEq)]
-enum SectionContext {
+pub(crate) enum SectionContext {
     ResponsesSection,
     ExamplesSection,
     MetadataSection,
@@ -143,7 +222,7 @@ enum SectionContext {
 }
 
 /// Detect which section (if any) the current line is in
-fn detect_section_context(lines: &[&str], current_line: usize) -> SectionContext {
+pub(crate) fn detect_section_context(lines: &[&str], current_line: usize) -> SectionContext {
     // Look backwards from current line to find the most recent section header
     for i in (0..=current_line).rev() {
         let line = lines[i].trim();
@@ -236,6 +315,48 @@ fn get_response_line_completions() -> Vec<CompletionItem> {
     ]
 }
 
+/// The partial type name being typed after a response or example entry's status
+///
+/// Returns the typed prefix and whether it sits inside a generic like `Json<`,
+/// or `None` once the cursor has moved past the type into the description.
+fn response_type_prefix(after_doc: &str) -> Option<(&str, bool)> {
+    let colon = crate::parser::response_status_end(after_doc)?;
+    let rest = after_doc[colon + 1..].trim_start();
+    let (typed, in_generic) = match rest.rfind('<') {
+        Some(pos) => (&rest[pos + 1..], true),
+        None => (rest, false),
+    };
+    typed
+        .chars()
+        .all(|c| c.is_alphanumeric() || c == '_')
+        .then_some((typed, in_generic))
+}
+
+/// Get completions for the structs, enums and type aliases defined in the document
+///
+/// Inside a generic like `Json<` the closing `>` is inserted along with the name.
+fn get_type_completions(content: &str, typed: &str, in_generic: bool) -> Vec<CompletionItem> {
+    let mut types = crate::type_resolver::find_type_definitions(content);
+    types.retain(|(name, _)| name.starts_with(typed));
+    types.sort();
+    types.dedup_by(|a, b| a.0 == b.0);
+
+    types
+        .into_iter()
+        .map(|(name, line)| CompletionItem {
+            insert_text: Some(if in_generic {
+                format!("{name}>")
+            } else {
+                name.clone()
+            }),
+            label: name,
+            kind: CompletionItemKind::Class,
+            detail: Some(format!("Defined on line {}", line + 1)),
+            documentation: None,
+        })
+        .collect()
+}
+
 /// Get completions for example lines in # Examples section
 fn get_example_line_completions() -> Vec<CompletionItem> {
     vec![CompletionItem {
@@ -433,8 +554,30 @@ fn get_metadata_annotation_completions(typed: &str) -> Vec<CompletionItem> {
     let annotations = [
         ("tag", "@tag ${1:tag_name}"),
         ("security", "@security ${1:bearer}"),
+        ("security-and", "@security-and ${1:bearer} ${2:apiKey}"),
         ("id", "@id ${1:operation_id}"),
         ("hidden", "@hidden"),
+        ("idempotent", "@idempotent"),
+        ("concurrency", "@concurrency"),
+        ("sunset", "@sunset ${1:2025-12-31}"),
+        ("ratelimit", "@ratelimit ${1:100} per-${2:minute}"),
+        ("summary", "@summary ${1:Short summary}"),
+        ("body", "@body ${1:Json<T>} - ${2:description}"),
+        ("errors", "@errors ${1:standard}"),
+        (
+            "external-docs",
+            "@external-docs ${1:https://docs.example.com} ${2:description}",
+        ),
+        (
+            "header",
+            "@header ${1:201} ${2:Location} ${3:string} ${4:description}",
+        ),
+        ("content-type", "@content-type ${1:200} ${2:text/csv}"),
+        ("produces", "@produces ${1:text/csv}"),
+        (
+            "link",
+            "@link ${1:201} ${2:operationId} ${3:id}=${4:\\$response.body#/id}",
+        ),
     ];
 
     for (label, snippet) in annotations {
@@ -495,6 +638,122 @@ fn get_security_scheme_completions(filter: &str) -> Vec<CompletionItem> {
         .collect()
 }
 
+/// Get completions for the error response sets `@errors` accepts
+fn get_error_set_completions(filter: &str) -> Vec<CompletionItem> {
+    let sets = [(
+        "standard",
+        "400, 401, 403 and 500 error responses",
+        "**Standard error responses**\n\nAdds `400 Bad request`, `401 Unauthorized`, `403 Forbidden` and `500 Internal server error` responses.\n\nStatus codes documented in `# Responses` are kept as written. Change the set with `Router::with_standard_errors`.",
+    )];
+
+    sets.iter()
+        .filter(|(set, _, _)| filter.is_empty() || set.starts_with(filter))
+        .map(|(set, desc, docs)| CompletionItem {
+            label: set.to_string(),
+            kind: CompletionItemKind::Keyword,
+            detail: Some(desc.to_string()),
+            documentation: Some(docs.to_string()),
+            insert_text: Some(set.to_string()),
+        })
+        .collect()
+}
+
+/// Get completions for the `per-<window>` part of `@ratelimit`
+fn get_ratelimit_window_completions(filter: &str) -> Vec<CompletionItem> {
+    let windows = [
+        ("per-second", "Requests allowed each second"),
+        ("per-minute", "Requests allowed each minute"),
+        ("per-hour", "Requests allowed each hour"),
+    ];
+
+    windows
+        .iter()
+        .filter(|(window, _)| filter.is_empty() || window.starts_with(filter))
+        .map(|(window, desc)| CompletionItem {
+            label: window.to_string(),
+            kind: CompletionItemKind::Keyword,
+            detail: Some(desc.to_string()),
+            documentation: None,
+            insert_text: Some(window.to_string()),
+        })
+        .collect()
+}
+
+fn get_content_type_completions(filter: &str) -> Vec<CompletionItem> {
+    let content_types = [
+        ("application/json", "JSON document"),
+        ("application/xml", "XML document"),
+        ("application/octet-stream", "Arbitrary binary data"),
+        ("application/pdf", "PDF document"),
+        ("application/x-www-form-urlencoded", "URL-encoded form data"),
+        (
+            "multipart/form-data",
+            "Multipart form data, e.g. file uploads",
+        ),
+        ("text/plain", "Plain text"),
+        ("text/csv", "Comma-separated values"),
+        ("text/html", "HTML document"),
+        ("text/event-stream", "Server-sent events"),
+        ("image/png", "PNG image"),
+        ("image/jpeg", "JPEG image"),
+    ];
+
+    content_types
+        .iter()
+        .filter(|(content_type, _)| filter.is_empty() || content_type.starts_with(filter))
+        .map(|(content_type, desc)| CompletionItem {
+            label: content_type.to_string(),
+            kind: CompletionItemKind::Keyword,
+            detail: Some(desc.to_string()),
+            documentation: None,
+            insert_text: Some(content_type.to_string()),
+        })
+        .collect()
+}
+
+/// Get completions for the operation IDs declared in the document, for `@link`
+/// Get completions for the tags already used in the document, except on the current line
+fn get_tag_completions(content: &str, current_line: usize, filter: &str) -> Vec<CompletionItem> {
+    let mut tags: Vec<String> = crate::parser::parse_annotations(content)
+        .into_iter()
+        .filter(|ann| ann.kind == crate::parser::AnnotationKind::Tag && ann.line != current_line)
+        .filter_map(|ann| ann.tag_name)
+        .filter(|tag| tag.starts_with(filter))
+        .collect();
+    tags.sort();
+    tags.dedup();
+
+    tags.into_iter()
+        .map(|tag| CompletionItem {
+            label: tag.clone(),
+            kind: CompletionItemKind::Keyword,
+            detail: Some("Tag used in this document".to_string()),
+            documentation: None,
+            insert_text: Some(tag),
+        })
+        .collect()
+}
+
+fn get_operation_id_completions(content: &str, filter: &str) -> Vec<CompletionItem> {
+    let mut ids: Vec<String> = crate::handlers::collect_operation_ids(content)
+        .into_iter()
+        .map(|site| site.id)
+        .filter(|id| id.starts_with(filter))
+        .collect();
+    ids.sort();
+    ids.dedup();
+
+    ids.into_iter()
+        .map(|id| CompletionItem {
+            label: id.clone(),
+            kind: CompletionItemKind::Keyword,
+            detail: Some("Operation ID".to_string()),
+            documentation: None,
+            insert_text: Some(id),
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -507,7 +766,7 @@ mod tests {
             character: 5,
         };
         let completions = get_completions(content, position);
-        assert_eq!(completions.len(), 4); // Only metadata annotations
+        assert_eq!(completions.len(), 17); // Only metadata annotations
         assert!(completions.iter().any(|c| c.label == "@tag"));
         assert!(completions.iter().any(|c| c.label == "@security"));
         assert!(completions.iter().any(|c| c.label == "@id"));
@@ -516,14 +775,17 @@ mod tests {
 
     #[test]
     fn test_filters_by_prefix() {
-        let content = "/// @s"; // 's' for security
+        let content = "/// @s"; // 's' for security and sunset
         let position = Position {
             line: 0,
             character: 6,
         };
         let completions = get_completions(content, position);
-        assert_eq!(completions.len(), 1);
+        assert_eq!(completions.len(), 4);
         assert_eq!(completions[0].label, "@security");
+        assert_eq!(completions[1].label, "@security-and");
+        assert_eq!(completions[2].label, "@sunset");
+        assert_eq!(completions[3].label, "@summary");
     }
 
     #[test]
@@ -705,8 +967,8 @@ mod tests {
             character: 9,
         };
         let completions = get_completions(content, position);
-        // Should work with indented comments - 4 metadata annotations
-        assert_eq!(completions.len(), 4);
+        // Should work with indented comments - 10 metadata annotations
+        assert_eq!(completions.len(), 17);
     }
 
     #[test]
@@ -743,8 +1005,8 @@ mod tests {
             character: 5,
         };
         let completions = get_completions(content, position);
-        // Should show all 4 metadata annotations
-        assert_eq!(completions.len(), 4);
+        // Should show all 10 metadata annotations
+        assert_eq!(completions.len(), 17);
     }
 
     #[test]
@@ -755,9 +1017,9 @@ mod tests {
             character: 6,
         };
         let completions = get_completions(content, position);
-        // Should only show @security
-        assert_eq!(completions.len(), 1);
-        assert_eq!(completions[0].label, "@security");
+        // Should only show @security, @security-and, @sunset and @summary
+        assert_eq!(completions.len(), 4);
+        assert!(completions.iter().all(|c| c.label.starts_with("@s")));
     }
 
     #[test]
@@ -798,8 +1060,8 @@ mod tests {
             character: 5,
         };
         let completions = get_completions(content, position);
-        // Should work on second line - 4 metadata annotations
-        assert_eq!(completions.len(), 4);
+        // Should work on second line - 10 metadata annotations
+        assert_eq!(completions.len(), 17);
     }
 
     #[test]
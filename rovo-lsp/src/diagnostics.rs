@@ -1,4 +1,4 @@
-use crate::parser::AnnotationKind;
+use crate::parser::{Annotation, AnnotationKind};
 
 /// Severity level for diagnostic messages
 #[derive(Debug, Clone, PartialEq)]
@@ -7,6 +7,8 @@ pub enum DiagnosticSeverity {
     Error,
     /// A warning that should be addressed
     Warning,
+    /// A low-priority suggestion for improvement
+    Hint,
 }
 
 /// A diagnostic message indicating an issue with annotations
@@ -62,6 +64,162 @@ fn parse_example_error(code: &str) -> String {
     }
 }
 
+/// Response type patterns that schemars can't generate a schema for
+///
+/// Deliberately conservative: only constructs that can never implement
+/// `JsonSchema` are listed, so the check has no false positives on real types.
+const UNSCHEMATIZABLE_PATTERNS: &[(&str, &str)] = &[
+    ("dyn ", "trait objects"),
+    ("impl ", "`impl Trait` types"),
+    ("fn(", "function pointers"),
+    ("Fn(", "closures"),
+    ("FnMut(", "closures"),
+    ("FnOnce(", "closures"),
+    ("*const ", "raw pointers"),
+    ("*mut ", "raw pointers"),
+];
+
+/// Find a known-problematic pattern in a response type
+///
+/// Returns the byte offset of the match within the type and a description of
+/// the kind of type that was found.
+fn find_unschematizable_pattern(response_type: &str) -> Option<(usize, &'static str)> {
+    UNSCHEMATIZABLE_PATTERNS
+        .iter()
+        .filter_map(|(pattern, kind)| {
+            response_type
+                .match_indices(pattern)
+                // Require a token boundary so e.g. `MyFn(` or `Simple ` don't match
+                .find(|(pos, _)| {
+                    !response_type[..*pos]
+                        .chars()
+                        .next_back()
+                        .is_some_and(|c| c.is_alphanumeric() || c == '_')
+                })
+                .map(|(pos, _)| (pos, *kind))
+        })
+        .min_by_key(|(pos, _)| *pos)
+}
+
+/// Warn when a response type defined in the document doesn't derive `JsonSchema`
+///
+/// Primitives and `()` are skipped, as are types defined elsewhere.
+fn check_response_type_derives_jsonschema(
+    content: &str,
+    ann: &crate::parser::Annotation,
+    lines: &[&str],
+) -> Option<Diagnostic> {
+    let response_type = ann.response_type.as_deref()?;
+    let inner = crate::type_resolver::extract_type_from_response(response_type)?;
+    let type_name = crate::type_resolver::local_type_name(&inner);
+    if type_name == "()" || type_name == "String" || type_name.starts_with(char::is_lowercase) {
+        return None;
+    }
+
+    let definition = crate::type_resolver::find_missing_derive(content, type_name, "JsonSchema")?;
+
+    // Highlight the type name within the response type
+    let (char_start, char_end) = lines
+        .get(ann.line)
+        .and_then(|line| {
+            let type_start = line.find(response_type)?;
+            let offset = response_type.find(type_name)?;
+            Some(type_start + offset)
+        })
+        .map_or((None, None), |pos| (Some(pos), Some(pos + type_name.len())));
+
+    Some(Diagnostic {
+        line: ann.line,
+        message: format!(
+            "Type '{}' (line {}) doesn't derive JsonSchema, so no schema can be generated for this response.\nAdd #[derive(JsonSchema)] to its definition.",
+            type_name,
+            definition + 1
+        ),
+        severity: DiagnosticSeverity::Warning,
+        char_start,
+        char_end,
+        end_line: None,
+        end_char: None,
+    })
+}
+
+/// Canonical reason phrase for an HTTP status code (RFC 9110 and common extensions)
+fn canonical_reason(status: u16) -> Option<&'static str> {
+    let reason = match status {
+        100 => "Continue",
+        101 => "Switching Protocols",
+        200 => "OK",
+        201 => "Created",
+        202 => "Accepted",
+        203 => "Non-Authoritative Information",
+        204 => "No Content",
+        205 => "Reset Content",
+        206 => "Partial Content",
+        300 => "Multiple Choices",
+        301 => "Moved Permanently",
+        302 => "Found",
+        303 => "See Other",
+        304 => "Not Modified",
+        307 => "Temporary Redirect",
+        308 => "Permanent Redirect",
+        400 => "Bad Request",
+        401 => "Unauthorized",
+        402 => "Payment Required",
+        403 => "Forbidden",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        406 => "Not Acceptable",
+        407 => "Proxy Authentication Required",
+        408 => "Request Timeout",
+        409 => "Conflict",
+        410 => "Gone",
+        411 => "Length Required",
+        412 => "Precondition Failed",
+        413 => "Content Too Large",
+        414 => "URI Too Long",
+        415 => "Unsupported Media Type",
+        416 => "Range Not Satisfiable",
+        417 => "Expectation Failed",
+        418 => "I'm a teapot",
+        421 => "Misdirected Request",
+        422 => "Unprocessable Content",
+        423 => "Locked",
+        424 => "Failed Dependency",
+        425 => "Too Early",
+        426 => "Upgrade Required",
+        428 => "Precondition Required",
+        429 => "Too Many Requests",
+        431 => "Request Header Fields Too Large",
+        451 => "Unavailable For Legal Reasons",
+        500 => "Internal Server Error",
+        501 => "Not Implemented",
+        502 => "Bad Gateway",
+        503 => "Service Unavailable",
+        504 => "Gateway Timeout",
+        505 => "HTTP Version Not Supported",
+        _ => return None,
+    };
+    Some(reason)
+}
+
+/// Check whether a response description only restates the status reason phrase
+///
+/// Also accepts the older names for 413 and 422 ("Payload Too Large",
+/// "Unprocessable Entity"), which are still widely used.
+fn is_reason_phrase_only(status: u16, description: &str) -> bool {
+    let description = description.trim().trim_end_matches('.');
+    let legacy = match status {
+        413 => Some("Payload Too Large"),
+        422 => Some("Unprocessable Entity"),
+        _ => None,
+    };
+
+    canonical_reason(status)
+        .into_iter()
+        .chain(legacy)
+        .any(|reason| reason.eq_ignore_ascii_case(description))
+}
+
 /// Validate Rovo annotations in the given content
 ///
 /// Checks for issues like invalid HTTP status codes and example syntax errors.
@@ -74,33 +232,98 @@ fn parse_example_error(code: &str) -> String {
 pub fn validate_annotations(content: &str) -> Vec<Diagnostic> {
     let annotations = crate::parser::parse_annotations(content);
     let lines: Vec<&str> = content.lines().collect();
-    let mut diagnostics = Vec::new();
+    let handlers = handler_lines(&lines);
+    let mut diagnostics = check_header_status_codes(&annotations, &lines, &handlers);
+    diagnostics.extend(check_duplicate_operation_ids(&annotations, &lines));
+    diagnostics.extend(check_example_status_codes(&annotations, &lines, &handlers));
+    diagnostics.extend(check_tags_on_hidden_handlers(
+        &annotations,
+        &lines,
+        &handlers,
+    ));
+    diagnostics.extend(check_duplicate_tags(&annotations, &lines, &handlers));
+    diagnostics.extend(check_duplicate_response_statuses(
+        &annotations,
+        &lines,
+        &handlers,
+    ));
+    diagnostics.extend(check_secured_get_auth_responses(
+        content,
+        &annotations,
+        &lines,
+        &handlers,
+    ));
 
     for ann in annotations {
         match ann.kind {
             AnnotationKind::Response => {
                 if let Some(status) = ann.status {
-                    if status < 100 || status > 599 {
-                        // Find the position of the status code in the line
-                        let (char_start, char_end) = if ann.line < lines.len() {
-                            let line = lines[ann.line];
-                            let status_str = status.to_string();
-                            if let Some(pos) = line.find(&status_str) {
-                                (Some(pos), Some(pos + status_str.len()))
-                            } else {
-                                (None, None)
-                            }
-                        } else {
-                            (None, None)
-                        };
+                    diagnostics.extend(check_status_code(status, ann.line, &lines));
+                }
+
+                // Only an unknown `StatusCode::NAME` constant leaves a response without a status
+                if ann.status.is_none() && ann.status_range.is_none() {
+                    let line = lines.get(ann.line).unwrap_or(&"");
+                    if let Some(start) = line.find("StatusCode::") {
+                        let end = line[start + "StatusCode::".len()..]
+                            .find(':')
+                            .map_or(line.len(), |pos| start + "StatusCode::".len() + pos);
 
                         diagnostics.push(Diagnostic {
                             line: ann.line,
                             message: format!(
-                                "Invalid HTTP status code: {}. Must be between 100 and 599.",
-                                status
+                                "Unknown status code constant '{}'. Use the numeric status code instead, e.g. '404: () - Not found'.",
+                                line[start..end].trim()
                             ),
                             severity: DiagnosticSeverity::Error,
+                            char_start: Some(start),
+                            char_end: Some(end),
+                            end_line: None,
+                            end_char: None,
+                        });
+                    }
+                }
+
+                if let Some(range) = ann.status_range.as_deref() {
+                    if range != "default" && !matches!(range.as_bytes()[0], b'1'..=b'5') {
+                        let (char_start, char_end) = lines
+                            .get(ann.line)
+                            .and_then(|line| line.find(range))
+                            .map_or((None, None), |pos| (Some(pos), Some(pos + range.len())));
+
+                        diagnostics.push(Diagnostic {
+                            line: ann.line,
+                            message: format!(
+                                "Invalid HTTP status code range: {}. Must be one of 1XX, 2XX, 3XX, 4XX, 5XX.",
+                                range
+                            ),
+                            severity: DiagnosticSeverity::Error,
+                            char_start,
+                            char_end,
+                            end_line: None,
+                            end_char: None,
+                        });
+                    }
+                }
+
+                if let (Some(status), Some(description)) = (ann.status, ann.description.as_deref())
+                {
+                    if is_reason_phrase_only(status, description) {
+                        // Highlight just the description
+                        let (char_start, char_end) = lines
+                            .get(ann.line)
+                            .and_then(|line| line.rfind(description.trim()))
+                            .map_or((None, None), |pos| {
+                                (Some(pos), Some(pos + description.trim().len()))
+                            });
+
+                        diagnostics.push(Diagnostic {
+                            line: ann.line,
+                            message: format!(
+                                "Description '{}' only repeats the status reason phrase.\nDescribe when this response occurs, e.g. 'User does not exist'.",
+                                description.trim()
+                            ),
+                            severity: DiagnosticSeverity::Hint,
                             char_start,
                             char_end,
                             end_line: None,
@@ -108,8 +331,59 @@ pub fn validate_annotations(content: &str) -> Vec<Diagnostic> {
                         });
                     }
                 }
+
+                if let Some(response_type) = ann.response_type.as_deref() {
+                    if let Some((_, kind)) = find_unschematizable_pattern(response_type) {
+                        // Highlight the whole response type in the line
+                        let (char_start, char_end) = lines
+                            .get(ann.line)
+                            .and_then(|line| line.find(response_type))
+                            .map_or((None, None), |pos| {
+                                (Some(pos), Some(pos + response_type.len()))
+                            });
+
+                        diagnostics.push(Diagnostic {
+                            line: ann.line,
+                            message: format!(
+                                "Response type '{}' contains {}, which schemars can't generate a schema for.\nUse a concrete type that implements JsonSchema instead.",
+                                response_type, kind
+                            ),
+                            severity: DiagnosticSeverity::Warning,
+                            char_start,
+                            char_end,
+                            end_line: None,
+                            end_char: None,
+                        });
+                    }
+                }
+                if let Some(diagnostic) =
+                    check_response_type_derives_jsonschema(content, &ann, &lines)
+                {
+                    diagnostics.push(diagnostic);
+                }
+
+                if ann.response_type.as_deref() == Some("String") {
+                    let (char_start, char_end) = lines
+                        .get(ann.line)
+                        .and_then(|line| line.find("String"))
+                        .map_or((None, None), |pos| (Some(pos), Some(pos + "String".len())));
+
+                    diagnostics.push(Diagnostic {
+                        line: ann.line,
+                        message: "A bare 'String' documents a text/plain response.\nDid you mean 'Json<String>' for a JSON string?".to_string(),
+                        severity: DiagnosticSeverity::Hint,
+                        char_start,
+                        char_end,
+                        end_line: None,
+                        end_char: None,
+                    });
+                }
             }
             AnnotationKind::Example => {
+                if let Some(status) = ann.status {
+                    diagnostics.extend(check_status_code(status, ann.line, &lines));
+                }
+
                 // Validate example syntax
                 if let Some(example_code) = ann.example_value {
                     let error_msg = parse_example_error(&example_code);
@@ -125,7 +399,7 @@ pub fn validate_annotations(content: &str) -> Vec<Diagnostic> {
                                 let content = line.trim_start_matches("///").trim();
 
                                 // Check if this line starts a new example entry (STATUS: ...)
-                                let starts_new_example = content
+                                let starts_new_example = crate::parser::strip_list_bullet(content)
                                     .chars()
                                     .next()
                                     .map(|c| c.is_ascii_digit())
@@ -176,6 +450,45 @@ pub fn validate_annotations(content: &str) -> Vec<Diagnostic> {
                     }
                 }
             }
+            AnnotationKind::Server | AnnotationKind::ExternalDocs => {
+                if let Some(url) = ann.url.as_deref() {
+                    let host = url
+                        .strip_prefix("https://")
+                        .or_else(|| url.strip_prefix("http://"));
+                    if host.is_none_or(str::is_empty) {
+                        let (char_start, char_end) = lines
+                            .get(ann.line)
+                            .and_then(|line| line.find(url))
+                            .map_or((None, None), |pos| (Some(pos), Some(pos + url.len())));
+
+                        diagnostics.push(Diagnostic {
+                            line: ann.line,
+                            message: format!(
+                                "Invalid {} URL: {}. Must start with http:// or https:// followed by a host.",
+                                if ann.kind == AnnotationKind::Server {
+                                    "server"
+                                } else {
+                                    "external docs"
+                                },
+                                url
+                            ),
+                            severity: DiagnosticSeverity::Warning,
+                            char_start,
+                            char_end,
+                            end_line: None,
+                            end_char: None,
+                        });
+                    }
+                }
+            }
+            AnnotationKind::ResponsesSection
+            | AnnotationKind::ExamplesSection
+            | AnnotationKind::MetadataSection
+            | AnnotationKind::PathParametersSection => {
+                if let Some(diagnostic) = check_blank_line_after_header(&lines, ann.line) {
+                    diagnostics.push(diagnostic);
+                }
+            }
             _ => {}
         }
     }
@@ -183,23 +496,677 @@ pub fn validate_annotations(content: &str) -> Vec<Diagnostic> {
     // Check for undocumented path parameters
     diagnostics.extend(check_undocumented_path_params(content, &lines));
 
+    diagnostics.extend(check_max_version_without_deprecation(&lines));
+    diagnostics.extend(check_ratelimit_values(&lines));
+    diagnostics.extend(check_annotations_missing_at(&lines));
+    diagnostics.extend(check_unknown_annotations(&lines));
+
     diagnostics
 }
 
-/// Check for undocumented path parameters and emit warnings
-fn check_undocumented_path_params(_content: &str, lines: &[&str]) -> Vec<Diagnostic> {
+/// Error when a response or example status code is outside 100-599, like the macro
+fn check_status_code(status: u16, line: usize, lines: &[&str]) -> Option<Diagnostic> {
+    if (100..=599).contains(&status) {
+        return None;
+    }
+
+    // Find the position of the status code in the line
+    let status_str = status.to_string();
+    let (char_start, char_end) = lines
+        .get(line)
+        .and_then(|text| text.find(&status_str))
+        .map_or((None, None), |pos| {
+            (Some(pos), Some(pos + status_str.len()))
+        });
+
+    Some(Diagnostic {
+        line,
+        message: format!(
+            "Invalid HTTP status code: {}. Must be between 100 and 599.",
+            status
+        ),
+        severity: DiagnosticSeverity::Error,
+        char_start,
+        char_end,
+        end_line: None,
+        end_char: None,
+    })
+}
+
+/// Error on every `@id` whose operation ID is also declared elsewhere in the document
+fn check_duplicate_operation_ids(annotations: &[Annotation], lines: &[&str]) -> Vec<Diagnostic> {
+    let ids: Vec<(&str, usize)> = annotations
+        .iter()
+        .filter(|ann| ann.kind == AnnotationKind::Id)
+        .filter_map(|ann| Some((ann.operation_id.as_deref()?, ann.line)))
+        .collect();
+
+    ids.iter()
+        .filter_map(|&(id, line)| {
+            let others: Vec<String> = ids
+                .iter()
+                .filter(|&&(other, other_line)| other == id && other_line != line)
+                .map(|(_, other_line)| (other_line + 1).to_string())
+                .collect();
+            if others.is_empty() {
+                return None;
+            }
+
+            // Highlight the ID after `@id`
+            let char_start = lines.get(line).and_then(|text| {
+                let at = text.find("@id")? + "@id".len();
+                text[at..].find(id).map(|offset| at + offset)
+            });
+
+            Some(Diagnostic {
+                line,
+                message: format!(
+                    "Duplicate operation ID '{}', also used on line {}.\nOperation IDs must be unique within the API.",
+                    id,
+                    others.join(", ")
+                ),
+                severity: DiagnosticSeverity::Error,
+                char_start,
+                char_end: char_start.map(|pos| pos + id.len()),
+                end_line: None,
+                end_char: None,
+            })
+        })
+        .collect()
+}
+
+/// Warn when an example's status code has no matching response in its handler
+///
+/// Like the macro, the status must be documented exactly (a `4XX` range doesn't
+/// cover a `404` example), and handlers without any responses aren't checked.
+fn check_example_status_codes(
+    annotations: &[Annotation],
+    lines: &[&str],
+    handlers: &[Option<usize>],
+) -> Vec<Diagnostic> {
+    annotations
+        .iter()
+        .filter(|ann| ann.kind == AnnotationKind::Example)
+        .filter_map(|example| {
+            let status = example.status?;
+            let handler = handler_of(handlers, example.line);
+            let responses: Vec<&Annotation> = annotations
+                .iter()
+                .filter(|ann| {
+                    ann.kind == AnnotationKind::Response && handler_of(handlers, ann.line) == handler
+                })
+                .collect();
+            if responses.is_empty() || responses.iter().any(|ann| ann.status == Some(status)) {
+                return None;
+            }
+
+            let available: Vec<String> = responses
+                .iter()
+                .filter_map(|ann| {
+                    ann.status
+                        .map(|code| code.to_string())
+                        .or_else(|| ann.status_range.clone())
+                })
+                .collect();
+            let status_str = status.to_string();
+            let char_start = lines
+                .get(example.line)
+                .and_then(|line| line.find(&status_str));
+
+            Some(Diagnostic {
+                line: example.line,
+                message: format!(
+                    "Example for status code {} has no matching response. Available status codes: {}.\nAdd a '{}: ...' entry under # Responses or fix the example's status code.",
+                    status,
+                    available.join(", "),
+                    status
+                ),
+                severity: DiagnosticSeverity::Warning,
+                char_start,
+                char_end: char_start.map(|pos| pos + status_str.len()),
+                end_line: None,
+                end_char: None,
+            })
+        })
+        .collect()
+}
+
+/// Warn on every response after the first for the same status code (or range)
+/// in a handler, like the macro's duplicate response error
+fn check_duplicate_response_statuses(
+    annotations: &[Annotation],
+    lines: &[&str],
+    handlers: &[Option<usize>],
+) -> Vec<Diagnostic> {
+    let mut seen: Vec<(Option<usize>, String, usize)> = Vec::new();
     let mut diagnostics = Vec::new();
+    for ann in annotations
+        .iter()
+        .filter(|ann| ann.kind == AnnotationKind::Response)
+    {
+        let Some(status) = ann
+            .status
+            .map(|code| code.to_string())
+            .or_else(|| ann.status_range.clone())
+        else {
+            continue;
+        };
+        let handler = handler_of(handlers, ann.line);
 
-    // Find all #[rovo] blocks
-    for (rovo_line, line) in lines.iter().enumerate() {
-        if !line.contains("#[rovo]") {
+        let Some(&(_, _, first_line)) = seen
+            .iter()
+            .find(|(other_handler, other, _)| *other_handler == handler && *other == status)
+        else {
+            seen.push((handler, status, ann.line));
+            continue;
+        };
+
+        let char_start = lines
+            .get(ann.line)
+            .and_then(|line| line.find(status.as_str()));
+        diagnostics.push(Diagnostic {
+            line: ann.line,
+            message: format!(
+                "Duplicate status code {}, already documented on line {}.\nEach status code can only be documented once; merge the descriptions or change the status code.",
+                status,
+                first_line + 1
+            ),
+            severity: DiagnosticSeverity::Warning,
+            char_start,
+            char_end: char_start.map(|pos| pos + status.len()),
+            end_line: None,
+            end_char: None,
+        });
+    }
+
+    diagnostics
+}
+
+/// Warn on every `@tag` repeating a tag already given to the same handler
+fn check_duplicate_tags(
+    annotations: &[Annotation],
+    lines: &[&str],
+    handlers: &[Option<usize>],
+) -> Vec<Diagnostic> {
+    let mut seen: Vec<(Option<usize>, &str, usize)> = Vec::new();
+    let mut diagnostics = Vec::new();
+    for ann in annotations
+        .iter()
+        .filter(|ann| ann.kind == AnnotationKind::Tag)
+    {
+        let Some(tag) = ann.tag_name.as_deref() else {
+            continue;
+        };
+        let handler = handler_of(handlers, ann.line);
+
+        let Some(&(_, _, first_line)) = seen
+            .iter()
+            .find(|(other_handler, other, _)| *other_handler == handler && *other == tag)
+        else {
+            seen.push((handler, tag, ann.line));
+            continue;
+        };
+
+        let char_start = lines.get(ann.line).and_then(|line| line.find("@tag"));
+        diagnostics.push(Diagnostic {
+            line: ann.line,
+            message: format!(
+                "Duplicate tag '{}', already added on line {}.\nRemove this line.",
+                tag,
+                first_line + 1
+            ),
+            severity: DiagnosticSeverity::Warning,
+            char_start,
+            char_end: char_start.map(|_| lines[ann.line].trim_end().len()),
+            end_line: None,
+            end_char: None,
+        });
+    }
+
+    diagnostics
+}
+
+/// Warn on each `@tag` of a handler that's also `@hidden`, since hidden
+/// operations never show up under their tags
+fn check_tags_on_hidden_handlers(
+    annotations: &[Annotation],
+    lines: &[&str],
+    handlers: &[Option<usize>],
+) -> Vec<Diagnostic> {
+    let hidden: Vec<Option<usize>> = annotations
+        .iter()
+        .filter(|ann| ann.kind == AnnotationKind::Hidden)
+        .map(|ann| handler_of(handlers, ann.line))
+        .collect();
+
+    annotations
+        .iter()
+        .filter(|ann| ann.kind == AnnotationKind::Tag && hidden.contains(&handler_of(handlers, ann.line)))
+        .map(|tag| {
+            let char_start = lines.get(tag.line).and_then(|line| line.find("@tag"));
+            Diagnostic {
+                line: tag.line,
+                message: format!(
+                    "Tag '{}' has no effect because the operation is @hidden.\nRemove the tag, or remove @hidden if the operation should be documented.",
+                    tag.tag_name.as_deref().unwrap_or_default()
+                ),
+                severity: DiagnosticSeverity::Warning,
+                char_start,
+                char_end: char_start.map(|_| lines[tag.line].trim_end().len()),
+                end_line: None,
+                end_char: None,
+            }
+        })
+        .collect()
+}
+
+/// Error when an `@header` refers to a status code its handler doesn't document
+fn check_header_status_codes(
+    annotations: &[Annotation],
+    lines: &[&str],
+    handlers: &[Option<usize>],
+) -> Vec<Diagnostic> {
+    annotations
+        .iter()
+        .filter(|ann| ann.kind == AnnotationKind::Header)
+        .filter_map(|header| {
+            let status = header.status?;
+            let handler = handler_of(handlers, header.line);
+            let documented = annotations.iter().any(|ann| {
+                ann.kind == AnnotationKind::Response
+                    && ann.status == Some(status)
+                    && handler_of(handlers, ann.line) == handler
+            });
+            if documented {
+                return None;
+            }
+
+            let status_str = status.to_string();
+            let char_start = lines.get(header.line).and_then(|line| {
+                line.find("@header")
+                    .and_then(|pos| line[pos..].find(&status_str).map(|offset| pos + offset))
+            });
+
+            Some(Diagnostic {
+                line: header.line,
+                message: format!(
+                    "Header '{}' refers to status code {}, which is not documented in # Responses.",
+                    header.header_name.as_deref().unwrap_or_default(),
+                    status
+                ),
+                severity: DiagnosticSeverity::Error,
+                char_start,
+                char_end: char_start.map(|pos| pos + status_str.len()),
+                end_line: None,
+                end_char: None,
+            })
+        })
+        .collect()
+}
+
+/// Hint when a secured GET handler documents neither a 401 nor a 403 response
+///
+/// The method is inferred from the handler's route registration in the same
+/// document; handlers whose method can't be inferred are skipped.
+fn check_secured_get_auth_responses(
+    content: &str,
+    annotations: &[Annotation],
+    lines: &[&str],
+    handlers: &[Option<usize>],
+) -> Vec<Diagnostic> {
+    let mut seen_handlers = Vec::new();
+    let mut diagnostics = Vec::new();
+
+    for security in annotations.iter().filter(|ann| {
+        matches!(
+            ann.kind,
+            AnnotationKind::Security | AnnotationKind::SecurityAnd
+        )
+    }) {
+        let Some(handler) = handler_of(handlers, security.line) else {
+            continue;
+        };
+        if seen_handlers.contains(&handler) {
+            continue;
+        }
+        seen_handlers.push(handler);
+
+        let documents_auth_failure = annotations.iter().any(|ann| {
+            ann.kind == AnnotationKind::Response
+                && handler_of(handlers, ann.line) == Some(handler)
+                && (matches!(ann.status, Some(401 | 403))
+                    || ann.status_range.as_deref() == Some("4XX"))
+        });
+        if documents_auth_failure {
             continue;
         }
 
-        // Skip #[rovo] that appears inside doc comments (//! or ///)
-        // These are example code in documentation, not real attributes
+        let method = find_fn_line_after_rovo(lines, handler)
+            .and_then(|fn_line| fn_name(lines[fn_line]))
+            .and_then(|name| infer_http_method(content, name));
+        if method != Some("get") {
+            continue;
+        }
+
+        let line = lines.get(security.line).unwrap_or(&"");
+        let char_start = line.find('@');
+        diagnostics.push(Diagnostic {
+            line: security.line,
+            message: "Secured GET endpoint does not document a 401 or 403 response.\nProtected endpoints should document their auth-failure responses, or drop @security if the endpoint is meant to be public.".to_string(),
+            severity: DiagnosticSeverity::Hint,
+            char_start,
+            char_end: char_start.map(|_| line.trim_end().len()),
+            end_line: None,
+            end_char: None,
+        });
+    }
+
+    diagnostics
+}
+
+/// Extract the function name from a `fn` signature line
+fn fn_name(line: &str) -> Option<&str> {
+    let after_fn = line.split("fn ").nth(1)?;
+    let end = after_fn
+        .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+        .unwrap_or(after_fn.len());
+    (end > 0).then(|| &after_fn[..end])
+}
+
+/// Infer a handler's HTTP method from its route registration, e.g. `get(list_users)`
+///
+/// Returns `None` if the handler isn't routed in this document or is routed
+/// with more than one method.
+fn infer_http_method(content: &str, handler: &str) -> Option<&'static str> {
+    const METHODS: [&str; 8] = [
+        "get", "post", "put", "patch", "delete", "head", "options", "trace",
+    ];
+
+    let pattern = format!(
+        r"\b(get|post|put|patch|delete|head|options|trace)(?:_with)?\s*\(\s*(?:[\w:]+::)?{}\s*[,)]",
+        regex::escape(handler)
+    );
+    let re = regex::Regex::new(&pattern).ok()?;
+
+    let mut methods = re
+        .captures_iter(content)
+        .filter_map(|captures| METHODS.iter().find(|m| **m == &captures[1]).copied());
+    let method = methods.next()?;
+    methods.all(|other| other == method).then_some(method)
+}
+
+/// Warn when `@max-version` is set but neither `@deprecated` nor `#[deprecated]` marks the handler
+///
+/// Looks at the doc comments and attributes around each `#[rovo]`, since an
+/// attribute like `#[deprecated]` between the docs and `#[rovo]` ends the
+/// doc block the parser sees.
+fn check_max_version_without_deprecation(lines: &[&str]) -> Vec<Diagnostic> {
+    let is_block_line = |line: &str| {
         let trimmed = line.trim();
-        if trimmed.starts_with("//!") || trimmed.starts_with("///") {
+        trimmed.is_empty() || trimmed.starts_with("///") || trimmed.starts_with("#[")
+    };
+
+    let mut diagnostics = Vec::new();
+    for (rovo_line, line) in lines.iter().enumerate() {
+        if !crate::parser::is_rovo_attribute(line) {
+            continue;
+        }
+
+        let start = (0..rovo_line)
+            .rev()
+            .take_while(|&idx| is_block_line(lines[idx]))
+            .last()
+            .unwrap_or(rovo_line);
+        let end = (rovo_line..lines.len())
+            .take_while(|&idx| is_block_line(lines[idx]))
+            .last()
+            .unwrap_or(rovo_line);
+
+        let doc_content = |idx: usize| lines[idx].trim().strip_prefix("///").map(str::trim);
+        let Some(max_version_line) = (start..=end)
+            .find(|&idx| doc_content(idx).is_some_and(|doc| doc.starts_with("@max-version")))
+        else {
+            continue;
+        };
+
+        let deprecated = (start..=end).any(|idx| {
+            lines[idx].trim().starts_with("#[deprecated")
+                || doc_content(idx)
+                    .is_some_and(|doc| doc == "@deprecated" || doc.starts_with("@deprecated "))
+        });
+        if deprecated {
+            continue;
+        }
+
+        let line = lines[max_version_line];
+        let char_start = line.find("@max-version");
+        diagnostics.push(Diagnostic {
+            line: max_version_line,
+            message: "@max-version is set but the operation is not deprecated.\nAdd @deprecated or #[deprecated] so clients know it's going away.".to_string(),
+            severity: DiagnosticSeverity::Warning,
+            char_start,
+            char_end: char_start.map(|_| line.trim_end().len()),
+            end_line: None,
+            end_char: None,
+        });
+    }
+
+    diagnostics
+}
+
+/// Error when an `@ratelimit` in a handler's doc block isn't `<count> per-<window>`
+/// with a positive count and a `second`, `minute` or `hour` window
+fn check_ratelimit_values(lines: &[&str]) -> Vec<Diagnostic> {
+    let mut diagnostics = Vec::new();
+    for rovo_pos in (0..lines.len()).filter(|&idx| crate::parser::is_rovo_attribute(lines[idx])) {
+        for (idx, doc_line) in crate::parser::collect_doc_block(lines, rovo_pos) {
+            let line = lines[idx];
+            let doc = doc_line.trim_start_matches("///").trim();
+            let Some(value) = doc.strip_prefix("@ratelimit") else {
+                continue;
+            };
+            if !value.is_empty() && !value.starts_with(char::is_whitespace) {
+                continue;
+            }
+
+            let annotation_start = line.find("@ratelimit").unwrap_or_default();
+            let mut error = |message: String, token: Option<&str>| {
+                // Highlight the offending word, or the whole annotation if it's missing
+                let char_start = token
+                    .and_then(|token| line[annotation_start..].find(token))
+                    .map_or(annotation_start, |pos| annotation_start + pos);
+                let char_end =
+                    token.map_or(line.trim_end().len(), |token| char_start + token.len());
+                diagnostics.push(Diagnostic {
+                    line: idx,
+                    message,
+                    severity: DiagnosticSeverity::Error,
+                    char_start: Some(char_start),
+                    char_end: Some(char_end),
+                    end_line: None,
+                    end_char: None,
+                });
+            };
+
+            let words: Vec<&str> = value.split_whitespace().collect();
+            let [count, window] = words.as_slice() else {
+                error(
+                    "Invalid @ratelimit annotation.\nExpected '@ratelimit <count> per-<window>', e.g. '@ratelimit 100 per-minute'.".to_string(),
+                    None,
+                );
+                continue;
+            };
+
+            if !count.parse::<u64>().is_ok_and(|count| count > 0) {
+                error(
+                    format!(
+                        "Invalid rate limit count: {}. Must be a positive integer.",
+                        count
+                    ),
+                    Some(count),
+                );
+            }
+            if !matches!(*window, "per-second" | "per-minute" | "per-hour") {
+                error(
+                    format!(
+                        "Invalid rate limit window: {}. Must be per-second, per-minute or per-hour.",
+                        window
+                    ),
+                    Some(window),
+                );
+            }
+        }
+    }
+
+    diagnostics
+}
+
+/// Error on `@` lines in a `# Metadata` section that aren't a known annotation,
+/// suggesting the closest one like the macro does
+fn check_unknown_annotations(lines: &[&str]) -> Vec<Diagnostic> {
+    let mut diagnostics = Vec::new();
+
+    for rovo_pos in (0..lines.len()).filter(|&idx| crate::parser::is_rovo_attribute(lines[idx])) {
+        let mut in_metadata = false;
+        for (line_num, line) in crate::parser::collect_doc_block(lines, rovo_pos) {
+            let doc = line.trim_start_matches("///").trim();
+            if let Some(section) = doc.strip_prefix("# ") {
+                in_metadata = section.trim() == "Metadata";
+                continue;
+            }
+
+            let Some(name) = doc
+                .strip_prefix('@')
+                .and_then(|rest| rest.split_whitespace().next())
+            else {
+                continue;
+            };
+            if !in_metadata || crate::utils::ANNOTATIONS.contains(&name) {
+                continue;
+            }
+
+            let message = if name == "tag-description" {
+                "'@tag-description' can't be used on a handler.\nDescribe the tag in a #[rovo_module] doc comment, and tag the handler with '@tag'.".to_string()
+            } else {
+                let suggestion = crate::utils::find_closest_annotation(name)
+                    .map(|closest| format!("\nDid you mean '@{}'?", closest))
+                    .unwrap_or_default();
+                format!("Unknown annotation '@{}'.{}", name, suggestion)
+            };
+            let char_start = lines[line_num].find('@');
+
+            diagnostics.push(Diagnostic {
+                line: line_num,
+                message,
+                severity: DiagnosticSeverity::Error,
+                char_start,
+                char_end: char_start.map(|pos| pos + 1 + name.len()),
+                end_line: None,
+                end_char: None,
+            });
+        }
+    }
+
+    diagnostics
+}
+
+/// Annotations that are easy to write without their leading `@`, and whether
+/// they take arguments
+const BARE_ANNOTATIONS: [(&str, bool); 10] = [
+    ("tag", true),
+    ("security", true),
+    ("security-and", true),
+    ("id", true),
+    ("summary", true),
+    ("errors", true),
+    ("produces", true),
+    ("hidden", false),
+    ("deprecated", false),
+    ("idempotent", false),
+];
+
+/// Hint when a `# Metadata` line looks like an annotation written without its `@`,
+/// e.g. `tag users` instead of `@tag users`
+fn check_annotations_missing_at(lines: &[&str]) -> Vec<Diagnostic> {
+    let mut diagnostics = Vec::new();
+    let mut in_metadata = false;
+
+    for (idx, line) in lines.iter().enumerate() {
+        let Some(doc) = line.trim().strip_prefix("///").map(str::trim) else {
+            in_metadata = false;
+            continue;
+        };
+        if doc.starts_with('#') {
+            in_metadata = doc == "# Metadata";
+            continue;
+        }
+        if !in_metadata {
+            continue;
+        }
+
+        let (word, args) = doc.split_once(' ').unwrap_or((doc, ""));
+        let looks_like_annotation = BARE_ANNOTATIONS
+            .iter()
+            .any(|&(name, takes_args)| name == word && takes_args != args.trim().is_empty());
+        if !looks_like_annotation {
+            continue;
+        }
+
+        let char_start = line.find(doc);
+        diagnostics.push(Diagnostic {
+            line: idx,
+            message: format!(
+                "'{}' looks like an annotation missing its '@'.\nWrite '@{}' so it's applied to the operation.",
+                word, word
+            ),
+            severity: DiagnosticSeverity::Hint,
+            char_start,
+            char_end: char_start.map(|pos| pos + word.len()),
+            end_line: None,
+            end_char: None,
+        });
+    }
+
+    diagnostics
+}
+
+/// Hint when a section header is directly followed by content instead of a blank `///` line
+fn check_blank_line_after_header(lines: &[&str], header_line: usize) -> Option<Diagnostic> {
+    let header = lines.get(header_line)?;
+    let next_content = lines
+        .get(header_line + 1)?
+        .trim()
+        .strip_prefix("///")?
+        .trim();
+    if next_content.is_empty() {
+        return None;
+    }
+
+    let heading = header.trim().trim_start_matches("///").trim();
+    let char_start = header.find('#');
+
+    Some(Diagnostic {
+        line: header_line,
+        message: format!(
+            "Missing blank line after section header '{}'.\nSome Markdown renderers require a blank `///` line after headers.",
+            heading
+        ),
+        severity: DiagnosticSeverity::Hint,
+        char_start,
+        char_end: char_start.map(|pos| header.trim_end().len().max(pos)),
+        end_line: None,
+        end_char: None,
+    })
+}
+
+/// Check for undocumented path parameters and emit warnings
+fn check_undocumented_path_params(_content: &str, lines: &[&str]) -> Vec<Diagnostic> {
+    let mut diagnostics = Vec::new();
+
+    // Find all #[rovo] blocks
+    for (rovo_line, line) in lines.iter().enumerate() {
+        // `#[rovo]` inside doc comments (//! or ///) is example code, not an attribute
+        if !crate::parser::is_rovo_attribute(line) {
             continue;
         }
 
@@ -218,13 +1185,32 @@ fn check_undocumented_path_params(_content: &str, lines: &[&str]) -> Vec<Diagnos
             .filter(|b| !b.starts_with('_') && !documented.contains(*b))
             .collect();
 
+        // Find the function signature line for the diagnostic location
+        let fn_line = find_fn_line_after_rovo(lines, rovo_line).unwrap_or(rovo_line);
+
         if undocumented.is_empty() {
+            // Names all line up, but the extractor may still take more or fewer
+            // values than documented (e.g. `Path(ids): Path<(u64, String)>`)
+            if let Some(arity) = extract_path_arity_from_signature(lines, rovo_line) {
+                if !documented.is_empty() && documented.len() != arity {
+                    diagnostics.push(Diagnostic {
+                        line: fn_line,
+                        message: format!(
+                            "Path extractor takes {} parameter(s), but {} are documented in `# Path Parameters`.\nDocument exactly one entry per path parameter.",
+                            arity,
+                            documented.len()
+                        ),
+                        severity: DiagnosticSeverity::Warning,
+                        char_start: None,
+                        char_end: None,
+                        end_line: None,
+                        end_char: None,
+                    });
+                }
+            }
             continue;
         }
 
-        // Find the function signature line for the diagnostic location
-        let fn_line = find_fn_line_after_rovo(lines, rovo_line).unwrap_or(rovo_line);
-
         let param_list = undocumented
             .iter()
             .map(|s| format!("'{}'", s))
@@ -248,9 +1234,8 @@ fn check_undocumented_path_params(_content: &str, lines: &[&str]) -> Vec<Diagnos
     diagnostics
 }
 
-/// Extract path bindings from function signature starting at rovo_line
-fn extract_path_bindings_from_signature(lines: &[&str], rovo_line: usize) -> Vec<String> {
-    // Collect signature until opening brace
+/// Collect the function signature after rovo_line, up to the opening brace
+fn collect_signature(lines: &[&str], rovo_line: usize) -> String {
     let mut signature = String::new();
     for line in lines.iter().skip(rovo_line) {
         let trimmed = line.trim();
@@ -264,6 +1249,76 @@ fn extract_path_bindings_from_signature(lines: &[&str], rovo_line: usize) -> Vec
         signature.push_str(line);
         signature.push(' ');
     }
+    signature
+}
+
+/// Number of values a `Path<T>` extractor takes: the element count for tuples,
+/// 1 for primitives, `None` for structs and other types whose arity isn't visible
+fn extract_path_arity_from_signature(lines: &[&str], rovo_line: usize) -> Option<usize> {
+    const PRIMITIVES: &[&str] = &[
+        "u8",
+        "u16",
+        "u32",
+        "u64",
+        "u128",
+        "usize",
+        "i8",
+        "i16",
+        "i32",
+        "i64",
+        "i128",
+        "isize",
+        "bool",
+        "char",
+        "String",
+        "Uuid",
+        "uuid::Uuid",
+    ];
+
+    let signature = collect_signature(lines, rovo_line);
+    let start = signature.find("Path<")? + "Path<".len();
+
+    // Find the matching closing angle bracket
+    let mut depth = 1;
+    let mut inner = None;
+    for (i, ch) in signature[start..].char_indices() {
+        match ch {
+            '<' => depth += 1,
+            '>' => {
+                depth -= 1;
+                if depth == 0 {
+                    inner = Some(signature[start..start + i].trim());
+                    break;
+                }
+            }
+            _ => {}
+        }
+    }
+    let inner = inner?;
+
+    if let Some(elements) = inner.strip_prefix('(').and_then(|s| s.strip_suffix(')')) {
+        // Count top-level tuple elements
+        let mut depth = 0;
+        let mut count = 1;
+        for ch in elements.trim().trim_end_matches(',').chars() {
+            match ch {
+                '<' | '(' | '[' => depth += 1,
+                '>' | ')' | ']' => depth -= 1,
+                ',' if depth == 0 => count += 1,
+                _ => {}
+            }
+        }
+        Some(count)
+    } else if PRIMITIVES.contains(&inner) {
+        Some(1)
+    } else {
+        None
+    }
+}
+
+/// Extract path bindings from function signature starting at rovo_line
+fn extract_path_bindings_from_signature(lines: &[&str], rovo_line: usize) -> Vec<String> {
+    let signature = collect_signature(lines, rovo_line);
 
     // Extract Path bindings
     let mut bindings = Vec::new();
@@ -344,7 +1399,12 @@ fn get_documented_path_params_for_block(lines: &[&str], rovo_line: usize) -> Vec
         } else if content.starts_with("# ") {
             in_path_params = false;
         } else if in_path_params {
-            if let Some(colon_pos) = content.find(':') {
+            if let Some(rest) = content.strip_prefix("@path ") {
+                // "@path name description"
+                if let Some(name) = rest.split_whitespace().next() {
+                    documented.push(name.to_string());
+                }
+            } else if let Some(colon_pos) = content.find(':') {
                 let name = content[..colon_pos].trim();
                 if !name.is_empty() && name.chars().all(|c| c.is_alphanumeric() || c == '_') {
                     documented.push(name.to_string());
@@ -356,6 +1416,27 @@ fn get_documented_path_params_for_block(lines: &[&str], rovo_line: usize) -> Vec
     documented
 }
 
+/// The `#[rovo]` line of the handler each line belongs to, indexed by line
+///
+/// Annotations belong to the handler of the next `#[rovo]` line.
+fn handler_lines(lines: &[&str]) -> Vec<Option<usize>> {
+    let mut handlers = vec![None; lines.len()];
+    let mut next_rovo = None;
+    for (idx, line) in lines.iter().enumerate().rev() {
+        if crate::parser::is_rovo_attribute(line) {
+            next_rovo = Some(idx);
+        }
+        handlers[idx] = next_rovo;
+    }
+    handlers
+}
+
+/// The `#[rovo]` line of the handler whose doc block contains `line`, from
+/// [`handler_lines`]
+fn handler_of(handlers: &[Option<usize>], line: usize) -> Option<usize> {
+    handlers.get(line).copied().flatten()
+}
+
 /// Find the fn line after a #[rovo] attribute
 fn find_fn_line_after_rovo(lines: &[&str], rovo_line: usize) -> Option<usize> {
     for (i, line) in lines.iter().enumerate().skip(rovo_line + 1) {
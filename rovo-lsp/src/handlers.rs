@@ -1,6 +1,7 @@
 use crate::completion;
 use crate::diagnostics;
 use crate::utils::{byte_index_to_utf16_col, utf16_pos_to_byte_index};
+use once_cell::sync::Lazy;
 use tower_lsp::lsp_types::*;
 
 /// Handle completion request for a text document
@@ -34,6 +35,18 @@ pub fn text_document_completion(content: &str, position: Position) -> Option<Com
             let kind = match item.kind {
                 completion::CompletionItemKind::Keyword => CompletionItemKind::KEYWORD,
                 completion::CompletionItemKind::Snippet => CompletionItemKind::SNIPPET,
+                completion::CompletionItemKind::Class => CompletionItemKind::CLASS,
+            };
+
+            // Only text with tab stops is a snippet; a stray `$` would otherwise be one
+            let insert_text_format = if item
+                .insert_text
+                .as_deref()
+                .is_some_and(has_snippet_placeholders)
+            {
+                InsertTextFormat::SNIPPET
+            } else {
+                InsertTextFormat::PLAIN_TEXT
             };
 
             CompletionItem {
@@ -47,7 +60,7 @@ pub fn text_document_completion(content: &str, position: Position) -> Option<Com
                     })
                 }),
                 insert_text: item.insert_text.clone(),
-                insert_text_format: Some(InsertTextFormat::SNIPPET),
+                insert_text_format: Some(insert_text_format),
                 ..Default::default()
             }
         })
@@ -56,6 +69,14 @@ pub fn text_document_completion(content: &str, position: Position) -> Option<Com
     Some(CompletionResponse::Array(lsp_items))
 }
 
+/// Whether completion text has snippet tab stops like `$1` or `${1:name}`
+fn has_snippet_placeholders(text: &str) -> bool {
+    text.match_indices('$').any(|(idx, _)| {
+        !text[..idx].ends_with('\\')
+            && text[idx + 1..].starts_with(|c: char| c == '{' || c.is_ascii_digit())
+    })
+}
+
 /// Handle hover request for a text document
 ///
 /// Provides information when hovering over status codes, security schemes, or types.
@@ -82,6 +103,17 @@ pub fn text_document_hover(content: &str, position: Position) -> Option<Hover> {
     let line = lines[line_idx];
     let char_idx = utf16_pos_to_byte_index(line, position.character as usize)?;
 
+    // Check if cursor is on an @external-docs URL
+    if let Some(link) = get_external_docs_link_at_position(line, char_idx) {
+        return Some(Hover {
+            contents: HoverContents::Markup(MarkupContent {
+                kind: MarkupKind::Markdown,
+                value: link,
+            }),
+            range: None,
+        });
+    }
+
     // Check if cursor is on a status code
     if let Some(status_info) = get_status_code_at_position(line, char_idx) {
         return Some(Hover {
@@ -108,15 +140,23 @@ pub fn text_document_hover(content: &str, position: Position) -> Option<Hover> {
     if let Some((response_type, _, _)) = crate::type_resolver::get_type_at_position(line, char_idx)
     {
         if let Some(type_name) = crate::type_resolver::extract_type_from_response(&response_type) {
-            if let Some(def_line) = crate::type_resolver::find_type_definition(content, &type_name)
+            let hover_text = if let Some(def_line) =
+                crate::type_resolver::find_type_definition(content, &type_name)
             {
-                let hover_text = format!(
+                Some(format!(
                     "**{}**\n\nDefined at line {}\n\n```rust\n{}\n```",
-                    type_name,
+                    crate::type_resolver::local_type_name(&type_name),
                     def_line + 1,
                     lines.get(def_line).unwrap_or(&"")
-                );
+                ))
+            } else if type_name.contains("::") {
+                // Qualified path defined elsewhere - still show what it refers to
+                Some(format!("**{}**\n\nDefined outside this file", type_name))
+            } else {
+                None
+            };
 
+            if let Some(hover_text) = hover_text {
                 return Some(Hover {
                     contents: HoverContents::Markup(MarkupContent {
                         kind: MarkupKind::Markdown,
@@ -132,10 +172,19 @@ pub fn text_document_hover(content: &str, position: Position) -> Option<Hover> {
     if let Some(annotation_type) = get_annotation_at_position(line, char_idx) {
         let documentation = crate::docs::get_annotation_documentation(&annotation_type);
 
+        // Lead the section's docs with an overview of the responses it lists
+        let value = match annotation_type.as_str() {
+            "section:responses" => match responses_table(content, line_idx) {
+                Some(table) => format!("{}\n\n---\n\n{}", table, documentation),
+                None => documentation.to_string(),
+            },
+            _ => documentation.to_string(),
+        };
+
         return Some(Hover {
             contents: HoverContents::Markup(MarkupContent {
                 kind: MarkupKind::Markdown,
-                value: documentation.to_string(),
+                value,
             }),
             range: None,
         });
@@ -144,6 +193,56 @@ pub fn text_document_hover(content: &str, position: Position) -> Option<Hover> {
     None
 }
 
+/// Summarize the responses of the `# Responses` section starting at `header_line`
+/// as a Markdown table
+fn responses_table(content: &str, header_line: usize) -> Option<String> {
+    let lines: Vec<&str> = content.lines().collect();
+
+    // The section runs until the next section header or the end of the doc block
+    let section_end = lines
+        .iter()
+        .enumerate()
+        .skip(header_line + 1)
+        .find(|(_, line)| {
+            let trimmed = line.trim();
+            !trimmed.starts_with("///")
+                || trimmed.trim_start_matches("///").trim().starts_with("# ")
+        })
+        .map_or(lines.len(), |(idx, _)| idx);
+
+    let rows: Vec<String> = crate::parser::parse_annotations(content)
+        .into_iter()
+        .filter(|ann| {
+            ann.kind == crate::parser::AnnotationKind::Response
+                && ann.line > header_line
+                && ann.line < section_end
+        })
+        .map(|ann| {
+            let status = ann
+                .status
+                .map(|status| status.to_string())
+                .or(ann.status_range)
+                .unwrap_or_default();
+            let escape = |text: &str| text.replace('|', "\\|");
+            format!(
+                "| {} | `{}` | {} |",
+                status,
+                escape(ann.response_type.as_deref().unwrap_or_default()),
+                escape(ann.description.as_deref().unwrap_or_default())
+            )
+        })
+        .collect();
+
+    if rows.is_empty() {
+        return None;
+    }
+
+    Some(format!(
+        "| Status | Type | Description |\n|---|---|---|\n{}",
+        rows.join("\n")
+    ))
+}
+
 fn get_annotation_at_position(line: &str, char_idx: usize) -> Option<String> {
     // Check if we're in a doc comment
     if !line.trim_start().starts_with("///") {
@@ -170,7 +269,28 @@ fn get_annotation_at_position(line: &str, char_idx: usize) -> Option<String> {
     }
 
     // Find the annotation keyword at the cursor position (for metadata section)
-    let annotations = ["@tag", "@security", "@id", "@hidden"];
+    let annotations = [
+        "@tag",
+        // Before "@security" so the longer keyword wins
+        "@security-and",
+        "@security",
+        // Before "@id" for the same reason
+        "@idempotent",
+        "@id",
+        "@hidden",
+        "@concurrency",
+        "@sunset",
+        "@summary",
+        "@server",
+        "@external-docs",
+        "@body",
+        "@errors",
+        "@header",
+        "@content-type",
+        "@produces",
+        "@ratelimit",
+        "@link",
+    ];
 
     for annotation in annotations {
         if let Some(pos) = line.find(annotation) {
@@ -184,6 +304,178 @@ fn get_annotation_at_position(line: &str, char_idx: usize) -> Option<String> {
     None
 }
 
+/// Handle signature help request for a text document
+///
+/// Shows the arguments expected by the annotation, or the `# Responses` /
+/// `# Examples` entry, being typed on the cursor line, with the argument under
+/// the cursor highlighted.
+///
+/// # Arguments
+/// * `content` - The document content
+/// * `position` - Cursor position where signature help was requested
+///
+/// # Returns
+/// The signature of the annotation or entry, or `None` if it takes no arguments
+pub fn signature_help(content: &str, position: Position) -> Option<SignatureHelp> {
+    let line_idx = position.line as usize;
+    if !crate::parser::is_near_rovo_attribute(content, line_idx) {
+        return None;
+    }
+
+    let lines: Vec<&str> = content.lines().collect();
+    let line = lines.get(line_idx)?;
+    let trimmed = line.trim_start();
+    if !trimmed.starts_with("///") {
+        return None;
+    }
+
+    let cursor = utf16_pos_to_byte_index(line, position.character as usize).unwrap_or(line.len());
+    let doc_start = line.len() - trimmed.len() + 3;
+    if cursor < doc_start {
+        return None;
+    }
+    let typed = crate::parser::strip_list_bullet(line[doc_start..cursor].trim_start());
+
+    let (syntax, docs, args) = if let Some(rest) = typed.strip_prefix('@') {
+        // Only once the annotation name is complete, i.e. followed by a space
+        let (name, args) = rest.split_once(char::is_whitespace)?;
+        let docs = crate::docs::get_annotation_documentation(&format!("@{name}"));
+        (annotation_syntax(docs)?, docs, args)
+    } else {
+        let (status, args) = typed.split_once(':')?;
+        if status.is_empty() || !status.chars().all(|c| c.is_ascii_alphanumeric()) {
+            return None;
+        }
+        match completion::detect_section_context(&lines, line_idx) {
+            completion::SectionContext::ResponsesSection => {
+                ("STATUS: TYPE - DESCRIPTION", RESPONSE_ENTRY_DOCS, args)
+            }
+            completion::SectionContext::ExamplesSection => {
+                ("STATUS: EXPRESSION", EXAMPLE_ENTRY_DOCS, args)
+            }
+            _ => return None,
+        }
+    };
+
+    let words: Vec<&str> = syntax.split_whitespace().collect();
+    // The first word is the annotation name or, for entries, the status code
+    let (parameters, word_params) = signature_parameters(syntax, &words[1..], docs);
+    if parameters.is_empty() {
+        return None;
+    }
+
+    // Map each argument typed so far onto the syntax, so a free-form last
+    // argument like a description keeps its parameter highlighted
+    let dash = words[1..].iter().position(|word| *word == "-");
+    let mut typed_args: Vec<&str> = args.split_whitespace().collect();
+    if args.is_empty() || args.ends_with(char::is_whitespace) {
+        typed_args.push("");
+    }
+    let mut word_idx = 0;
+    for arg in &typed_args[..typed_args.len() - 1] {
+        word_idx = match dash {
+            Some(dash) if *arg == "-" => dash + 1,
+            _ if words[1 + word_idx.min(words.len() - 2)].ends_with("...") => word_idx,
+            _ => word_idx + 1,
+        };
+    }
+    let word_idx = word_idx.min(word_params.len() - 1);
+    let active = word_params[word_idx..]
+        .iter()
+        .chain(word_params[..word_idx].iter().rev())
+        .find_map(|param| *param)
+        .unwrap_or(0);
+
+    let documentation = docs
+        .lines()
+        .skip(1)
+        .map(str::trim)
+        .find(|line| !line.is_empty())
+        .map(|summary| Documentation::String(summary.to_string()));
+
+    Some(SignatureHelp {
+        signatures: vec![SignatureInformation {
+            label: syntax.to_string(),
+            documentation,
+            parameters: Some(parameters),
+            active_parameter: Some(active),
+        }],
+        active_signature: Some(0),
+        active_parameter: Some(active),
+    })
+}
+
+const RESPONSE_ENTRY_DOCS: &str = "# Responses\n\n\
+    Document a response the handler can return.\n\n\
+    - `STATUS`: HTTP status code, e.g. `200` or `4XX`\n\
+    - `TYPE`: The response type, e.g. `Json<User>` or `()` for an empty body\n\
+    - `DESCRIPTION`: What the response means";
+
+const EXAMPLE_ENTRY_DOCS: &str = "# Examples\n\n\
+    Give an example value for a documented response.\n\n\
+    - `STATUS`: The status code of the response the example belongs to\n\
+    - `EXPRESSION`: A Rust expression building the example value";
+
+/// The syntax line of an annotation's documentation, e.g.
+/// `@header STATUS NAME TYPE DESCRIPTION`
+fn annotation_syntax(docs: &str) -> Option<&str> {
+    docs.lines()
+        .skip_while(|line| line.trim() != "## Syntax")
+        .map(str::trim)
+        .find_map(|line| line.strip_prefix("///"))
+        .map(str::trim)
+}
+
+/// Build the parameters of a signature from the placeholder words of its syntax
+///
+/// Returns the parameters along with the index of the parameter each word
+/// describes, or `None` for literal words like `-`.
+fn signature_parameters(
+    syntax: &str,
+    words: &[&str],
+    docs: &str,
+) -> (Vec<ParameterInformation>, Vec<Option<u32>>) {
+    let mut parameters = Vec::new();
+    let mut word_params = Vec::new();
+    let mut search_from = syntax.find(char::is_whitespace).unwrap_or(syntax.len());
+
+    for word in words {
+        let word_start = search_from + syntax[search_from..].find(word).unwrap_or(0);
+        search_from = word_start + word.len();
+
+        // `[SCOPES...]` names `SCOPES`, `per-WINDOW` names `WINDOW`
+        let Some((offset, name)) = word
+            .char_indices()
+            .find(|(_, c)| c.is_ascii_uppercase())
+            .map(|(offset, _)| {
+                let name = word[offset..]
+                    .split(|c: char| !(c.is_ascii_uppercase() || c == '_'))
+                    .next()
+                    .unwrap_or_default();
+                (offset, name)
+            })
+        else {
+            word_params.push(None);
+            continue;
+        };
+
+        let start = (word_start + offset) as u32;
+        let documentation = docs
+            .lines()
+            .filter_map(|line| line.trim().strip_prefix(&format!("- `{name}`")))
+            .find_map(|rest| rest.split_once(": "))
+            .map(|(_, description)| Documentation::String(description.to_string()));
+
+        word_params.push(Some(parameters.len() as u32));
+        parameters.push(ParameterInformation {
+            label: ParameterLabel::LabelOffsets([start, start + name.len() as u32]),
+            documentation,
+        });
+    }
+
+    (parameters, word_params)
+}
+
 /// Handle document change and return diagnostics
 ///
 /// # Arguments
@@ -202,6 +494,7 @@ pub fn text_document_did_change(content: &str, _uri: Url) -> Vec<Diagnostic> {
             let severity = match diag.severity {
                 diagnostics::DiagnosticSeverity::Error => DiagnosticSeverity::ERROR,
                 diagnostics::DiagnosticSeverity::Warning => DiagnosticSeverity::WARNING,
+                diagnostics::DiagnosticSeverity::Hint => DiagnosticSeverity::HINT,
             };
 
             // Get the line content to convert byte indices to UTF-16 positions
@@ -256,6 +549,809 @@ pub fn text_document_did_change(content: &str, _uri: Url) -> Vec<Diagnostic> {
         .collect()
 }
 
+/// An operation ID declared by a `#[rovo]` handler (explicit `@id` or the function name)
+pub(crate) struct OperationIdSite {
+    pub(crate) id: String,
+    pub(crate) range: Range,
+    /// Line of the handler's `#[rovo]` attribute
+    pub(crate) handler_line: usize,
+}
+
+/// Collect the operation IDs of every `#[rovo]` handler in a document
+pub(crate) fn collect_operation_ids(content: &str) -> Vec<OperationIdSite> {
+    let lines: Vec<&str> = content.lines().collect();
+    let mut sites = Vec::new();
+
+    let site = |handler_line: usize, line_idx: usize, start: usize, id: &str| {
+        let line = lines[line_idx];
+        OperationIdSite {
+            id: id.to_string(),
+            range: Range {
+                start: Position {
+                    line: line_idx as u32,
+                    character: byte_index_to_utf16_col(line, start) as u32,
+                },
+                end: Position {
+                    line: line_idx as u32,
+                    character: byte_index_to_utf16_col(line, start + id.len()) as u32,
+                },
+            },
+            handler_line,
+        }
+    };
+
+    for (rovo_idx, _) in lines
+        .iter()
+        .enumerate()
+        .filter(|(_, line)| crate::parser::is_rovo_attribute(line))
+    {
+        // An explicit @id in the doc block above takes precedence
+        let explicit = lines[..rovo_idx]
+            .iter()
+            .enumerate()
+            .rev()
+            .take_while(|(_, line)| {
+                let trimmed = line.trim();
+                trimmed.is_empty() || trimmed.starts_with("///") || trimmed.starts_with("#[")
+            })
+            .find_map(|(idx, line)| {
+                let at = line.find("@id ")?;
+                if !line[..at].trim().starts_with("///") {
+                    return None;
+                }
+                let value = line[at + 4..].split_whitespace().next()?;
+                let start = at + 4 + line[at + 4..].find(value)?;
+                Some(site(rovo_idx, idx, start, value))
+            });
+
+        if let Some(explicit) = explicit {
+            sites.push(explicit);
+            continue;
+        }
+
+        // Otherwise the function name is the default operation ID
+        let default = lines
+            .iter()
+            .enumerate()
+            .skip(rovo_idx + 1)
+            .take_while(|(_, line)| {
+                let trimmed = line.trim();
+                trimmed.is_empty() || trimmed.starts_with("#[") || trimmed.contains("fn ")
+            })
+            .find_map(|(idx, line)| {
+                let fn_pos = line.find("fn ")?;
+                let after = &line[fn_pos + 3..];
+                let name_len = after
+                    .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+                    .unwrap_or(after.len());
+                (name_len > 0).then(|| site(rovo_idx, idx, fn_pos + 3, &after[..name_len]))
+            });
+
+        sites.extend(default);
+    }
+
+    sites
+}
+
+/// Build diagnostics for operation IDs shared by handlers in different documents
+///
+/// Every colliding occurrence gets a warning whose `related_information` points at
+/// the occurrences in the other documents. Every input document is present in the
+/// result (possibly with no diagnostics) so stale warnings can be cleared.
+///
+/// # Arguments
+/// * `documents` - URI and content of each open document
+///
+/// # Returns
+/// Diagnostics per document URI
+pub fn duplicate_operation_id_diagnostics(
+    documents: &[(Url, &str)],
+) -> std::collections::HashMap<Url, Vec<Diagnostic>> {
+    let index: std::collections::HashMap<Url, Vec<OperationIdSite>> = documents
+        .iter()
+        .map(|(uri, content)| (uri.clone(), collect_operation_ids(content)))
+        .collect();
+    indexed_duplicate_operation_id_diagnostics(&index)
+}
+
+/// Like [`duplicate_operation_id_diagnostics`], from an index of each document's
+/// operation IDs so unchanged documents don't have to be scanned again
+pub(crate) fn indexed_duplicate_operation_id_diagnostics(
+    index: &std::collections::HashMap<Url, Vec<OperationIdSite>>,
+) -> std::collections::HashMap<Url, Vec<Diagnostic>> {
+    let mut by_id: std::collections::HashMap<&str, Vec<(&Url, &OperationIdSite)>> =
+        std::collections::HashMap::new();
+    for (uri, sites) in index {
+        for site in sites {
+            by_id.entry(&site.id).or_default().push((uri, site));
+        }
+    }
+
+    let mut result: std::collections::HashMap<Url, Vec<Diagnostic>> =
+        index.keys().map(|uri| (uri.clone(), Vec::new())).collect();
+
+    for (id, sites) in &by_id {
+        for (uri, site) in sites {
+            let others: Vec<DiagnosticRelatedInformation> = sites
+                .iter()
+                .filter(|(other_uri, _)| other_uri != uri)
+                .map(|(other_uri, other)| DiagnosticRelatedInformation {
+                    location: Location {
+                        uri: (*other_uri).clone(),
+                        range: other.range,
+                    },
+                    message: format!("Operation ID '{}' is also used here", id),
+                })
+                .collect();
+
+            if others.is_empty() {
+                continue;
+            }
+
+            let files: Vec<String> = others
+                .iter()
+                .map(|info| {
+                    let path = info.location.uri.path();
+                    format!(
+                        "{}:{}",
+                        path.rsplit('/').next().unwrap_or(path),
+                        info.location.range.start.line + 1
+                    )
+                })
+                .collect();
+
+            result.entry((*uri).clone()).or_default().push(Diagnostic {
+                range: site.range,
+                severity: Some(DiagnosticSeverity::WARNING),
+                source: Some("rovo-lsp".to_string()),
+                message: format!(
+                    "Operation ID '{}' is not unique: also used in {}",
+                    id,
+                    files.join(", ")
+                ),
+                code: None,
+                code_description: None,
+                related_information: Some(others),
+                tags: None,
+                data: None,
+            });
+        }
+    }
+
+    result
+}
+
+/// Handle document symbol request for a text document
+///
+/// Lists every `#[rovo]` handler as a function symbol spanning its doc block and
+/// body. Its doc comment sections are nested below it, each holding a symbol per
+/// entry (responses, examples, metadata annotations and path parameters).
+///
+/// # Arguments
+/// * `content` - The document content
+///
+/// # Returns
+/// The handler symbols, in document order
+pub fn document_symbol(content: &str) -> Vec<DocumentSymbol> {
+    use crate::parser::AnnotationKind;
+
+    let lines: Vec<&str> = content.lines().collect();
+    let annotations = crate::parser::parse_annotations(content);
+
+    let line_range = |line_idx: usize| Range {
+        start: Position {
+            line: line_idx as u32,
+            character: 0,
+        },
+        end: Position {
+            line: line_idx as u32,
+            character: byte_index_to_utf16_col(lines[line_idx], lines[line_idx].len()) as u32,
+        },
+    };
+    let word_range = |line_idx: usize, start: usize, len: usize| Range {
+        start: Position {
+            line: line_idx as u32,
+            character: byte_index_to_utf16_col(lines[line_idx], start) as u32,
+        },
+        end: Position {
+            line: line_idx as u32,
+            character: byte_index_to_utf16_col(lines[line_idx], start + len) as u32,
+        },
+    };
+    // Select `text` where it appears in the doc comment, or the whole line
+    let text_range = |line_idx: usize, text: &str| {
+        let line = lines[line_idx];
+        let doc_start = line.find("///").map_or(0, |pos| pos + 3);
+        line[doc_start..].find(text).map_or_else(
+            || line_range(line_idx),
+            |pos| word_range(line_idx, doc_start + pos, text.len()),
+        )
+    };
+
+    let entry_symbol = |ann: &crate::parser::Annotation| {
+        let text = lines[ann.line].trim().trim_start_matches("///").trim();
+        let text = crate::parser::strip_list_bullet(text);
+        let status = || {
+            ann.status
+                .map(|status| status.to_string())
+                .or_else(|| ann.status_range.clone())
+        };
+
+        let (name, detail, kind) = match ann.kind {
+            AnnotationKind::Response => (
+                status().unwrap_or_else(|| text.split(": ").next().unwrap_or(text).to_string()),
+                ann.response_type.clone(),
+                SymbolKind::PROPERTY,
+            ),
+            AnnotationKind::Example => (
+                status()?,
+                ann.example_value
+                    .as_deref()
+                    .and_then(|value| value.lines().next())
+                    .map(str::to_string),
+                SymbolKind::CONSTANT,
+            ),
+            AnnotationKind::PathParameter => (
+                ann.param_name.clone()?,
+                ann.param_description.clone(),
+                SymbolKind::VARIABLE,
+            ),
+            _ => {
+                let (keyword, rest) = text.split_once(char::is_whitespace).unwrap_or((text, ""));
+                (
+                    keyword.to_string(),
+                    Some(rest.trim().to_string()).filter(|rest| !rest.is_empty()),
+                    SymbolKind::KEY,
+                )
+            }
+        };
+
+        #[allow(deprecated)]
+        Some(DocumentSymbol {
+            selection_range: text_range(ann.line, &name),
+            name,
+            detail,
+            kind,
+            tags: None,
+            deprecated: None,
+            range: line_range(ann.line),
+            children: None,
+        })
+    };
+
+    let mut symbols = Vec::new();
+    for (rovo_idx, _) in lines
+        .iter()
+        .enumerate()
+        .filter(|(_, line)| crate::parser::is_rovo_attribute(line))
+    {
+        // `#[rovo]` on an impl block rather than a function isn't a handler
+        let Some((fn_idx, name_start, name)) = lines
+            .iter()
+            .enumerate()
+            .skip(rovo_idx + 1)
+            .take_while(|(_, line)| {
+                let trimmed = line.trim();
+                trimmed.is_empty() || trimmed.starts_with("#[") || trimmed.contains("fn ")
+            })
+            .find_map(|(idx, line)| {
+                let fn_pos = line.find("fn ")?;
+                let after = &line[fn_pos + 3..];
+                let name_len = after
+                    .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+                    .unwrap_or(after.len());
+                (name_len > 0).then(|| (idx, fn_pos + 3, &after[..name_len]))
+            })
+        else {
+            continue;
+        };
+        let Some((doc_start, fn_end)) = find_rovo_block_boundaries(content, rovo_idx) else {
+            continue;
+        };
+
+        let block: Vec<&crate::parser::Annotation> = annotations
+            .iter()
+            .filter(|ann| (doc_start..rovo_idx).contains(&ann.line))
+            .collect();
+        let sections = block
+            .iter()
+            .filter(|ann| {
+                matches!(
+                    ann.kind,
+                    AnnotationKind::ResponsesSection
+                        | AnnotationKind::ExamplesSection
+                        | AnnotationKind::MetadataSection
+                        | AnnotationKind::PathParametersSection
+                )
+            })
+            .map(|section| {
+                let end = section_content_end(&lines, section.line).unwrap_or(section.line);
+                let entries = block
+                    .iter()
+                    .filter(|ann| ann.line > section.line && ann.line <= end)
+                    .filter_map(|ann| entry_symbol(ann))
+                    .collect();
+                let title = lines[section.line]
+                    .trim()
+                    .trim_start_matches("///")
+                    .trim()
+                    .to_string();
+
+                #[allow(deprecated)]
+                DocumentSymbol {
+                    selection_range: text_range(section.line, &title),
+                    name: title,
+                    detail: None,
+                    kind: SymbolKind::NAMESPACE,
+                    tags: None,
+                    deprecated: None,
+                    range: Range {
+                        start: line_range(section.line).start,
+                        end: line_range(end).end,
+                    },
+                    children: Some(entries),
+                }
+            })
+            .collect();
+
+        #[allow(deprecated)]
+        symbols.push(DocumentSymbol {
+            name: name.to_string(),
+            detail: None,
+            kind: SymbolKind::FUNCTION,
+            tags: None,
+            deprecated: None,
+            range: Range {
+                start: line_range(doc_start).start,
+                end: line_range(fn_end).end,
+            },
+            selection_range: word_range(fn_idx, name_start, name.len()),
+            children: Some(sections),
+        });
+    }
+
+    symbols
+}
+
+/// The last non-empty line of the doc comment section whose header is on
+/// `header_line`, or `None` if the section is empty
+///
+/// The section ends at the next header or the end of the doc block.
+fn section_content_end(lines: &[&str], header_line: usize) -> Option<usize> {
+    lines
+        .iter()
+        .enumerate()
+        .skip(header_line + 1)
+        .map_while(|(idx, line)| {
+            let text = line.trim().strip_prefix("///")?.trim();
+            (!text.starts_with("# ")).then_some((idx, text))
+        })
+        .filter(|(_, text)| !text.is_empty())
+        .last()
+        .map(|(idx, _)| idx)
+}
+
+/// Handle folding range request for a text document
+///
+/// Each doc comment section (`# Responses`, `# Examples`, `# Metadata` and
+/// `# Path Parameters`) folds from its header to its last non-empty line.
+///
+/// # Arguments
+/// * `content` - The document content
+///
+/// # Returns
+/// A folding range for every section with content
+pub fn get_folding_ranges(content: &str) -> Vec<FoldingRange> {
+    use crate::parser::AnnotationKind;
+
+    let lines: Vec<&str> = content.lines().collect();
+
+    crate::parser::parse_annotations(content)
+        .into_iter()
+        .filter(|ann| {
+            matches!(
+                ann.kind,
+                AnnotationKind::ResponsesSection
+                    | AnnotationKind::ExamplesSection
+                    | AnnotationKind::MetadataSection
+                    | AnnotationKind::PathParametersSection
+            )
+        })
+        .filter_map(|ann| {
+            let end = section_content_end(&lines, ann.line)?;
+
+            Some(FoldingRange {
+                start_line: ann.line as u32,
+                start_character: None,
+                end_line: end as u32,
+                end_character: None,
+                kind: Some(FoldingRangeKind::Region),
+                collapsed_text: None,
+            })
+        })
+        .collect()
+}
+
+/// Handle selection range request for a text document
+///
+/// Expanding the selection inside a `#[rovo]` doc comment grows from the word
+/// under the cursor to its entry (e.g. a whole response line), its section, the
+/// doc block and finally the handler.
+///
+/// # Arguments
+/// * `content` - The document content
+/// * `positions` - Cursor positions to build selection ranges for
+///
+/// # Returns
+/// One selection range per position; positions outside a doc block get an
+/// empty range at the position
+pub fn get_selection_ranges(content: &str, positions: &[Position]) -> Vec<SelectionRange> {
+    let lines: Vec<&str> = content.lines().collect();
+    let annotations = crate::parser::parse_annotations(content);
+
+    positions
+        .iter()
+        .map(|&position| {
+            selection_range_at(content, &lines, &annotations, position).unwrap_or(SelectionRange {
+                range: Range {
+                    start: position,
+                    end: position,
+                },
+                parent: None,
+            })
+        })
+        .collect()
+}
+
+/// Build the nested selection ranges for a position inside a `#[rovo]` doc block
+fn selection_range_at(
+    content: &str,
+    lines: &[&str],
+    annotations: &[crate::parser::Annotation],
+    position: Position,
+) -> Option<SelectionRange> {
+    use crate::parser::AnnotationKind;
+
+    let line_idx = position.line as usize;
+    let line = lines.get(line_idx)?;
+    let is_doc = |line: &&str| line.trim_start().starts_with("///");
+    if !is_doc(line) || !crate::parser::is_near_rovo_attribute(content, line_idx) {
+        return None;
+    }
+
+    let pos = |line_idx: usize, byte_idx: usize| Position {
+        line: line_idx as u32,
+        character: byte_index_to_utf16_col(lines[line_idx], byte_idx) as u32,
+    };
+    let lines_range = |start: usize, end: usize| Range {
+        start: pos(start, 0),
+        end: pos(end, lines[end].len()),
+    };
+
+    // Outermost first: handler, doc block, section, entry, word
+    let mut ranges = Vec::new();
+
+    if let Some((doc_start, fn_end)) = find_rovo_block_boundaries(content, line_idx) {
+        ranges.push(lines_range(doc_start, fn_end));
+    }
+
+    let block_start = (0..=line_idx)
+        .rev()
+        .take_while(|&idx| is_doc(&lines[idx]))
+        .last()?;
+    let block_end = (line_idx..lines.len())
+        .take_while(|&idx| is_doc(&lines[idx]))
+        .last()?;
+    ranges.push(lines_range(block_start, block_end));
+
+    let in_block: Vec<&crate::parser::Annotation> = annotations
+        .iter()
+        .filter(|ann| (block_start..=block_end).contains(&ann.line))
+        .collect();
+    let is_section = |ann: &&&crate::parser::Annotation| {
+        matches!(
+            ann.kind,
+            AnnotationKind::ResponsesSection
+                | AnnotationKind::ExamplesSection
+                | AnnotationKind::MetadataSection
+                | AnnotationKind::PathParametersSection
+        )
+    };
+
+    let section = in_block
+        .iter()
+        .rev()
+        .filter(is_section)
+        .find(|ann| ann.line <= line_idx)
+        .map(|header| {
+            let end = section_content_end(lines, header.line).unwrap_or(header.line);
+            (header.line, end)
+        })
+        .filter(|&(_, end)| line_idx <= end);
+    if let Some((start, end)) = section {
+        ranges.push(lines_range(start, end));
+    }
+
+    // An entry runs until the next annotation or the end of its section,
+    // covering multi-line descriptions and examples
+    let limit = section.map_or(block_end, |(_, end)| end);
+    let entry = in_block
+        .iter()
+        .rev()
+        .find(|ann| !is_section(ann) && ann.line <= line_idx)
+        .and_then(|entry| {
+            let next = in_block
+                .iter()
+                .map(|ann| ann.line)
+                .filter(|&line| line > entry.line)
+                .min()
+                .map_or(limit, |line| line - 1)
+                .min(limit);
+            let end = (entry.line..=next).rev().find(|&idx| {
+                !lines[idx]
+                    .trim()
+                    .trim_start_matches("///")
+                    .trim()
+                    .is_empty()
+            })?;
+            (line_idx <= end).then_some((entry.line, end))
+        });
+    if let Some((start, end)) = entry {
+        let text_start = lines[start].find("///").map_or(0, |pos| pos + 3);
+        let text = &lines[start][text_start..];
+        let text_start = text_start + text.len() - text.trim_start().len();
+        ranges.push(Range {
+            start: pos(start, text_start),
+            end: pos(end, lines[end].len()),
+        });
+    }
+
+    // The word under the cursor, without the colon after a status code
+    let char_idx = utf16_pos_to_byte_index(line, position.character as usize)?;
+    let doc_start = line.find("///")? + 3;
+    if char_idx >= doc_start {
+        let word_start = line[..char_idx]
+            .rfind(char::is_whitespace)
+            .map_or(0, |pos| pos + 1)
+            .max(doc_start);
+        let word_end = line[char_idx..]
+            .find(char::is_whitespace)
+            .map_or(line.len(), |pos| char_idx + pos);
+        let word = line[word_start..word_end].trim_end_matches([':', ',']);
+        if !word.is_empty() {
+            ranges.push(Range {
+                start: pos(line_idx, word_start),
+                end: pos(line_idx, word_start + word.len()),
+            });
+        }
+    }
+
+    ranges.dedup();
+    ranges.into_iter().fold(None, |parent, range| {
+        Some(SelectionRange {
+            range,
+            parent: parent.map(Box::new),
+        })
+    })
+}
+
+/// Handle inlay hint request for a text document
+///
+/// Shows the operation summary the macro will use after each `#[rovo]`
+/// attribute in `range`: the `@summary` annotation if there is one, otherwise
+/// the first line of the doc comment.
+///
+/// # Arguments
+/// * `content` - The document content
+/// * `range` - The visible range hints are requested for
+///
+/// # Returns
+/// A hint per `#[rovo]` attribute with a summary
+pub fn get_inlay_hints(content: &str, range: Range) -> Vec<InlayHint> {
+    let lines: Vec<&str> = content.lines().collect();
+    let annotations = crate::parser::parse_annotations(content);
+    let visible = range.start.line as usize..=range.end.line as usize;
+
+    lines
+        .iter()
+        .enumerate()
+        .filter(|(idx, line)| visible.contains(idx) && crate::parser::is_rovo_attribute(line))
+        .filter_map(|(rovo_idx, line)| {
+            let doc_lines: Vec<(usize, &str)> = lines[..rovo_idx]
+                .iter()
+                .enumerate()
+                .rev()
+                .take_while(|(_, line)| {
+                    let trimmed = line.trim();
+                    trimmed.is_empty() || trimmed.starts_with("///") || trimmed.starts_with("#[")
+                })
+                .filter_map(|(idx, line)| Some((idx, line.trim().strip_prefix("///")?.trim())))
+                .collect();
+            let block_start = doc_lines.last().map_or(rovo_idx, |(idx, _)| *idx);
+
+            let explicit = annotations
+                .iter()
+                .filter(|ann| (block_start..rovo_idx).contains(&ann.line))
+                .find_map(|ann| ann.summary.clone());
+
+            // The title is the first text line, before any section starts
+            let title = explicit.or_else(|| {
+                doc_lines
+                    .iter()
+                    .rev()
+                    .map(|(_, text)| *text)
+                    .take_while(|text| !text.starts_with("# "))
+                    .find(|text| !text.is_empty() && !text.starts_with('@'))
+                    .map(str::to_string)
+            })?;
+
+            Some(InlayHint {
+                position: Position {
+                    line: rovo_idx as u32,
+                    character: byte_index_to_utf16_col(line, line.len()) as u32,
+                },
+                label: InlayHintLabel::String(title),
+                kind: None,
+                text_edits: None,
+                tooltip: Some(InlayHintTooltip::String("Operation summary".to_string())),
+                padding_left: Some(true),
+                padding_right: None,
+                data: None,
+            })
+        })
+        .collect()
+}
+
+/// Command run by code lenses to reveal a line, taking the document URI and line
+pub const SHOW_LOCATION_COMMAND: &str = "rovo.showLocation";
+
+/// Data carried by an unresolved code lens, turned into its command on resolve
+#[derive(serde::Serialize, serde::Deserialize)]
+struct CodeLensData {
+    title: String,
+    uri: Url,
+    line: u32,
+}
+
+/// Build the code lenses shown on each `#[rovo]` line
+///
+/// Every handler gets lenses for its operation ID, tag count and response count,
+/// which reveal the `@id` or function name, the first `@tag` and the
+/// `# Responses` header. The lenses are unresolved; [`resolve_code_lens`] adds
+/// their commands.
+///
+/// # Arguments
+/// * `content` - The document content
+/// * `uri` - Document URI, passed to the lens commands
+///
+/// # Returns
+/// Three code lenses per handler
+pub fn get_code_lenses(content: &str, uri: &Url) -> Vec<CodeLens> {
+    let lines: Vec<&str> = content.lines().collect();
+    let annotations = crate::parser::parse_annotations(content);
+    let operation_ids = collect_operation_ids(content);
+
+    let mut lenses = Vec::new();
+    for (rovo_idx, line) in lines
+        .iter()
+        .enumerate()
+        .filter(|(_, line)| crate::parser::is_rovo_attribute(line))
+    {
+        let block_start = lines[..rovo_idx]
+            .iter()
+            .rposition(|line| {
+                let trimmed = line.trim();
+                !(trimmed.is_empty() || trimmed.starts_with("///") || trimmed.starts_with("#["))
+            })
+            .map_or(0, |idx| idx + 1);
+        let block: Vec<&crate::parser::Annotation> = annotations
+            .iter()
+            .filter(|ann| (block_start..rovo_idx).contains(&ann.line))
+            .collect();
+        let count = |kind: crate::parser::AnnotationKind| {
+            block.iter().filter(|ann| ann.kind == kind).count()
+        };
+        let first_line = |kind: crate::parser::AnnotationKind| {
+            block
+                .iter()
+                .find(|ann| ann.kind == kind)
+                .map_or(rovo_idx, |ann| ann.line)
+        };
+        let plural = |n: usize, noun: &str| format!("{n} {noun}{}", if n == 1 { "" } else { "s" });
+
+        let operation_id = operation_ids
+            .iter()
+            .find(|site| site.handler_line == rovo_idx);
+        let tags = count(crate::parser::AnnotationKind::Tag);
+        let responses = count(crate::parser::AnnotationKind::Response);
+
+        let range = Range {
+            start: Position {
+                line: rovo_idx as u32,
+                character: 0,
+            },
+            end: Position {
+                line: rovo_idx as u32,
+                character: byte_index_to_utf16_col(line, line.len()) as u32,
+            },
+        };
+        let targets = [
+            operation_id.map(|site| (format!("op: {}", site.id), site.range.start.line as usize)),
+            Some((
+                plural(tags, "tag"),
+                first_line(crate::parser::AnnotationKind::Tag),
+            )),
+            Some((
+                plural(responses, "response"),
+                first_line(crate::parser::AnnotationKind::ResponsesSection),
+            )),
+        ];
+        for (title, target_line) in targets.into_iter().flatten() {
+            let data = CodeLensData {
+                title,
+                uri: uri.clone(),
+                line: target_line as u32,
+            };
+            lenses.push(CodeLens {
+                range,
+                command: None,
+                data: serde_json::to_value(data).ok(),
+            });
+        }
+    }
+
+    lenses
+}
+
+/// Add the command to a code lens from [`get_code_lenses`]
+///
+/// The command is [`SHOW_LOCATION_COMMAND`] with the document URI and the line
+/// to reveal. Lenses without rovo data are returned unchanged.
+pub fn resolve_code_lens(mut lens: CodeLens) -> CodeLens {
+    let Some(data) = lens
+        .data
+        .clone()
+        .and_then(|data| serde_json::from_value::<CodeLensData>(data).ok())
+    else {
+        return lens;
+    };
+
+    lens.command = Some(Command {
+        title: data.title,
+        command: SHOW_LOCATION_COMMAND.to_string(),
+        arguments: Some(vec![
+            serde_json::Value::String(data.uri.to_string()),
+            serde_json::Value::from(data.line),
+        ]),
+    });
+    lens
+}
+
+/// Parse a [`SHOW_LOCATION_COMMAND`] invocation into a request to show its line
+pub fn show_location_params(params: &ExecuteCommandParams) -> Option<ShowDocumentParams> {
+    if params.command != SHOW_LOCATION_COMMAND {
+        return None;
+    }
+    let [uri, line] = params.arguments.as_slice() else {
+        return None;
+    };
+    let uri = Url::parse(uri.as_str()?).ok()?;
+    let position = Position {
+        line: u32::try_from(line.as_u64()?).ok()?,
+        character: 0,
+    };
+
+    Some(ShowDocumentParams {
+        uri,
+        external: None,
+        take_focus: Some(true),
+        selection: Some(Range {
+            start: position,
+            end: position,
+        }),
+    })
+}
+
 /// Find all references to a tag in the document
 ///
 /// # Arguments
@@ -434,6 +1530,32 @@ pub fn find_path_param_references(
     }
 }
 
+/// Handle go-to-definition request for a text document
+///
+/// A path parameter jumps to its `# Path Parameters` entry; otherwise the type
+/// under the cursor in a response or example jumps to its Rust definition.
+///
+/// # Arguments
+/// * `content` - The document content
+/// * `position` - Cursor position where definition was requested
+/// * `uri` - Document URI for constructing locations
+///
+/// # Returns
+/// The definition location, or `None` if nothing under the cursor resolves
+pub fn goto_definition(
+    content: &str,
+    position: Position,
+    uri: Url,
+) -> Option<GotoDefinitionResponse> {
+    if !crate::parser::is_near_rovo_attribute(content, position.line as usize) {
+        return None;
+    }
+
+    goto_path_param_definition(content, position, uri.clone())
+        .or_else(|| type_definition(content, position, uri))
+        .map(GotoDefinitionResponse::Scalar)
+}
+
 /// Go to the definition of a path parameter (the doc comment)
 pub fn goto_path_param_definition(content: &str, position: Position, uri: Url) -> Option<Location> {
     let line_idx = position.line as usize;
@@ -639,6 +1761,73 @@ fn get_path_param_usage_at_position(
     Some(ident)
 }
 
+/// Go to the Rust definition of a type used in a response or example
+///
+/// The identifier under the cursor is tried first (so `Error` in
+/// `Result<User, Error>` resolves to `Error`), then the innermost type of the
+/// whole entry (so the cursor on `Json` still jumps to `User`).
+pub fn type_definition(content: &str, position: Position, uri: Url) -> Option<Location> {
+    let line_idx = position.line as usize;
+    let lines: Vec<&str> = content.lines().collect();
+    let line = lines.get(line_idx)?;
+
+    if !crate::parser::is_near_rovo_attribute(content, line_idx) {
+        return None;
+    }
+
+    let char_idx = utf16_pos_to_byte_index(line, position.character as usize)?;
+    let (response_type, start, end) = crate::type_resolver::get_type_at_position(line, char_idx)?;
+
+    // Identifier under the cursor, limited to the response/example token
+    let is_ident = |c: char| c.is_alphanumeric() || c == '_';
+    let word_start = line[start..char_idx]
+        .rfind(|c: char| !is_ident(c))
+        .map_or(start, |pos| start + pos + 1);
+    let word_end = line[char_idx..end]
+        .find(|c: char| !is_ident(c))
+        .map_or(end, |pos| char_idx + pos);
+    let word = &line[word_start..word_end];
+
+    let type_name = if !word.is_empty()
+        && crate::type_resolver::find_type_definition(content, word).is_some()
+    {
+        word.to_string()
+    } else {
+        crate::type_resolver::extract_type_from_response(&response_type)?
+    };
+
+    let def_line = crate::type_resolver::find_type_definition(content, &type_name)?;
+    let type_name = crate::type_resolver::local_type_name(&type_name);
+    let def_line_text = lines.get(def_line)?;
+
+    // Find the type name after the struct/enum/type keyword, ignoring comments
+    let code_part = def_line_text.split("//").next().unwrap_or(def_line_text);
+    let def_col = ["struct", "enum", "type"]
+        .iter()
+        .find_map(|keyword| {
+            let keyword_pos = code_part.find(keyword)?;
+            let after_keyword = &code_part[keyword_pos + keyword.len()..];
+            let name_pos = after_keyword.find(type_name)?;
+            Some(keyword_pos + keyword.len() + name_pos)
+        })
+        .or_else(|| code_part.find(type_name))
+        .unwrap_or(0);
+
+    Some(Location {
+        uri,
+        range: Range {
+            start: Position {
+                line: def_line as u32,
+                character: byte_index_to_utf16_col(def_line_text, def_col) as u32,
+            },
+            end: Position {
+                line: def_line as u32,
+                character: byte_index_to_utf16_col(def_line_text, def_col + type_name.len()) as u32,
+            },
+        },
+    })
+}
+
 pub fn find_tag_references(content: &str, position: Position, uri: Url) -> Option<Vec<Location>> {
     let line_idx = position.line as usize;
     let lines: Vec<&str> = content.lines().collect();
@@ -1312,12 +2501,15 @@ fn get_status_code_at_position(line: &str, char_idx: usize) -> Option<String> {
     let content = line.trim_start().trim_start_matches("///").trim();
 
     // Check if line contains status code patterns
-    // Format: "200: Type - Description" or "200: example_code"
+    // Format: "200: Type - Description", "StatusCode::OK: Type - Description"
+    // or "200: example_code"
+    let content = crate::parser::strip_list_bullet(content);
     let has_status_context = content
         .chars()
         .next()
         .map(|c| c.is_ascii_digit())
-        .unwrap_or(false);
+        .unwrap_or(false)
+        || content.starts_with("StatusCode::");
 
     if !has_status_context {
         return None;
@@ -1334,7 +2526,12 @@ fn get_status_code_at_position(line: &str, char_idx: usize) -> Option<String> {
             if char_idx >= abs_start && char_idx <= abs_end {
                 // Check if it's a status code potentially followed by a colon
                 let trimmed_word = word.trim_end_matches(':');
-                if let Ok(code) = trimmed_word.parse::<u16>() {
+                let code = trimmed_word.parse::<u16>().ok().or_else(|| {
+                    trimmed_word
+                        .strip_prefix("StatusCode::")
+                        .and_then(crate::parser::status_code_from_constant)
+                });
+                if let Some(code) = code {
                     if (100..=599).contains(&code) {
                         return Some(get_status_code_info(code));
                     }
@@ -1365,6 +2562,30 @@ fn get_status_code_info(code: u16) -> String {
     }
 }
 
+/// Render the URL of an `@external-docs` line as a link when the cursor is on it
+fn get_external_docs_link_at_position(line: &str, char_idx: usize) -> Option<String> {
+    if !line.trim_start().starts_with("///") {
+        return None;
+    }
+
+    let keyword = "@external-docs";
+    let after_keyword = line.find(keyword)? + keyword.len();
+    let rest = &line[after_keyword..];
+    let url = rest.split_whitespace().next()?;
+    let url_start = after_keyword + rest.find(url)?;
+    if char_idx < url_start || char_idx > url_start + url.len() {
+        return None;
+    }
+
+    let description = line[url_start + url.len()..].trim();
+    let text = if description.is_empty() {
+        url
+    } else {
+        description
+    };
+    Some(format!("**External documentation**\n\n[{}]({})", text, url))
+}
+
 fn get_security_scheme_at_position(line: &str, char_idx: usize) -> Option<String> {
     // Check if we're in a doc comment with @security
     if !line.trim_start().starts_with("///") {
@@ -1409,31 +2630,90 @@ fn get_security_scheme_at_position(line: &str, char_idx: usize) -> Option<String
 /// Generate semantic tokens for the document
 ///
 /// Token types (indices in legend):
-/// 0: KEYWORD - for annotations (@tag, @security, @id, @hidden, @rovo-ignore)
-/// 1: NUMBER - for status codes (200, 404, etc.)
+/// 0: KEYWORD - for annotations (@tag, @security, @security-and, @id, @hidden, @sunset, @body, @errors, @header, @content-type, @rovo-ignore)
+/// 1: NUMBER - for status codes (200, 404, 4XX, etc.)
 /// 2: TYPE - for security schemes (bearer, oauth2, etc.)
 pub fn semantic_tokens_full(content: &str) -> Option<SemanticTokensResult> {
-    eprintln!(
-        "[ROVO] semantic_tokens_full called, content length: {}",
-        content.len()
-    );
+    let tokens = semantic_tokens(content, 0..usize::MAX);
+
+    if tokens.is_empty() {
+        None
+    } else {
+        Some(SemanticTokensResult::Tokens(SemanticTokens {
+            result_id: None,
+            data: tokens,
+        }))
+    }
+}
+
+/// Generate semantic tokens for the lines in `range`
+///
+/// Editors request the visible range of large files, so only the lines it covers
+/// are tokenized. Token positions are still encoded relative to the document start.
+pub fn semantic_tokens_range(content: &str, range: Range) -> Option<SemanticTokensRangeResult> {
+    let lines = range.start.line as usize..range.end.line as usize + 1;
+    let tokens = semantic_tokens(content, lines);
+
+    if tokens.is_empty() {
+        None
+    } else {
+        Some(SemanticTokensRangeResult::Tokens(SemanticTokens {
+            result_id: None,
+            data: tokens,
+        }))
+    }
+}
+
+/// Any annotation from [`ANNOTATIONS`](crate::utils::ANNOTATIONS), e.g. `@tag`
+///
+/// Longer names come first so `@security-and` isn't matched as `@security`, and
+/// a name must end the word so `@tag-description` isn't matched as `@tag`. The
+/// annotation is capture group 1, without its `@`.
+static ANNOTATION_RE: Lazy<regex::Regex> = Lazy::new(|| {
+    let mut names: Vec<&str> = crate::utils::ANNOTATIONS.to_vec();
+    names.sort_by_key(|name| std::cmp::Reverse(name.len()));
+    let alternation = names
+        .iter()
+        .map(|name| regex::escape(name))
+        .collect::<Vec<_>>()
+        .join("|");
+    regex::Regex::new(&format!(r"@({alternation})(?:[^\w-]|$)")).unwrap()
+});
+
+/// Generate the delta-encoded semantic tokens for the lines in `lines`
+fn semantic_tokens(content: &str, lines: std::ops::Range<usize>) -> Vec<SemanticToken> {
     let mut tokens = Vec::new();
     let mut prev_line: u32 = 0;
     let mut prev_start: u32 = 0;
 
     // Compile regexes once outside the loop for efficiency
-    let annotation_regex = regex::Regex::new(r"@(tag|security|id|hidden|rovo-ignore)\b").unwrap();
     let tag_value_regex = regex::Regex::new(r"@(?:tag|id)\s+(\w+)").unwrap();
-    let status_regex = regex::Regex::new(r"\b([1-5][0-9]{2})\b").unwrap();
+    let status_regex = regex::Regex::new(r"\b([1-5][0-9]{2}|[1-5]XX)\b").unwrap();
     let security_regex = regex::Regex::new(r"\b(bearer|basic|apiKey|oauth2)\b").unwrap();
     let section_regex =
         regex::Regex::new(r"^///\s*#\s+(Path Parameters|Responses|Examples|Metadata)\b").unwrap();
     // Match path param lines: "/// param_name: description"
     let path_param_regex = regex::Regex::new(r"^///\s+(\w+):\s").unwrap();
 
-    let mut in_path_params_section = false;
+    // A range can start inside a # Path Parameters section
+    let all_lines: Vec<&str> = content.lines().collect();
+    let mut in_path_params_section = all_lines
+        .get(..lines.start.min(all_lines.len()))
+        .unwrap_or_default()
+        .iter()
+        .rev()
+        .take_while(|line| line.trim().starts_with("///"))
+        .find(|line| line.trim().starts_with("/// #"))
+        .and_then(|line| section_regex.captures(line.trim()))
+        .is_some_and(|cap| &cap[1] == "Path Parameters");
 
-    for (line_idx, line) in content.lines().enumerate() {
+    for (line_idx, line) in all_lines
+        .iter()
+        .copied()
+        .enumerate()
+        .take(lines.end)
+        .skip(lines.start)
+    {
         // Only process lines near #[rovo] attributes
         if !crate::parser::is_near_rovo_attribute(content, line_idx) {
             continue;
@@ -1511,12 +2791,17 @@ pub fn semantic_tokens_full(content: &str) -> Option<SemanticTokensResult> {
             }
         }
 
-        // Match annotations: @tag, @security, @id, @hidden, @rovo-ignore
-        for cap in annotation_regex.captures_iter(line) {
-            if let Some(m) = cap.get(0) {
-                let start_byte = m.start();
+        // Match annotations: @tag, @security-and, @rovo-ignore, ...
+        for cap in ANNOTATION_RE.captures_iter(line) {
+            if let Some(m) = cap.get(1) {
+                // Include the `@` before the name
+                let start_byte = m.start() - 1;
                 let start_col = byte_index_to_utf16_col(line, start_byte) as u32;
-                let length: u32 = m.as_str().chars().map(|ch| ch.len_utf16() as u32).sum();
+                let length: u32 = 1 + m
+                    .as_str()
+                    .chars()
+                    .map(|ch| ch.len_utf16() as u32)
+                    .sum::<u32>();
 
                 // Calculate delta encoding (UTF-16 units)
                 let delta_line = (line_idx as u32).saturating_sub(prev_line);
@@ -1621,14 +2906,5 @@ pub fn semantic_tokens_full(content: &str) -> Option<SemanticTokensResult> {
         }
     }
 
-    eprintln!("[ROVO] Found {} semantic tokens", tokens.len());
-
-    if tokens.is_empty() {
-        None
-    } else {
-        Some(SemanticTokensResult::Tokens(SemanticTokens {
-            result_id: None,
-            data: tokens,
-        }))
-    }
+    tokens
 }
@@ -4,7 +4,15 @@ use regex::Regex;
 // Static regex patterns to avoid recompilation
 static TAG_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"@tag\s+(\S+)").unwrap());
 static SECURITY_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"@security\s+(\S+)").unwrap());
+static SECURITY_AND_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"@security-and((?:\s+\S+)+)").unwrap());
+static SUMMARY_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"@summary\s+(.+)").unwrap());
+static SERVER_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"@server\s+(\S+)(?:\s+(.*))?").unwrap());
+static EXTERNAL_DOCS_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"@external-docs\s+(\S+)(?:\s+(.*))?").unwrap());
 static ID_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"@id\s+(\S+)").unwrap());
+static HEADER_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"@header\s+(\d+)\s+(\S+)\s+(\S+)(?:\s+(?:-\s+)?(.*))?").unwrap());
 
 /// Type of Rovo annotation
 #[derive(Debug, Clone, PartialEq)]
@@ -15,12 +23,22 @@ pub enum AnnotationKind {
     Tag,
     /// @security - Specify security scheme
     Security,
+    /// @security-and - Require several security schemes together
+    SecurityAnd,
     /// Example entry from # Examples section
     Example,
     /// @id - Set operation ID
     Id,
+    /// @summary - Set the operation summary explicitly
+    Summary,
+    /// @server - Declare a per-operation server
+    Server,
+    /// @external-docs - Link to external documentation
+    ExternalDocs,
     /// @hidden - Mark endpoint as hidden from docs
     Hidden,
+    /// @header - Document a response header
+    Header,
     /// # Responses section header
     ResponsesSection,
     /// # Examples section header
@@ -44,6 +62,8 @@ pub struct Annotation {
     // Response fields (from # Responses section)
     /// HTTP status code for response entries
     pub status: Option<u16>,
+    /// Status range (`2XX`) or `default` for response entries without an exact code
+    pub status_range: Option<String>,
     /// Response type (e.g., Json<User>)
     pub response_type: Option<String>,
     /// Description for response entries
@@ -56,6 +76,8 @@ pub struct Annotation {
     // Security fields
     /// Security scheme name for @security annotations
     pub security_scheme: Option<String>,
+    /// Security scheme names for @security-and annotations
+    pub security_schemes: Option<Vec<String>>,
 
     // Example fields (from # Examples section)
     /// Example value
@@ -65,6 +87,20 @@ pub struct Annotation {
     /// Operation ID for @id annotations
     pub operation_id: Option<String>,
 
+    // Summary fields
+    /// Summary text for @summary annotations
+    pub summary: Option<String>,
+
+    // URL fields
+    /// URL for @server and @external-docs annotations (the description uses the response field)
+    pub url: Option<String>,
+
+    // Header fields
+    /// Header name for @header annotations (status and description use the response fields)
+    pub header_name: Option<String>,
+    /// Header type for @header annotations
+    pub header_type: Option<String>,
+
     // Path parameter fields (from # Path Parameters section)
     /// Path parameter name
     pub param_name: Option<String>,
@@ -78,25 +114,38 @@ impl Annotation {
             kind,
             line,
             status: None,
+            status_range: None,
             response_type: None,
             description: None,
             tag_name: None,
             security_scheme: None,
+            security_schemes: None,
             example_value: None,
             operation_id: None,
+            summary: None,
+            url: None,
+            header_name: None,
+            header_type: None,
             param_name: None,
             param_description: None,
         }
     }
 }
 
+/// Check if a line is a `#[rovo]` attribute, with or without options like
+/// `#[rovo(code_samples)]`
+pub fn is_rovo_attribute(line: &str) -> bool {
+    let trimmed = line.trim();
+    trimmed == "#[rovo]" || (trimmed.starts_with("#[rovo(") && trimmed.ends_with(")]"))
+}
+
 /// Check if a given position (line number) is near a #[rovo] attribute
 pub fn is_near_rovo_attribute(content: &str, target_line: usize) -> bool {
     let lines: Vec<&str> = content.lines().collect();
 
     // Look ahead up to 20 lines to find a #[rovo] attribute
     for i in target_line..std::cmp::min(target_line + 20, lines.len()) {
-        if lines[i].trim() == "#[rovo]" || lines[i].contains("#[") && lines[i].contains("rovo") {
+        if is_rovo_attribute(lines[i]) || lines[i].contains("#[") && lines[i].contains("rovo") {
             return true;
         }
         // Stop if we hit a non-comment, non-attribute line
@@ -134,53 +183,17 @@ pub fn parse_annotations(content: &str) -> Vec<Annotation> {
     let lines: Vec<&str> = content.lines().collect();
     let mut annotations = Vec::new();
 
-    // Find all #[rovo] attributes
-    // Note: We only support the exact `#[rovo]` form for parsing, not variants like
-    // `#[rovo(...)]`. This is intentional - the simpler form matches actual usage.
-    // (is_near_rovo_attribute uses a broader check for LSP feature guards, but
-    // parsing only needs to handle the exact attribute form.)
+    // Find all #[rovo] and #[rovo(...)] attributes
     let mut rovo_positions = Vec::new();
     for (idx, line) in lines.iter().enumerate() {
-        if line.trim() == "#[rovo]" {
+        if is_rovo_attribute(line) {
             rovo_positions.push(idx);
         }
     }
 
     // For each #[rovo], collect doc comments and parse them
     for rovo_pos in rovo_positions {
-        // First, collect all doc comment lines above #[rovo]
-        let mut doc_lines = Vec::new();
-        let mut i = rovo_pos;
-        while i > 0 {
-            i -= 1;
-            let line = lines[i].trim();
-
-            // Skip empty lines
-            if line.is_empty() {
-                continue;
-            }
-
-            // Stop if we hit a non-doc-comment line
-            if !line.starts_with("///") {
-                break;
-            }
-
-            let doc_content = line.trim_start_matches("///").trim();
-
-            // Check for @rovo-ignore directive - everything AFTER this line (closer to #[rovo])
-            // should be ignored, so we clear doc_lines and continue collecting lines BEFORE it
-            if doc_content.starts_with("@rovo-ignore") {
-                doc_lines.clear();
-                continue;
-            }
-
-            // Only add lines if we haven't hit @rovo-ignore yet, or if we're past it
-            // Since we scan backwards, lines collected after clearing are BEFORE @rovo-ignore
-            doc_lines.push((i, line));
-        }
-
-        // Reverse to process in forward order
-        doc_lines.reverse();
+        let doc_lines = collect_doc_block(&lines, rovo_pos);
 
         // Now parse the doc lines in forward order
         let mut current_section: Option<Section> = None;
@@ -222,11 +235,22 @@ pub fn parse_annotations(content: &str) -> Vec<Annotation> {
                 continue;
             }
 
+            // @summary may appear anywhere in the doc block
+            if doc_content.starts_with("@summary") {
+                annotations.extend(parse_summary(doc_content, line_num));
+                idx += 1;
+                continue;
+            }
+
             // Parse content based on current section or annotation
             match current_section {
                 Some(Section::Responses) => {
-                    // Try to parse a multi-line response
-                    if let Some((ann, lines_consumed)) = parse_multiline_response(&doc_lines[idx..])
+                    // @header lines can sit next to the response they belong to
+                    if doc_content.starts_with("@header") {
+                        annotations.extend(parse_header(doc_content, line_num));
+                        idx += 1;
+                    } else if let Some((ann, lines_consumed)) =
+                        parse_multiline_response(&doc_lines[idx..])
                     {
                         annotations.push(ann);
                         idx += lines_consumed;
@@ -271,6 +295,46 @@ pub fn parse_annotations(content: &str) -> Vec<Annotation> {
     annotations
 }
 
+/// Collect the trimmed doc comment lines above the `#[rovo]` on `rovo_pos`, with
+/// their line numbers, in forward order
+///
+/// Lines after an `@rovo-ignore` directive (closer to `#[rovo]`) are left out.
+pub(crate) fn collect_doc_block<'a>(lines: &[&'a str], rovo_pos: usize) -> Vec<(usize, &'a str)> {
+    let mut doc_lines = Vec::new();
+    let mut i = rovo_pos;
+    while i > 0 {
+        i -= 1;
+        let line = lines[i].trim();
+
+        // Skip empty lines
+        if line.is_empty() {
+            continue;
+        }
+
+        // Stop if we hit a non-doc-comment line
+        if !line.starts_with("///") {
+            break;
+        }
+
+        let doc_content = line.trim_start_matches("///").trim();
+
+        // Check for @rovo-ignore directive - everything AFTER this line (closer to #[rovo])
+        // should be ignored, so we clear doc_lines and continue collecting lines BEFORE it
+        if doc_content.starts_with("@rovo-ignore") {
+            doc_lines.clear();
+            continue;
+        }
+
+        // Only add lines if we haven't hit @rovo-ignore yet, or if we're past it
+        // Since we scan backwards, lines collected after clearing are BEFORE @rovo-ignore
+        doc_lines.push((i, line));
+    }
+
+    // Reverse to process in forward order
+    doc_lines.reverse();
+    doc_lines
+}
+
 /// Parse a potentially multi-line response from # Responses section
 /// Format: STATUS: TYPE - DESCRIPTION (description can continue on following lines)
 /// Returns the annotation and the number of lines consumed
@@ -280,15 +344,22 @@ fn parse_multiline_response(doc_lines: &[(usize, &str)]) -> Option<(Annotation,
     }
 
     let (line_num, first_line) = doc_lines[0];
-    let content = first_line.trim_start_matches("///").trim();
+    let content = strip_list_bullet(first_line.trim_start_matches("///").trim());
 
-    // Check if this line starts with STATUS:
-    let colon_pos = content.find(':')?;
+    // Check if this line starts with STATUS: (a code, a `StatusCode::NAME`
+    // constant, a `2XX` range or `default`)
+    let colon_pos = response_status_end(content)?;
     let before_colon = content[..colon_pos].trim();
-    if !before_colon.chars().all(|c| c.is_ascii_digit()) {
+    let (status, status_range) = if let Some(name) = before_colon.strip_prefix("StatusCode::") {
+        // An unknown constant keeps no status so diagnostics can flag it
+        (status_code_from_constant(name.trim()), None)
+    } else if is_status_range(before_colon) {
+        (None, Some(before_colon.to_string()))
+    } else if before_colon.chars().all(|c| c.is_ascii_digit()) {
+        (Some(before_colon.parse::<u16>().ok()?), None)
+    } else {
         return None;
-    }
-    let status: u16 = before_colon.parse().ok()?;
+    };
 
     let after_colon = content[colon_pos + 1..].trim();
 
@@ -325,12 +396,15 @@ fn parse_multiline_response(doc_lines: &[(usize, &str)]) -> Option<(Annotation,
         }
 
         // New response entry (valid STATUS: format) ends the description
-        // Must look like a status code (3+ digits, parseable as u16)
-        let starts_new_response = if let Some(colon_pos) = next_content.find(':') {
-            let before_colon = next_content[..colon_pos].trim();
-            before_colon.len() >= 3
-                && before_colon.chars().all(|c| c.is_ascii_digit())
-                && before_colon.parse::<u16>().is_ok()
+        // Must look like a status code (3+ digits, parseable as u16) or a range/default
+        let entry = strip_list_bullet(next_content);
+        let starts_new_response = if let Some(colon_pos) = response_status_end(entry) {
+            let before_colon = entry[..colon_pos].trim();
+            before_colon.starts_with("StatusCode::")
+                || is_status_range(before_colon)
+                || before_colon.len() >= 3
+                    && before_colon.chars().all(|c| c.is_ascii_digit())
+                    && before_colon.parse::<u16>().is_ok()
         } else {
             false
         };
@@ -345,8 +419,9 @@ fn parse_multiline_response(doc_lines: &[(usize, &str)]) -> Option<(Annotation,
     }
 
     let mut ann = Annotation::new(AnnotationKind::Response, line_num);
-    ann.status = Some(status);
-    ann.response_type = Some(response_type);
+    ann.status = status;
+    ann.status_range = status_range;
+    ann.response_type = Some(expand_array_shorthand(&response_type));
     if !description_parts.is_empty() {
         ann.description = Some(description_parts.join(" "));
     }
@@ -354,10 +429,113 @@ fn parse_multiline_response(doc_lines: &[(usize, &str)]) -> Option<(Annotation,
     Some((ann, lines_consumed))
 }
 
+/// Strip a Markdown list bullet (`- ` or `* `) from the start of a section entry
+pub(crate) fn strip_list_bullet(content: &str) -> &str {
+    content
+        .strip_prefix("- ")
+        .or_else(|| content.strip_prefix("* "))
+        .map_or(content, str::trim_start)
+}
+
+/// Well-known `StatusCode` constants accepted in place of numeric status codes, matching the macro
+const STATUS_CODE_NAMES: &[(&str, u16)] = &[
+    ("CONTINUE", 100),
+    ("SWITCHING_PROTOCOLS", 101),
+    ("OK", 200),
+    ("CREATED", 201),
+    ("ACCEPTED", 202),
+    ("NON_AUTHORITATIVE_INFORMATION", 203),
+    ("NO_CONTENT", 204),
+    ("RESET_CONTENT", 205),
+    ("PARTIAL_CONTENT", 206),
+    ("MOVED_PERMANENTLY", 301),
+    ("FOUND", 302),
+    ("SEE_OTHER", 303),
+    ("NOT_MODIFIED", 304),
+    ("TEMPORARY_REDIRECT", 307),
+    ("PERMANENT_REDIRECT", 308),
+    ("BAD_REQUEST", 400),
+    ("UNAUTHORIZED", 401),
+    ("PAYMENT_REQUIRED", 402),
+    ("FORBIDDEN", 403),
+    ("NOT_FOUND", 404),
+    ("METHOD_NOT_ALLOWED", 405),
+    ("NOT_ACCEPTABLE", 406),
+    ("REQUEST_TIMEOUT", 408),
+    ("CONFLICT", 409),
+    ("GONE", 410),
+    ("LENGTH_REQUIRED", 411),
+    ("PRECONDITION_FAILED", 412),
+    ("PAYLOAD_TOO_LARGE", 413),
+    ("URI_TOO_LONG", 414),
+    ("UNSUPPORTED_MEDIA_TYPE", 415),
+    ("RANGE_NOT_SATISFIABLE", 416),
+    ("EXPECTATION_FAILED", 417),
+    ("IM_A_TEAPOT", 418),
+    ("UNPROCESSABLE_ENTITY", 422),
+    ("LOCKED", 423),
+    ("FAILED_DEPENDENCY", 424),
+    ("UPGRADE_REQUIRED", 426),
+    ("PRECONDITION_REQUIRED", 428),
+    ("TOO_MANY_REQUESTS", 429),
+    ("REQUEST_HEADER_FIELDS_TOO_LARGE", 431),
+    ("UNAVAILABLE_FOR_LEGAL_REASONS", 451),
+    ("INTERNAL_SERVER_ERROR", 500),
+    ("NOT_IMPLEMENTED", 501),
+    ("BAD_GATEWAY", 502),
+    ("SERVICE_UNAVAILABLE", 503),
+    ("GATEWAY_TIMEOUT", 504),
+    ("HTTP_VERSION_NOT_SUPPORTED", 505),
+];
+
+/// Resolve a `StatusCode::NAME` constant (without the prefix) to its numeric code
+pub fn status_code_from_constant(name: &str) -> Option<u16> {
+    STATUS_CODE_NAMES
+        .iter()
+        .find(|(known, _)| *known == name)
+        .map(|&(_, code)| code)
+}
+
+/// Position of the colon ending a response entry's status, skipping the `::` in `StatusCode::NAME`
+pub(crate) fn response_status_end(content: &str) -> Option<usize> {
+    let skip = if content.starts_with("StatusCode::") {
+        "StatusCode::".len()
+    } else {
+        0
+    };
+    content[skip..].find(':').map(|pos| pos + skip)
+}
+
+/// Whether a response status is a range like `2XX` or `default`
+///
+/// Any leading digit is accepted so that diagnostics can flag ranges like `6XX`.
+fn is_status_range(status: &str) -> bool {
+    status == "default"
+        || status.len() == 3
+            && status.starts_with(|c: char| c.is_ascii_digit())
+            && status.ends_with("XX")
+}
+
+/// Expand the `[T]` array shorthand to `Json<Vec<T>>`, matching the macro
+///
+/// Fixed-size array types like `[u8; 4]` and non-bracketed types are returned unchanged.
+pub fn expand_array_shorthand(response_type: &str) -> String {
+    let trimmed = response_type.trim();
+    trimmed
+        .strip_prefix('[')
+        .and_then(|rest| rest.strip_suffix(']'))
+        .map(str::trim)
+        .filter(|inner| !inner.is_empty() && !inner.contains(';'))
+        .map_or_else(
+            || trimmed.to_string(),
+            |inner| format!("Json<Vec<{}>>", inner),
+        )
+}
+
 /// Count delimiter depths while ignoring delimiters inside string/char literals
 /// Handles regular strings, raw strings (r"...", r#"..."#), and char literals
 /// Returns (brace_depth, bracket_depth, paren_depth)
-fn count_delimiters(content: &str) -> (i32, i32, i32) {
+pub(crate) fn count_delimiters(content: &str) -> (i32, i32, i32) {
     let mut brace_depth = 0i32;
     let mut bracket_depth = 0i32;
     let mut paren_depth = 0i32;
@@ -452,13 +630,13 @@ fn count_delimiters(content: &str) -> (i32, i32, i32) {
 
 /// Parse a potentially multi-line example from # Examples section
 /// Returns the annotation and the number of lines consumed
-fn parse_multiline_example(doc_lines: &[(usize, &str)]) -> Option<(Annotation, usize)> {
+pub(crate) fn parse_multiline_example(doc_lines: &[(usize, &str)]) -> Option<(Annotation, usize)> {
     if doc_lines.is_empty() {
         return None;
     }
 
     let (line_num, first_line) = doc_lines[0];
-    let content = first_line.trim_start_matches("///").trim();
+    let content = strip_list_bullet(first_line.trim_start_matches("///").trim());
 
     // Check if this line starts with STATUS:
     let colon_pos = content.find(':')?;
@@ -556,15 +734,23 @@ fn parse_annotation_line(line: &str, line_num: usize) -> Option<Annotation> {
         return None;
     }
 
-    // Parse metadata annotations (@tag, @security, @id, @hidden)
-    if content.starts_with("@tag") {
+    // Parse metadata annotations (@tag, @security, @security-and, @id, @hidden, @header, @server, @external-docs)
+    if content.split_whitespace().next() == Some("@tag") {
         parse_tag(content, line_num)
+    } else if content.starts_with("@security-and") {
+        parse_security_and(content, line_num)
     } else if content.starts_with("@security") {
         parse_security(content, line_num)
     } else if content.starts_with("@id") {
         parse_id(content, line_num)
     } else if content.starts_with("@hidden") {
         Some(Annotation::new(AnnotationKind::Hidden, line_num))
+    } else if content.starts_with("@header") {
+        parse_header(content, line_num)
+    } else if content.starts_with("@server") {
+        parse_server(content, line_num)
+    } else if content.starts_with("@external-docs") {
+        parse_external_docs(content, line_num)
     } else {
         None
     }
@@ -631,6 +817,22 @@ fn parse_security(content: &str, line_num: usize) -> Option<Annotation> {
     }
 }
 
+fn parse_security_and(content: &str, line_num: usize) -> Option<Annotation> {
+    // Format: @security-and SCHEME SCHEME...
+    let captures = SECURITY_AND_RE.captures(content)?;
+    let schemes: Vec<String> = captures
+        .get(1)?
+        .as_str()
+        .split_whitespace()
+        .map(str::to_string)
+        .collect();
+
+    let mut ann = Annotation::new(AnnotationKind::SecurityAnd, line_num);
+    ann.security_schemes = Some(schemes);
+
+    Some(ann)
+}
+
 fn parse_id(content: &str, line_num: usize) -> Option<Annotation> {
     // Format: @id OPERATION_ID
     if let Some(captures) = ID_RE.captures(content) {
@@ -645,10 +847,76 @@ fn parse_id(content: &str, line_num: usize) -> Option<Annotation> {
     }
 }
 
+fn parse_summary(content: &str, line_num: usize) -> Option<Annotation> {
+    // Format: @summary TEXT
+    let captures = SUMMARY_RE.captures(content)?;
+
+    let mut ann = Annotation::new(AnnotationKind::Summary, line_num);
+    ann.summary = Some(captures.get(1)?.as_str().trim().to_string());
+
+    Some(ann)
+}
+
+fn parse_server(content: &str, line_num: usize) -> Option<Annotation> {
+    // Format: @server URL [DESCRIPTION]
+    parse_url_annotation(&SERVER_RE, AnnotationKind::Server, content, line_num)
+}
+
+fn parse_external_docs(content: &str, line_num: usize) -> Option<Annotation> {
+    // Format: @external-docs URL [DESCRIPTION]
+    parse_url_annotation(
+        &EXTERNAL_DOCS_RE,
+        AnnotationKind::ExternalDocs,
+        content,
+        line_num,
+    )
+}
+
+fn parse_url_annotation(
+    re: &Regex,
+    kind: AnnotationKind,
+    content: &str,
+    line_num: usize,
+) -> Option<Annotation> {
+    let captures = re.captures(content)?;
+
+    let mut ann = Annotation::new(kind, line_num);
+    ann.url = Some(captures.get(1)?.as_str().to_string());
+    ann.description = captures
+        .get(2)
+        .map(|m| m.as_str().trim().to_string())
+        .filter(|description| !description.is_empty());
+
+    Some(ann)
+}
+
+fn parse_header(content: &str, line_num: usize) -> Option<Annotation> {
+    // Format: @header STATUS NAME TYPE DESCRIPTION
+    let captures = HEADER_RE.captures(content)?;
+
+    let mut ann = Annotation::new(AnnotationKind::Header, line_num);
+    ann.status = captures.get(1)?.as_str().parse().ok();
+    ann.header_name = Some(captures.get(2)?.as_str().to_string());
+    ann.header_type = Some(captures.get(3)?.as_str().to_string());
+    ann.description = captures.get(4).map(|m| m.as_str().trim().to_string());
+
+    Some(ann)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_is_rovo_attribute() {
+        assert!(is_rovo_attribute("#[rovo]"));
+        assert!(is_rovo_attribute(
+            "    #[rovo(code_samples, strict_responses)]"
+        ));
+        assert!(!is_rovo_attribute("#[rovo_module]"));
+        assert!(!is_rovo_attribute("/// #[rovo]"));
+    }
+
     #[test]
     fn test_parse_tag() {
         let line = "/// @tag users";
@@ -665,6 +933,74 @@ mod tests {
         assert_eq!(ann.security_scheme, Some("bearer".to_string()));
     }
 
+    #[test]
+    fn test_parse_security_and() {
+        let line = "/// @security-and bearer apiKey";
+        let ann = parse_annotation_line(line, 0).unwrap();
+        assert_eq!(ann.kind, AnnotationKind::SecurityAnd);
+        assert_eq!(
+            ann.security_schemes,
+            Some(vec!["bearer".to_string(), "apiKey".to_string()])
+        );
+        assert_eq!(ann.security_scheme, None);
+    }
+
+    #[test]
+    fn test_summary_anywhere_in_doc_block() {
+        let content = r#"
+/// Fetches a single user record by its numeric identifier.
+/// @summary Get user by ID
+///
+/// # Responses
+///
+/// 200: Json<User> - Found
+/// @summary Second summary
+#[rovo]
+async fn get_user() {}
+"#;
+        let annotations = parse_annotations(content);
+        let summaries: Vec<_> = annotations
+            .iter()
+            .filter(|a| a.kind == AnnotationKind::Summary)
+            .filter_map(|a| a.summary.as_deref())
+            .collect();
+        assert_eq!(summaries, vec!["Get user by ID", "Second summary"]);
+        assert_eq!(
+            annotations
+                .iter()
+                .filter(|a| a.kind == AnnotationKind::Response)
+                .count(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_parse_server() {
+        let line = "/// @server https://uploads.example.com Upload server";
+        let ann = parse_annotation_line(line, 0).unwrap();
+        assert_eq!(ann.kind, AnnotationKind::Server);
+        assert_eq!(ann.url, Some("https://uploads.example.com".to_string()));
+        assert_eq!(ann.description, Some("Upload server".to_string()));
+
+        let ann = parse_annotation_line("/// @server https://uploads.example.com", 0).unwrap();
+        assert_eq!(ann.description, None);
+    }
+
+    #[test]
+    fn test_parse_external_docs() {
+        let line = "/// @external-docs https://docs.example.com/api/users See the full users guide";
+        let ann = parse_annotation_line(line, 0).unwrap();
+        assert_eq!(ann.kind, AnnotationKind::ExternalDocs);
+        assert_eq!(
+            ann.url,
+            Some("https://docs.example.com/api/users".to_string())
+        );
+        assert_eq!(
+            ann.description,
+            Some("See the full users guide".to_string())
+        );
+    }
+
     #[test]
     fn test_parse_id() {
         let line = "/// @id getUserById";
@@ -680,6 +1016,44 @@ mod tests {
         assert_eq!(ann.kind, AnnotationKind::Hidden);
     }
 
+    #[test]
+    fn test_parse_header() {
+        let line = "/// @header 201 Location string The URL of the created resource";
+        let ann = parse_annotation_line(line, 0).unwrap();
+        assert_eq!(ann.kind, AnnotationKind::Header);
+        assert_eq!(ann.status, Some(201));
+        assert_eq!(ann.header_name.as_deref(), Some("Location"));
+        assert_eq!(ann.header_type.as_deref(), Some("string"));
+        assert_eq!(
+            ann.description.as_deref(),
+            Some("The URL of the created resource")
+        );
+    }
+
+    #[test]
+    fn test_parse_header_in_responses_section() {
+        let content = r#"/// # Responses
+///
+/// 201: Json<User> - Created
+/// @header 201 Location string The URL of the created user
+/// 404: () - Not found
+#[rovo]
+async fn handler() {}
+"#;
+        let annotations = parse_annotations(content);
+        let kinds: Vec<_> = annotations.iter().map(|a| a.kind.clone()).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                AnnotationKind::ResponsesSection,
+                AnnotationKind::Response,
+                AnnotationKind::Header,
+                AnnotationKind::Response,
+            ]
+        );
+        assert_eq!(annotations[1].description.as_deref(), Some("Created"));
+    }
+
     #[test]
     fn test_parse_rust_style_responses() {
         let content = r#"
@@ -709,6 +1083,24 @@ async fn handler() {}
         );
     }
 
+    #[test]
+    fn test_parse_array_shorthand_response() {
+        let content = r#"
+/// # Responses
+///
+/// 200: [User] - List of users
+#[rovo]
+async fn handler() {}
+"#;
+        let annotations = parse_annotations(content);
+        let response = annotations
+            .iter()
+            .find(|a| a.kind == AnnotationKind::Response)
+            .unwrap();
+        assert_eq!(response.response_type, Some("Json<Vec<User>>".to_string()));
+        assert_eq!(response.description, Some("List of users".to_string()));
+    }
+
     #[test]
     fn test_parse_rust_style_examples() {
         let content = r#"
@@ -756,6 +1148,47 @@ async fn handler() {}
             .any(|a| a.kind == AnnotationKind::Security));
     }
 
+    #[test]
+    fn test_parse_bulleted_entries() {
+        let parse = |bullet: &str| {
+            let content = format!(
+                "
+/// # Responses
+///
+/// {bullet}200: Json<User> - Success
+/// {bullet}404: () - No user
+/// with that ID
+///
+/// # Examples
+///
+/// {bullet}200: User::default()
+#[rovo]
+async fn handler() {{}}
+"
+            );
+            parse_annotations(&content)
+                .into_iter()
+                .filter(|a| matches!(a.kind, AnnotationKind::Response | AnnotationKind::Example))
+                .map(|a| {
+                    (
+                        a.kind,
+                        a.line,
+                        a.status,
+                        a.response_type,
+                        a.description,
+                        a.example_value,
+                    )
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let plain = parse("");
+        assert_eq!(plain.len(), 3);
+        assert_eq!(plain[1].4.as_deref(), Some("No user with that ID"));
+        assert_eq!(parse("- "), plain);
+        assert_eq!(parse("* "), plain);
+    }
+
     #[test]
     fn test_parse_multiline_example() {
         let content = r#"
@@ -1099,4 +1532,26 @@ async fn handler() {}
             Some("Zero-based item index".to_string())
         );
     }
+
+    #[test]
+    fn test_rovo_on_first_line_has_no_annotations() {
+        let content = "#[rovo]\nasync fn handler() -> impl IntoApiResponse {\n    Json(())\n}\n";
+        let annotations = parse_annotations(content);
+        assert!(annotations.is_empty());
+    }
+
+    #[test]
+    fn test_rovo_on_first_line_does_not_affect_later_handlers() {
+        let content = r#"#[rovo]
+async fn first() {}
+
+/// @tag users
+#[rovo]
+async fn second() {}
+"#;
+        let annotations = parse_annotations(content);
+        assert_eq!(annotations.len(), 1);
+        assert_eq!(annotations[0].kind, AnnotationKind::Tag);
+        assert_eq!(annotations[0].line, 3);
+    }
 }
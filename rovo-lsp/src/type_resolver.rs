@@ -22,10 +22,15 @@ static ANNOTATION_TYPE_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"///\s*\d+:\s*
 /// - `"Json<TodoItem>"` -> `"TodoItem"`
 /// - `"Json<Vec<TodoItem>>"` -> `"TodoItem"`
 /// - `"Vec<Option<User>>"` -> `"User"`
+/// - `"[User]"` -> `"User"`
 /// - `"TodoItem"` -> `"TodoItem"`
 pub fn extract_type_from_response(response_type: &str) -> Option<String> {
     let trimmed = response_type.trim();
-    if let Some(captures) = WRAPPER_RE.captures(trimmed) {
+    let expanded = crate::parser::expand_array_shorthand(trimmed);
+    if expanded != trimmed {
+        // `[T]` array shorthand
+        extract_type_from_response(&expanded)
+    } else if let Some(captures) = WRAPPER_RE.captures(trimmed) {
         let inner = captures.get(1)?.as_str();
         // Recursively unwrap nested generics
         extract_type_from_response(inner)
@@ -35,9 +40,28 @@ pub fn extract_type_from_response(response_type: &str) -> Option<String> {
     }
 }
 
+/// Strip any module path from a type name, leaving the final path segment
+///
+/// # Examples
+/// - `"crate::models::User"` -> `"User"`
+/// - `"User"` -> `"User"`
+pub fn local_type_name(type_name: &str) -> &str {
+    let trimmed = type_name.trim();
+    trimmed
+        .rsplit("::")
+        .next()
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .unwrap_or(trimmed)
+}
+
 /// Find the definition line of a type in the content
+///
+/// Fully-qualified names like `crate::models::User` are resolved by their
+/// final segment, so a local `struct User` definition is found.
 pub fn find_type_definition(content: &str, type_name: &str) -> Option<usize> {
     let lines: Vec<&str> = content.lines().collect();
+    let type_name = local_type_name(type_name);
 
     // Build dynamic pattern for the type name (need to match word boundary)
     let type_pattern = format!(r"{}\b", regex::escape(type_name));
@@ -55,6 +79,55 @@ pub fn find_type_definition(content: &str, type_name: &str) -> Option<usize> {
     None
 }
 
+/// Find every struct, enum and type alias defined in the content
+///
+/// Returns each type's name and definition line, in document order.
+pub fn find_type_definitions(content: &str) -> Vec<(String, usize)> {
+    content
+        .lines()
+        .enumerate()
+        .filter_map(|(idx, line)| {
+            let keyword_end = [&*STRUCT_RE, &*ENUM_RE, &*TYPE_RE]
+                .iter()
+                .find_map(|re| re.find(line))?
+                .end();
+            let name: String = line[keyword_end..]
+                .chars()
+                .take_while(|c| c.is_alphanumeric() || *c == '_')
+                .collect();
+            (!name.is_empty()).then_some((name, idx))
+        })
+        .collect()
+}
+
+/// Find the definition line of a struct or enum that doesn't derive `trait_name`
+///
+/// Returns `None` when the type derives it or isn't defined in the content. The
+/// attributes above the definition are searched, including `#[derive(...)]`
+/// lists split over several lines.
+pub fn find_missing_derive(content: &str, type_name: &str, trait_name: &str) -> Option<usize> {
+    let lines: Vec<&str> = content.lines().collect();
+    let type_name = local_type_name(type_name);
+
+    let definition = lines.iter().position(|line| {
+        [&*STRUCT_RE, &*ENUM_RE]
+            .iter()
+            .find_map(|re| re.find(line))
+            .and_then(|keyword| line[keyword.end()..].strip_prefix(type_name))
+            .is_some_and(|after| !after.starts_with(|c: char| c.is_alphanumeric() || c == '_'))
+    })?;
+
+    // Attributes and doc comments run up to the end of the previous item
+    let derives = lines[..definition]
+        .iter()
+        .map(|line| line.trim())
+        .rev()
+        .take_while(|line| !line.is_empty() && !line.ends_with(['}', ';', '{']))
+        .any(|line| !line.starts_with("//") && line.contains(trait_name));
+
+    (!derives).then_some(definition)
+}
+
 /// Check if cursor is on a type in an annotation
 pub fn get_type_at_position(line: &str, char_idx: usize) -> Option<(String, usize, usize)> {
     // Pattern: /// 200: Json<TodoItem> - Description (in # Responses section)
@@ -116,6 +189,29 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_extract_type_keeps_module_path() {
+        assert_eq!(
+            extract_type_from_response("Json<crate::models::User>"),
+            Some("crate::models::User".to_string())
+        );
+    }
+
+    #[test]
+    fn test_extract_type_from_array_shorthand() {
+        assert_eq!(
+            extract_type_from_response("[User]"),
+            Some("User".to_string())
+        );
+    }
+
+    #[test]
+    fn test_local_type_name_strips_module_path() {
+        assert_eq!(local_type_name("crate::models::User"), "User");
+        assert_eq!(local_type_name("::models::User"), "User");
+        assert_eq!(local_type_name("User"), "User");
+    }
+
     #[test]
     fn test_find_struct_definition() {
         let content = r#"
@@ -125,4 +221,61 @@ struct TodoItem {
 "#;
         assert_eq!(find_type_definition(content, "TodoItem"), Some(1));
     }
+
+    #[test]
+    fn test_find_definition_for_qualified_path() {
+        let content = r#"
+struct User {
+    id: u32,
+}
+"#;
+        assert_eq!(
+            find_type_definition(content, "crate::models::User"),
+            Some(1)
+        );
+    }
+
+    #[test]
+    fn test_find_all_type_definitions() {
+        let content = r#"
+pub struct User {
+    id: u32,
+}
+pub(crate) enum Role { Admin }
+type UserList = Vec<User>;
+"#;
+        assert_eq!(
+            find_type_definitions(content),
+            vec![
+                ("User".to_string(), 1),
+                ("Role".to_string(), 4),
+                ("UserList".to_string(), 5),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_find_missing_derive() {
+        let content = r#"
+#[derive(Serialize, JsonSchema)]
+struct User {
+    id: u32,
+}
+
+/// Mentions JsonSchema in its docs only
+#[derive(
+    Debug,
+    Serialize,
+)]
+enum Role { Admin }
+struct Users(Vec<User>);
+"#;
+        assert_eq!(find_missing_derive(content, "User", "JsonSchema"), None);
+        assert_eq!(find_missing_derive(content, "Role", "JsonSchema"), Some(11));
+        assert_eq!(
+            find_missing_derive(content, "Users", "JsonSchema"),
+            Some(12)
+        );
+        assert_eq!(find_missing_derive(content, "Missing", "JsonSchema"), None);
+    }
 }
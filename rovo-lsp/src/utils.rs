@@ -1,4 +1,6 @@
-/// Utility functions for LSP position handling
+//! Utility functions for LSP position handling and annotation names
+
+use tower_lsp::lsp_types::{Position, TextDocumentContentChangeEvent};
 
 /// Convert UTF-8 byte index to LSP UTF-16 character position
 ///
@@ -48,9 +50,200 @@ pub fn utf16_pos_to_byte_index(line: &str, utf16_col: usize) -> Option<usize> {
     }
 }
 
+/// Convert an LSP position to a UTF-8 byte offset into `text`
+///
+/// Positions past the end of a line or the document are clamped to it, as the
+/// LSP specification requires.
+fn position_to_byte_offset(text: &str, position: Position) -> usize {
+    let mut line_start = 0;
+    for _ in 0..position.line {
+        match text[line_start..].find('\n') {
+            Some(newline) => line_start += newline + 1,
+            None => return text.len(),
+        }
+    }
+
+    let line_end = text[line_start..]
+        .find('\n')
+        .map_or(text.len(), |newline| line_start + newline);
+    let line = text[line_start..line_end].trim_end_matches('\r');
+    let line_len_utf16 = byte_index_to_utf16_col(line, line.len());
+    let col = (position.character as usize).min(line_len_utf16);
+
+    // A position inside a surrogate pair falls back to the start of the character
+    let byte_idx = (0..=col)
+        .rev()
+        .find_map(|col| utf16_pos_to_byte_index(line, col))
+        .unwrap_or(0);
+    line_start + byte_idx
+}
+
+/// Apply `textDocument/didChange` content changes to a document, in order
+///
+/// A change without a range replaces the whole document; otherwise the text in
+/// its range is replaced with the change's text.
+pub fn apply_content_changes(text: &mut String, changes: &[TextDocumentContentChangeEvent]) {
+    for change in changes {
+        match change.range {
+            Some(range) => {
+                let start = position_to_byte_offset(text, range.start);
+                let end = position_to_byte_offset(text, range.end).max(start);
+                text.replace_range(start..end, &change.text);
+            }
+            None => text.clone_from(&change.text),
+        }
+    }
+}
+
+/// Annotations the `#[rovo]` macro accepts in a `# Metadata` section, without their `@`
+///
+/// Mirrors the list in the macro's `find_closest_annotation`.
+pub const ANNOTATIONS: &[&str] = &[
+    "tag",
+    "security",
+    "security-and",
+    "id",
+    "summary",
+    "hidden",
+    "i18n",
+    "idempotent",
+    "concurrency",
+    "cfg",
+    "min-version",
+    "max-version",
+    "ratelimit",
+    "sunset",
+    "server",
+    "external-docs",
+    "async-job",
+    "param",
+    "query",
+    "paginated",
+    "body",
+    "errors",
+    "header",
+    "cacheable",
+    "deprecated",
+    "deprecated-message",
+    "content-type",
+    "produces",
+    "link",
+    "extension",
+    "rovo-ignore",
+];
+
+/// Calculate Levenshtein distance between two strings
+pub fn levenshtein_distance(s1: &str, s2: &str) -> usize {
+    let len1 = s1.len();
+    let len2 = s2.len();
+    let mut matrix = vec![vec![0; len2 + 1]; len1 + 1];
+
+    for (i, row) in matrix.iter_mut().enumerate().take(len1 + 1) {
+        row[0] = i;
+    }
+    for (j, cell) in matrix[0].iter_mut().enumerate().take(len2 + 1) {
+        *cell = j;
+    }
+
+    for (i, c1) in s1.chars().enumerate() {
+        for (j, c2) in s2.chars().enumerate() {
+            let cost = usize::from(c1 != c2);
+            matrix[i + 1][j + 1] = (matrix[i][j + 1] + 1)
+                .min(matrix[i + 1][j] + 1)
+                .min(matrix[i][j] + cost);
+        }
+    }
+
+    matrix[len1][len2]
+}
+
+/// Find the closest matching annotation, like the macro's suggestion for an
+/// unknown annotation
+pub fn find_closest_annotation(input: &str) -> Option<&'static str> {
+    let input_lower = input.to_lowercase();
+    let mut best_match = None;
+    let mut best_distance = usize::MAX;
+
+    for &annotation in ANNOTATIONS {
+        let distance = levenshtein_distance(&input_lower, annotation);
+        // Only suggest if distance is small (≤ 2 characters different)
+        if distance < best_distance && distance <= 2 {
+            best_distance = distance;
+            best_match = Some(annotation);
+        }
+    }
+
+    best_match
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use tower_lsp::lsp_types::Range;
+
+    fn change(
+        range: Option<((u32, u32), (u32, u32))>,
+        text: &str,
+    ) -> TextDocumentContentChangeEvent {
+        TextDocumentContentChangeEvent {
+            range: range.map(|(start, end)| Range {
+                start: Position::new(start.0, start.1),
+                end: Position::new(end.0, end.1),
+            }),
+            range_length: None,
+            text: text.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_apply_full_change() {
+        let mut text = "old".to_string();
+        apply_content_changes(&mut text, &[change(None, "new\ncontent")]);
+        assert_eq!(text, "new\ncontent");
+    }
+
+    #[test]
+    fn test_apply_incremental_changes_in_order() {
+        let mut text = "/// @tag users\n#[rovo]\nasync fn handler() {}\n".to_string();
+        apply_content_changes(
+            &mut text,
+            &[
+                // Replace "users" with "admin"
+                change(Some(((0, 9), (0, 14))), "admin"),
+                // Insert a new doc line before #[rovo]
+                change(Some(((1, 0), (1, 0))), "/// @hidden\n"),
+                // Delete "async " (now on line 3)
+                change(Some(((3, 0), (3, 6))), ""),
+            ],
+        );
+        assert_eq!(
+            text,
+            "/// @tag admin\n/// @hidden\n#[rovo]\nfn handler() {}\n"
+        );
+    }
+
+    #[test]
+    fn test_apply_change_spanning_lines() {
+        let mut text = "line one\nline two\nline three".to_string();
+        apply_content_changes(&mut text, &[change(Some(((0, 5), (2, 5))), "")]);
+        assert_eq!(text, "line three");
+    }
+
+    #[test]
+    fn test_apply_change_with_utf16_positions() {
+        // The emoji is 2 UTF-16 code units but 4 bytes
+        let mut text = "/// 👋 hi\r\nnext".to_string();
+        apply_content_changes(&mut text, &[change(Some(((0, 7), (0, 9))), "yo")]);
+        assert_eq!(text, "/// 👋 yo\r\nnext");
+    }
+
+    #[test]
+    fn test_apply_change_clamps_out_of_range_positions() {
+        let mut text = "short\nend".to_string();
+        apply_content_changes(&mut text, &[change(Some(((0, 99), (0, 99))), "er")]);
+        apply_content_changes(&mut text, &[change(Some(((9, 0), (9, 0))), "!")]);
+        assert_eq!(text, "shorter\nend!");
+    }
 
     #[test]
     fn test_ascii_string() {
@@ -117,4 +310,11 @@ mod tests {
         assert_eq!(byte_index_to_utf16_col(line, 3), 3); // Start of emoji
         assert_eq!(byte_index_to_utf16_col(line, 7), 5); // After emoji (2 UTF-16 units)
     }
+
+    #[test]
+    fn test_find_closest_annotation() {
+        assert_eq!(find_closest_annotation("tga"), Some("tag"));
+        assert_eq!(find_closest_annotation("Hiden"), Some("hidden"));
+        assert_eq!(find_closest_annotation("unrelated"), None);
+    }
 }
@@ -292,6 +292,28 @@ struct User {
     assert!(!titles.iter().any(|t| t.contains("JsonSchema")));
 }
 
+#[test]
+fn offers_jsonschema_on_response_line() {
+    let content = r#"
+#[derive(Debug, Serialize)]
+struct User {
+    name: String,
+}
+
+/// # Responses
+///
+/// 200: Json<User> - The user
+#[rovo]
+async fn get_user() -> Json<User> {}
+"#;
+
+    let actions = code_actions::get_code_actions(content, range_at_line(8), test_uri());
+    let titles = get_action_titles(&actions);
+
+    let jsonschema: Vec<_> = titles.iter().filter(|t| t.contains("JsonSchema")).collect();
+    assert_eq!(jsonschema, vec!["Add JsonSchema to derive"]);
+}
+
 #[test]
 fn offers_jsonschema_in_enum() {
     let content = r#"
@@ -345,6 +367,48 @@ async fn handler() {}
     assert_eq!(titles.len(), 5); // 200, 201, 400, 404, 500
 }
 
+#[test]
+fn diagnostic_quick_fix_for_duplicate_status() {
+    let content = r#"
+/// # Responses
+///
+/// 200: Json<User> - The user
+/// 200: Json<Admin> - The admin
+#[rovo]
+async fn handler() {}
+"#;
+
+    let duplicate = rovo_lsp::diagnostics::validate_annotations(content)
+        .into_iter()
+        .find(|d| d.message.starts_with("Duplicate status code"))
+        .expect("should warn about the duplicate status");
+    let diagnostic = Diagnostic {
+        range: Range {
+            start: Position {
+                line: duplicate.line as u32,
+                character: duplicate.char_start.unwrap() as u32,
+            },
+            end: Position {
+                line: duplicate.line as u32,
+                character: duplicate.char_end.unwrap() as u32,
+            },
+        },
+        message: duplicate.message,
+        ..Default::default()
+    };
+
+    let actions = code_actions::get_diagnostic_code_actions(content, &diagnostic, test_uri());
+    assert_eq!(
+        get_action_titles(&actions),
+        vec![
+            "Change to 201",
+            "Change to 400",
+            "Change to 404",
+            "Change to 500"
+        ]
+    );
+}
+
 #[test]
 fn diagnostic_quick_fix_sets_preferred() {
     let content = r#"
@@ -1148,3 +1212,352 @@ async fn handler(State(app): State<AppState>, Path(id): Path<u64>) {}
         titles
     );
 }
+
+#[test]
+fn extracts_inline_example_to_const() {
+    let content = r#"/// Get a user.
+///
+/// # Responses
+///
+/// 200: Json<User> - The user
+///
+/// # Examples
+///
+/// 200: User {
+///     id: 1,
+/// }
+#[rovo]
+async fn get_user() -> impl IntoApiResponse {}
+"#;
+    let uri = test_uri();
+    let actions = code_actions::get_code_actions(content, range_at_line(9), uri.clone());
+
+    let action = actions
+        .iter()
+        .find_map(|action| match action {
+            CodeActionOrCommand::CodeAction(ca) if ca.title == "Extract example to const" => {
+                Some(ca)
+            }
+            _ => None,
+        })
+        .expect("Should offer to extract the example");
+
+    let edits = &action.edit.as_ref().unwrap().changes.as_ref().unwrap()[&uri];
+    assert_eq!(edits.len(), 2);
+
+    // The const is inserted above the doc block, typed with the response body type
+    assert_eq!(edits[0].range.start.line, 0);
+    assert_eq!(
+        edits[0].new_text,
+        "const EXAMPLE_200: User = User {\n    id: 1,\n};\n\n"
+    );
+
+    // The whole multi-line example is replaced with a reference to the const
+    assert_eq!(edits[1].range.start.line, 8);
+    assert_eq!(edits[1].range.end.line, 10);
+    assert_eq!(edits[1].new_text, "/// 200: EXAMPLE_200");
+}
+
+#[test]
+fn no_extract_action_for_const_example() {
+    let content = r#"/// # Responses
+///
+/// 200: Json<User> - The user
+///
+/// # Examples
+///
+/// 200: EXAMPLE_200
+#[rovo]
+async fn get_user() -> impl IntoApiResponse {}
+"#;
+    let actions = code_actions::get_code_actions(content, range_at_line(6), test_uri());
+    let titles = get_action_titles(&actions);
+    assert!(!titles.contains(&"Extract example to const".to_string()));
+}
+
+#[test]
+fn missing_blank_line_after_header_quick_fix() {
+    let content = r#"
+    /// # Responses
+    /// 200: Json<User> - User found
+    #[rovo]
+    async fn handler() {}
+"#;
+
+    let hint = rovo_lsp::diagnostics::validate_annotations(content)
+        .into_iter()
+        .find(|d| {
+            d.message
+                .starts_with("Missing blank line after section header")
+        })
+        .expect("should hint about the missing blank line");
+    assert_eq!(hint.line, 1);
+
+    let diagnostic = Diagnostic {
+        range: range_at_line(1),
+        message: hint.message,
+        ..Default::default()
+    };
+
+    let actions = code_actions::get_diagnostic_code_actions(content, &diagnostic, test_uri());
+    assert_eq!(actions.len(), 1);
+
+    let CodeActionOrCommand::CodeAction(action) = &actions[0] else {
+        panic!("expected a code action");
+    };
+    assert_eq!(action.title, "Insert blank line after header");
+    assert_eq!(action.kind, Some(CodeActionKind::QUICKFIX));
+
+    let edits = &action.edit.as_ref().unwrap().changes.as_ref().unwrap()[&test_uri()];
+    assert_eq!(edits.len(), 1);
+    assert_eq!(
+        edits[0].range.start,
+        Position {
+            line: 2,
+            character: 0
+        }
+    );
+    assert_eq!(edits[0].range.end, edits[0].range.start);
+    assert_eq!(edits[0].new_text, "    ///\n");
+}
+
+#[test]
+fn annotation_missing_at_quick_fix() {
+    let content = r#"
+/// # Metadata
+///
+/// tag users
+#[rovo]
+async fn handler() {}
+"#;
+
+    let hint = rovo_lsp::diagnostics::validate_annotations(content)
+        .into_iter()
+        .find(|d| {
+            d.message
+                .contains("looks like an annotation missing its '@'")
+        })
+        .expect("should hint about the missing '@'");
+    assert_eq!(hint.line, 3);
+    assert_eq!(
+        hint.severity,
+        rovo_lsp::diagnostics::DiagnosticSeverity::Hint
+    );
+    assert_eq!(hint.char_start, Some(4));
+    assert_eq!(hint.char_end, Some(7));
+
+    let start = Position {
+        line: 3,
+        character: 4,
+    };
+    let diagnostic = Diagnostic {
+        range: Range { start, end: start },
+        message: hint.message,
+        ..Default::default()
+    };
+
+    let actions = code_actions::get_diagnostic_code_actions(content, &diagnostic, test_uri());
+    assert_eq!(get_action_titles(&actions), vec!["Add missing '@'"]);
+
+    let CodeActionOrCommand::CodeAction(action) = &actions[0] else {
+        panic!("expected a code action");
+    };
+    let edits = &action.edit.as_ref().unwrap().changes.as_ref().unwrap()[&test_uri()];
+    assert_eq!(edits.len(), 1);
+    assert_eq!(edits[0].range, Range { start, end: start });
+    assert_eq!(edits[0].new_text, "@");
+}
+
+/// Apply a whole-line text edit (both ends at character 0) to `content`
+fn apply_line_edit(content: &str, edit: &TextEdit) -> String {
+    let lines: Vec<&str> = content.split_inclusive('\n').collect();
+    let start = edit.range.start.line as usize;
+    let end = edit.range.end.line as usize;
+    format!(
+        "{}{}{}",
+        lines[..start].concat(),
+        edit.new_text,
+        lines[end..].concat()
+    )
+}
+
+#[test]
+fn reorders_reversed_sections() {
+    let content = r#"
+/// Get a user.
+///
+/// # Metadata
+///
+/// @tag users
+///
+/// # Examples
+///
+/// 200: User::default()
+///
+/// # Responses
+///
+/// 200: Json<User> - User found
+/// 404: () - User does not exist
+#[rovo]
+async fn handler() {}
+"#;
+
+    let actions = code_actions::get_code_actions(content, range_at_line(16), test_uri());
+    let action = actions
+        .iter()
+        .find_map(|a| match a {
+            CodeActionOrCommand::CodeAction(ca) if ca.title == "Reorder doc sections" => Some(ca),
+            _ => None,
+        })
+        .expect("should offer to reorder sections");
+
+    let edits = &action.edit.as_ref().unwrap().changes.as_ref().unwrap()[&test_uri()];
+    assert_eq!(edits.len(), 1);
+
+    let expected = r#"
+/// Get a user.
+///
+/// # Responses
+///
+/// 200: Json<User> - User found
+/// 404: () - User does not exist
+///
+/// # Examples
+///
+/// 200: User::default()
+///
+/// # Metadata
+///
+/// @tag users
+#[rovo]
+async fn handler() {}
+"#;
+    assert_eq!(apply_line_edit(content, &edits[0]), expected);
+}
+
+#[test]
+fn no_reorder_action_for_ordered_sections() {
+    let content = r#"
+/// # Responses
+///
+/// 200: Json<User> - User found
+///
+/// # Metadata
+///
+/// @tag users
+#[rovo]
+async fn handler() {}
+"#;
+
+    let actions = code_actions::get_code_actions(content, range_at_line(9), test_uri());
+    let titles = get_action_titles(&actions);
+    assert!(!titles.iter().any(|t| t == "Reorder doc sections"));
+}
+
+#[test]
+fn moves_loose_tag_into_metadata_in_order() {
+    let content = r#"
+/// Get a user.
+/// @tag users
+///
+/// # Responses
+///
+/// 200: Json<User> - User found
+///
+/// # Metadata
+///
+/// @id getUser
+/// @security bearer
+#[rovo]
+async fn handler() {}
+"#;
+
+    let actions = code_actions::get_code_actions(content, range_at_line(2), test_uri());
+    let action = actions
+        .iter()
+        .find_map(|a| match a {
+            CodeActionOrCommand::CodeAction(ca) if ca.title == "Move @tag into # Metadata" => {
+                Some(ca)
+            }
+            _ => None,
+        })
+        .expect("Should offer to move the loose @tag");
+
+    // Apply the edits bottom-up so earlier line numbers stay valid
+    let mut edits = action.edit.as_ref().unwrap().changes.as_ref().unwrap()[&test_uri()].clone();
+    edits.sort_by_key(|edit| std::cmp::Reverse(edit.range.start.line));
+    let result = edits
+        .iter()
+        .fold(content.to_string(), |acc, edit| apply_line_edit(&acc, edit));
+
+    let expected = r#"
+/// Get a user.
+///
+/// # Responses
+///
+/// 200: Json<User> - User found
+///
+/// # Metadata
+///
+/// @id getUser
+/// @tag users
+/// @security bearer
+#[rovo]
+async fn handler() {}
+"#;
+    assert_eq!(result, expected);
+
+    // Annotations already in # Metadata are not offered
+    let actions = code_actions::get_code_actions(&result, range_at_line(10), test_uri());
+    assert!(!actions.iter().any(|a| matches!(
+        a,
+        CodeActionOrCommand::CodeAction(ca) if ca.title.starts_with("Move @")
+    )));
+}
+
+#[test]
+fn moving_loose_tag_creates_metadata_section() {
+    let content = r#"
+/// Get a user.
+/// @tag users
+///
+/// # Responses
+///
+/// 200: Json<User> - User found
+#[rovo]
+async fn handler() {}
+"#;
+
+    let actions = code_actions::get_code_actions(content, range_at_line(2), test_uri());
+    let action = actions
+        .iter()
+        .find_map(|a| match a {
+            CodeActionOrCommand::CodeAction(ca) if ca.title == "Move @tag into # Metadata" => {
+                Some(ca)
+            }
+            _ => None,
+        })
+        .expect("Should offer to move the loose @tag");
+
+    let mut edits = action.edit.as_ref().unwrap().changes.as_ref().unwrap()[&test_uri()].clone();
+    edits.sort_by_key(|edit| std::cmp::Reverse(edit.range.start.line));
+    let result = edits
+        .iter()
+        .fold(content.to_string(), |acc, edit| apply_line_edit(&acc, edit));
+
+    let expected = r#"
+/// Get a user.
+///
+/// # Responses
+///
+/// 200: Json<User> - User found
+///
+/// # Metadata
+///
+/// @tag users
+///
+#[rovo]
+async fn handler() {}
+"#;
+    assert_eq!(result, expected);
+}
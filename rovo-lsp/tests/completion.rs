@@ -1,4 +1,4 @@
-use rovo_lsp::completion::{get_completions, Position};
+use rovo_lsp::completion::{get_completions, CompletionItemKind, Position};
 
 #[test]
 fn completes_annotation_keywords() {
@@ -21,11 +21,36 @@ fn completes_all_annotations_on_at_sign() {
     let completions = get_completions(content, position);
 
     // Only metadata annotations (use sections for responses/examples)
-    assert_eq!(completions.len(), 4);
+    assert_eq!(completions.len(), 17);
     assert!(completions.iter().any(|c| c.label == "@tag"));
     assert!(completions.iter().any(|c| c.label == "@security"));
+    assert!(completions.iter().any(|c| c.label == "@security-and"));
     assert!(completions.iter().any(|c| c.label == "@id"));
     assert!(completions.iter().any(|c| c.label == "@hidden"));
+    assert!(completions.iter().any(|c| c.label == "@sunset"));
+    assert!(completions.iter().any(|c| c.label == "@body"));
+    assert!(completions.iter().any(|c| c.label == "@errors"));
+    assert!(completions.iter().any(|c| c.label == "@header"));
+    assert!(completions.iter().any(|c| c.label == "@content-type"));
+    assert!(completions.iter().any(|c| c.label == "@link"));
+    assert!(completions.iter().any(|c| c.label == "@summary"));
+    assert!(completions.iter().any(|c| c.label == "@external-docs"));
+    assert!(completions.iter().any(|c| c.label == "@idempotent"));
+    assert!(completions.iter().any(|c| c.label == "@concurrency"));
+    assert!(completions.iter().any(|c| c.label == "@produces"));
+    assert!(completions.iter().any(|c| c.label == "@ratelimit"));
+}
+
+#[test]
+fn completes_external_docs_prefix() {
+    let content = "/// @ext";
+    let position = Position {
+        line: 0,
+        character: 8,
+    };
+    let completions = get_completions(content, position);
+    let labels: Vec<&str> = completions.iter().map(|c| c.label.as_str()).collect();
+    assert_eq!(labels, vec!["@external-docs"]);
 }
 
 #[test]
@@ -128,6 +153,25 @@ fn completes_hidden_prefix() {
     );
 }
 
+#[test]
+fn completes_sunset_with_date_placeholder() {
+    let content = "/// @su";
+    let position = Position {
+        line: 0,
+        character: 7,
+    };
+    let completions = get_completions(content, position);
+    let sunset = completions
+        .iter()
+        .find(|c| c.label == "@sunset")
+        .expect("Should include @sunset completion");
+    assert_eq!(
+        sunset.insert_text.as_deref(),
+        Some("@sunset ${1:2025-12-31}")
+    );
+    assert!(sunset.documentation.as_ref().unwrap().contains("RFC 8594"));
+}
+
 #[test]
 fn handles_multiline_doc_comments() {
     let content = "/// This is a comment\n/// @t";
@@ -138,3 +182,266 @@ fn handles_multiline_doc_comments() {
     let completions = get_completions(content, position);
     assert!(completions.iter().any(|c| c.label == "@tag"));
 }
+
+#[test]
+fn includes_snippet_for_body() {
+    let content = "/// @b";
+    let position = Position {
+        line: 0,
+        character: 6,
+    };
+    let completions = get_completions(content, position);
+
+    assert_eq!(completions.len(), 1);
+    assert_eq!(completions[0].label, "@body");
+    assert_eq!(
+        completions[0].insert_text.as_deref(),
+        Some("@body ${1:Json<T>} - ${2:description}")
+    );
+}
+
+#[test]
+fn completes_error_sets() {
+    let content = "/// @errors ";
+    let position = Position {
+        line: 0,
+        character: 12,
+    };
+    let completions = get_completions(content, position);
+
+    assert_eq!(completions.len(), 1);
+    assert_eq!(completions[0].label, "standard");
+}
+
+#[test]
+fn completes_content_types_after_status() {
+    let content = "/// @content-type 200 appl";
+    let position = Position {
+        line: 0,
+        character: 26,
+    };
+    let completions = get_completions(content, position);
+
+    assert!(completions.iter().any(|c| c.label == "application/json"));
+    assert!(completions.iter().any(|c| c.label == "application/xml"));
+    assert!(completions.iter().all(|c| c.label.starts_with("appl")));
+
+    // No content types before the status code is written
+    let content = "/// @content-type ";
+    let position = Position {
+        line: 0,
+        character: 18,
+    };
+    let completions = get_completions(content, position);
+    assert!(completions.iter().all(|c| !c.label.contains('/')));
+}
+
+#[test]
+fn completes_ratelimit_windows_after_count() {
+    let content = "/// @ratelimit 100 ";
+    let position = Position {
+        line: 0,
+        character: content.len(),
+    };
+    let labels: Vec<_> = get_completions(content, position)
+        .into_iter()
+        .map(|c| c.label)
+        .collect();
+    assert_eq!(labels, vec!["per-second", "per-minute", "per-hour"]);
+
+    let content = "/// @ratelimit 100 per-m";
+    let position = Position {
+        line: 0,
+        character: content.len(),
+    };
+    let labels: Vec<_> = get_completions(content, position)
+        .into_iter()
+        .map(|c| c.label)
+        .collect();
+    assert_eq!(labels, vec!["per-minute"]);
+
+    // Nothing to complete while the count is being written
+    let content = "/// @ratelimit 10";
+    let position = Position {
+        line: 0,
+        character: content.len(),
+    };
+    assert!(get_completions(content, position)
+        .iter()
+        .all(|c| !c.label.starts_with("per-")));
+}
+
+#[test]
+fn completes_content_types_after_produces() {
+    let content = "/// @produces ";
+    let position = Position {
+        line: 0,
+        character: 14,
+    };
+    let completions = get_completions(content, position);
+    assert!(completions.iter().any(|c| c.label == "text/csv"));
+    assert!(completions
+        .iter()
+        .any(|c| c.label == "application/octet-stream"));
+
+    let content = "/// @produces text/";
+    let position = Position {
+        line: 0,
+        character: 19,
+    };
+    let completions = get_completions(content, position);
+    assert!(!completions.is_empty());
+    assert!(completions.iter().all(|c| c.label.starts_with("text/")));
+}
+
+#[test]
+fn completes_operation_ids_for_link() {
+    let content = r#"/// @id getUser
+#[rovo]
+async fn get_user() {}
+
+#[rovo]
+async fn list_users() {}
+
+/// # Metadata
+///
+/// @link 201 get"#;
+    let position = Position {
+        line: 9,
+        character: 17,
+    };
+    let completions = get_completions(content, position);
+    let labels: Vec<&str> = completions.iter().map(|c| c.label.as_str()).collect();
+    assert_eq!(labels, vec!["getUser"]);
+
+    // Every operation ID in the document is offered once the status is written
+    let content = content.replace("@link 201 get", "@link 201 ");
+    let position = Position {
+        line: 9,
+        character: 14,
+    };
+    let completions = get_completions(&content, position);
+    let labels: Vec<&str> = completions.iter().map(|c| c.label.as_str()).collect();
+    assert_eq!(labels, vec!["getUser", "list_users"]);
+}
+
+#[test]
+fn completes_security_scheme_before_scopes() {
+    let content = "/// @security oa";
+    let position = Position {
+        line: 0,
+        character: 16,
+    };
+    let completions = get_completions(content, position);
+    let labels: Vec<&str> = completions.iter().map(|c| c.label.as_str()).collect();
+    assert_eq!(labels, vec!["oauth2"]);
+
+    // Scopes are free-form, so no scheme names are offered after the scheme
+    let content = "/// @security oauth2 read:users ";
+    let position = Position {
+        line: 0,
+        character: 32,
+    };
+    let completions = get_completions(content, position);
+    assert!(completions
+        .iter()
+        .all(|c| !["bearer", "basic", "apiKey", "oauth2"].contains(&c.label.as_str())));
+}
+
+#[test]
+fn completes_security_and_schemes() {
+    let content = "/// @sec";
+    let position = Position {
+        line: 0,
+        character: 8,
+    };
+    let completions = get_completions(content, position);
+    assert!(completions.iter().any(|c| c.label == "@security-and"));
+
+    // Every scheme after the keyword is completed, not just the first
+    let content = "/// @security-and bearer b";
+    let position = Position {
+        line: 0,
+        character: 26,
+    };
+    let completions = get_completions(content, position);
+    assert!(completions.iter().all(|c| c.label.starts_with('b')));
+    assert!(completions.iter().any(|c| c.label == "basic"));
+}
+
+#[test]
+fn completes_document_types_in_json_response() {
+    let content = r#"#[derive(Serialize, JsonSchema)]
+pub struct User {
+    id: u64,
+}
+
+enum UserRole { Admin }
+
+/// # Responses
+///
+/// 200: Json<U
+#[rovo]
+async fn get_user() {}"#;
+    let position = Position {
+        line: 9,
+        character: 15,
+    };
+    let completions = get_completions(content, position);
+    let labels: Vec<&str> = completions.iter().map(|c| c.label.as_str()).collect();
+    assert_eq!(labels, vec!["User", "UserRole"]);
+    assert_eq!(completions[0].insert_text.as_deref(), Some("User>"));
+    assert!(matches!(completions[0].kind, CompletionItemKind::Class));
+
+    // A bare type is completed without the closing bracket
+    let content = content.replace("200: Json<U", "200: Us");
+    let position = Position {
+        line: 9,
+        character: 11,
+    };
+    let completions = get_completions(&content, position);
+    assert_eq!(completions[0].insert_text.as_deref(), Some("User"));
+
+    // Types aren't offered once the description starts
+    let content = content.replace("200: Us", "200: User - A u");
+    let position = Position {
+        line: 9,
+        character: 19,
+    };
+    assert!(get_completions(&content, position)
+        .iter()
+        .all(|c| !matches!(c.kind, CompletionItemKind::Class)));
+}
+
+#[test]
+fn completes_tags_used_in_document() {
+    let content = r#"/// @tag users
+#[rovo]
+async fn list_users() {}
+
+/// @tag admin
+/// @tag users
+#[rovo]
+async fn delete_user() {}
+
+/// @tag u
+#[rovo]
+async fn get_user() {}"#;
+    let position = Position {
+        line: 9,
+        character: 10,
+    };
+    let completions = get_completions(content, position);
+    let labels: Vec<&str> = completions.iter().map(|c| c.label.as_str()).collect();
+    assert_eq!(labels, vec!["users"]);
+
+    // Every tag is offered, once, right after the keyword
+    let content = content.replace("/// @tag u\n", "/// @tag \n");
+    let position = Position {
+        line: 9,
+        character: 9,
+    };
+    let completions = get_completions(&content, position);
+    let labels: Vec<&str> = completions.iter().map(|c| c.label.as_str()).collect();
+    assert_eq!(labels, vec!["admin", "users"]);
+}
@@ -1,5 +1,7 @@
+use rovo_lsp::handlers;
 use rovo_lsp::type_resolver;
 use rovo_lsp::utils;
+use tower_lsp::lsp_types::{Position, Url};
 
 #[test]
 fn finds_struct_definition() {
@@ -239,3 +241,97 @@ fn ignores_whitespace_in_type_extraction() {
     let extracted = type_resolver::extract_type_from_response("  Json<User>  ");
     assert_eq!(extracted, Some("User".to_string()));
 }
+
+#[test]
+fn type_definition_resolves_response_type() {
+    let content = r#"
+pub struct User {
+    name: String,
+}
+
+/// # Responses
+///
+/// 200: Json<User> - Success
+#[rovo]
+async fn handler() {}
+"#;
+    let uri = Url::parse("file:///test.rs").unwrap();
+    let position = Position {
+        line: 7,
+        character: 16, // On "User" in Json<User>
+    };
+
+    let location = handlers::type_definition(content, position, uri.clone()).unwrap();
+    assert_eq!(location.uri, uri);
+    assert_eq!(location.range.start.line, 1);
+    assert_eq!(location.range.start.character, 11);
+    assert_eq!(location.range.end.character, 15);
+}
+
+#[test]
+fn type_definition_on_wrapper_resolves_inner_type() {
+    let content = r#"
+struct User {
+    name: String,
+}
+
+/// # Responses
+///
+/// 200: Json<User> - Success
+#[rovo]
+async fn handler() {}
+"#;
+    let uri = Url::parse("file:///test.rs").unwrap();
+    let position = Position {
+        line: 7,
+        character: 10, // On "Json"
+    };
+
+    let location = handlers::type_definition(content, position, uri).unwrap();
+    assert_eq!(location.range.start.line, 1);
+}
+
+#[test]
+fn type_definition_resolves_example_type() {
+    let content = r#"
+struct User {
+    name: String,
+}
+
+/// # Responses
+///
+/// 200: Json<User> - Success
+///
+/// # Examples
+///
+/// 200: User::default()
+#[rovo]
+async fn handler() {}
+"#;
+    let uri = Url::parse("file:///test.rs").unwrap();
+    let position = Position {
+        line: 11,
+        character: 10, // On "User" in the example
+    };
+
+    let location = handlers::type_definition(content, position, uri).unwrap();
+    assert_eq!(location.range.start.line, 1);
+}
+
+#[test]
+fn type_definition_none_for_unknown_type() {
+    let content = r#"
+/// # Responses
+///
+/// 200: Json<Missing> - Success
+#[rovo]
+async fn handler() {}
+"#;
+    let uri = Url::parse("file:///test.rs").unwrap();
+    let position = Position {
+        line: 3,
+        character: 14,
+    };
+
+    assert!(handlers::type_definition(content, position, uri).is_none());
+}
@@ -53,6 +53,108 @@ async fn handler() {}
     }
 }
 
+#[test]
+fn hover_shows_external_docs_url_as_link() {
+    let content = r#"
+/// # Metadata
+///
+/// @external-docs https://docs.example.com/api/users See the full users guide
+#[rovo]
+async fn handler() {}
+"#;
+
+    let position = Position {
+        line: 3,
+        character: 25, // On the URL
+    };
+
+    let hover = handlers::text_document_hover(content, position).unwrap();
+    match hover.contents {
+        HoverContents::Markup(markup) => {
+            assert!(markup
+                .value
+                .contains("[See the full users guide](https://docs.example.com/api/users)"));
+        }
+        _ => panic!("Expected markup content"),
+    }
+}
+
+#[test]
+fn hover_provides_idempotent_annotation_info() {
+    let content = r#"
+/// # Metadata
+///
+/// @idempotent
+#[rovo]
+async fn handler() {}
+"#;
+
+    let position = Position {
+        line: 3,
+        character: 5, // On "@id" within "@idempotent"
+    };
+
+    let hover = handlers::text_document_hover(content, position).unwrap();
+    match hover.contents {
+        HoverContents::Markup(markup) => {
+            assert!(markup.value.contains("# @idempotent"));
+            assert!(markup.value.contains("Idempotency-Key"));
+        }
+        _ => panic!("Expected markup content"),
+    }
+}
+
+#[test]
+fn hover_provides_concurrency_annotation_info() {
+    let content = r#"
+/// # Metadata
+///
+/// @concurrency
+#[rovo]
+async fn handler() {}
+"#;
+
+    let position = Position {
+        line: 3,
+        character: 8,
+    };
+
+    let hover = handlers::text_document_hover(content, position).unwrap();
+    match hover.contents {
+        HoverContents::Markup(markup) => {
+            assert!(markup.value.contains("# @concurrency"));
+            assert!(markup.value.contains("If-Match"));
+            assert!(markup.value.contains("412"));
+        }
+        _ => panic!("Expected markup content"),
+    }
+}
+
+#[test]
+fn hover_provides_sunset_annotation_info() {
+    let content = r#"
+/// # Metadata
+///
+/// @sunset 2025-12-31
+#[rovo]
+async fn handler() {}
+"#;
+
+    let position = Position {
+        line: 3,
+        character: 6, // On "@sunset"
+    };
+
+    let hover = handlers::text_document_hover(content, position).unwrap();
+    match hover.contents {
+        HoverContents::Markup(markup) => {
+            assert!(markup.value.contains("# @sunset"));
+            assert!(markup.value.contains("RFC 8594"));
+        }
+        _ => panic!("Expected markup content"),
+    }
+}
+
 #[test]
 fn hover_on_type_shows_definition() {
     let content = r#"
@@ -85,6 +187,88 @@ async fn handler() {}
     }
 }
 
+#[test]
+fn hover_on_qualified_type_resolves_local_definition() {
+    let content = r#"
+struct User {
+    name: String,
+}
+
+/// # Responses
+///
+/// 200: Json<crate::models::User> - Success
+#[rovo]
+async fn handler() {}
+"#;
+
+    let position = Position {
+        line: 7,
+        character: 30, // On "User" in the qualified path
+    };
+
+    let hover = handlers::text_document_hover(content, position).unwrap();
+    match hover.contents {
+        HoverContents::Markup(markup) => {
+            assert!(markup.value.contains("**User**"));
+            assert!(markup.value.contains("line 2"));
+        }
+        _ => panic!("Expected markup content"),
+    }
+}
+
+#[test]
+fn hover_on_array_shorthand_resolves_element_type() {
+    let content = r#"
+struct User {
+    name: String,
+}
+
+/// # Responses
+///
+/// 200: [User] - List of users
+#[rovo]
+async fn handler() {}
+"#;
+
+    let position = Position {
+        line: 7,
+        character: 11, // On "User" inside the brackets
+    };
+
+    let hover = handlers::text_document_hover(content, position).unwrap();
+    match hover.contents {
+        HoverContents::Markup(markup) => {
+            assert!(markup.value.contains("**User**"));
+            assert!(markup.value.contains("line 2"));
+        }
+        _ => panic!("Expected markup content"),
+    }
+}
+
+#[test]
+fn hover_on_qualified_type_without_local_definition() {
+    let content = r#"
+/// # Responses
+///
+/// 200: Json<crate::models::User> - Success
+#[rovo]
+async fn handler() {}
+"#;
+
+    let position = Position {
+        line: 3,
+        character: 30,
+    };
+
+    let hover = handlers::text_document_hover(content, position).unwrap();
+    match hover.contents {
+        HoverContents::Markup(markup) => {
+            assert!(markup.value.contains("crate::models::User"));
+        }
+        _ => panic!("Expected markup content"),
+    }
+}
+
 #[test]
 fn hover_handles_utf16_positions() {
     // Content with emoji (4 bytes UTF-8, 2 UTF-16 code units)
@@ -219,6 +403,77 @@ async fn handler() {}
     assert_eq!(placeholder, "用户");
 }
 
+#[test]
+fn prepare_rename_covers_emoji_tags() {
+    // "🚀" is a surrogate pair and "👩‍🚀" three code points joined by a ZWJ,
+    // so UTF-16 columns, chars and bytes all differ
+    let content = r#"
+/// @tag 🚀rockets
+/// @tag 👩‍🚀crew
+#[rovo]
+async fn handler() {}
+"#;
+
+    // "/// @tag " is 9 UTF-16 units; "🚀rockets" is 2 + 7
+    for character in [5, 9, 11, 18] {
+        let (range, placeholder) =
+            handlers::prepare_rename(content, Position { line: 1, character }).unwrap();
+        assert_eq!(placeholder, "🚀rockets");
+        assert_eq!((range.start.character, range.end.character), (9, 18));
+    }
+
+    // "👩‍🚀crew" is 2 + 1 + 2 + 4; a cursor inside the cluster still covers all of it
+    for character in [9, 11, 12, 14] {
+        let (range, placeholder) =
+            handlers::prepare_rename(content, Position { line: 2, character }).unwrap();
+        assert_eq!(placeholder, "👩‍🚀crew");
+        assert_eq!((range.start.character, range.end.character), (9, 18));
+    }
+}
+
+#[test]
+fn rename_tag_replaces_whole_emoji_tag() {
+    let content = r#"
+/// @tag 🚀rockets
+#[rovo]
+async fn launch() {}
+
+/// @tag 🚀rockets
+/// @tag rockets
+#[rovo]
+async fn land() {}
+"#;
+
+    let uri = Url::parse("file:///test.rs").unwrap();
+    let position = Position {
+        line: 1,
+        character: 11, // Just after the emoji
+    };
+    let edit = handlers::rename_tag(content, position, "✨launches", uri.clone()).unwrap();
+    let edits = &edit.changes.unwrap()[&uri];
+
+    let ranges: Vec<_> = edits
+        .iter()
+        .map(|edit| {
+            (
+                edit.range.start.line,
+                edit.range.start.character,
+                edit.range.end.character,
+            )
+        })
+        .collect();
+    assert_eq!(ranges, vec![(1, 9, 18), (5, 9, 18)]);
+    assert!(edits.iter().all(|edit| edit.new_text == "✨launches"));
+
+    let references = handlers::find_tag_references(content, position, uri).unwrap();
+    let ranges: Vec<_> = references
+        .iter()
+        .map(|location| (location.range.start.character, location.range.end.character))
+        .collect();
+    // References span the `@tag` keyword through the end of the tag
+    assert_eq!(ranges, vec![(4, 18), (4, 18)]);
+}
+
 #[test]
 fn rename_tag_updates_all_references() {
     let content = r#"
@@ -343,6 +598,34 @@ async fn handler() {}
     }
 }
 
+#[test]
+fn completion_uses_plain_text_without_placeholders() {
+    let content = r#"
+/// @
+#[rovo]
+async fn handler() {}
+"#;
+
+    let position = Position {
+        line: 1,
+        character: 5,
+    };
+
+    let Some(CompletionResponse::Array(items)) =
+        handlers::text_document_completion(content, position)
+    else {
+        panic!("Expected array of completions");
+    };
+    let format_of = |label: &str| {
+        items
+            .iter()
+            .find(|i| i.label == label)
+            .and_then(|i| i.insert_text_format)
+    };
+    assert_eq!(format_of("@hidden"), Some(InsertTextFormat::PLAIN_TEXT));
+    assert_eq!(format_of("@tag"), Some(InsertTextFormat::SNIPPET));
+}
+
 #[test]
 fn no_completion_outside_rovo_block() {
     let content = r#"
@@ -1100,6 +1383,40 @@ async fn handler() {}
     }
 }
 
+#[test]
+fn semantic_tokens_full_finds_every_annotation() {
+    let content = r#"
+/// @paginated
+/// @deprecated-message Use the v2 endpoint
+#[rovo]
+async fn handler() {}
+"#;
+
+    let Some(SemanticTokensResult::Tokens(tokens)) = handlers::semantic_tokens_full(content) else {
+        panic!("expected semantic tokens");
+    };
+    let lengths: Vec<u32> = tokens.data.iter().map(|token| token.length).collect();
+    // The whole of `@paginated` and `@deprecated-message`, not `@deprecated`
+    assert_eq!(lengths, vec![10, 19]);
+}
+
+#[test]
+fn semantic_tokens_full_skips_annotation_prefixes() {
+    let content = r#"
+/// @tag-description users Endpoints for managing users
+/// @tag users
+#[rovo]
+async fn handler() {}
+"#;
+
+    let Some(SemanticTokensResult::Tokens(tokens)) = handlers::semantic_tokens_full(content) else {
+        panic!("expected semantic tokens");
+    };
+    let lines: Vec<u32> = tokens.data.iter().map(|token| token.delta_line).collect();
+    // Only `@tag users` on the second line, not the `@tag` in `@tag-description`
+    assert_eq!(lines, vec![2, 0]);
+}
+
 #[test]
 fn semantic_tokens_full_finds_status_codes() {
     let content = r#"
@@ -1286,6 +1603,80 @@ async fn handler() {}
     }
 }
 
+#[test]
+fn semantic_tokens_range_only_covers_requested_lines() {
+    let content = r#"
+/// Get a user by ID
+///
+/// # Responses
+///
+/// 200: Json<User> - Success
+/// 404: Json<Error> - Not found
+///
+/// # Examples
+///
+/// 200: User::default()
+///
+/// # Metadata
+///
+/// @tag users
+/// @id getUserById
+/// @security bearer
+#[rovo]
+async fn handler() {}
+"#;
+
+    let range = Range {
+        start: Position::new(14, 0),
+        end: Position::new(16, 20),
+    };
+    let Some(SemanticTokensRangeResult::Tokens(tokens)) =
+        handlers::semantic_tokens_range(content, range)
+    else {
+        panic!("Should return semantic tokens for the range");
+    };
+
+    // @tag, users, @id, getUserById, @security, bearer
+    assert_eq!(tokens.data.len(), 6);
+    // Positions are still relative to the start of the document
+    assert_eq!(tokens.data[0].delta_line, 14);
+    assert_eq!(tokens.data[0].delta_start, 4);
+    assert_eq!(tokens.data[0].token_type, 0);
+
+    let outside = Range {
+        start: Position::new(18, 0),
+        end: Position::new(19, 0),
+    };
+    assert!(handlers::semantic_tokens_range(content, outside).is_none());
+}
+
+#[test]
+fn semantic_tokens_range_starting_inside_path_parameters() {
+    let content = r#"
+/// # Path Parameters
+///
+/// user_id: The user identifier
+/// item_id: The item identifier
+#[rovo]
+async fn get_item(Path((user_id, item_id)): Path<(u64, u64)>) {}
+"#;
+
+    let range = Range {
+        start: Position::new(4, 0),
+        end: Position::new(4, 30),
+    };
+    let Some(SemanticTokensRangeResult::Tokens(tokens)) =
+        handlers::semantic_tokens_range(content, range)
+    else {
+        panic!("Should return semantic tokens for the range");
+    };
+
+    assert_eq!(tokens.data.len(), 1);
+    assert_eq!(tokens.data[0].delta_line, 4);
+    assert_eq!(tokens.data[0].length, "item_id".len() as u32);
+    assert_eq!(tokens.data[0].token_type, 5);
+}
+
 // Additional edge case tests for position helpers
 
 #[test]
@@ -2123,3 +2514,517 @@ async fn get_item(Path((user_id, item_id)): Path<(u64, u64)>) {}
         );
     }
 }
+
+#[test]
+fn duplicate_operation_ids_across_documents_get_related_info() {
+    let users_uri = Url::parse("file:///project/src/users.rs").unwrap();
+    let admin_uri = Url::parse("file:///project/src/admin.rs").unwrap();
+    let users = r#"
+/// # Metadata
+///
+/// @id getUser
+#[rovo]
+async fn get_user() {}
+"#;
+    let admin = r#"
+/// Get a user as an admin.
+#[rovo]
+async fn getUser() {}
+
+/// Unrelated handler.
+#[rovo]
+async fn list_users() {}
+"#;
+
+    let diagnostics = handlers::duplicate_operation_id_diagnostics(&[
+        (users_uri.clone(), users),
+        (admin_uri.clone(), admin),
+    ]);
+
+    let users_diags = &diagnostics[&users_uri];
+    assert_eq!(users_diags.len(), 1);
+    assert_eq!(users_diags[0].range.start, Position::new(3, 8));
+    assert!(users_diags[0].message.contains("'getUser'"));
+    assert!(users_diags[0].message.contains("admin.rs:4"));
+    let related = users_diags[0].related_information.as_ref().unwrap();
+    assert_eq!(related.len(), 1);
+    assert_eq!(related[0].location.uri, admin_uri);
+    assert_eq!(related[0].location.range.start, Position::new(3, 9));
+
+    let admin_diags = &diagnostics[&admin_uri];
+    assert_eq!(admin_diags.len(), 1);
+    let related = admin_diags[0].related_information.as_ref().unwrap();
+    assert_eq!(related[0].location.uri, users_uri);
+    assert_eq!(related[0].location.range.start, Position::new(3, 8));
+}
+
+#[test]
+fn unique_operation_ids_produce_no_diagnostics() {
+    let a = Url::parse("file:///project/src/a.rs").unwrap();
+    let b = Url::parse("file:///project/src/b.rs").unwrap();
+    let content_a = "#[rovo]\nasync fn get_user() {}\n";
+    let content_b = "/// @id listUsers\n#[rovo]\nasync fn get_user() {}\n";
+
+    let diagnostics = handlers::duplicate_operation_id_diagnostics(&[
+        (a.clone(), content_a),
+        (b.clone(), content_b),
+    ]);
+
+    assert!(diagnostics[&a].is_empty());
+    assert!(diagnostics[&b].is_empty());
+}
+
+fn active_parameter_label(help: &SignatureHelp) -> &str {
+    let signature = &help.signatures[0];
+    let params = signature.parameters.as_ref().unwrap();
+    let ParameterLabel::LabelOffsets([start, end]) =
+        params[help.active_parameter.unwrap() as usize].label
+    else {
+        panic!("expected label offsets");
+    };
+    &signature.label[start as usize..end as usize]
+}
+
+#[test]
+fn signature_help_tracks_annotation_arguments() {
+    let content = "/// @header 201 \n/// @body Json<User> - \n#[rovo]\nasync fn handler() {}\n";
+
+    let help = handlers::signature_help(content, Position::new(0, 16)).unwrap();
+    assert_eq!(
+        help.signatures[0].label,
+        "@header STATUS NAME TYPE DESCRIPTION"
+    );
+    assert_eq!(help.active_parameter, Some(1));
+    assert_eq!(active_parameter_label(&help), "NAME");
+
+    let help = handlers::signature_help(content, Position::new(0, 12)).unwrap();
+    assert_eq!(active_parameter_label(&help), "STATUS");
+
+    // `-` skips the optional content type
+    let help = handlers::signature_help(content, Position::new(1, 23)).unwrap();
+    assert_eq!(active_parameter_label(&help), "DESCRIPTION");
+}
+
+#[test]
+fn signature_help_covers_response_and_example_entries() {
+    let content = r#"
+/// # Responses
+///
+/// 200: Json<User> - Found
+///
+/// # Examples
+///
+/// 200: 
+#[rovo]
+async fn handler() {}
+"#;
+
+    let help = handlers::signature_help(content, Position::new(3, 9)).unwrap();
+    assert_eq!(help.signatures[0].label, "STATUS: TYPE - DESCRIPTION");
+    assert_eq!(active_parameter_label(&help), "TYPE");
+
+    let help = handlers::signature_help(content, Position::new(3, 22)).unwrap();
+    assert_eq!(active_parameter_label(&help), "DESCRIPTION");
+
+    let help = handlers::signature_help(content, Position::new(7, 9)).unwrap();
+    assert_eq!(help.signatures[0].label, "STATUS: EXPRESSION");
+}
+
+#[test]
+fn signature_help_ignores_annotations_without_arguments() {
+    let content = "/// @hidden \n/// @tag\n#[rovo]\nasync fn handler() {}\n";
+
+    assert!(handlers::signature_help(content, Position::new(0, 12)).is_none());
+    // The name is still being typed
+    assert!(handlers::signature_help(content, Position::new(1, 8)).is_none());
+}
+
+#[test]
+fn document_symbol_lists_handlers_sections_and_entries() {
+    let content = r#"use axum::Json;
+
+/// Get a user.
+///
+/// # Responses
+///
+/// 200: Json<User> - The user
+/// 404: () - Not found
+///
+/// # Examples
+///
+/// 200: User::default()
+///
+/// # Metadata
+///
+/// @tag users
+#[rovo]
+async fn get_user() -> Json<User> {
+    todo!()
+}
+
+async fn helper() {}
+
+/// # Path Parameters
+///
+/// id: The ID
+///
+/// # Responses
+///
+/// 4XX: Json<Error> - Client error
+#[rovo]
+pub async fn delete_user() {}
+"#;
+
+    let symbols = handlers::document_symbol(content);
+    assert_eq!(symbols.len(), 2);
+
+    let get_user = &symbols[0];
+    assert_eq!(get_user.name, "get_user");
+    assert_eq!(get_user.kind, SymbolKind::FUNCTION);
+    assert_eq!(get_user.range.start, Position::new(2, 0));
+    assert_eq!(get_user.range.end, Position::new(19, 1));
+    assert_eq!(get_user.selection_range.start, Position::new(17, 9));
+
+    let sections = get_user.children.as_ref().unwrap();
+    let names: Vec<&str> = sections.iter().map(|s| s.name.as_str()).collect();
+    assert_eq!(names, vec!["# Responses", "# Examples", "# Metadata"]);
+    assert_eq!(sections[0].kind, SymbolKind::NAMESPACE);
+    assert_eq!(sections[0].range.start, Position::new(4, 0));
+    assert_eq!(sections[0].range.end, Position::new(7, 23));
+
+    let responses = sections[0].children.as_ref().unwrap();
+    let names: Vec<&str> = responses.iter().map(|r| r.name.as_str()).collect();
+    assert_eq!(names, vec!["200", "404"]);
+    assert_eq!(responses[0].kind, SymbolKind::PROPERTY);
+    assert_eq!(responses[0].detail.as_deref(), Some("Json<User>"));
+    assert_eq!(responses[1].selection_range.start, Position::new(7, 4));
+
+    let example = &sections[1].children.as_ref().unwrap()[0];
+    assert_eq!(example.name, "200");
+    assert_eq!(example.detail.as_deref(), Some("User::default()"));
+
+    let tag = &sections[2].children.as_ref().unwrap()[0];
+    assert_eq!(tag.name, "@tag");
+    assert_eq!(tag.detail.as_deref(), Some("users"));
+
+    let delete_user = &symbols[1];
+    assert_eq!(delete_user.name, "delete_user");
+    let sections = delete_user.children.as_ref().unwrap();
+    assert_eq!(sections[0].children.as_ref().unwrap()[0].name, "id");
+    assert_eq!(sections[1].children.as_ref().unwrap()[0].name, "4XX");
+}
+
+#[test]
+fn document_symbol_ranges_use_utf16_columns() {
+    let content =
+        "/// # Responses\n///\n/// 200: Json<Café> - Le café ☕\n#[rovo]\nasync fn café() {}\n";
+
+    let symbols = handlers::document_symbol(content);
+    let response = &symbols[0].children.as_ref().unwrap()[0]
+        .children
+        .as_ref()
+        .unwrap()[0];
+    // "☕" is one UTF-16 unit but three bytes, "é" one unit but two bytes
+    assert_eq!(response.range.end, Position::new(2, 31));
+    assert_eq!(symbols[0].selection_range.end, Position::new(4, 13));
+}
+
+#[test]
+fn document_symbol_empty_without_handlers() {
+    assert!(handlers::document_symbol("async fn plain() {}\n").is_empty());
+}
+
+#[test]
+fn hover_on_responses_header_summarizes_responses() {
+    let content = r#"
+/// Get a user.
+///
+/// # Responses
+///
+/// 200: Json<User> - The user
+/// 404: () - No user with that ID
+/// 5XX: Json<Error> - Server error
+///
+/// # Examples
+///
+/// 200: User::default()
+#[rovo]
+async fn get_user() {}
+"#;
+
+    let hover = handlers::text_document_hover(content, Position::new(3, 8)).unwrap();
+    let HoverContents::Markup(markup) = hover.contents else {
+        panic!("expected markup");
+    };
+
+    assert!(markup.value.starts_with("| Status | Type | Description |"));
+    assert!(markup.value.contains("| 200 | `Json<User>` | The user |"));
+    assert!(markup
+        .value
+        .contains("| 404 | `()` | No user with that ID |"));
+    assert!(markup
+        .value
+        .contains("| 5XX | `Json<Error>` | Server error |"));
+    // The section's own documentation follows the table
+    assert!(markup.value.contains("# Responses Section"));
+}
+
+#[test]
+fn folding_ranges_cover_each_section() {
+    let content = r#"
+/// Get a user.
+///
+/// # Path Parameters
+///
+/// id: The user ID
+///
+/// # Responses
+///
+/// 200: Json<User> - The user
+/// 404: () - Not found
+///
+/// # Examples
+///
+/// # Metadata
+///
+/// @tag users
+#[rovo]
+async fn get_user() {}
+"#;
+
+    let ranges: Vec<(u32, u32)> = handlers::get_folding_ranges(content)
+        .iter()
+        .map(|range| (range.start_line, range.end_line))
+        .collect();
+
+    // The empty # Examples section has nothing to fold
+    assert_eq!(ranges, vec![(3, 5), (7, 10), (14, 16)]);
+}
+
+#[test]
+fn goto_definition_jumps_to_response_type() {
+    let content = r#"
+struct User {
+    id: u64,
+}
+
+/// # Responses
+///
+/// 200: Json<User> - The user
+/// 404: () - Not found
+#[rovo]
+async fn get_user() {}
+"#;
+    let uri = Url::parse("file:///test.rs").unwrap();
+
+    let Some(GotoDefinitionResponse::Scalar(location)) =
+        handlers::goto_definition(content, Position::new(7, 16), uri.clone())
+    else {
+        panic!("expected a single location");
+    };
+    assert_eq!(location.uri, uri);
+    assert_eq!(location.range.start, Position::new(1, 7));
+    assert_eq!(location.range.end, Position::new(1, 11));
+
+    // Like hover, nothing to resolve on the unit type or the description
+    assert!(handlers::goto_definition(content, Position::new(8, 10), uri.clone()).is_none());
+    assert!(handlers::goto_definition(content, Position::new(7, 25), uri).is_none());
+}
+
+#[test]
+fn hover_resolves_status_code_constants() {
+    let content = r#"
+/// # Responses
+///
+/// StatusCode::CREATED: Json<User> - ok
+#[rovo]
+async fn handler() {}
+"#;
+
+    let hover = handlers::text_document_hover(content, Position::new(3, 16)).unwrap();
+    let HoverContents::Markup(markup) = hover.contents else {
+        panic!("expected markup");
+    };
+    assert!(markup.value.contains("201 Created"));
+}
+
+#[test]
+fn selection_ranges_expand_from_status_code_to_handler() {
+    let content = r#"
+/// Get a user.
+///
+/// # Responses
+///
+/// 200: Json<User> - Success
+/// 404: () - No user with
+/// that ID
+///
+/// # Metadata
+///
+/// @tag users
+#[rovo]
+async fn get_user() {}
+"#;
+
+    let ranges = handlers::get_selection_ranges(
+        content,
+        &[
+            Position::new(5, 5),
+            Position::new(7, 6),
+            Position::new(13, 2),
+        ],
+    );
+    assert_eq!(ranges.len(), 3);
+
+    let mut chain = Vec::new();
+    let mut current = Some(&ranges[0]);
+    while let Some(selection) = current {
+        chain.push(selection.range);
+        current = selection.parent.as_deref();
+    }
+    assert_eq!(
+        chain,
+        vec![
+            // The status code, then the response entry
+            Range::new(Position::new(5, 4), Position::new(5, 7)),
+            Range::new(Position::new(5, 4), Position::new(5, 29)),
+            // The # Responses section, then the doc block
+            Range::new(Position::new(3, 0), Position::new(7, 11)),
+            Range::new(Position::new(1, 0), Position::new(11, 14)),
+            // The whole handler
+            Range::new(Position::new(1, 0), Position::new(13, 22)),
+        ]
+    );
+
+    // A continuation line belongs to its multi-line entry
+    let entry = ranges[1].parent.as_deref().unwrap();
+    assert_eq!(
+        entry.range,
+        Range::new(Position::new(6, 4), Position::new(7, 11))
+    );
+
+    // Outside a doc block the range is empty
+    assert_eq!(
+        ranges[2].range,
+        Range::new(Position::new(13, 2), Position::new(13, 2))
+    );
+    assert!(ranges[2].parent.is_none());
+}
+
+#[test]
+fn inlay_hints_show_the_operation_summary() {
+    let content = r#"
+/// List users.
+///
+/// Returns every user, paginated.
+///
+/// # Responses
+///
+/// 200: Json<Vec<User>> - The users
+#[rovo]
+async fn list_users() {}
+
+/// Delete a user.
+///
+/// # Metadata
+///
+/// @summary Remove a user
+#[rovo]
+async fn delete_user() {}
+
+/// # Responses
+///
+/// 204: () - Done
+#[rovo]
+async fn untitled() {}
+"#;
+    let everything = Range::new(Position::new(0, 0), Position::new(30, 0));
+
+    let hints = handlers::get_inlay_hints(content, everything);
+    let labels: Vec<(Position, String)> = hints
+        .into_iter()
+        .map(|hint| match hint.label {
+            InlayHintLabel::String(label) => (hint.position, label),
+            InlayHintLabel::LabelParts(_) => panic!("expected a string label"),
+        })
+        .collect();
+    assert_eq!(
+        labels,
+        vec![
+            (Position::new(8, 7), "List users.".to_string()),
+            (Position::new(16, 7), "Remove a user".to_string()),
+        ]
+    );
+
+    // Only attributes in the requested range get hints
+    let first_handler = Range::new(Position::new(0, 0), Position::new(9, 0));
+    assert_eq!(handlers::get_inlay_hints(content, first_handler).len(), 1);
+}
+
+#[test]
+fn code_lenses_summarize_each_handler() {
+    let content = r#"
+/// Get a user.
+///
+/// # Responses
+///
+/// 200: Json<User> - The user
+/// 404: () - No such user
+///
+/// # Metadata
+///
+/// @id getUserById
+/// @tag users
+/// @tag admin
+#[rovo]
+async fn get_user() {}
+
+/// Health check.
+#[rovo]
+async fn health() {}
+"#;
+    let uri = Url::parse("file:///test.rs").unwrap();
+
+    let lenses: Vec<(u32, String, Vec<serde_json::Value>)> =
+        handlers::get_code_lenses(content, &uri)
+            .into_iter()
+            .map(|lens| {
+                assert!(lens.command.is_none());
+                let command = handlers::resolve_code_lens(lens.clone()).command.unwrap();
+                assert_eq!(command.command, handlers::SHOW_LOCATION_COMMAND);
+                (
+                    lens.range.start.line,
+                    command.title,
+                    command.arguments.unwrap(),
+                )
+            })
+            .collect();
+
+    let target = |line: u32| {
+        vec![
+            serde_json::json!("file:///test.rs"),
+            serde_json::json!(line),
+        ]
+    };
+    assert_eq!(
+        lenses,
+        vec![
+            (13, "op: getUserById".to_string(), target(10)),
+            (13, "2 tags".to_string(), target(11)),
+            (13, "2 responses".to_string(), target(3)),
+            (17, "op: health".to_string(), target(18)),
+            (17, "0 tags".to_string(), target(17)),
+            (17, "0 responses".to_string(), target(17)),
+        ]
+    );
+
+    // Clicking a lens reveals its line
+    let show = handlers::show_location_params(&ExecuteCommandParams {
+        command: handlers::SHOW_LOCATION_COMMAND.to_string(),
+        arguments: target(10),
+        work_done_progress_params: WorkDoneProgressParams::default(),
+    })
+    .unwrap();
+    assert_eq!(show.uri, uri);
+    assert_eq!(show.selection.unwrap().start, Position::new(10, 0));
+}
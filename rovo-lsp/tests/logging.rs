@@ -0,0 +1,113 @@
+use std::io::{BufRead, BufReader, Write};
+use std::process::{Command, Stdio};
+
+/// Frame a JSON-RPC message with the LSP `Content-Length` header
+fn frame(body: &str) -> String {
+    format!("Content-Length: {}\r\n\r\n{}", body.len(), body)
+}
+
+/// Read a single framed message body from the server's stdout
+fn read_message(reader: &mut impl BufRead) -> String {
+    let mut content_length = 0;
+    loop {
+        let mut header = String::new();
+        reader.read_line(&mut header).unwrap();
+        let header = header.trim();
+        if header.is_empty() {
+            break;
+        }
+        if let Some(len) = header.strip_prefix("Content-Length:") {
+            content_length = len.trim().parse().unwrap();
+        }
+    }
+    let mut body = vec![0; content_length];
+    reader.read_exact(&mut body).unwrap();
+    String::from_utf8(body).unwrap()
+}
+
+/// Read messages until the response with the given id arrives
+fn read_response(reader: &mut impl BufRead, id: u32) -> String {
+    let needle = format!("\"id\":{}", id);
+    loop {
+        let message = read_message(reader);
+        if message.contains(&needle) {
+            return message;
+        }
+    }
+}
+
+#[test]
+fn semantic_tokens_do_not_write_to_stderr_by_default() {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_rovo-lsp"))
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("failed to start rovo-lsp");
+
+    let mut stdin = child.stdin.take().unwrap();
+    let mut stdout = BufReader::new(child.stdout.take().unwrap());
+
+    let text = "/// @tag users\\n#[rovo]\\nasync fn handler() {}\\n";
+    let uri = "file:///test.rs";
+
+    stdin
+        .write_all(
+            frame(r#"{"jsonrpc":"2.0","id":1,"method":"initialize","params":{"capabilities":{}}}"#)
+                .as_bytes(),
+        )
+        .unwrap();
+    read_response(&mut stdout, 1);
+
+    stdin
+        .write_all(frame(r#"{"jsonrpc":"2.0","method":"initialized","params":{}}"#).as_bytes())
+        .unwrap();
+    stdin
+        .write_all(
+            frame(&format!(
+                r#"{{"jsonrpc":"2.0","method":"textDocument/didOpen","params":{{"textDocument":{{"uri":"{}","languageId":"rust","version":1,"text":"{}"}}}}}}"#,
+                uri, text
+            ))
+            .as_bytes(),
+        )
+        .unwrap();
+
+    // didOpen is a notification, so retry until the document has been stored
+    let mut tokens = String::new();
+    for id in 2..12 {
+        stdin
+            .write_all(
+                frame(&format!(
+                    r#"{{"jsonrpc":"2.0","id":{},"method":"textDocument/semanticTokens/full","params":{{"textDocument":{{"uri":"{}"}}}}}}"#,
+                    id, uri
+                ))
+                .as_bytes(),
+            )
+            .unwrap();
+        tokens = read_response(&mut stdout, id);
+        if tokens.contains("\"data\"") {
+            break;
+        }
+    }
+    assert!(
+        tokens.contains("\"data\""),
+        "expected semantic tokens: {}",
+        tokens
+    );
+
+    stdin
+        .write_all(frame(r#"{"jsonrpc":"2.0","id":100,"method":"shutdown"}"#).as_bytes())
+        .unwrap();
+    read_response(&mut stdout, 100);
+    stdin
+        .write_all(frame(r#"{"jsonrpc":"2.0","method":"exit"}"#).as_bytes())
+        .unwrap();
+    drop(stdin);
+
+    let output = child.wait_with_output().unwrap();
+    assert!(
+        output.stderr.is_empty(),
+        "rovo-lsp wrote to stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
@@ -48,8 +48,8 @@ fn accepts_valid_status_codes() {
     let content = r#"
 /// # Responses
 ///
-/// 200: Json<User> - OK
-/// 404: Json<Error> - Not found
+/// 200: Json<User> - User found
+/// 404: Json<Error> - User does not exist
 /// 500: Json<Error> - Server error
 #[rovo]
 async fn handler() {}
@@ -75,6 +75,46 @@ async fn handler() {}
     assert_eq!(diagnostics.len(), 0);
 }
 
+#[test]
+fn accepts_status_ranges_and_default() {
+    let content = r#"
+/// # Responses
+///
+/// 200: Json<User> - User found
+/// 4XX: Json<Error> - The request was rejected
+///      for any client-side reason
+/// default: Json<Error> - Anything else
+#[rovo]
+async fn handler() {}
+"#;
+    let diagnostics = validate_annotations(content);
+    assert_eq!(diagnostics.len(), 0);
+
+    let annotations = rovo_lsp::parser::parse_annotations(content);
+    let ranges: Vec<_> = annotations
+        .iter()
+        .filter_map(|ann| ann.status_range.as_deref())
+        .collect();
+    assert_eq!(ranges, vec!["4XX", "default"]);
+}
+
+#[test]
+fn reports_invalid_status_range() {
+    let content = r#"
+/// # Responses
+///
+/// 6XX: Json<Error> - Out of range
+#[rovo]
+async fn handler() {}
+"#;
+    let diagnostics = validate_annotations(content);
+    assert_eq!(diagnostics.len(), 1);
+    assert!(diagnostics[0]
+        .message
+        .contains("Invalid HTTP status code range: 6XX"));
+    assert_eq!(diagnostics[0].char_start, Some(4));
+}
+
 #[test]
 fn reports_multiple_errors() {
     let content = r#"
@@ -102,6 +142,26 @@ async fn handler() {}
     assert_eq!(diagnostics.len(), 0);
 }
 
+#[test]
+fn reports_out_of_range_example_status() {
+    let content = r#"
+/// # Examples
+///
+/// 999: User::default()
+#[rovo]
+async fn handler() {}
+"#;
+    let diagnostics = validate_annotations(content);
+    assert_eq!(diagnostics.len(), 1);
+    assert!(diagnostics[0]
+        .message
+        .contains("Invalid HTTP status code: 999. Must be between 100 and 599."));
+    assert_eq!(diagnostics[0].severity, DiagnosticSeverity::Error);
+    assert_eq!(diagnostics[0].line, 3);
+    assert_eq!(diagnostics[0].char_start, Some(4));
+    assert_eq!(diagnostics[0].char_end, Some(7));
+}
+
 #[test]
 fn reports_invalid_example_syntax() {
     let content = r#"
@@ -858,3 +918,781 @@ async fn real_handler(Path(user_id): Path<u64>) {}
     );
     assert!(path_param_warnings[0].message.contains("'user_id'"));
 }
+
+#[test]
+fn warns_on_trait_object_response_type() {
+    let content = r#"
+/// # Responses
+///
+/// 200: Json<Box<dyn Any>> - x
+#[rovo]
+async fn handler() {}
+"#;
+    let diagnostics = validate_annotations(content);
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].severity, DiagnosticSeverity::Warning);
+    assert!(diagnostics[0].message.contains("trait objects"));
+    assert!(diagnostics[0].message.contains("schemars"));
+    // Highlights the response type
+    assert_eq!(diagnostics[0].char_start, Some(9));
+    assert_eq!(
+        diagnostics[0].char_end,
+        Some(9 + "Json<Box<dyn Any>>".len())
+    );
+}
+
+#[test]
+fn warns_on_closure_response_type() {
+    let content = r#"
+/// # Responses
+///
+/// 200: Box<dyn Fn() -> String> - x
+#[rovo]
+async fn handler() {}
+"#;
+    let diagnostics = validate_annotations(content);
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].severity, DiagnosticSeverity::Warning);
+}
+
+#[test]
+fn no_schema_warning_for_types_containing_keywords() {
+    let content = r#"
+/// # Responses
+///
+/// 200: Json<Dynamic> - Type name starts with "dyn"
+/// 201: Json<MyFn> - Type name ends with "Fn"
+/// 202: Json<Simple> - Type name ends with "impl"
+#[rovo]
+async fn handler() {}
+"#;
+    let diagnostics = validate_annotations(content);
+    assert_eq!(diagnostics.len(), 0);
+}
+
+#[test]
+fn hints_on_reason_phrase_only_description() {
+    let content = r#"
+/// # Responses
+///
+/// 404: () - Not Found
+#[rovo]
+async fn handler() {}
+"#;
+    let diagnostics = validate_annotations(content);
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].severity, DiagnosticSeverity::Hint);
+    assert!(diagnostics[0].message.contains("reason phrase"));
+    // Highlights only the description
+    assert_eq!(diagnostics[0].char_start, Some(14));
+    assert_eq!(diagnostics[0].char_end, Some(23));
+}
+
+#[test]
+fn hints_on_reason_phrase_case_insensitively() {
+    let content = r#"
+/// # Responses
+///
+/// 200: Json<User> - ok
+/// 422: Json<Error> - Unprocessable entity.
+#[rovo]
+async fn handler() {}
+"#;
+    let diagnostics = validate_annotations(content);
+    assert_eq!(diagnostics.len(), 2);
+    assert!(diagnostics
+        .iter()
+        .all(|d| d.severity == DiagnosticSeverity::Hint));
+}
+
+#[test]
+fn no_hint_for_specific_description() {
+    let content = r#"
+/// # Responses
+///
+/// 404: () - User does not exist
+#[rovo]
+async fn handler() {}
+"#;
+    let diagnostics = validate_annotations(content);
+    assert_eq!(diagnostics.len(), 0);
+}
+
+#[test]
+fn hints_on_missing_blank_line_after_section_header() {
+    let content = r#"
+/// # Responses
+/// 200: Json<User> - User found
+#[rovo]
+async fn handler() {}
+"#;
+    let diagnostics = validate_annotations(content);
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].severity, DiagnosticSeverity::Hint);
+    assert_eq!(diagnostics[0].line, 1);
+    assert!(diagnostics[0]
+        .message
+        .contains("Missing blank line after section header '# Responses'"));
+}
+
+#[test]
+fn no_hint_when_section_header_followed_by_blank_line() {
+    let content = r#"
+/// # Metadata
+///
+/// @tag users
+#[rovo]
+async fn handler() {}
+"#;
+    let diagnostics = validate_annotations(content);
+    assert!(diagnostics.is_empty());
+}
+
+#[test]
+fn errors_on_header_for_undocumented_status() {
+    let content = r#"
+/// # Responses
+///
+/// 200: Json<User> - User found
+/// @header 201 Location string The URL of the created user
+#[rovo]
+async fn handler() {}
+"#;
+    let diagnostics = validate_annotations(content);
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].severity, DiagnosticSeverity::Error);
+    assert_eq!(diagnostics[0].line, 4);
+    assert!(diagnostics[0]
+        .message
+        .contains("Header 'Location' refers to status code 201"));
+}
+
+#[test]
+fn no_error_on_header_for_documented_status() {
+    let content = r#"
+/// # Responses
+///
+/// 201: Json<User> - User created
+///
+/// # Metadata
+///
+/// @header 201 Location string The URL of the created user
+#[rovo]
+async fn create() {}
+
+/// # Responses
+///
+/// 200: Json<User> - User found
+#[rovo]
+async fn get() {}
+"#;
+    let diagnostics = validate_annotations(content);
+    assert!(diagnostics.is_empty());
+}
+
+#[test]
+fn warns_on_server_url_without_scheme() {
+    let content = r#"
+/// # Metadata
+///
+/// @server uploads.example.com Upload server
+/// @server https:// Missing host
+/// @server https://uploads.example.com Upload server
+#[rovo]
+async fn upload() {}
+"#;
+    let diagnostics = validate_annotations(content);
+    let lines: Vec<usize> = diagnostics.iter().map(|d| d.line).collect();
+    assert_eq!(lines, vec![3, 4]);
+    assert!(diagnostics
+        .iter()
+        .all(|d| d.severity == DiagnosticSeverity::Warning));
+    assert!(diagnostics[0]
+        .message
+        .contains("Invalid server URL: uploads.example.com"));
+    assert_eq!(diagnostics[0].char_start, Some(12));
+}
+
+#[test]
+fn warns_on_invalid_external_docs_url() {
+    let content = r#"
+/// # Metadata
+///
+/// @external-docs docs.example.com/users Users guide
+/// @external-docs https://docs.example.com/users Users guide
+#[rovo]
+async fn list() {}
+"#;
+    let diagnostics = validate_annotations(content);
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].line, 3);
+    assert_eq!(diagnostics[0].severity, DiagnosticSeverity::Warning);
+    assert!(diagnostics[0]
+        .message
+        .contains("Invalid external docs URL: docs.example.com/users"));
+}
+
+#[test]
+fn warns_on_max_version_without_deprecation() {
+    let content = r#"
+/// # Metadata
+///
+/// @max-version 3.5
+#[rovo]
+async fn legacy_search() {}
+
+/// # Metadata
+///
+/// @max-version 3.5
+/// @deprecated Use /v4/search
+#[rovo]
+async fn old_search() {}
+
+/// # Metadata
+///
+/// @max-version 3.5
+#[deprecated]
+#[rovo]
+async fn older_search() {}
+
+/// # Metadata
+///
+/// @min-version 2.0
+#[rovo]
+async fn search() {}
+"#;
+    let diagnostics = validate_annotations(content);
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].line, 3);
+    assert_eq!(diagnostics[0].severity, DiagnosticSeverity::Warning);
+    assert!(diagnostics[0]
+        .message
+        .contains("@max-version is set but the operation is not deprecated"));
+}
+
+#[test]
+fn validates_ratelimit_values() {
+    let content = r#"
+/// # Metadata
+///
+/// @ratelimit 100 per-minute
+/// @ratelimit 0 per-second
+/// @ratelimit 1.5 per-hour
+/// @ratelimit 100 per-day
+/// @ratelimit 100
+#[rovo]
+async fn search() {}
+"#;
+    let diagnostics = validate_annotations(content);
+    let found: Vec<_> = diagnostics
+        .iter()
+        .map(|d| (d.line, d.char_start, d.char_end))
+        .collect();
+    assert_eq!(
+        found,
+        vec![
+            (4, Some(15), Some(16)),
+            (5, Some(15), Some(18)),
+            (6, Some(19), Some(26)),
+            (7, Some(4), Some(18)),
+        ]
+    );
+    assert!(diagnostics
+        .iter()
+        .all(|d| d.severity == DiagnosticSeverity::Error));
+    assert!(diagnostics[0]
+        .message
+        .contains("Invalid rate limit count: 0. Must be a positive integer."));
+    assert!(diagnostics[2]
+        .message
+        .contains("Invalid rate limit window: per-day"));
+    assert!(diagnostics[3]
+        .message
+        .contains("Expected '@ratelimit <count> per-<window>'"));
+}
+
+#[test]
+fn ignores_ratelimit_lines_outside_rovo_doc_blocks() {
+    let content = r#"
+/// @ratelimit is documented in the README
+struct Limits;
+
+/// # Metadata
+///
+/// @rovo-ignore
+/// @ratelimit lots per-day
+#[rovo]
+async fn search() {}
+"#;
+    let diagnostics = validate_annotations(content);
+    assert!(
+        diagnostics
+            .iter()
+            .all(|d| !d.message.contains("rate limit") && !d.message.contains("@ratelimit")),
+        "got: {diagnostics:?}"
+    );
+}
+
+#[test]
+fn hints_on_secured_get_without_auth_failure_response() {
+    let content = r#"
+/// # Responses
+///
+/// 200: Json<User> - The current user
+///
+/// # Metadata
+///
+/// @security bearer
+#[rovo]
+async fn current_user() {}
+
+/// # Responses
+///
+/// 200: Json<User> - The user
+/// 401: () - Missing credentials
+///
+/// # Metadata
+///
+/// @security bearer
+#[rovo]
+async fn get_user() {}
+
+/// # Responses
+///
+/// 201: Json<User> - The created user
+///
+/// # Metadata
+///
+/// @security bearer
+#[rovo]
+async fn create_user() {}
+
+/// # Metadata
+///
+/// @security bearer
+#[rovo]
+async fn unrouted() {}
+
+fn app() -> Router {
+    Router::new()
+        .route("/me", get(current_user))
+        .route("/users/{id}", get(get_user))
+        .route("/users", post(create_user))
+}
+"#;
+    let diagnostics = validate_annotations(content);
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].line, 7);
+    assert_eq!(diagnostics[0].severity, DiagnosticSeverity::Hint);
+    assert!(diagnostics[0]
+        .message
+        .contains("Secured GET endpoint does not document a 401 or 403 response"));
+}
+
+#[test]
+fn warns_when_tuple_path_has_fewer_documented_params() {
+    let content = r#"
+/// # Path Parameters
+///
+/// ids: The user ID and post slug
+#[rovo]
+async fn handler(Path(ids): Path<(u64, String)>) {}
+"#;
+    let diagnostics = validate_annotations(content);
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].severity, DiagnosticSeverity::Warning);
+    assert_eq!(diagnostics[0].line, 5);
+    assert!(diagnostics[0]
+        .message
+        .contains("Path extractor takes 2 parameter(s), but 1 are documented"));
+}
+
+#[test]
+fn warns_when_path_has_more_documented_params() {
+    let content = r#"
+/// # Path Parameters
+///
+/// id: The user ID
+/// slug: The post slug
+/// page: The page number
+#[rovo]
+async fn handler(Path((id, slug)): Path<(u64, String)>) {}
+"#;
+    let diagnostics = validate_annotations(content);
+    assert_eq!(diagnostics.len(), 1);
+    assert!(diagnostics[0]
+        .message
+        .contains("Path extractor takes 2 parameter(s), but 3 are documented"));
+}
+
+#[test]
+fn no_arity_warning_when_counts_match() {
+    let content = r#"
+/// # Path Parameters
+///
+/// @path id The user ID
+/// slug: The post slug
+#[rovo]
+async fn handler(Path((id, slug)): Path<(u64, Vec<String>)>) {}
+"#;
+    let diagnostics = validate_annotations(content);
+    assert!(diagnostics.is_empty(), "{diagnostics:?}");
+}
+
+#[test]
+fn accepts_status_code_constants() {
+    let content = r#"
+/// # Responses
+///
+/// StatusCode::CREATED: Json<User> - ok
+/// StatusCode::NOPE: () - Never
+#[rovo]
+async fn handler() {}
+"#;
+    let annotations = rovo_lsp::parser::parse_annotations(content);
+    assert_eq!(annotations[1].status, Some(201));
+
+    let diagnostics = validate_annotations(content);
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].line, 4);
+    assert!(diagnostics[0]
+        .message
+        .contains("Unknown status code constant 'StatusCode::NOPE'"));
+    assert_eq!(diagnostics[0].char_start, Some(4));
+    assert_eq!(diagnostics[0].char_end, Some(20));
+}
+
+#[test]
+fn warns_when_response_type_lacks_jsonschema() {
+    let content = r#"
+#[derive(Serialize)]
+struct User {
+    id: u64,
+}
+
+/// # Responses
+///
+/// 200: Json<User> - The user
+#[rovo]
+async fn handler() {}
+"#;
+    let diagnostics = validate_annotations(content);
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].severity, DiagnosticSeverity::Warning);
+    assert!(diagnostics[0]
+        .message
+        .contains("Type 'User' (line 3) doesn't derive JsonSchema"));
+    assert_eq!(diagnostics[0].line, 8);
+    assert_eq!(diagnostics[0].char_start, Some(14));
+    assert_eq!(diagnostics[0].char_end, Some(18));
+}
+
+#[test]
+fn no_jsonschema_warning_for_derived_or_primitive_types() {
+    let content = r#"
+#[derive(Serialize, JsonSchema)]
+struct User {
+    id: u64,
+}
+
+/// # Responses
+///
+/// 200: Json<User> - The user
+/// 201: Json<u64> - The new ID
+/// 204: () - Nothing
+/// 404: Json<ApiError> - Defined in another file
+#[rovo]
+async fn handler() {}
+"#;
+    let diagnostics = validate_annotations(content);
+    assert_eq!(diagnostics.len(), 0);
+}
+
+#[test]
+fn hints_only_at_bare_annotations_in_metadata() {
+    let content = r#"
+/// Tag users by their role.
+///
+/// # Metadata
+///
+/// @tag users
+/// hidden
+/// id
+#[rovo]
+async fn handler() {}
+"#;
+    let diagnostics = validate_annotations(content);
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].line, 6);
+    assert!(diagnostics[0]
+        .message
+        .starts_with("'hidden' looks like an annotation missing its '@'"));
+}
+
+#[test]
+fn reports_duplicate_operation_ids() {
+    let content = r#"
+/// # Metadata
+///
+/// @id getUser
+#[rovo]
+async fn get_user() {}
+
+/// # Metadata
+///
+/// @id getUser
+#[rovo]
+async fn get_user_again() {}
+
+/// # Metadata
+///
+/// @id listUsers
+#[rovo]
+async fn list_users() {}
+"#;
+    let diagnostics = validate_annotations(content);
+    assert_eq!(diagnostics.len(), 2);
+
+    assert_eq!(diagnostics[0].line, 3);
+    assert!(diagnostics[0]
+        .message
+        .starts_with("Duplicate operation ID 'getUser', also used on line 10."));
+    assert_eq!(diagnostics[0].severity, DiagnosticSeverity::Error);
+    assert_eq!(diagnostics[0].char_start, Some(8));
+    assert_eq!(diagnostics[0].char_end, Some(15));
+
+    assert_eq!(diagnostics[1].line, 9);
+    assert!(diagnostics[1].message.contains("also used on line 4."));
+}
+
+#[test]
+fn warns_about_examples_without_matching_response() {
+    let content = r#"
+/// # Responses
+///
+/// 200: Json<User> - The user
+/// 5XX: Json<ApiError> - Server failure
+///
+/// # Examples
+///
+/// 200: User::default()
+/// 503: ApiError::default()
+#[rovo]
+async fn handler() {}
+
+/// # Examples
+///
+/// 404: ApiError::default()
+#[rovo]
+async fn without_responses() {}
+"#;
+    let diagnostics = validate_annotations(content);
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].line, 9);
+    assert!(diagnostics[0]
+        .message
+        .contains("Available status codes: 200, 5XX."));
+    assert_eq!(diagnostics[0].severity, DiagnosticSeverity::Warning);
+    assert!(diagnostics[0]
+        .message
+        .starts_with("Example for status code 503 has no matching response."));
+    assert_eq!(diagnostics[0].char_start, Some(4));
+    assert_eq!(diagnostics[0].char_end, Some(7));
+}
+
+#[test]
+fn warns_about_tags_on_hidden_handlers() {
+    let content = r#"
+/// # Metadata
+///
+/// @tag internal
+/// @hidden
+#[rovo]
+async fn hidden() {}
+
+/// # Metadata
+///
+/// @tag users
+#[rovo]
+async fn visible() {}
+"#;
+    let diagnostics = validate_annotations(content);
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].line, 3);
+    assert_eq!(diagnostics[0].severity, DiagnosticSeverity::Warning);
+    assert!(diagnostics[0]
+        .message
+        .starts_with("Tag 'internal' has no effect because the operation is @hidden."));
+    assert_eq!(diagnostics[0].char_start, Some(4));
+    assert_eq!(diagnostics[0].char_end, Some(17));
+}
+
+#[test]
+fn warns_about_duplicate_response_statuses() {
+    let content = r#"
+/// # Responses
+///
+/// 200: Json<User> - The user
+/// 4XX: Json<ApiError> - Client error
+/// 200: Json<Admin> - The admin
+/// 4XX: () - Also a client error
+#[rovo]
+async fn handler() {}
+
+/// # Responses
+///
+/// 200: Json<User> - Another handler's user
+#[rovo]
+async fn other() {}
+"#;
+    let diagnostics = validate_annotations(content);
+    assert_eq!(diagnostics.len(), 2);
+
+    assert_eq!(diagnostics[0].line, 5);
+    assert_eq!(diagnostics[0].severity, DiagnosticSeverity::Warning);
+    assert!(diagnostics[0]
+        .message
+        .starts_with("Duplicate status code 200, already documented on line 4."));
+    assert_eq!(diagnostics[0].char_start, Some(4));
+    assert_eq!(diagnostics[0].char_end, Some(7));
+
+    assert_eq!(diagnostics[1].line, 6);
+    assert!(diagnostics[1]
+        .message
+        .starts_with("Duplicate status code 4XX, already documented on line 5."));
+}
+
+#[test]
+fn hints_at_bare_string_response() {
+    let content = r#"
+/// # Responses
+///
+/// 200: String - text
+#[rovo]
+async fn handler() {}
+"#;
+    let diagnostics = validate_annotations(content);
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].severity, DiagnosticSeverity::Hint);
+    assert!(diagnostics[0]
+        .message
+        .contains("Did you mean 'Json<String>' for a JSON string?"));
+    assert_eq!(diagnostics[0].char_start, Some(9));
+    assert_eq!(diagnostics[0].char_end, Some(15));
+}
+
+#[test]
+fn no_hint_for_json_string_response() {
+    let content = r#"
+/// # Responses
+///
+/// 200: Json<String> - json
+#[rovo]
+async fn handler() {}
+"#;
+    let diagnostics = validate_annotations(content);
+    assert_eq!(diagnostics.len(), 0);
+}
+
+#[test]
+fn reports_unknown_metadata_annotations() {
+    let content = r#"
+/// Mentions @someone in the description.
+///
+/// # Metadata
+///
+/// @tga users
+/// @frobnicate
+/// @rovo-ignore
+/// @anything goes here
+#[rovo]
+async fn handler() {}
+"#;
+    let diagnostics = validate_annotations(content);
+    assert_eq!(diagnostics.len(), 2);
+
+    assert_eq!(diagnostics[0].line, 5);
+    assert_eq!(diagnostics[0].severity, DiagnosticSeverity::Error);
+    assert_eq!(
+        diagnostics[0].message,
+        "Unknown annotation '@tga'.\nDid you mean '@tag'?"
+    );
+    assert_eq!(diagnostics[0].char_start, Some(4));
+    assert_eq!(diagnostics[0].char_end, Some(8));
+
+    assert_eq!(diagnostics[1].line, 6);
+    assert_eq!(diagnostics[1].message, "Unknown annotation '@frobnicate'.");
+}
+
+#[test]
+fn points_tag_descriptions_on_handlers_to_rovo_module() {
+    let content = r#"
+/// # Metadata
+///
+/// @tag-description users Endpoints for managing users
+#[rovo]
+async fn handler() {}
+"#;
+    let diagnostics = validate_annotations(content);
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].severity, DiagnosticSeverity::Error);
+    assert!(diagnostics[0].message.contains("#[rovo_module]"));
+    assert_eq!(diagnostics[0].char_start, Some(4));
+    assert_eq!(diagnostics[0].char_end, Some(20));
+}
+
+#[test]
+fn warns_about_duplicate_tags() {
+    let content = r#"
+/// # Metadata
+///
+/// @tag users
+/// @tag admin
+/// @tag users
+#[rovo]
+async fn list_users() {}
+
+/// # Metadata
+///
+/// @tag users
+#[rovo]
+async fn get_user() {}
+"#;
+    let diagnostics = validate_annotations(content);
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].line, 5);
+    assert_eq!(diagnostics[0].severity, DiagnosticSeverity::Warning);
+    assert!(diagnostics[0]
+        .message
+        .starts_with("Duplicate tag 'users', already added on line 4."));
+    assert_eq!(diagnostics[0].char_start, Some(4));
+    assert_eq!(diagnostics[0].char_end, Some(14));
+}
+
+#[test]
+fn validates_handlers_with_rovo_options() {
+    let content = r#"
+/// # Responses
+///
+/// 200: Json<User> - The user
+/// 200: Json<Admin> - The admin
+///
+/// # Metadata
+///
+/// @tag a
+/// @tag a
+/// @bogus
+#[rovo(code_samples)]
+async fn handler() {}
+"#;
+    let diagnostics = validate_annotations(content);
+    let messages: Vec<&str> = diagnostics.iter().map(|d| d.message.as_str()).collect();
+    assert_eq!(diagnostics.len(), 3, "{messages:?}");
+    assert!(messages
+        .iter()
+        .any(|m| m.starts_with("Duplicate status code 200")));
+    assert!(messages.iter().any(|m| m.starts_with("Duplicate tag 'a'")));
+    assert!(messages
+        .iter()
+        .any(|m| m.starts_with("Unknown annotation '@bogus'")));
+}
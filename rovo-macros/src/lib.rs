@@ -19,7 +19,11 @@ use quote::{quote, quote_spanned};
 mod parser;
 mod utils;
 
-use parser::{parse_rovo_function, PathParamDoc, PathParamInfo};
+use parser::{
+    is_impl_block, parse_rovo_function, parse_rovo_impl, parse_rovo_module, DocInfo, ExampleInfo,
+    ExampleLabel, FuncItem, LinkDoc, MethodInfo, ParamDoc, ParamLocation, ParseWarning,
+    PathParamDoc, PathParamInfo, StatusKey,
+};
 
 /// Known primitive types that map to `OpenAPI` types
 const PRIMITIVE_TYPES: &[&str] = &[
@@ -53,6 +57,18 @@ fn extract_tuple_types(type_str: &str) -> Vec<String> {
         .collect()
 }
 
+/// Check if a type is `Option<T>`, however its path is written
+fn is_option_type(ty: &proc_macro2::TokenStream) -> bool {
+    let Ok(syn::Type::Path(type_path)) = syn::parse2::<syn::Type>(ty.clone()) else {
+        return false;
+    };
+    type_path.qself.is_none()
+        && type_path.path.segments.last().is_some_and(|segment| {
+            segment.ident == "Option"
+                && matches!(segment.arguments, syn::PathArguments::AngleBracketed(_))
+        })
+}
+
 /// Generate path parameter setters for primitive types
 fn generate_path_param_setters(
     path_info: Option<&PathParamInfo>,
@@ -64,7 +80,7 @@ fn generate_path_param_setters(
 
     // If it's a struct pattern, let aide handle it via JsonSchema
     if info.is_struct_pattern {
-        return vec![];
+        return generate_path_description_setters(path_docs);
     }
 
     // Check if the type is primitive (single or tuple)
@@ -75,7 +91,7 @@ fn generate_path_param_setters(
     };
 
     if !is_primitive {
-        return vec![];
+        return generate_path_description_setters(path_docs);
     }
 
     // Extract types for each binding
@@ -141,6 +157,440 @@ fn generate_path_param_setters(
         .collect()
 }
 
+/// Generate a setter that documents a `@param`/`@query` path or query parameter
+///
+/// Describes the parameter aide already generated from the extractor, or adds it
+/// if the extractor's type doesn't expose it (e.g. primitive `Path` types).
+fn generate_param_setter(param: &ParamDoc) -> proc_macro2::TokenStream {
+    let name = &param.name;
+    let description = &param.description;
+    let param_type = &param.param_type;
+
+    let (variant, style_fields, required) = if param.location == Some(ParamLocation::Path) {
+        (
+            quote! { Path },
+            quote! { style: ::rovo::aide::openapi::PathStyle::Simple, },
+            true,
+        )
+    } else {
+        (
+            quote! { Query },
+            quote! {
+                allow_reserved: false,
+                style: ::rovo::aide::openapi::QueryStyle::Form,
+                allow_empty_value: None,
+            },
+            !is_option_type(param_type),
+        )
+    };
+
+    quote! {
+        .with(|mut op| {
+            let parameters = &mut op.inner_mut().parameters;
+            let existing = parameters.iter_mut().find_map(|parameter| match parameter {
+                ::rovo::aide::openapi::ReferenceOr::Item(
+                    ::rovo::aide::openapi::Parameter::#variant { parameter_data, .. }
+                ) if parameter_data.name == #name => Some(parameter_data),
+                _ => None,
+            });
+            if let Some(parameter_data) = existing {
+                parameter_data.description = Some(#description.to_string());
+            } else {
+                parameters.push(::rovo::aide::openapi::ReferenceOr::Item(
+                    ::rovo::aide::openapi::Parameter::#variant {
+                        parameter_data: ::rovo::aide::openapi::ParameterData {
+                            name: #name.to_string(),
+                            description: Some(#description.to_string()),
+                            required: #required,
+                            deprecated: None,
+                            format: ::rovo::aide::openapi::ParameterSchemaOrContent::Schema(
+                                ::rovo::aide::openapi::SchemaObject {
+                                    json_schema: <#param_type as ::rovo::schemars::JsonSchema>::json_schema(
+                                        &mut ::rovo::schemars::SchemaGenerator::default()
+                                    ),
+                                    example: None,
+                                    external_docs: None,
+                                }
+                            ),
+                            example: None,
+                            examples: ::std::default::Default::default(),
+                            explode: None,
+                            extensions: ::std::default::Default::default(),
+                        },
+                        #style_fields
+                    }
+                ));
+            }
+            op
+        })
+    }
+}
+
+/// Generate setters that describe path parameters aide generated from the `Path` type
+fn generate_path_description_setters(path_docs: &[PathParamDoc]) -> Vec<proc_macro2::TokenStream> {
+    path_docs
+        .iter()
+        .map(|doc| {
+            let name = &doc.name;
+            let description = &doc.description;
+            quote! {
+                .with(|mut op| {
+                    for parameter in &mut op.inner_mut().parameters {
+                        if let ::rovo::aide::openapi::ReferenceOr::Item(
+                            ::rovo::aide::openapi::Parameter::Path { parameter_data, .. }
+                        ) = parameter {
+                            if parameter_data.name == #name {
+                                parameter_data.description = Some(#description.to_string());
+                            }
+                        }
+                    }
+                    op
+                })
+            }
+        })
+        .collect()
+}
+
+/// Generate a compiler warning for a non-fatal documentation problem
+///
+/// Stable Rust has no warning API for proc macros, so this goes through a
+/// deprecated item whose note carries the message.
+fn generate_warning(warning: &ParseWarning) -> proc_macro2::TokenStream {
+    let message = &warning.message;
+    quote_spanned! {warning.span=>
+        const _: () = {
+            #[deprecated(note = #message)]
+            #[allow(non_upper_case_globals)]
+            const rovo_warning: () = ();
+            rovo_warning
+        };
+    }
+}
+
+/// Generate a setter that adds a `String` header parameter to the operation
+fn generate_header_param_setter(
+    name: &str,
+    description: &str,
+    required: bool,
+) -> proc_macro2::TokenStream {
+    quote! {
+        .with(|mut op| {
+            op.inner_mut().parameters.push(
+                ::rovo::aide::openapi::ReferenceOr::Item(
+                    ::rovo::aide::openapi::Parameter::Header {
+                        parameter_data: ::rovo::aide::openapi::ParameterData {
+                            name: #name.to_string(),
+                            description: Some(#description.to_string()),
+                            required: #required,
+                            deprecated: None,
+                            format: ::rovo::aide::openapi::ParameterSchemaOrContent::Schema(
+                                ::rovo::aide::openapi::SchemaObject {
+                                    json_schema: <String as ::rovo::schemars::JsonSchema>::json_schema(
+                                        &mut ::rovo::schemars::SchemaGenerator::default()
+                                    ),
+                                    example: None,
+                                    external_docs: None,
+                                }
+                            ),
+                            example: None,
+                            examples: ::std::default::Default::default(),
+                            explode: None,
+                            extensions: ::std::default::Default::default(),
+                        },
+                        style: ::rovo::aide::openapi::HeaderStyle::Simple,
+                    }
+                )
+            );
+            op
+        })
+    }
+}
+
+/// Generate a setter that adds a `String` header to every documented response
+///
+/// Must be emitted after the response setters, since it only touches responses
+/// that already exist on the operation.
+fn generate_response_header_setter(name: &str, description: &str) -> proc_macro2::TokenStream {
+    generate_header_setter(None, name, &quote! { String }, description, None)
+}
+
+/// Generate a setter that adds a header to the response for `status`, or to
+/// every documented response when `status` is `None`
+fn generate_header_setter(
+    status: Option<u16>,
+    name: &str,
+    header_type: &proc_macro2::TokenStream,
+    description: &str,
+    example: Option<&str>,
+) -> proc_macro2::TokenStream {
+    let example = example.map_or_else(
+        || quote! { None },
+        |example| quote! { Some(::rovo::__serde_json::Value::String(#example.to_string())) },
+    );
+    let targets = status.map_or_else(
+        || quote! { responses.responses.values_mut() },
+        |code| {
+            quote! {
+                responses
+                    .responses
+                    .iter_mut()
+                    .filter(|(status, _)| **status == ::rovo::aide::openapi::StatusCode::Code(#code))
+                    .map(|(_, response)| response)
+            }
+        },
+    );
+
+    quote! {
+        .with(|mut op| {
+            if let Some(responses) = op.inner_mut().responses.as_mut() {
+                for response in #targets {
+                    if let ::rovo::aide::openapi::ReferenceOr::Item(response) = response {
+                        response.headers.insert(
+                            #name.to_string(),
+                            ::rovo::aide::openapi::ReferenceOr::Item(
+                                ::rovo::aide::openapi::Header {
+                                    description: Some(#description.to_string()),
+                                    style: ::rovo::aide::openapi::HeaderStyle::Simple,
+                                    required: false,
+                                    deprecated: None,
+                                    format: ::rovo::aide::openapi::ParameterSchemaOrContent::Schema(
+                                        ::rovo::aide::openapi::SchemaObject {
+                                            json_schema: <#header_type as ::rovo::schemars::JsonSchema>::json_schema(
+                                                &mut ::rovo::schemars::SchemaGenerator::default()
+                                            ),
+                                            example: None,
+                                            external_docs: None,
+                                        }
+                                    ),
+                                    example: #example,
+                                    examples: ::std::default::Default::default(),
+                                    extensions: ::std::default::Default::default(),
+                                }
+                            ),
+                        );
+                    }
+                }
+            }
+            op
+        })
+    }
+}
+
+/// Generate a setter that adds a `links` entry to the response for `link.status_code`
+fn generate_link_setter(link: &LinkDoc) -> proc_macro2::TokenStream {
+    let operation_id = &link.operation_id;
+    let response = generate_response_lookup(StatusKey::Exact(link.status_code));
+    let names = link.parameters.iter().map(|(name, _)| name);
+    let expressions = link.parameters.iter().map(|(_, expression)| expression);
+
+    quote! {
+        .with(|mut op| {
+            let link = ::rovo::aide::openapi::Link {
+                description: None,
+                operation: ::rovo::aide::openapi::LinkOperation::OperationId(
+                    #operation_id.to_string(),
+                ),
+                request_body: None,
+                parameters: [#((#names.to_string(), ::rovo::__serde_json::Value::from(#expressions))),*]
+                    .into_iter()
+                    .collect(),
+                server: None,
+                extensions: Default::default(),
+            };
+            if let Some(responses) = op.inner_mut().responses.as_mut() {
+                if let Some(::rovo::aide::openapi::ReferenceOr::Item(response)) = #response {
+                    response.links.insert(
+                        #operation_id.to_string(),
+                        ::rovo::aide::openapi::ReferenceOr::Item(link),
+                    );
+                }
+            }
+            op
+        })
+    }
+}
+
+/// Generate a setter that adds a `callbacks` entry for an `@async-job` operation
+///
+/// The callback is keyed by its operation ID and targets the `callbackUrl` field
+/// of the request body, the conventional place for clients to register one.
+fn generate_async_job_callback_setter(callback_id: &str) -> proc_macro2::TokenStream {
+    quote! {
+        .with(|mut op| {
+            let callback = ::rovo::__serde_json::json!({
+                "{$request.body#/callbackUrl}": {
+                    "post": {
+                        "operationId": #callback_id,
+                        "description": "Called once the asynchronous job has completed",
+                        "responses": {
+                            "200": { "description": "Callback received" }
+                        }
+                    }
+                }
+            });
+            if let Ok(callback) = ::rovo::__serde_json::from_value(callback) {
+                op.inner_mut().callbacks.insert(#callback_id.to_string(), callback);
+            }
+            op
+        })
+    }
+}
+
+/// Generate the `response_with` call (or its range/default variant) for a response
+fn generate_response_with(
+    status: StatusKey,
+    response_type: &proc_macro2::TokenStream,
+    transform: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    match status {
+        StatusKey::Exact(code) => {
+            quote! { .response_with::<#code, #response_type, _>(#transform) }
+        }
+        StatusKey::Range(digit) => {
+            quote! { .response_range_with::<#digit, #response_type, _>(#transform) }
+        }
+        StatusKey::Default => {
+            quote! { .default_response_with::<#response_type, _>(#transform) }
+        }
+    }
+}
+
+/// Generate an expression looking up a documented response in `responses`
+fn generate_response_lookup(status: StatusKey) -> proc_macro2::TokenStream {
+    match status {
+        StatusKey::Exact(code) => quote! {
+            responses.responses.get_mut(&::rovo::aide::openapi::StatusCode::Code(#code))
+        },
+        StatusKey::Range(digit) => quote! {
+            responses.responses.get_mut(&::rovo::aide::openapi::StatusCode::Range(#digit))
+        },
+        StatusKey::Default => quote! { responses.default.as_mut() },
+    }
+}
+
+/// Generate a setter that files a response under its documented content types
+///
+/// The primary type is added by `response_with`, which picks its own content type;
+/// it is moved to the documented one, and each further type gets its own media type.
+fn generate_content_types_setter(
+    status: StatusKey,
+    content: &[(Option<String>, proc_macro2::TokenStream)],
+) -> proc_macro2::TokenStream {
+    let Some(((primary_content_type, _), others)) = content.split_first() else {
+        return quote! {};
+    };
+    if primary_content_type.is_none() && others.is_empty() {
+        return quote! {};
+    }
+
+    let rename_primary = primary_content_type.as_ref().map(|content_type| {
+        quote! {
+            if response.content.len() == 1 && !response.content.contains_key(#content_type) {
+                if let Some((_, media)) = response.content.pop() {
+                    response.content.insert(#content_type.to_string(), media);
+                }
+            }
+        }
+    });
+    // The parser requires a content type on every type after the first
+    let other_media_types = others.iter().filter_map(|(content_type, response_type)| {
+        let content_type = content_type.as_ref()?;
+        Some(quote! {
+            let json_schema = ::rovo::aide::generate::in_context(|ctx| {
+                ctx.schema.subschema_for::<
+                    <#response_type as ::rovo::aide::operation::OperationOutput>::Inner
+                >()
+            });
+            response.content.insert(
+                #content_type.to_string(),
+                ::rovo::aide::openapi::MediaType {
+                    schema: Some(::rovo::aide::openapi::SchemaObject {
+                        json_schema,
+                        example: None,
+                        external_docs: None,
+                    }),
+                    ..::std::default::Default::default()
+                },
+            );
+        })
+    });
+
+    let lookup = generate_response_lookup(status);
+    quote! {
+        .with(|mut op| {
+            if let Some(responses) = op.inner_mut().responses.as_mut() {
+                if let Some(::rovo::aide::openapi::ReferenceOr::Item(response)) = #lookup {
+                    #rename_primary
+                    #(#other_media_types)*
+                }
+            }
+            op
+        })
+    }
+}
+
+/// Generate a setter that adds a named example (with optional summary) to a documented response
+fn generate_named_example_setter(
+    response_type: &proc_macro2::TokenStream,
+    example: &ExampleInfo,
+    label: &ExampleLabel,
+) -> proc_macro2::TokenStream {
+    let code = example.status_code;
+    let example_code = &example.example_code;
+    let name = &label.name;
+    let summary = label.summary.as_ref().map_or_else(
+        || quote! { None },
+        |summary| quote! { Some(#summary.to_string()) },
+    );
+
+    quote! {
+        .with(|mut op| {
+            let value: <#response_type as ::rovo::aide::operation::OperationOutput>::Inner =
+                ::std::convert::Into::into(#example_code);
+            let value = ::rovo::__serde_json::to_value(value).ok();
+            if let Some(responses) = op.inner_mut().responses.as_mut() {
+                if let Some(::rovo::aide::openapi::ReferenceOr::Item(response)) = responses
+                    .responses
+                    .get_mut(&::rovo::aide::openapi::StatusCode::Code(#code))
+                {
+                    for media in response.content.values_mut() {
+                        media.examples.insert(
+                            #name.to_string(),
+                            ::rovo::aide::openapi::ReferenceOr::Item(
+                                ::rovo::aide::openapi::Example {
+                                    summary: #summary,
+                                    description: None,
+                                    value: value.clone(),
+                                    external_value: None,
+                                    extensions: ::std::default::Default::default(),
+                                }
+                            ),
+                        );
+                    }
+                }
+            }
+            op
+        })
+    }
+}
+
+/// Generate the compile-time assertion for `#[rovo(strict_responses)]`
+///
+/// The handler's return type must be the documented 200 response type, optionally
+/// wrapped in `Result<_, E>` or `(StatusCode, _)`.
+fn generate_strict_response_check(
+    return_type: Option<&proc_macro2::TokenStream>,
+    documented_type: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let return_type = return_type.map_or_else(|| quote! { () }, Clone::clone);
+
+    quote! {
+        const _: fn() = || {
+            ::rovo::__assert_documented_response::<#return_type, #documented_type>();
+        };
+    }
+}
+
 /// Macro that generates `OpenAPI` documentation from doc comments.
 ///
 /// This macro automatically generates `OpenAPI` documentation for your handlers
@@ -152,7 +602,7 @@ fn generate_path_param_setters(
 ///
 /// ## Sections
 /// - `# Path Parameters` - Document path parameters for primitive types
-/// - `# Responses` - Document response status codes
+/// - `# Responses` - Document response status codes (`200`, ranges like `4XX`, or `default`)
 /// - `# Examples` - Provide example responses
 /// - `# Metadata` - Add tags, security, and other metadata
 ///
@@ -173,15 +623,88 @@ fn generate_path_param_setters(
 ///
 /// For complex types, continue using structs with `#[derive(JsonSchema)]`.
 ///
+/// ## Named Examples
+///
+/// Examples can be named, with an optional summary, to populate the response's
+/// `examples` map instead of its single `example`. `OpenAPI` doesn't allow both,
+/// so a status code's examples must be all named or a single unnamed one:
+///
+/// ```rust,ignore
+/// /// # Examples
+/// ///
+/// /// 200 "empty" (summary: "No results"): Vec::<User>::new()
+/// ```
+///
+/// ## Strict Responses
+///
+/// `#[rovo(strict_responses)]` checks at compile time that the handler returns the
+/// documented `200` response type, optionally wrapped in `Result<_, E>` or
+/// `(StatusCode, _)`. The handler must declare a concrete return type.
+///
+/// ## Code Samples
+///
+/// `#[rovo(code_samples)]` adds an `x-codeSamples` curl sample (rendered by Redoc)
+/// built from the route's method and path, and the request body example if any.
+///
+/// ## Impl Blocks
+///
+/// `#[rovo]` on an inherent impl block documents each method marked `#[rovo]`
+/// (options on the block apply to all of them). Each handler is routed through an
+/// associated const, and methods taking `&self` get `self` from the router state:
+///
+/// ```rust,ignore
+/// #[rovo]
+/// impl UserHandlers {
+///     /// # Responses
+///     ///
+///     /// 200: Json<Vec<User>> - All users
+///     #[rovo]
+///     async fn list_users(&self) -> Json<Vec<User>> {
+///         // ...
+///     }
+/// }
+///
+/// Router::new()
+///     .route("/users", get(UserHandlers::LIST_USERS))
+///     .with_state(user_handlers)
+/// ```
+///
 /// ## Metadata Annotations
 /// - `@tag <tag_name>` - Add a tag for grouping operations (can be used multiple times)
-/// - `@security <scheme_name>` - Add security requirements (can be used multiple times)
+/// - `@security <scheme_name> [scopes...]` - Add security requirements (can be used multiple times)
+/// - `@security-and <scheme_name> <scheme_name>...` - Add one requirement that needs all listed schemes
 /// - `@id <operation_id>` - Set a custom operation ID (defaults to function name)
+/// - `@summary <text>` - Set the summary explicitly instead of using the first doc line
 /// - `@hidden` - Hide this operation from documentation
+/// - `@cfg <feature>` - Record the Cargo feature this operation depends on as `x-cfg`
+/// - `@min-version <version>` - Record the first API version with the operation as `x-min-version`
+/// - `@max-version <version>` - Record the last API version with the operation as `x-max-version`
+/// - `@i18n` - Document the `Accept-Language` header and `Content-Language` response header
+/// - `@idempotent` - Document a required `Idempotency-Key` header parameter
+/// - `@concurrency` - Document a required `If-Match` header and a `412 Precondition Failed` response
+/// - `@ratelimit <count> per-<window>` - Document a rate limit as `x-ratelimit`
+/// - `@sunset <date>` - Announce a removal date via `x-sunset` and a `Sunset` response header
+/// - `@server <url> [description]` - Add an operation-level server (repeatable)
+/// - `@external-docs <url> [description]` - Link the operation to fuller documentation
+/// - `@async-job <callbackOperationId>` - Document a `202 Accepted` response and a callback
+/// - `@param <name> <type> <description>` - Document a `Path` binding or `Query` parameter
+/// - `@query <name> <type> <description>` - Document a query parameter (repeatable)
+/// - `@paginated` - Document the `page`, `per_page` and `sort` query parameters
+/// - `@body <type> [<content-type>] - <description>` - Document the request body type and description
+/// - `@header <status> <name> <type> <description>` - Document a response header
+/// - `@cacheable <directive>` - Document a `Cache-Control` header on the success response
+/// - `@content-type <status> <content-type>` - Override the content type of a response
+/// - `@produces <content-type>` - Override the content type of the `200` response
+/// - `@link <status> <operationId> <name>=<expression>` - Link a response to another operation
+/// - `@deprecated [reason]` - Mark the operation as deprecated, appending the reason to the description
+/// - `@deprecated-message <message>` - Explain a deprecation via `x-deprecated-message`
+/// - `@extension <x-key> <json_value>` - Add an `x-` extension field to the operation
+/// - `@errors standard` - Add the standard `400`/`401`/`403`/`500` error responses
 /// - `@rovo-ignore` - Stop processing annotations after this point
 ///
 /// Additionally, the Rust `#[deprecated]` attribute is automatically detected
-/// and will mark the operation as deprecated in the `OpenAPI` spec.
+/// and will mark the operation as deprecated in the `OpenAPI` spec. Its `note`
+/// and `since` are appended to the operation description.
 ///
 /// # Examples
 ///
@@ -269,192 +792,775 @@ fn generate_path_param_setters(
 /// }
 /// ```
 #[proc_macro_attribute]
-pub fn rovo(_attr: TokenStream, item: TokenStream) -> TokenStream {
-    let input = item;
+pub fn rovo(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let item: proc_macro2::TokenStream = item.into();
 
-    match parse_rovo_function(input.into()) {
-        Ok((func_item, doc_info)) => {
-            let func_name = &func_item.name;
+    if is_impl_block(&item) {
+        return match parse_rovo_impl(&attr.into(), item) {
+            Ok(block) => {
+                let impl_tokens = block.tokens;
+                let handlers = block
+                    .handlers
+                    .iter()
+                    .map(|(func_item, doc_info)| expand_handler(func_item, doc_info));
+                quote! {
+                    #impl_tokens
+                    #(#handlers)*
+                }
+                .into()
+            }
+            Err(err) => compile_error(&err).into(),
+        };
+    }
 
-            let title = doc_info.title.as_deref().unwrap_or("");
-            let description = doc_info.description.as_deref().unwrap_or("");
+    match parse_rovo_function(attr.into(), item) {
+        Ok((func_item, doc_info)) => expand_handler(&func_item, &doc_info).into(),
+        Err(err) => compile_error(&err).into(),
+    }
+}
 
-            // Generate response setters if we have doc comments
-            let response_code_setters = if doc_info.responses.is_empty() {
-                // No responses specified - generate a minimal docs function
-                vec![]
-            } else {
-                doc_info
-                    .responses
+/// The items that differ between free function and method handlers
+struct HandlerItems {
+    /// Zero-sized type implementing `IntoApiMethodRouter`
+    handler_type: proc_macro2::Ident,
+    /// Expression for the axum handler the routes call
+    handler: proc_macro2::TokenStream,
+    /// Function backing `handler`, if one needs generating
+    impl_func: proc_macro2::TokenStream,
+    /// Path reported by `Router::unrouted_handlers`
+    handler_name: proc_macro2::TokenStream,
+    /// The uppercase const routes can use to name the handler
+    handler_const: proc_macro2::TokenStream,
+}
+
+/// Generate the handler items for a method in a `#[rovo]` impl block
+///
+/// The handler type lives next to the impl block, and the uppercase const is an
+/// associated const (`get(UserHandlers::LIST_USERS)`). A method taking `self` is
+/// called through a wrapper that extracts `self` from the router state.
+fn generate_method_handler_items(
+    func_item: &FuncItem,
+    method: &MethodInfo,
+    const_name: &proc_macro2::Ident,
+) -> HandlerItems {
+    let func_name = &func_item.name;
+    let self_ty = &method.self_ty;
+    let type_name = self_ty
+        .clone()
+        .into_iter()
+        .filter_map(|token| match token {
+            proc_macro2::TokenTree::Ident(ident) => Some(ident.to_string()),
+            _ => None,
+        })
+        .last()
+        .unwrap_or_default();
+    let handler_type = quote::format_ident!("__{}_{}", type_name, func_name);
+
+    let (handler, impl_func) = if method.has_receiver {
+        let impl_name = quote::format_ident!("__{}_{}_impl", type_name, func_name);
+        let args: Vec<_> = (0..method.param_types.len())
+            .map(|idx| quote::format_ident!("__arg{}", idx))
+            .collect();
+        let param_types = &method.param_types;
+        let return_type = func_item
+            .return_type
+            .as_ref()
+            .map(|return_type| quote! { -> #return_type });
+        let (asyncness, awaited) = if method.is_async {
+            (quote! { async }, quote! { .await })
+        } else {
+            (quote! {}, quote! {})
+        };
+
+        (
+            quote! { #impl_name },
+            quote! {
+                #[allow(non_snake_case)]
+                #asyncness fn #impl_name(
+                    ::rovo::extract::State(__this): ::rovo::extract::State<#self_ty>,
+                    #(#args: #param_types),*
+                ) #return_type {
+                    __this.#func_name(#(#args),*) #awaited
+                }
+            },
+        )
+    } else {
+        (quote! { <#self_ty>::#func_name }, quote! {})
+    };
+
+    HandlerItems {
+        handler_type: handler_type.clone(),
+        handler,
+        impl_func,
+        handler_name: quote! {
+            concat!(module_path!(), "::", stringify!(#self_ty), "::", stringify!(#func_name))
+        },
+        handler_const: quote! {
+            impl #self_ty {
+                #[allow(non_upper_case_globals)]
+                pub const #const_name: #handler_type = #handler_type;
+            }
+        },
+    }
+}
+
+/// Generate the documented handler type and its trait impls for a parsed handler
+fn expand_handler(func_item: &FuncItem, doc_info: &DocInfo) -> proc_macro2::TokenStream {
+    let func_name = &func_item.name;
+
+    let title = doc_info.title.as_deref().unwrap_or("");
+    let description = match (&doc_info.description, &doc_info.deprecation_note) {
+        (Some(description), Some(note)) => format!("{description}\n\n{note}"),
+        (Some(text), None) | (None, Some(text)) => text.clone(),
+        (None, None) => String::new(),
+    };
+
+    // Generate response setters if we have doc comments
+    let response_code_setters = if doc_info.responses.is_empty() {
+        // No responses specified - generate a minimal docs function
+        vec![]
+    } else {
+        doc_info
+            .responses
+            .iter()
+            .map(|resp| {
+                let status = resp.status_code;
+                let response_type = resp.response_type();
+                let desc = &resp.description;
+                let content_types_setter = generate_content_types_setter(status, &resp.content);
+
+                // Check if there's an explicit example for this status code
+                let transform = doc_info
+                    .examples
                     .iter()
-                    .map(|resp| {
-                        let code = resp.status_code;
-                        let response_type = &resp.response_type;
-                        let desc = &resp.description;
-
-                        // Check if there's an explicit example for this status code
-                        doc_info
-                            .examples
-                            .iter()
-                            .find(|e| e.status_code == code)
-                            .map_or_else(
-                                || {
-                                    // No explicit example, just add the description
-                                    quote! {
-                                        .response_with::<#code, #response_type, _>(|res| {
-                                            res.description(#desc)
-                                        })
-                                    }
-                                },
-                                |example| {
-                                    let example_code = &example.example_code;
-                                    quote! {
-                                        .response_with::<#code, #response_type, _>(|res| {
-                                            res.description(#desc)
-                                                .example(#example_code)
-                                        })
-                                    }
-                                },
-                            )
-                    })
-                    .collect()
-            };
+                    .find(|e| status == e.status_code && e.label.is_none())
+                    .map_or_else(
+                        || {
+                            // No explicit example, just add the description
+                            quote! { |res| res.description(#desc) }
+                        },
+                        |example| {
+                            let example_code = &example.example_code;
+                            quote! {
+                                |res| {
+                                    res.description(#desc)
+                                        .example(#example_code)
+                                }
+                            }
+                        },
+                    );
+                let response_setter = generate_response_with(status, response_type, &transform);
 
-            // Generate tag setters
-            let tag_setters: Vec<_> = doc_info
-                .tags
-                .iter()
-                .map(|tag| {
-                    quote! { .tag(#tag) }
-                })
-                .collect();
+                quote! { #response_setter #content_types_setter }
+            })
+            .collect()
+    };
 
-            // Generate security requirement setters
-            let security_setters: Vec<_> = doc_info
-                .security_requirements
+    // Generate named example setters (run after the responses exist)
+    let named_example_setters: Vec<_> = doc_info
+        .examples
+        .iter()
+        .filter_map(|example| {
+            let label = example.label.as_ref()?;
+            let response = doc_info
+                .responses
                 .iter()
-                .map(|scheme| {
-                    quote! { .security_requirement(#scheme) }
-                })
-                .collect();
-
-            // Generate operation ID setter
-            let operation_id_setter = doc_info.operation_id.as_ref().map_or_else(
-                || {
-                    // Default to function name if no custom ID provided
-                    let default_id = func_name.to_string();
-                    quote! { .id(#default_id) }
-                },
-                |id| quote! { .id(#id) },
-            );
+                .find(|r| r.status_code == example.status_code)?;
+            Some(generate_named_example_setter(
+                response.response_type(),
+                example,
+                label,
+            ))
+        })
+        .collect();
 
-            // Generate deprecated setter
-            let deprecated_setter = if doc_info.deprecated {
-                quote! { .with(|mut op| { op.inner_mut().deprecated = true; op }) }
+    // Generate tag setters
+    let tag_setters: Vec<_> = doc_info
+        .tags
+        .iter()
+        .map(|tag| {
+            quote! { .tag(#tag) }
+        })
+        .collect();
+
+    // Generate security requirement setters
+    let security_setters: Vec<_> = doc_info
+        .security_requirements
+        .iter()
+        .map(|requirement| match requirement.as_slice() {
+            [(scheme, scopes)] if scopes.is_empty() => {
+                quote! { .security_requirement(#scheme) }
+            }
+            [(scheme, scopes)] => {
+                quote! { .security_requirement_scopes(#scheme, [#(#scopes),*]) }
+            }
+            requirement => {
+                let schemes = requirement.iter().map(|(scheme, _)| scheme);
+                quote! { .security_requirement_multi([#(#schemes),*]) }
+            }
+        })
+        .collect();
+
+    // Generate operation-level server setters
+    let server_setters: Vec<_> = doc_info
+        .servers
+        .iter()
+        .map(|(url, description)| {
+            let description = if description.is_empty() {
+                quote! { None }
             } else {
-                quote! {}
+                quote! { Some(#description.to_string()) }
             };
+            quote! {
+                .with(|mut op| {
+                    op.inner_mut().servers.push(::rovo::aide::openapi::Server {
+                        url: #url.to_string(),
+                        description: #description,
+                        ..::std::default::Default::default()
+                    });
+                    op
+                })
+            }
+        })
+        .collect();
 
-            // Generate hidden setter
-            let hidden_setter = if doc_info.hidden {
-                quote! { .hidden(true) }
+    // Generate externalDocs setter
+    let external_docs_setter = doc_info.external_docs.as_ref().map_or_else(
+        || quote! {},
+        |(url, description)| {
+            let description = if description.is_empty() {
+                quote! { None }
             } else {
-                quote! {}
+                quote! { Some(#description.to_string()) }
             };
+            quote! {
+                .with(|mut op| {
+                    op.inner_mut().external_docs =
+                        Some(::rovo::aide::openapi::ExternalDocumentation {
+                            url: #url.to_string(),
+                            description: #description,
+                            ..::std::default::Default::default()
+                        });
+                    op
+                })
+            }
+        },
+    );
 
-            // Generate path parameter setters for primitive types
-            let path_param_setters =
-                generate_path_param_setters(func_item.path_params.as_ref(), &doc_info.path_params);
+    // Generate operation ID setter
+    let operation_id_setter = doc_info.operation_id.as_ref().map_or_else(
+        || {
+            // Default to function name if no custom ID provided
+            let default_id = func_name.to_string();
+            quote! { .id(#default_id) }
+        },
+        |id| quote! { .id(#id) },
+    );
 
-            // Generate an internal implementation name
-            let impl_name = quote::format_ident!("__{}_impl", func_name);
+    // Generate deprecated setter
+    let deprecated_setter = if doc_info.deprecated {
+        quote! { .with(|mut op| { op.inner_mut().deprecated = true; op }) }
+    } else {
+        quote! {}
+    };
 
-            // Get the renamed function tokens
-            let impl_func = func_item.with_renamed(&impl_name);
+    // Generate hidden setter
+    let hidden_setter = if doc_info.hidden {
+        quote! { .hidden(true) }
+    } else {
+        quote! {}
+    };
 
-            // Create a const with an uppercase version of the handler name
-            let const_name = quote::format_ident!("{}", func_name.to_string().to_uppercase());
+    // Generate path parameter setters for primitive types
+    let path_param_setters =
+        generate_path_param_setters(func_item.path_params.as_ref(), &doc_info.path_params);
 
-            // Determine the state type for the trait implementation
-            let state_type = func_item
-                .state_type
-                .as_ref()
-                .map_or_else(|| quote! { () }, |st| quote! { #st });
+    // Generate @param/@query setters (after the path setters so they can describe them)
+    let param_setters: Vec<_> = doc_info.params.iter().map(generate_param_setter).collect();
 
-            let output = quote! {
-                // Internal implementation with renamed function
-                #[allow(non_snake_case, private_interfaces)]
-                #impl_func
+    // Generate the @body request body setter
+    let body_setter = doc_info.body.as_ref().map_or_else(
+        || quote! {},
+        |body| {
+            let body_type = &body.body_type;
+            let description = &body.description;
+            let content_type_setter = body.content_type.as_ref().map(|content_type| {
+                quote! {
+                    if let Some((_, media)) = request_body.content.pop() {
+                        request_body.content.clear();
+                        request_body.content.insert(#content_type.to_string(), media);
+                    }
+                }
+            });
+            quote! {
+                .input::<#body_type>()
+                .with(|mut op| {
+                    if let Some(::rovo::aide::openapi::ReferenceOr::Item(request_body)) =
+                        op.inner_mut().request_body.as_mut()
+                    {
+                        request_body.description = Some(#description.to_string());
+                        #content_type_setter
+                    }
+                    op
+                })
+            }
+        },
+    );
 
-                // Create a zero-sized type that can be passed to routing functions
-                #[allow(non_camel_case_types)]
-                #[derive(Clone, Copy)]
-                pub struct #func_name;
+    // Generate x-cfg extension setter
+    let cfg_setter = doc_info.cfg_feature.as_ref().map_or_else(
+        || quote! {},
+        |feature| {
+            quote! {
+                .with(|mut op| {
+                    op.inner_mut().extensions.insert(
+                        "x-cfg".to_string(),
+                        ::rovo::__serde_json::Value::String(#feature.to_string()),
+                    );
+                    op
+                })
+            }
+        },
+    );
 
-                impl #func_name {
-                    #[doc(hidden)]
-                    pub fn __docs(op: ::rovo::aide::transform::TransformOperation) -> ::rovo::aide::transform::TransformOperation {
+    // Generate x-min-version/x-max-version extension setters
+    let version_setters: Vec<_> = [
+        ("x-min-version", &doc_info.min_version),
+        ("x-max-version", &doc_info.max_version),
+    ]
+    .into_iter()
+    .filter_map(|(key, version)| {
+        version.as_ref().map(|version| {
+            quote! {
+                .with(|mut op| {
+                    op.inner_mut().extensions.insert(
+                        #key.to_string(),
+                        ::rovo::__serde_json::Value::String(#version.to_string()),
+                    );
+                    op
+                })
+            }
+        })
+    })
+    .collect();
+
+    // Generate x-deprecated-message extension setter
+    let deprecated_message_setter = doc_info.deprecated_message.as_ref().map_or_else(
+        || quote! {},
+        |(message, _)| {
+            quote! {
+                .with(|mut op| {
+                    op.inner_mut().extensions.insert(
+                        "x-deprecated-message".to_string(),
+                        ::rovo::__serde_json::Value::String(#message.to_string()),
+                    );
+                    op
+                })
+            }
+        },
+    );
+
+    // Generate x-ratelimit extension setter
+    let ratelimit_setter = doc_info.rate_limit.as_ref().map_or_else(
+        || quote! {},
+        |(count, window)| {
+            quote! {
+                .with(|mut op| {
+                    op.inner_mut().extensions.insert(
+                        "x-ratelimit".to_string(),
+                        ::rovo::__serde_json::json!({ "limit": #count, "window": #window }),
+                    );
+                    op
+                })
+            }
+        },
+    );
+
+    // Generate x-sunset extension and Sunset response header setters
+    let (sunset_setter, sunset_response_setter) = doc_info.sunset.as_ref().map_or_else(
+        || (quote! {}, quote! {}),
+        |date| {
+            (
+                quote! {
+                    .with(|mut op| {
+                        op.inner_mut().extensions.insert(
+                            "x-sunset".to_string(),
+                            ::rovo::__serde_json::Value::String(#date.to_string()),
+                        );
                         op
-                            #operation_id_setter
-                            .summary(#title)
-                            .description(#description)
-                            #(#tag_setters)*
-                            #deprecated_setter
-                            #hidden_setter
-                            #(#security_setters)*
-                            #(#path_param_setters)*
-                            #(#response_code_setters)*
-                    }
-                }
+                    })
+                },
+                generate_response_header_setter(
+                    "Sunset",
+                    &format!("Date after which this endpoint will be removed: {date}"),
+                ),
+            )
+        },
+    );
 
-                // Implement the IntoApiMethodRouter trait
-                impl ::rovo::IntoApiMethodRouter<#state_type> for #func_name {
-                    fn into_get_route(self) -> ::rovo::aide::axum::routing::ApiMethodRouter<#state_type> {
-                        ::rovo::aide::axum::routing::get_with(#impl_name, Self::__docs)
+    // Generate the 202 response and callbacks entry for long-running operations
+    let (async_job_callback_setter, async_job_response_setter) =
+        doc_info.async_job_callback.as_ref().map_or_else(
+            || (quote! {}, quote! {}),
+            |callback_id| {
+                let response_setter = if doc_info.responses.iter().any(|r| r.status_code == 202) {
+                    quote! {}
+                } else {
+                    quote! {
+                        .response_with::<202, (), _>(|res| {
+                            res.description("The job was accepted and will complete asynchronously")
+                        })
                     }
+                };
+                (
+                    generate_async_job_callback_setter(callback_id),
+                    response_setter,
+                )
+            },
+        );
 
-                    fn into_post_route(self) -> ::rovo::aide::axum::routing::ApiMethodRouter<#state_type> {
-                        ::rovo::aide::axum::routing::post_with(#impl_name, Self::__docs)
-                    }
+    // Generate i18n header setters (request parameter + response header)
+    let (i18n_param_setter, i18n_response_setter) = if doc_info.i18n {
+        (
+            generate_header_param_setter(
+                "Accept-Language",
+                "Preferred languages for the response",
+                false,
+            ),
+            generate_response_header_setter("Content-Language", "Language of the response content"),
+        )
+    } else {
+        (quote! {}, quote! {})
+    };
 
-                    fn into_patch_route(self) -> ::rovo::aide::axum::routing::ApiMethodRouter<#state_type> {
-                        ::rovo::aide::axum::routing::patch_with(#impl_name, Self::__docs)
-                    }
+    // Generate the Idempotency-Key header parameter setter
+    let idempotent_setter = if doc_info.idempotent {
+        generate_header_param_setter(
+            "Idempotency-Key",
+            "Unique key that makes retries of this request safe",
+            true,
+        )
+    } else {
+        quote! {}
+    };
 
-                    fn into_delete_route(self) -> ::rovo::aide::axum::routing::ApiMethodRouter<#state_type> {
-                        ::rovo::aide::axum::routing::delete_with(#impl_name, Self::__docs)
-                    }
+    // Generate the If-Match header parameter setter for optimistic concurrency
+    let concurrency_setter = if doc_info.concurrency {
+        generate_header_param_setter(
+            "If-Match",
+            "ETag of the version being modified; the request fails with 412 if it has changed",
+            true,
+        )
+    } else {
+        quote! {}
+    };
 
-                    fn into_put_route(self) -> ::rovo::aide::axum::routing::ApiMethodRouter<#state_type> {
-                        ::rovo::aide::axum::routing::put_with(#impl_name, Self::__docs)
-                    }
-                }
+    // Generate response header setters (run after the responses exist)
+    let header_setters: Vec<_> = doc_info
+        .headers
+        .iter()
+        .map(|header| {
+            generate_header_setter(
+                Some(header.status_code),
+                &header.name,
+                &header.header_type,
+                &header.description,
+                header.example.as_deref(),
+            )
+        })
+        .collect();
 
-                // Also create a CONST for explicit use
-                #[allow(non_upper_case_globals)]
-                pub const #const_name: #func_name = #func_name;
-            };
+    // Generate @link setters (run after the responses exist)
+    let link_setters: Vec<_> = doc_info.links.iter().map(generate_link_setter).collect();
 
-            output.into()
+    // Mark the operation for the router to add x-codeSamples once the
+    // method and path are known
+    let code_samples_setter = if doc_info.code_samples {
+        quote! {
+            .with(|mut op| {
+                op.inner_mut().extensions.insert(
+                    "x-rovo-code-samples".to_string(),
+                    ::rovo::__serde_json::Value::Bool(true),
+                );
+                op
+            })
         }
-        Err(err) => {
-            let err_msg = err.to_string();
-            // Use the span from the error if available, otherwise use call_site
-            let error_tokens = err.span().map_or_else(
-                || {
-                    quote! {
-                        compile_error!(#err_msg);
+    } else {
+        quote! {}
+    };
+
+    // Mark the operation with its module's tag descriptions for the
+    // router to merge into the spec's tags
+    let tag_descriptions_setter = if doc_info.tag_descriptions.is_empty() {
+        quote! {}
+    } else {
+        let (tags, descriptions): (Vec<_>, Vec<_>) =
+            doc_info.tag_descriptions.iter().cloned().unzip();
+        quote! {
+            .with(|mut op| {
+                op.inner_mut().extensions.insert(
+                    "x-rovo-tag-descriptions".to_string(),
+                    ::rovo::__serde_json::json!({ #(#tags: #descriptions),* }),
+                );
+                op
+            })
+        }
+    };
+
+    // Generate @extension setters; the values were validated while parsing
+    let extension_setters: Vec<_> = doc_info
+        .extensions
+        .iter()
+        .map(|(key, json)| {
+            quote! {
+                .with(|mut op| {
+                    if let Ok(value) = ::rovo::__serde_json::from_str(#json) {
+                        op.inner_mut().extensions.insert(#key.to_string(), value);
                     }
+                    op
+                })
+            }
+        })
+        .collect();
+
+    // Mark the responses added by `@errors` so the router can replace
+    // them with its configured set
+    let standard_errors_setter = if doc_info.standard_errors.is_empty() {
+        quote! {}
+    } else {
+        let codes = &doc_info.standard_errors;
+        quote! {
+            .with(|mut op| {
+                op.inner_mut().extensions.insert(
+                    "x-rovo-errors".to_string(),
+                    ::rovo::__serde_json::json!([#(#codes),*]),
+                );
+                op
+            })
+        }
+    };
+
+    // Mark the query parameters added by `@paginated` so the router can
+    // apply its pagination style
+    let pagination_setter = if doc_info.pagination_params.is_empty() {
+        quote! {}
+    } else {
+        let names = &doc_info.pagination_params;
+        quote! {
+            .with(|mut op| {
+                op.inner_mut().extensions.insert(
+                    "x-rovo-paginated".to_string(),
+                    ::rovo::__serde_json::json!([#(#names),*]),
+                );
+                op
+            })
+        }
+    };
+
+    // Link the documented 200 response type to the handler's return type
+    let strict_response_check = if doc_info.strict_responses {
+        doc_info
+            .responses
+            .iter()
+            .find(|r| r.status_code == 200)
+            .map(|resp| {
+                generate_strict_response_check(func_item.return_type.as_ref(), resp.response_type())
+            })
+    } else {
+        None
+    };
+
+    let warnings: Vec<_> = doc_info.warnings.iter().map(generate_warning).collect();
+
+    // Create a const with an uppercase version of the handler name
+    let const_name = quote::format_ident!("{}", func_name.to_string().to_uppercase());
+
+    let HandlerItems {
+        handler_type,
+        handler,
+        impl_func,
+        handler_name,
+        handler_const,
+    } = func_item.method.as_ref().map_or_else(
+        || {
+            // Generate an internal implementation name
+            let impl_name = quote::format_ident!("__{}_impl", func_name);
+
+            // Get the renamed function tokens
+            let impl_func = func_item.with_renamed(&impl_name);
+
+            HandlerItems {
+                handler_type: func_name.clone(),
+                handler: quote! { #impl_name },
+                impl_func: quote! {
+                    #[allow(non_snake_case, private_interfaces)]
+                    #impl_func
                 },
-                |span| {
-                    quote_spanned! {span=>
-                        compile_error!(#err_msg);
-                    }
+                handler_name: quote! { concat!(module_path!(), "::", stringify!(#func_name)) },
+                handler_const: quote! {
+                    #[allow(non_upper_case_globals)]
+                    pub const #const_name: #func_name = #func_name;
                 },
-            );
-            error_tokens.into()
+            }
+        },
+        |method| generate_method_handler_items(func_item, method, &const_name),
+    );
+
+    // Determine the state type for the trait implementation
+    let state_type = func_item
+        .state_type
+        .as_ref()
+        .map_or_else(|| quote! { () }, |st| quote! { #st });
+
+    let output = quote! {
+        // Internal implementation the routes call
+        #impl_func
+
+        // Create a zero-sized type that can be passed to routing functions
+        #[allow(non_camel_case_types)]
+        #[derive(Clone, Copy)]
+        pub struct #handler_type;
+
+        impl #handler_type {
+            #[doc(hidden)]
+            pub fn __docs(op: ::rovo::aide::transform::TransformOperation) -> ::rovo::aide::transform::TransformOperation {
+                op
+                    #operation_id_setter
+                    .summary(#title)
+                    .description(#description)
+                    #(#tag_setters)*
+                    #deprecated_setter
+                    #hidden_setter
+                    #cfg_setter
+                    #(#version_setters)*
+                    #ratelimit_setter
+                    #deprecated_message_setter
+                    #sunset_setter
+                    #async_job_callback_setter
+                    #code_samples_setter
+                    #tag_descriptions_setter
+                    #standard_errors_setter
+                    #pagination_setter
+                    #(#extension_setters)*
+                    #(#security_setters)*
+                    #(#server_setters)*
+                    #external_docs_setter
+                    #(#path_param_setters)*
+                    #(#param_setters)*
+                    #body_setter
+                    #i18n_param_setter
+                    #idempotent_setter
+                    #concurrency_setter
+                    #(#response_code_setters)*
+                    #async_job_response_setter
+                    #(#named_example_setters)*
+                    #i18n_response_setter
+                    #sunset_response_setter
+                    #(#header_setters)*
+                    #(#link_setters)*
+            }
         }
-    }
+
+        // Implement the IntoApiMethodRouter trait
+        impl ::rovo::IntoApiMethodRouter<#state_type> for #handler_type {
+            fn into_get_route(self) -> ::rovo::aide::axum::routing::ApiMethodRouter<#state_type> {
+                ::rovo::aide::axum::routing::get_with(#handler, Self::__docs)
+            }
+
+            fn into_post_route(self) -> ::rovo::aide::axum::routing::ApiMethodRouter<#state_type> {
+                ::rovo::aide::axum::routing::post_with(#handler, Self::__docs)
+            }
+
+            fn into_patch_route(self) -> ::rovo::aide::axum::routing::ApiMethodRouter<#state_type> {
+                ::rovo::aide::axum::routing::patch_with(#handler, Self::__docs)
+            }
+
+            fn into_delete_route(self) -> ::rovo::aide::axum::routing::ApiMethodRouter<#state_type> {
+                ::rovo::aide::axum::routing::delete_with(#handler, Self::__docs)
+            }
+
+            fn into_put_route(self) -> ::rovo::aide::axum::routing::ApiMethodRouter<#state_type> {
+                ::rovo::aide::axum::routing::put_with(#handler, Self::__docs)
+            }
+
+            fn into_head_route(self) -> ::rovo::aide::axum::routing::ApiMethodRouter<#state_type> {
+                ::rovo::aide::axum::routing::head_with(#handler, Self::__docs)
+            }
+
+            fn into_options_route(self) -> ::rovo::aide::axum::routing::ApiMethodRouter<#state_type> {
+                ::rovo::aide::axum::routing::options_with(#handler, Self::__docs)
+            }
+
+            fn handler_name(&self) -> ::core::option::Option<&'static str> {
+                ::core::option::Option::Some(#handler_name)
+            }
+        }
+
+        // Register the handler so the router can report it if it's never routed
+        ::rovo::__inventory::submit! {
+            ::rovo::__RegisteredHandler {
+                name: #handler_name,
+            }
+        }
+
+        // Also create a CONST for explicit use
+        #handler_const
+
+        #strict_response_check
+        #(#warnings)*
+    };
+
+    output
+}
+
+/// Attribute macro for modules that describes the tags used by their handlers.
+///
+/// `@tag-description <tag> <description>` lines in the module's doc comments
+/// are passed to every `#[rovo]` handler directly inside the module. The
+/// router merges them into the spec's top-level `tags` array when the API is
+/// finished, leaving descriptions already set on the `OpenApi` untouched.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// /// User management.
+/// ///
+/// /// @tag-description users Endpoints for managing user accounts
+/// #[rovo_module]
+/// mod users {
+///     /// List users.
+///     ///
+///     /// # Metadata
+///     ///
+///     /// @tag users
+///     #[rovo]
+///     pub async fn list_users() -> impl IntoApiResponse {
+///         // ...
+///     }
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn rovo_module(attr: TokenStream, item: TokenStream) -> TokenStream {
+    parse_rovo_module(attr.into(), item.into())
+        .unwrap_or_else(|err| compile_error(&err))
+        .into()
+}
+
+/// Turn a parse error into a `compile_error!`, at the error's span if it has one
+fn compile_error(err: &parser::ParseError) -> proc_macro2::TokenStream {
+    let err_msg = err.to_string();
+    err.span().map_or_else(
+        || {
+            quote! {
+                compile_error!(#err_msg);
+            }
+        },
+        |span| {
+            quote_spanned! {span=>
+                compile_error!(#err_msg);
+            }
+        },
+    )
 }
 
 /// Derive macro for [`JsonSchema`](trait@::schemars::JsonSchema) that automatically
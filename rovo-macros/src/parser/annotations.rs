@@ -1,5 +1,8 @@
 use super::error::ParseError;
-use super::types::{ExampleInfo, ResponseInfo};
+use super::types::{
+    BodyDoc, ExampleInfo, ExampleLabel, HeaderDoc, LinkDoc, ParamDoc, ParamLocation, PathParamDoc,
+    ResponseInfo, StatusKey,
+};
 use proc_macro2::{Span, TokenStream};
 
 /// Macro to parse simple annotations with format: @name <value>
@@ -50,14 +53,95 @@ macro_rules! parse_simple_annotation {
     }};
 }
 
+/// Well-known `StatusCode` constants accepted in place of numeric status codes
+const STATUS_CODE_NAMES: &[(&str, u16)] = &[
+    ("CONTINUE", 100),
+    ("SWITCHING_PROTOCOLS", 101),
+    ("OK", 200),
+    ("CREATED", 201),
+    ("ACCEPTED", 202),
+    ("NON_AUTHORITATIVE_INFORMATION", 203),
+    ("NO_CONTENT", 204),
+    ("RESET_CONTENT", 205),
+    ("PARTIAL_CONTENT", 206),
+    ("MOVED_PERMANENTLY", 301),
+    ("FOUND", 302),
+    ("SEE_OTHER", 303),
+    ("NOT_MODIFIED", 304),
+    ("TEMPORARY_REDIRECT", 307),
+    ("PERMANENT_REDIRECT", 308),
+    ("BAD_REQUEST", 400),
+    ("UNAUTHORIZED", 401),
+    ("PAYMENT_REQUIRED", 402),
+    ("FORBIDDEN", 403),
+    ("NOT_FOUND", 404),
+    ("METHOD_NOT_ALLOWED", 405),
+    ("NOT_ACCEPTABLE", 406),
+    ("REQUEST_TIMEOUT", 408),
+    ("CONFLICT", 409),
+    ("GONE", 410),
+    ("LENGTH_REQUIRED", 411),
+    ("PRECONDITION_FAILED", 412),
+    ("PAYLOAD_TOO_LARGE", 413),
+    ("URI_TOO_LONG", 414),
+    ("UNSUPPORTED_MEDIA_TYPE", 415),
+    ("RANGE_NOT_SATISFIABLE", 416),
+    ("EXPECTATION_FAILED", 417),
+    ("IM_A_TEAPOT", 418),
+    ("UNPROCESSABLE_ENTITY", 422),
+    ("LOCKED", 423),
+    ("FAILED_DEPENDENCY", 424),
+    ("UPGRADE_REQUIRED", 426),
+    ("PRECONDITION_REQUIRED", 428),
+    ("TOO_MANY_REQUESTS", 429),
+    ("REQUEST_HEADER_FIELDS_TOO_LARGE", 431),
+    ("UNAVAILABLE_FOR_LEGAL_REASONS", 451),
+    ("INTERNAL_SERVER_ERROR", 500),
+    ("NOT_IMPLEMENTED", 501),
+    ("BAD_GATEWAY", 502),
+    ("SERVICE_UNAVAILABLE", 503),
+    ("GATEWAY_TIMEOUT", 504),
+    ("HTTP_VERSION_NOT_SUPPORTED", 505),
+];
+
+/// Resolve a `StatusCode::NAME` constant (without the prefix) to its numeric code
+pub fn parse_status_code_name(name: &str, span: Span) -> Result<u16, ParseError> {
+    STATUS_CODE_NAMES
+        .iter()
+        .find(|(known, _)| *known == name)
+        .map(|&(_, code)| code)
+        .ok_or_else(|| {
+            ParseError::with_span(
+                format!(
+                    "Unknown status code constant 'StatusCode::{name}'\n\
+                     help: use the numeric status code instead, e.g. '404: () - Not found'\n\
+                     note: known constants include OK, CREATED, NO_CONTENT, BAD_REQUEST, NOT_FOUND, INTERNAL_SERVER_ERROR"
+                ),
+                span,
+            )
+        })
+}
+
 /// Parse response from pre-parsed parts (for Rust-style sections)
 pub fn parse_response_from_parts(
     response_type_str: &str,
-    status_code: u16,
+    status_code: StatusKey,
     description: &str,
     span: Span,
 ) -> Result<ResponseInfo, ParseError> {
-    validate_status_code(status_code, span)?;
+    match status_code {
+        StatusKey::Exact(code) => validate_status_code(code, span)?,
+        StatusKey::Range(digit) if !(1..=5).contains(&digit) => {
+            return Err(ParseError::with_span(
+                format!(
+                    "Status code range {digit}XX is out of valid range\n\
+                     help: status code ranges must be one of 1XX, 2XX, 3XX, 4XX, 5XX"
+                ),
+                span,
+            ));
+        }
+        StatusKey::Range(_) | StatusKey::Default => {}
+    }
 
     if description.trim().is_empty() {
         return Err(ParseError::with_span(
@@ -68,28 +152,186 @@ pub fn parse_response_from_parts(
         ));
     }
 
-    let response_type: TokenStream = response_type_str.parse().map_err(|_| {
+    let entries = split_response_types(response_type_str);
+    let mut content = Vec::with_capacity(entries.len());
+    for entry in &entries {
+        let (type_str, content_type) = split_content_type(entry);
+        if entries.len() > 1 && content_type.is_none() {
+            return Err(ParseError::with_span(
+                format!(
+                    "Missing content type for response type '{type_str}'\n\
+                     help: give each type a content type when a status has several\n\
+                     note: example '200: Json<User> [application/json], String [text/csv] - User data'"
+                ),
+                span,
+            ));
+        }
+
+        let expanded = expand_array_shorthand(type_str);
+        let response_type: TokenStream = expanded.parse().map_err(|_| {
+            ParseError::with_span(
+                format!(
+                    "Invalid response type '{type_str}'\n\
+                     help: response type must be valid Rust syntax\n\
+                     note: common types: Json<T>, (), (StatusCode, Json<T>)"
+                ),
+                span,
+            )
+        })?;
+        content.push((content_type.map(str::to_string), response_type));
+    }
+
+    Ok(ResponseInfo {
+        status_code,
+        content,
+        description: description.to_string(),
+    })
+}
+
+/// Split a response type list on top-level commas
+///
+/// `Json<User> [application/json], String [text/csv]` yields two entries, while
+/// commas inside brackets (`(StatusCode, Json<T>)`) are left alone.
+fn split_response_types(s: &str) -> Vec<&str> {
+    let mut entries = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+    for (idx, c) in s.char_indices() {
+        match c {
+            '<' | '(' | '[' => depth += 1,
+            '>' | ')' | ']' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => {
+                entries.push(s[start..idx].trim());
+                start = idx + 1;
+            }
+            _ => {}
+        }
+    }
+    entries.push(s[start..].trim());
+    entries
+}
+
+/// Split a trailing `[media/type]` content type off a response type
+///
+/// Only bracketed text containing a `/` counts, so `[User]` stays array shorthand.
+fn split_content_type(entry: &str) -> (&str, Option<&str>) {
+    let entry = entry.trim();
+    entry
+        .strip_suffix(']')
+        .and_then(|rest| {
+            rest.rfind('[')
+                .map(|open| (&rest[..open], &rest[open + 1..]))
+        })
+        .map(|(type_str, content_type)| (type_str.trim(), content_type.trim()))
+        .filter(|(type_str, content_type)| {
+            !type_str.is_empty()
+                && content_type.contains('/')
+                && !content_type.contains(char::is_whitespace)
+        })
+        .map_or((entry, None), |(type_str, content_type)| {
+            (type_str, Some(content_type))
+        })
+}
+
+/// Expand the `[T]` array shorthand to `Json<Vec<T>>`
+///
+/// Fixed-size array types like `[u8; 4]` and non-bracketed types are returned unchanged.
+pub fn expand_array_shorthand(response_type_str: &str) -> String {
+    let trimmed = response_type_str.trim();
+    trimmed
+        .strip_prefix('[')
+        .and_then(|rest| rest.strip_suffix(']'))
+        .map(str::trim)
+        .filter(|inner| !inner.is_empty() && !inner.contains(';'))
+        .map_or_else(
+            || trimmed.to_string(),
+            |inner| format!("Json<Vec<{inner}>>"),
+        )
+}
+
+/// Status code, optional label and expression of an `# Examples` line
+pub type ExampleLine<'a> = (u16, Option<ExampleLabel>, &'a str);
+
+/// Split an `# Examples` line into its status code, optional label and expression
+///
+/// Accepts `200: expr`, `200 "name": expr` and `200 "name" (summary: "..."): expr`.
+/// Returns `Ok(None)` for lines that don't start a new example.
+pub fn split_example_line(line: &str, span: Span) -> Result<Option<ExampleLine<'_>>, ParseError> {
+    let invalid_label = || {
         ParseError::with_span(
             format!(
-                "Invalid response type '{response_type_str}'\n\
-                 help: response type must be valid Rust syntax\n\
-                 note: common types: Json<T>, (), (StatusCode, Json<T>)"
+                "Invalid example label in '{line}'\n\
+                 help: expected '<status> \"name\" (summary: \"...\"): <expression>'\n\
+                 note: example '200 \"empty\" (summary: \"No results\"): Vec::<User>::new()'"
             ),
             span,
         )
-    })?;
+    };
 
-    Ok(ResponseInfo {
-        status_code,
-        response_type,
-        description: description.to_string(),
-    })
+    let digits_end = line
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(line.len());
+    if digits_end == 0 {
+        return Ok(None);
+    }
+    let (status_str, rest) = line.split_at(digits_end);
+    let rest = rest.trim_start();
+
+    let (label, rest) = if let Some(quoted) = rest.strip_prefix('"') {
+        let (name, rest) = quoted.split_once('"').ok_or_else(invalid_label)?;
+        if name.trim().is_empty() {
+            return Err(invalid_label());
+        }
+
+        let rest = rest.trim_start();
+        let (summary, rest) = if let Some(group) = rest.strip_prefix('(') {
+            let quoted = group
+                .trim_start()
+                .strip_prefix("summary")
+                .map(str::trim_start)
+                .and_then(|s| s.strip_prefix(':'))
+                .map(str::trim_start)
+                .and_then(|s| s.strip_prefix('"'))
+                .ok_or_else(invalid_label)?;
+            let (summary, rest) = quoted.split_once('"').ok_or_else(invalid_label)?;
+            let rest = rest
+                .trim_start()
+                .strip_prefix(')')
+                .ok_or_else(invalid_label)?;
+            (Some(summary.to_string()), rest.trim_start())
+        } else {
+            (None, rest)
+        };
+
+        let label = ExampleLabel {
+            name: name.to_string(),
+            summary,
+        };
+        (Some(label), rest)
+    } else {
+        (None, rest)
+    };
+
+    let Some(code) = rest.strip_prefix(':') else {
+        return if label.is_some() {
+            Err(invalid_label())
+        } else {
+            Ok(None)
+        };
+    };
+
+    let status_code = status_str
+        .parse::<u16>()
+        .map_err(|_| ParseError::with_span(format!("Invalid status code '{status_str}'"), span))?;
+
+    Ok(Some((status_code, label, code.trim())))
 }
 
 /// Parse example from pre-parsed parts (for Rust-style sections)
 pub fn parse_example_from_parts(
     status_code: u16,
     example_code_str: &str,
+    label: Option<ExampleLabel>,
     span: Span,
 ) -> Result<ExampleInfo, ParseError> {
     validate_status_code(status_code, span)?;
@@ -103,133 +345,1610 @@ pub fn parse_example_from_parts(
         ));
     }
 
-    // Unescape quotes that come from doc comments
-    let unescaped = example_code_str.replace("\\\"", "\"");
+    // Unescape quotes that come from doc comments
+    let unescaped = example_code_str.replace("\\\"", "\"");
+
+    // First parse as TokenStream
+    let example_code: TokenStream = unescaped.parse().map_err(|_| {
+        ParseError::with_span(
+            format!(
+                "Invalid example expression '{example_code_str}'\n\
+                 help: expression must be valid Rust syntax\n\
+                 note: examples: 'User::default()', 'User {{ id: 1, name: \"Alice\".into() }}', 'vec![1, 2, 3]'"
+            ),
+            span,
+        )
+    })?;
+
+    // Validate it's a valid expression using syn
+    syn::parse2::<syn::Expr>(example_code.clone()).map_err(|e| {
+        ParseError::with_span(
+            format!(
+                "Invalid example expression '{example_code_str}'\n\
+                 help: expression must be valid Rust syntax\n\
+                 note: parse error: {e}\n\
+                 note: examples: 'User::default()', 'User {{ id: 1, name: \"Alice\".into() }}', 'vec![1, 2, 3]'"
+            ),
+            span,
+        )
+    })?;
+
+    Ok(ExampleInfo {
+        status_code,
+        example_code,
+        label,
+        span,
+    })
+}
+
+/// Parse @tag annotation
+pub fn parse_tag(trimmed: &str, span: Span) -> Result<String, ParseError> {
+    Ok(parse_simple_annotation!(
+        trimmed,
+        span,
+        "tag",
+        "<tag_name>",
+        "users"
+    ))
+}
+
+/// Parse @summary annotation
+pub fn parse_summary(trimmed: &str, span: Span) -> Result<String, ParseError> {
+    Ok(parse_simple_annotation!(
+        trimmed,
+        span,
+        "summary",
+        "<summary>",
+        "Get user by ID"
+    ))
+}
+
+/// Parse @security annotation
+///
+/// Any words after the scheme are the `OAuth2` scopes the requirement asks for,
+/// e.g. `@security oauth2 read:users write:users`.
+pub fn parse_security(trimmed: &str, span: Span) -> Result<(String, Vec<String>), ParseError> {
+    let value = parse_simple_annotation!(
+        trimmed,
+        span,
+        "security",
+        "<scheme_name> [scopes...]",
+        "bearer_auth"
+    );
+
+    let mut words = value.split_whitespace().map(str::to_string);
+    let scheme = words.next().unwrap_or_default();
+    Ok((scheme, words.collect()))
+}
+
+/// Parse @security-and annotation: `@security-and <scheme_name> <scheme_name>...`
+///
+/// The schemes form a single requirement, so a client must satisfy all of them.
+pub fn parse_security_and(trimmed: &str, span: Span) -> Result<Vec<String>, ParseError> {
+    let schemes: Vec<String> = trimmed
+        .strip_prefix("@security-and")
+        .unwrap_or_default()
+        .split_whitespace()
+        .map(str::to_string)
+        .collect();
+
+    if schemes.len() < 2 {
+        return Err(ParseError::with_span(
+            format!(
+                "Invalid @security-and annotation '{trimmed}'\n\
+                 help: expected '@security-and <scheme_name> <scheme_name>...' with at least two schemes\n\
+                 note: use '@security <scheme_name>' for a single scheme"
+            ),
+            span,
+        ));
+    }
+
+    Ok(schemes)
+}
+
+/// Parse @id annotation
+pub fn parse_id(trimmed: &str, span: Span) -> Result<String, ParseError> {
+    let id = parse_simple_annotation!(trimmed, span, "id", "<operation_id>", "getUserById");
+
+    if !id.chars().all(|c| c.is_alphanumeric() || c == '_') {
+        return Err(ParseError::with_span(
+            format!(
+                "Invalid operation ID '{id}'\n\
+                 help: operation IDs must contain only alphanumeric characters and underscores\n\
+                 note: valid examples: 'getUserById', 'create_user', 'deleteItem123'"
+            ),
+            span,
+        ));
+    }
+
+    Ok(id)
+}
+
+/// Parse @cfg annotation
+pub fn parse_cfg(trimmed: &str, span: Span) -> Result<String, ParseError> {
+    Ok(parse_simple_annotation!(
+        trimmed,
+        span,
+        "cfg",
+        "<feature>",
+        "premium"
+    ))
+}
+
+/// Parse @min-version annotation
+pub fn parse_min_version(trimmed: &str, span: Span) -> Result<String, ParseError> {
+    let version = parse_simple_annotation!(trimmed, span, "min-version", "<version>", "2.0");
+    validate_version(&version, "min-version", span)?;
+    Ok(version)
+}
+
+/// Parse @max-version annotation
+pub fn parse_max_version(trimmed: &str, span: Span) -> Result<String, ParseError> {
+    let version = parse_simple_annotation!(trimmed, span, "max-version", "<version>", "3.5");
+    validate_version(&version, "max-version", span)?;
+    Ok(version)
+}
+
+/// Check that a version is `MAJOR[.MINOR[.PATCH]]`, e.g. `2`, `2.0` or `3.5.1`
+fn validate_version(version: &str, annotation: &str, span: Span) -> Result<(), ParseError> {
+    let parts: Vec<&str> = version.split('.').collect();
+    let valid = parts.len() <= 3
+        && parts
+            .iter()
+            .all(|part| !part.is_empty() && part.chars().all(|c| c.is_ascii_digit()));
+
+    if valid {
+        Ok(())
+    } else {
+        Err(ParseError::with_span(
+            format!(
+                "Invalid version '{version}' in @{annotation} annotation\n\
+                 help: use MAJOR[.MINOR[.PATCH]] with numeric parts\n\
+                 note: valid examples: '2', '2.0', '3.5.1'"
+            ),
+            span,
+        ))
+    }
+}
+
+/// Numeric parts of a version validated by `validate_version`, for ordering
+pub fn version_key(version: &str) -> [u64; 3] {
+    let mut key = [0; 3];
+    for (slot, part) in key.iter_mut().zip(version.split('.')) {
+        *slot = part.parse().unwrap_or(u64::MAX);
+    }
+    key
+}
+
+/// Time windows accepted by `@ratelimit <count> per-<window>`
+pub const RATE_LIMIT_WINDOWS: &[&str] = &["second", "minute", "hour"];
+
+/// Parse @ratelimit annotation: `@ratelimit <count> per-<window>`
+///
+/// Returns the request count and the window, e.g. `(100, "minute")`.
+pub fn parse_ratelimit(trimmed: &str, span: Span) -> Result<(u64, String), ParseError> {
+    let value = parse_simple_annotation!(
+        trimmed,
+        span,
+        "ratelimit",
+        "<count> per-<window>",
+        "100 per-minute"
+    );
+
+    let mut words = value.split_whitespace();
+    let (Some(count), Some(window), None) = (words.next(), words.next(), words.next()) else {
+        return Err(ParseError::with_span(
+            format!(
+                "Invalid @ratelimit annotation '{trimmed}'\n\
+                 help: expected '@ratelimit <count> per-<window>'\n\
+                 note: example '@ratelimit 100 per-minute'"
+            ),
+            span,
+        ));
+    };
+
+    let count = match count.parse::<u64>() {
+        Ok(count) if count > 0 => count,
+        _ => {
+            return Err(ParseError::with_span(
+                format!(
+                    "Invalid rate limit count '{count}'\n\
+                     help: the count must be a positive integer"
+                ),
+                span,
+            ))
+        }
+    };
+
+    match window.strip_prefix("per-") {
+        Some(window) if RATE_LIMIT_WINDOWS.contains(&window) => Ok((count, window.to_string())),
+        _ => Err(ParseError::with_span(
+            format!(
+                "Invalid rate limit window '{window}'\n\
+                 help: use per-second, per-minute or per-hour"
+            ),
+            span,
+        )),
+    }
+}
+
+/// Parse @async-job annotation
+pub fn parse_async_job(trimmed: &str, span: Span) -> Result<String, ParseError> {
+    let id = parse_simple_annotation!(
+        trimmed,
+        span,
+        "async-job",
+        "<callback_operation_id>",
+        "jobCompleted"
+    );
+
+    if !id.chars().all(|c| c.is_alphanumeric() || c == '_') {
+        return Err(ParseError::with_span(
+            format!(
+                "Invalid @async-job callback operation ID '{id}'\n\
+                 help: operation IDs must contain only alphanumeric characters and underscores\n\
+                 note: valid examples: 'jobCompleted', 'export_finished'"
+            ),
+            span,
+        ));
+    }
+
+    Ok(id)
+}
+
+/// Parse @server annotation: `@server <url> [description]`
+pub fn parse_server(trimmed: &str, span: Span) -> Result<(String, String), ParseError> {
+    let value = parse_simple_annotation!(
+        trimmed,
+        span,
+        "server",
+        "<url> <description>",
+        "https://eu.api.example.com EU region"
+    );
+
+    Ok(split_url_description(&value))
+}
+
+/// Parse @external-docs annotation: `@external-docs <url> [description]`
+pub fn parse_external_docs(trimmed: &str, span: Span) -> Result<(String, String), ParseError> {
+    let value = parse_simple_annotation!(
+        trimmed,
+        span,
+        "external-docs",
+        "<url> <description>",
+        "https://wiki.example.com/users User guide"
+    );
+
+    Ok(split_url_description(&value))
+}
+
+/// Parse module-level @tag-description annotation: `@tag-description <tag> <description>`
+pub fn parse_tag_description(trimmed: &str, span: Span) -> Result<(String, String), ParseError> {
+    let value = parse_simple_annotation!(
+        trimmed,
+        span,
+        "tag-description",
+        "<tag> <description>",
+        "users Endpoints for managing user accounts"
+    );
+
+    match value.split_once(char::is_whitespace) {
+        Some((tag, description)) if !description.trim().is_empty() => {
+            Ok((tag.to_string(), description.trim().to_string()))
+        }
+        _ => Err(ParseError::with_span(
+            format!(
+                "Missing description for tag '{value}' in @tag-description annotation\n\
+                 help: add the description after the tag name\n\
+                 note: example '@tag-description users Endpoints for managing user accounts'"
+            ),
+            span,
+        )),
+    }
+}
+
+/// Split `<url> [- ]<description>` into the URL and the (possibly empty) description
+fn split_url_description(value: &str) -> (String, String) {
+    let (url, description) = value.split_once(char::is_whitespace).unwrap_or((value, ""));
+    let description = description.trim();
+    let description = description.strip_prefix("- ").unwrap_or(description).trim();
+
+    (url.to_string(), description.to_string())
+}
+
+/// Error responses added by `@errors standard`
+pub const STANDARD_ERRORS: &[(u16, &str)] = &[
+    (400, "Bad request"),
+    (401, "Unauthorized"),
+    (403, "Forbidden"),
+    (500, "Internal server error"),
+];
+
+/// Parse @errors annotation, returning the status codes and descriptions of the named set
+pub fn parse_errors(
+    trimmed: &str,
+    span: Span,
+) -> Result<&'static [(u16, &'static str)], ParseError> {
+    let set = parse_simple_annotation!(trimmed, span, "errors", "<set>", "standard");
+
+    match set.as_str() {
+        "standard" => Ok(STANDARD_ERRORS),
+        _ => Err(ParseError::with_span(
+            format!(
+                "Unknown @errors set '{set}'\n\
+                 note: available sets are: standard"
+            ),
+            span,
+        )),
+    }
+}
+
+/// Query parameters added by `@paginated`, as (name, type, description)
+pub const PAGINATION_PARAMS: &[(&str, &str, &str)] = &[
+    ("page", "i32", "Page number to return"),
+    ("per_page", "i32", "Number of items per page"),
+    ("sort", "String", "Field to sort the items by"),
+];
+
+/// Parse @param annotation: `@param <name> <type> <description>`
+pub fn parse_param(trimmed: &str, span: Span) -> Result<ParamDoc, ParseError> {
+    parse_param_line(
+        trimmed,
+        span,
+        "param",
+        "id u64 The user's unique identifier",
+        None,
+    )
+}
+
+/// Parse @path annotation in `# Path Parameters`: `@path <name> <description>`
+pub fn parse_path_param(trimmed: &str, span: Span) -> Result<PathParamDoc, ParseError> {
+    let invalid = || {
+        ParseError::with_span(
+            format!(
+                "Invalid @path annotation '{trimmed}'\n\
+                 help: expected '@path <name> <description>'\n\
+                 note: example '@path id The user's unique identifier'"
+            ),
+            span,
+        )
+    };
+
+    let (name, description) = trimmed
+        .strip_prefix("@path")
+        .filter(|rest| rest.starts_with(char::is_whitespace))
+        .and_then(|rest| rest.trim_start().split_once(char::is_whitespace))
+        .ok_or_else(invalid)?;
+    let description = description.trim();
+    if !name.chars().all(|c| c.is_alphanumeric() || c == '_') || description.is_empty() {
+        return Err(invalid());
+    }
+
+    Ok(PathParamDoc {
+        name: name.to_string(),
+        description: description.to_string(),
+        span,
+        warn_on_mismatch: true,
+    })
+}
+
+/// Parse @query annotation: `@query <name> <type> <description>`
+pub fn parse_query(trimmed: &str, span: Span) -> Result<ParamDoc, ParseError> {
+    parse_param_line(
+        trimmed,
+        span,
+        "query",
+        "page u32 Page number to return",
+        Some(ParamLocation::Query),
+    )
+}
+
+/// Shared parser for `@<annotation> <name> <type> <description>` parameter lines
+fn parse_param_line(
+    trimmed: &str,
+    span: Span,
+    annotation: &str,
+    example: &str,
+    location: Option<ParamLocation>,
+) -> Result<ParamDoc, ParseError> {
+    let invalid = || {
+        ParseError::with_span(
+            format!(
+                "Invalid @{annotation} annotation '{trimmed}'\n\
+                 help: expected '@{annotation} <name> <type> <description>'\n\
+                 note: example '@{annotation} {example}'"
+            ),
+            span,
+        )
+    };
+
+    let rest = trimmed
+        .strip_prefix('@')
+        .and_then(|rest| rest.strip_prefix(annotation))
+        .filter(|rest| rest.starts_with(char::is_whitespace))
+        .ok_or_else(invalid)?
+        .trim_start();
+    let (name, rest) = rest.split_once(char::is_whitespace).ok_or_else(invalid)?;
+    if !name.chars().all(|c| c.is_alphanumeric() || c == '_') {
+        return Err(invalid());
+    }
+
+    let (type_str, description) = split_leading_type(rest.trim_start());
+    let description = description.strip_prefix("- ").unwrap_or(description).trim();
+    if type_str.is_empty() || description.is_empty() {
+        return Err(invalid());
+    }
+
+    let param_type: TokenStream = type_str.parse().map_err(|_| {
+        ParseError::with_span(
+            format!(
+                "Invalid type '{type_str}' for @{annotation} '{name}'\n\
+                 help: the parameter type must be valid Rust syntax\n\
+                 note: common types: u64, String, Uuid, Option<u32>"
+            ),
+            span,
+        )
+    })?;
+
+    Ok(ParamDoc {
+        name: name.to_string(),
+        param_type,
+        description: description.to_string(),
+        location,
+        span,
+    })
+}
+
+/// Parse @body annotation: `@body <type> [<content-type>] - <description>`
+pub fn parse_body(trimmed: &str, span: Span) -> Result<BodyDoc, ParseError> {
+    let invalid = || {
+        ParseError::with_span(
+            format!(
+                "Invalid @body annotation '{trimmed}'\n\
+                 help: expected '@body <type> [<content-type>] - <description>'\n\
+                 note: example '@body Json<CreateUserRequest> - The user to create'"
+            ),
+            span,
+        )
+    };
+
+    let rest = trimmed
+        .strip_prefix("@body")
+        .filter(|rest| rest.starts_with(char::is_whitespace))
+        .ok_or_else(invalid)?;
+    let (type_str, description) = rest.split_once(" - ").ok_or_else(invalid)?;
+    let (type_str, description) = (type_str.trim(), description.trim());
+    if type_str.is_empty() || description.is_empty() {
+        return Err(invalid());
+    }
+
+    // Rust types never contain '/', so a trailing word with one is a content type
+    let (type_str, content_type) = match type_str.rsplit_once(char::is_whitespace) {
+        Some((type_part, content_type)) if content_type.contains('/') => {
+            let (kind, subtype) = content_type.split_once('/').unwrap_or_default();
+            if kind.is_empty() || subtype.is_empty() {
+                return Err(ParseError::with_span(
+                    format!(
+                        "Invalid @body content type '{content_type}'\n\
+                         help: a content type looks like 'type/subtype'\n\
+                         note: example '@body String text/plain - The raw document'"
+                    ),
+                    span,
+                ));
+            }
+            (type_part.trim_end(), Some(content_type.to_string()))
+        }
+        _ => (type_str, None),
+    };
+
+    let body_type: TokenStream = type_str.parse().map_err(|_| {
+        ParseError::with_span(
+            format!(
+                "Invalid @body type '{type_str}'\n\
+                 help: the body type must be valid Rust syntax\n\
+                 note: common types: Json<T>, Form<T>"
+            ),
+            span,
+        )
+    })?;
+
+    Ok(BodyDoc {
+        body_type,
+        content_type,
+        description: description.to_string(),
+    })
+}
+
+/// Parse @extension annotation: `@extension <key> <json_value>`
+///
+/// Returns the key and the JSON text, which is checked here so invalid values
+/// fail the build instead of being dropped at runtime.
+pub fn parse_extension(trimmed: &str, span: Span) -> Result<(String, String), ParseError> {
+    let (key, value) = trimmed
+        .strip_prefix("@extension")
+        .filter(|rest| rest.starts_with(char::is_whitespace))
+        .and_then(|rest| rest.trim_start().split_once(char::is_whitespace))
+        .map(|(key, value)| (key, value.trim()))
+        .filter(|(_, value)| !value.is_empty())
+        .ok_or_else(|| {
+            ParseError::with_span(
+                format!(
+                    "Invalid @extension annotation '{trimmed}'\n\
+                     help: expected '@extension <key> <json_value>'\n\
+                     note: example '@extension x-internal true'"
+                ),
+                span,
+            )
+        })?;
+
+    if !key.starts_with("x-") || key.len() == 2 {
+        return Err(ParseError::with_span(
+            format!(
+                "Invalid @extension key '{key}'\n\
+                 help: OpenAPI extension keys must start with 'x-', e.g. 'x-{}'",
+                key.trim_start_matches("x-")
+            ),
+            span,
+        ));
+    }
+
+    if let Err(err) = serde_json::from_str::<serde_json::Value>(value) {
+        return Err(ParseError::with_span(
+            format!(
+                "Invalid JSON value for @extension '{key}': {err}\n\
+                 help: use a JSON literal, e.g. true, 100, \"text\" or {{\"key\": 1}}"
+            ),
+            span,
+        ));
+    }
+
+    Ok((key.to_string(), value.to_string()))
+}
+
+/// Parse @deprecated annotation, returning the reason if one is given
+pub fn parse_deprecated(trimmed: &str) -> Option<String> {
+    let reason = trimmed.strip_prefix("@deprecated")?.trim();
+    (!reason.is_empty()).then(|| format!("Deprecated: {reason}"))
+}
+
+/// Parse @content-type annotation: `@content-type <status> <content-type>`
+pub fn parse_content_type(trimmed: &str, span: Span) -> Result<(u16, String), ParseError> {
+    let invalid = || {
+        ParseError::with_span(
+            format!(
+                "Invalid @content-type annotation '{trimmed}'\n\
+                 help: expected '@content-type <status> <type/subtype>'\n\
+                 note: example '@content-type 200 text/csv'"
+            ),
+            span,
+        )
+    };
+
+    let mut parts = trimmed
+        .strip_prefix("@content-type")
+        .ok_or_else(invalid)?
+        .split_whitespace();
+    let (Some(status), Some(content_type), None) = (parts.next(), parts.next(), parts.next())
+    else {
+        return Err(invalid());
+    };
+    let status_code: u16 = status.parse().map_err(|_| invalid())?;
+    validate_status_code(status_code, span)?;
+    match content_type.split_once('/') {
+        Some((kind, subtype)) if !kind.is_empty() && !subtype.is_empty() => {}
+        _ => return Err(invalid()),
+    }
+
+    Ok((status_code, content_type.to_string()))
+}
+
+/// Parse @produces annotation: `@produces <type/subtype>`, the 200 response's content type
+pub fn parse_produces(trimmed: &str, span: Span) -> Result<String, ParseError> {
+    let content_type =
+        parse_simple_annotation!(trimmed, span, "produces", "<content-type>", "text/csv");
+
+    match content_type.split_once('/') {
+        Some((kind, subtype))
+            if !kind.is_empty() && !subtype.is_empty() && !content_type.contains(' ') =>
+        {
+            Ok(content_type)
+        }
+        _ => Err(ParseError::with_span(
+            format!(
+                "Invalid content type '{content_type}' in @produces annotation\n\
+                 help: expected a media type like 'text/csv' or 'application/octet-stream'"
+            ),
+            span,
+        )),
+    }
+}
+
+/// Parse @deprecated-message annotation
+pub fn parse_deprecated_message(trimmed: &str, span: Span) -> Result<String, ParseError> {
+    Ok(parse_simple_annotation!(
+        trimmed,
+        span,
+        "deprecated-message",
+        "<message>",
+        "Use /v2/users instead"
+    ))
+}
+
+/// Parse @header annotation: `@header <status> <name> <type> <description>`
+///
+/// The type is a Rust type or one of the `OpenAPI` primitives `string`,
+/// `integer`, `number` and `boolean`.
+pub fn parse_header(trimmed: &str, span: Span) -> Result<HeaderDoc, ParseError> {
+    let invalid = || {
+        ParseError::with_span(
+            format!(
+                "Invalid @header annotation '{trimmed}'\n\
+                 help: expected '@header <status> <name> <type> <description>'\n\
+                 note: example '@header 201 Location string The URL of the created resource'"
+            ),
+            span,
+        )
+    };
+
+    let rest = trimmed
+        .strip_prefix("@header")
+        .filter(|rest| rest.starts_with(char::is_whitespace))
+        .ok_or_else(invalid)?
+        .trim_start();
+    let (status, rest) = rest.split_once(char::is_whitespace).ok_or_else(invalid)?;
+    let (name, rest) = rest
+        .trim_start()
+        .split_once(char::is_whitespace)
+        .ok_or_else(invalid)?;
+    let (type_str, description) = split_leading_type(rest.trim_start());
+    let description = description.strip_prefix("- ").unwrap_or(description).trim();
+    if type_str.is_empty() || description.is_empty() {
+        return Err(invalid());
+    }
+
+    let status_code = status
+        .parse::<u16>()
+        .ok()
+        .filter(|code| (100..=599).contains(code))
+        .ok_or_else(|| {
+            ParseError::with_span(
+                format!(
+                    "Invalid status code '{status}' in @header annotation\n\
+                     help: use an HTTP status code between 100 and 599"
+                ),
+                span,
+            )
+        })?;
+
+    if !name
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    {
+        return Err(ParseError::with_span(
+            format!(
+                "Invalid header name '{name}'\n\
+                 help: header names may only contain letters, digits, '-' and '_'"
+            ),
+            span,
+        ));
+    }
+
+    let header_type = match type_str {
+        "string" => quote::quote! { String },
+        "integer" => quote::quote! { i64 },
+        "number" => quote::quote! { f64 },
+        "boolean" => quote::quote! { bool },
+        _ => type_str.parse().map_err(|_| {
+            ParseError::with_span(
+                format!(
+                    "Invalid type '{type_str}' for @header '{name}'\n\
+                     help: use string, integer, number, boolean or a Rust type"
+                ),
+                span,
+            )
+        })?,
+    };
+
+    Ok(HeaderDoc {
+        status_code,
+        name: name.to_string(),
+        header_type,
+        description: description.to_string(),
+        example: None,
+        span,
+    })
+}
+
+/// Parse @cacheable annotation: `@cacheable <directive>`, returning the `Cache-Control` value
+pub fn parse_cacheable(trimmed: &str, span: Span) -> Result<String, ParseError> {
+    Ok(parse_simple_annotation!(
+        trimmed,
+        span,
+        "cacheable",
+        "<directive>",
+        "public, max-age=60"
+    ))
+}
+
+/// Parse @link annotation: `@link <status> <operationId> <name>=<expression>...`
+pub fn parse_link(trimmed: &str, span: Span) -> Result<LinkDoc, ParseError> {
+    let invalid = || {
+        ParseError::with_span(
+            format!(
+                "Invalid @link annotation '{trimmed}'\n\
+                 help: expected '@link <status> <operationId> <name>=<expression>'\n\
+                 note: example '@link 201 getUser id=$response.body#/id'"
+            ),
+            span,
+        )
+    };
+
+    let mut parts = trimmed
+        .strip_prefix("@link")
+        .filter(|rest| rest.starts_with(char::is_whitespace))
+        .ok_or_else(invalid)?
+        .split_whitespace();
+    let (Some(status), Some(operation_id)) = (parts.next(), parts.next()) else {
+        return Err(invalid());
+    };
+    let status_code: u16 = status.parse().map_err(|_| invalid())?;
+    validate_status_code(status_code, span)?;
+
+    let parameters = parts
+        .map(|pair| match pair.split_once('=') {
+            Some((name, expression)) if !name.is_empty() && !expression.is_empty() => {
+                Ok((name.to_string(), expression.to_string()))
+            }
+            _ => Err(ParseError::with_span(
+                format!(
+                    "Invalid @link parameter '{pair}'\n\
+                     help: link parameters are written as '<name>=<expression>', e.g. 'id=$response.body#/id'"
+                ),
+                span,
+            )),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    if parameters.is_empty() {
+        return Err(invalid());
+    }
+
+    Ok(LinkDoc {
+        status_code,
+        operation_id: operation_id.to_string(),
+        parameters,
+        span,
+    })
+}
+
+/// Split a type off the front of `s`, stopping at the first whitespace outside brackets
+fn split_leading_type(s: &str) -> (&str, &str) {
+    let mut depth = 0usize;
+    for (idx, c) in s.char_indices() {
+        match c {
+            '<' | '(' | '[' => depth += 1,
+            '>' | ')' | ']' => depth = depth.saturating_sub(1),
+            c if c.is_whitespace() && depth == 0 => return (&s[..idx], s[idx..].trim_start()),
+            _ => {}
+        }
+    }
+    (s, "")
+}
+
+/// Parse @sunset annotation
+pub fn parse_sunset(trimmed: &str, span: Span) -> Result<String, ParseError> {
+    let date = parse_simple_annotation!(trimmed, span, "sunset", "<date>", "2025-12-31");
+
+    if !is_valid_sunset_date(&date) {
+        return Err(ParseError::with_span(
+            format!(
+                "Invalid @sunset date '{date}'\n\
+                 help: use an ISO 8601 date or an RFC 1123 HTTP date\n\
+                 note: examples: '2025-12-31', '2025-12-31T23:59:59Z', 'Wed, 31 Dec 2025 23:59:59 GMT'"
+            ),
+            span,
+        ));
+    }
+
+    Ok(date)
+}
+
+/// Check that a `@sunset` date is an ISO 8601 date (or date-time) or an RFC 1123 HTTP date
+fn is_valid_sunset_date(date: &str) -> bool {
+    is_iso_date(date) || is_rfc1123_date(date)
+}
+
+/// `YYYY-MM-DD`, optionally followed by `THH:MM:SS` and a `Z` or `±HH:MM` offset
+fn is_iso_date(date: &str) -> bool {
+    let (day, time) = match date.split_once('T') {
+        Some((day, time)) => (day, Some(time)),
+        None => (date, None),
+    };
+
+    let parts: Vec<&str> = day.split('-').collect();
+    let [year, month, day] = parts.as_slice() else {
+        return false;
+    };
+    if year.len() != 4
+        || !year.chars().all(|c| c.is_ascii_digit())
+        || !is_valid_day_of_month(month, day)
+    {
+        return false;
+    }
+
+    time.is_none_or(|time| {
+        let time = time
+            .strip_suffix('Z')
+            .or_else(|| {
+                time.rfind(['+', '-'])
+                    .filter(|&pos| is_hour_minute(&time[pos + 1..]))
+                    .map(|pos| &time[..pos])
+            })
+            .unwrap_or(time);
+        is_time_of_day(time)
+    })
+}
+
+/// `Day, DD Mon YYYY HH:MM:SS GMT`
+fn is_rfc1123_date(date: &str) -> bool {
+    const WEEKDAYS: [&str; 7] = ["Mon,", "Tue,", "Wed,", "Thu,", "Fri,", "Sat,", "Sun,"];
+    const MONTHS: [&str; 12] = [
+        "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+    ];
+
+    let parts: Vec<&str> = date.split_whitespace().collect();
+    let [weekday, day, month, year, time, "GMT"] = parts.as_slice() else {
+        return false;
+    };
+    let Some(month) = MONTHS.iter().position(|m| m == month) else {
+        return false;
+    };
+
+    WEEKDAYS.contains(weekday)
+        && year.len() == 4
+        && year.chars().all(|c| c.is_ascii_digit())
+        && is_valid_day_of_month(&format!("{:02}", month + 1), day)
+        && is_time_of_day(time)
+}
+
+/// Two-digit month and day within range
+fn is_valid_day_of_month(month: &str, day: &str) -> bool {
+    let two_digits = |s: &str| s.len() == 2 && s.chars().all(|c| c.is_ascii_digit());
+    two_digits(month)
+        && two_digits(day)
+        && month.parse().is_ok_and(|m: u8| (1..=12).contains(&m))
+        && day.parse().is_ok_and(|d: u8| (1..=31).contains(&d))
+}
+
+/// `HH:MM`
+fn is_hour_minute(time: &str) -> bool {
+    matches!(
+        time.split(':').collect::<Vec<_>>().as_slice(),
+        [h, m] if is_clock_field(h, 23) && is_clock_field(m, 59)
+    )
+}
+
+/// `HH:MM:SS`
+fn is_time_of_day(time: &str) -> bool {
+    matches!(
+        time.split(':').collect::<Vec<_>>().as_slice(),
+        [h, m, s] if is_clock_field(h, 23) && is_clock_field(m, 59) && is_clock_field(s, 60)
+    )
+}
+
+fn is_clock_field(field: &str, max: u8) -> bool {
+    field.len() == 2
+        && field.chars().all(|c| c.is_ascii_digit())
+        && field.parse().is_ok_and(|v: u8| v <= max)
+}
+
+/// Validate HTTP status code
+fn validate_status_code(status_code: u16, span: Span) -> Result<(), ParseError> {
+    if (100..=599).contains(&status_code) {
+        Ok(())
+    } else {
+        Err(ParseError::with_span(
+            format!(
+                "Status code {status_code} is out of valid range\n\
+                 help: HTTP status codes must be between 100-599\n\
+                 note: common codes: 200 (OK), 201 (Created), 400 (Bad Request), 404 (Not Found), 500 (Internal Error)"
+            ),
+            span,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_valid_tag() {
+        let result = parse_tag("@tag users", Span::call_site());
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), "users");
+    }
+
+    #[test]
+    fn tag_requires_value() {
+        let result = parse_tag("@tag", Span::call_site());
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("Invalid @tag"));
+    }
+
+    #[test]
+    fn tag_rejects_empty_value() {
+        let result = parse_tag("@tag  ", Span::call_site());
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("Empty"));
+    }
+
+    #[test]
+    fn parses_valid_security() {
+        let result = parse_security("@security bearer_auth", Span::call_site());
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), ("bearer_auth".to_string(), Vec::new()));
+    }
+
+    #[test]
+    fn parses_security_with_scopes() {
+        let result = parse_security("@security oauth2 read:users write:users", Span::call_site());
+        assert_eq!(
+            result.unwrap(),
+            (
+                "oauth2".to_string(),
+                vec!["read:users".to_string(), "write:users".to_string()]
+            )
+        );
+    }
+
+    #[test]
+    fn security_requires_value() {
+        let result = parse_security("@security", Span::call_site());
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn parses_valid_security_and() {
+        let result = parse_security_and("@security-and bearer_auth  api_key", Span::call_site());
+        assert_eq!(result.unwrap(), vec!["bearer_auth", "api_key"]);
+    }
+
+    #[test]
+    fn security_and_requires_two_schemes() {
+        for line in ["@security-and", "@security-and bearer_auth"] {
+            let result = parse_security_and(line, Span::call_site());
+            assert!(
+                result
+                    .unwrap_err()
+                    .to_string()
+                    .contains("at least two schemes"),
+                "{line}"
+            );
+        }
+    }
+
+    #[test]
+    fn parses_valid_content_type() {
+        let result = parse_content_type("@content-type 200 text/csv", Span::call_site());
+        assert_eq!(result.unwrap(), (200, "text/csv".to_string()));
+    }
+
+    #[test]
+    fn rejects_malformed_content_type() {
+        for line in [
+            "@content-type",
+            "@content-type 200",
+            "@content-type ok text/csv",
+            "@content-type 200 csv",
+            "@content-type 200 text/csv extra",
+        ] {
+            let result = parse_content_type(line, Span::call_site());
+            assert!(
+                result
+                    .unwrap_err()
+                    .to_string()
+                    .contains("Invalid @content-type annotation"),
+                "{line}"
+            );
+        }
+    }
+
+    #[test]
+    fn parses_produces() {
+        let result = parse_produces("@produces application/octet-stream", Span::call_site());
+        assert_eq!(result.unwrap(), "application/octet-stream");
+
+        for line in [
+            "@produces csv",
+            "@produces text/csv extra",
+            "@produces text/",
+        ] {
+            let result = parse_produces(line, Span::call_site());
+            assert!(
+                result
+                    .unwrap_err()
+                    .to_string()
+                    .contains("in @produces annotation"),
+                "{line}"
+            );
+        }
+
+        let result = parse_produces("@produces", Span::call_site());
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("Invalid @produces annotation format"));
+    }
+
+    #[test]
+    fn parses_valid_deprecated_message() {
+        let result = parse_deprecated_message(
+            "@deprecated-message Use /v2/users instead",
+            Span::call_site(),
+        );
+        assert_eq!(result.unwrap(), "Use /v2/users instead");
+    }
+
+    #[test]
+    fn deprecated_message_requires_value() {
+        let result = parse_deprecated_message("@deprecated-message", Span::call_site());
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn parses_valid_cfg() {
+        let result = parse_cfg("@cfg premium", Span::call_site());
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), "premium");
+    }
+
+    #[test]
+    fn parses_versions() {
+        for (line, expected) in [("@min-version 2", "2"), ("@min-version 2.0.1", "2.0.1")] {
+            assert_eq!(
+                parse_min_version(line, Span::call_site()).unwrap(),
+                expected
+            );
+        }
+        assert_eq!(
+            parse_max_version("@max-version 3.5", Span::call_site()).unwrap(),
+            "3.5"
+        );
+        assert!(version_key("2.10") > version_key("2.9"));
+        assert_eq!(version_key("2"), version_key("2.0.0"));
+    }
+
+    #[test]
+    fn rejects_malformed_versions() {
+        for line in [
+            "@max-version v3",
+            "@max-version 3.",
+            "@max-version 1.2.3.4",
+            "@max-version 3.5-beta",
+        ] {
+            let result = parse_max_version(line, Span::call_site());
+            assert!(
+                result
+                    .unwrap_err()
+                    .to_string()
+                    .contains("in @max-version annotation"),
+                "{line}"
+            );
+        }
+        let result = parse_min_version("@min-version", Span::call_site());
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("Invalid @min-version annotation format"));
+    }
+
+    #[test]
+    fn parses_ratelimit() {
+        for (line, expected) in [
+            ("@ratelimit 100 per-minute", (100, "minute")),
+            ("@ratelimit 5 per-second", (5, "second")),
+            ("@ratelimit 1000  per-hour", (1000, "hour")),
+        ] {
+            let (count, window) = parse_ratelimit(line, Span::call_site()).unwrap();
+            assert_eq!((count, window.as_str()), expected);
+        }
+    }
+
+    #[test]
+    fn rejects_malformed_ratelimit() {
+        for (line, message) in [
+            ("@ratelimit", "Invalid @ratelimit annotation format"),
+            ("@ratelimit 100", "Invalid @ratelimit annotation"),
+            (
+                "@ratelimit 100 per-minute extra",
+                "Invalid @ratelimit annotation",
+            ),
+            ("@ratelimit 0 per-minute", "Invalid rate limit count '0'"),
+            ("@ratelimit -5 per-minute", "Invalid rate limit count '-5'"),
+            (
+                "@ratelimit 100 per-day",
+                "Invalid rate limit window 'per-day'",
+            ),
+            (
+                "@ratelimit 100 minute",
+                "Invalid rate limit window 'minute'",
+            ),
+        ] {
+            let err = parse_ratelimit(line, Span::call_site()).unwrap_err();
+            assert!(err.to_string().contains(message), "{line}: {err}");
+        }
+    }
+
+    #[test]
+    fn cfg_requires_value() {
+        let result = parse_cfg("@cfg", Span::call_site());
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("Invalid @cfg"));
+    }
+
+    #[test]
+    fn parses_valid_param() {
+        let param = parse_param(
+            "@param id u64 The user's unique identifier",
+            Span::call_site(),
+        )
+        .unwrap();
+        assert_eq!(param.name, "id");
+        assert_eq!(param.param_type.to_string(), "u64");
+        assert_eq!(param.description, "The user's unique identifier");
+        assert!(param.location.is_none());
+    }
+
+    #[test]
+    fn parses_param_with_generic_type_and_dash() {
+        let param = parse_param(
+            "@param filters HashMap<String, u32> - Field filters",
+            Span::call_site(),
+        )
+        .unwrap();
+        assert_eq!(param.name, "filters");
+        assert_eq!(param.param_type.to_string(), "HashMap < String , u32 >");
+        assert_eq!(param.description, "Field filters");
+    }
+
+    #[test]
+    fn rejects_malformed_param() {
+        for line in [
+            "@param",
+            "@param id",
+            "@param id u64",
+            "@param user-id u64 The id",
+            "@paramid u64 The id",
+        ] {
+            let result = parse_param(line, Span::call_site());
+            assert!(
+                result
+                    .unwrap_err()
+                    .to_string()
+                    .contains("Invalid @param annotation"),
+                "{line}"
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_param_with_invalid_type() {
+        let result = parse_param("@param id Vec<u64)> The ids", Span::call_site());
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("Invalid type 'Vec<u64)>' for @param 'id'"));
+    }
+
+    #[test]
+    fn parses_valid_path_param() {
+        let doc =
+            parse_path_param("@path id The user's unique identifier", Span::call_site()).unwrap();
+        assert_eq!(doc.name, "id");
+        assert_eq!(doc.description, "The user's unique identifier");
+        assert!(doc.warn_on_mismatch);
+    }
+
+    #[test]
+    fn rejects_malformed_path_param() {
+        for line in ["@path", "@path id", "@path id   ", "@path user-id The id"] {
+            let result = parse_path_param(line, Span::call_site());
+            assert!(
+                result
+                    .unwrap_err()
+                    .to_string()
+                    .contains("Invalid @path annotation"),
+                "{line}"
+            );
+        }
+    }
+
+    #[test]
+    fn parses_query_params_as_query() {
+        let param =
+            parse_query("@query page u32 Page number to return", Span::call_site()).unwrap();
+        assert_eq!(param.name, "page");
+        assert_eq!(param.param_type.to_string(), "u32");
+        assert_eq!(param.description, "Page number to return");
+        assert_eq!(param.location, Some(ParamLocation::Query));
+    }
+
+    #[test]
+    fn rejects_malformed_query() {
+        let err = parse_query("@query page", Span::call_site())
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("Invalid @query annotation '@query page'"));
+        assert!(err.contains("help: expected '@query <name> <type> <description>'"));
+    }
+
+    #[test]
+    fn parses_valid_body() {
+        let body = parse_body(
+            "@body Json<CreateUserRequest> - The user to create",
+            Span::call_site(),
+        )
+        .unwrap();
+        assert_eq!(body.body_type.to_string(), "Json < CreateUserRequest >");
+        assert_eq!(body.description, "The user to create");
+    }
+
+    #[test]
+    fn parses_body_with_content_type() {
+        let body = parse_body(
+            "@body String text/plain - The raw document",
+            Span::call_site(),
+        )
+        .unwrap();
+        assert_eq!(body.body_type.to_string(), "String");
+        assert_eq!(body.content_type.as_deref(), Some("text/plain"));
+        assert_eq!(body.description, "The raw document");
+
+        let body = parse_body(
+            "@body Json<CreateUserRequest> - The user to create",
+            Span::call_site(),
+        )
+        .unwrap();
+        assert_eq!(body.content_type, None);
+    }
+
+    #[test]
+    fn rejects_body_with_invalid_content_type() {
+        let result = parse_body("@body String text/ - The raw document", Span::call_site());
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("Invalid @body content type 'text/'"));
+    }
+
+    #[test]
+    fn rejects_malformed_body() {
+        for line in [
+            "@body",
+            "@body Json<User>",
+            "@body - The user",
+            "@body Json<User> - ",
+        ] {
+            let result = parse_body(line, Span::call_site());
+            assert!(
+                result
+                    .unwrap_err()
+                    .to_string()
+                    .contains("Invalid @body annotation"),
+                "{line}"
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_body_with_invalid_type() {
+        let result = parse_body("@body Json<User)> - The user", Span::call_site());
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("Invalid @body type 'Json<User)>'"));
+    }
+
+    #[test]
+    fn parses_valid_extensions() {
+        for (line, key, value) in [
+            ("@extension x-internal true", "x-internal", "true"),
+            ("@extension x-rate-limit 100", "x-rate-limit", "100"),
+            (
+                r#"@extension x-owner {"team": "billing"}"#,
+                "x-owner",
+                r#"{"team": "billing"}"#,
+            ),
+        ] {
+            let (parsed_key, parsed_value) = parse_extension(line, Span::call_site()).unwrap();
+            assert_eq!(parsed_key, key);
+            assert_eq!(parsed_value, value);
+        }
+    }
+
+    #[test]
+    fn rejects_extension_key_without_prefix() {
+        let result = parse_extension("@extension internal true", Span::call_site());
+        let err = result.unwrap_err().to_string();
+        assert!(err.contains("Invalid @extension key 'internal'"));
+        assert!(err.contains("e.g. 'x-internal'"));
+    }
+
+    #[test]
+    fn rejects_extension_with_invalid_json() {
+        let result = parse_extension("@extension x-owner billing team", Span::call_site());
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("Invalid JSON value for @extension 'x-owner'"));
+    }
+
+    #[test]
+    fn rejects_extension_without_value() {
+        let result = parse_extension("@extension x-internal", Span::call_site());
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("Invalid @extension annotation"));
+    }
+
+    #[test]
+    fn parses_deprecated_reason() {
+        assert_eq!(
+            parse_deprecated("@deprecated Use /v2/users instead").as_deref(),
+            Some("Deprecated: Use /v2/users instead")
+        );
+        assert_eq!(parse_deprecated("@deprecated"), None);
+    }
+
+    #[test]
+    fn parses_valid_header() {
+        let header = parse_header(
+            "@header 201 Location string The URL of the created resource",
+            Span::call_site(),
+        )
+        .unwrap();
+        assert_eq!(header.status_code, 201);
+        assert_eq!(header.name, "Location");
+        assert_eq!(header.header_type.to_string(), "String");
+        assert_eq!(header.description, "The URL of the created resource");
+    }
+
+    #[test]
+    fn parses_header_with_rust_type() {
+        let header = parse_header(
+            "@header 200 X-Rate-Limit Option<u32> - Requests left",
+            Span::call_site(),
+        )
+        .unwrap();
+        assert_eq!(header.header_type.to_string(), "Option < u32 >");
+        assert_eq!(header.description, "Requests left");
+    }
+
+    #[test]
+    fn rejects_malformed_header() {
+        for line in [
+            "@header",
+            "@header 201",
+            "@header 201 Location",
+            "@header 201 Location string",
+        ] {
+            let result = parse_header(line, Span::call_site());
+            assert!(
+                result
+                    .unwrap_err()
+                    .to_string()
+                    .contains("Invalid @header annotation"),
+                "{line}"
+            );
+        }
+    }
 
-    // First parse as TokenStream
-    let example_code: TokenStream = unescaped.parse().map_err(|_| {
-        ParseError::with_span(
-            format!(
-                "Invalid example expression '{example_code_str}'\n\
-                 help: expression must be valid Rust syntax\n\
-                 note: examples: 'User::default()', 'User {{ id: 1, name: \"Alice\".into() }}', 'vec![1, 2, 3]'"
-            ),
-            span,
-        )
-    })?;
+    #[test]
+    fn rejects_header_with_invalid_status() {
+        let result = parse_header("@header 2xx Location string The URL", Span::call_site());
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("Invalid status code '2xx' in @header annotation"));
+    }
 
-    // Validate it's a valid expression using syn
-    syn::parse2::<syn::Expr>(example_code.clone()).map_err(|e| {
-        ParseError::with_span(
-            format!(
-                "Invalid example expression '{example_code_str}'\n\
-                 help: expression must be valid Rust syntax\n\
-                 note: parse error: {e}\n\
-                 note: examples: 'User::default()', 'User {{ id: 1, name: \"Alice\".into() }}', 'vec![1, 2, 3]'"
-            ),
-            span,
-        )
-    })?;
+    #[test]
+    fn parses_link() {
+        let link =
+            parse_link("@link 201 getUser id=$response.body#/id", Span::call_site()).unwrap();
+        assert_eq!(link.status_code, 201);
+        assert_eq!(link.operation_id, "getUser");
+        assert_eq!(
+            link.parameters,
+            vec![("id".to_string(), "$response.body#/id".to_string())]
+        );
+    }
 
-    Ok(ExampleInfo {
-        status_code,
-        example_code,
-        span,
-    })
-}
+    #[test]
+    fn rejects_malformed_link() {
+        for line in [
+            "@link",
+            "@link 201",
+            "@link 201 getUser",
+            "@link abc getUser id=1",
+        ] {
+            let result = parse_link(line, Span::call_site());
+            assert!(
+                result
+                    .unwrap_err()
+                    .to_string()
+                    .contains("Invalid @link annotation"),
+                "{line}"
+            );
+        }
 
-/// Parse @tag annotation
-pub fn parse_tag(trimmed: &str, span: Span) -> Result<String, ParseError> {
-    Ok(parse_simple_annotation!(
-        trimmed,
-        span,
-        "tag",
-        "<tag_name>",
-        "users"
-    ))
-}
+        let result = parse_link("@link 201 getUser id", Span::call_site());
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("Invalid @link parameter 'id'"));
+    }
 
-/// Parse @security annotation
-pub fn parse_security(trimmed: &str, span: Span) -> Result<String, ParseError> {
-    Ok(parse_simple_annotation!(
-        trimmed,
-        span,
-        "security",
-        "<scheme_name>",
-        "bearer_auth"
-    ))
-}
+    #[test]
+    fn parses_standard_errors() {
+        let errors = parse_errors("@errors standard", Span::call_site()).unwrap();
+        let codes: Vec<u16> = errors.iter().map(|(code, _)| *code).collect();
+        assert_eq!(codes, vec![400, 401, 403, 500]);
+    }
 
-/// Parse @id annotation
-pub fn parse_id(trimmed: &str, span: Span) -> Result<String, ParseError> {
-    let id = parse_simple_annotation!(trimmed, span, "id", "<operation_id>", "getUserById");
+    #[test]
+    fn rejects_unknown_error_set() {
+        let result = parse_errors("@errors everything", Span::call_site());
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("Unknown @errors set 'everything'"));
+    }
 
-    if !id.chars().all(|c| c.is_alphanumeric() || c == '_') {
-        return Err(ParseError::with_span(
-            format!(
-                "Invalid operation ID '{id}'\n\
-                 help: operation IDs must contain only alphanumeric characters and underscores\n\
-                 note: valid examples: 'getUserById', 'create_user', 'deleteItem123'"
-            ),
-            span,
-        ));
+    #[test]
+    fn parses_valid_async_job() {
+        let result = parse_async_job("@async-job jobCompleted", Span::call_site());
+        assert_eq!(result.unwrap(), "jobCompleted");
     }
 
-    Ok(id)
-}
+    #[test]
+    fn rejects_invalid_async_job_id() {
+        let result = parse_async_job("@async-job job-completed", Span::call_site());
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("Invalid @async-job callback operation ID"));
+    }
 
-/// Validate HTTP status code
-fn validate_status_code(status_code: u16, span: Span) -> Result<(), ParseError> {
-    if (100..=599).contains(&status_code) {
-        Ok(())
-    } else {
-        Err(ParseError::with_span(
-            format!(
-                "Status code {status_code} is out of valid range\n\
-                 help: HTTP status codes must be between 100-599\n\
-                 note: common codes: 200 (OK), 201 (Created), 400 (Bad Request), 404 (Not Found), 500 (Internal Error)"
-            ),
-            span,
-        ))
+    #[test]
+    fn async_job_requires_value() {
+        let result = parse_async_job("@async-job", Span::call_site());
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("Invalid @async-job"));
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    #[test]
+    fn parses_valid_sunset_dates() {
+        for date in [
+            "2025-12-31",
+            "2025-12-31T23:59:59Z",
+            "2025-12-31T23:59:59+02:00",
+            "2025-12-31T23:59:59",
+            "Wed, 31 Dec 2025 23:59:59 GMT",
+        ] {
+            let result = parse_sunset(&format!("@sunset {date}"), Span::call_site());
+            assert_eq!(result.unwrap(), date);
+        }
+    }
 
     #[test]
-    fn parses_valid_tag() {
-        let result = parse_tag("@tag users", Span::call_site());
-        assert!(result.is_ok());
-        assert_eq!(result.unwrap(), "users");
+    fn rejects_invalid_sunset_dates() {
+        for date in [
+            "tomorrow",
+            "2025-13-01",
+            "2025-12-32",
+            "25-12-31",
+            "2025-12-31T25:00:00Z",
+            "2025-12-31T23:59",
+            "31 Dec 2025",
+            "Wed, 31 Foo 2025 23:59:59 GMT",
+            "Wed, 31 Dec 2025 23:59:59 UTC",
+        ] {
+            let result = parse_sunset(&format!("@sunset {date}"), Span::call_site());
+            assert!(
+                result
+                    .unwrap_err()
+                    .to_string()
+                    .contains("Invalid @sunset date"),
+                "{date}"
+            );
+        }
     }
 
     #[test]
-    fn tag_requires_value() {
-        let result = parse_tag("@tag", Span::call_site());
-        assert!(result.is_err());
-        assert!(result.unwrap_err().to_string().contains("Invalid @tag"));
+    fn sunset_requires_value() {
+        let result = parse_sunset("@sunset", Span::call_site());
+        assert!(result.unwrap_err().to_string().contains("Invalid @sunset"));
     }
 
     #[test]
-    fn tag_rejects_empty_value() {
-        let result = parse_tag("@tag  ", Span::call_site());
-        assert!(result.is_err());
-        assert!(result.unwrap_err().to_string().contains("Empty"));
+    fn parses_server_with_description() {
+        let result = parse_server(
+            "@server https://eu.api.example.com EU region",
+            Span::call_site(),
+        );
+        assert_eq!(
+            result.unwrap(),
+            (
+                "https://eu.api.example.com".to_string(),
+                "EU region".to_string()
+            )
+        );
+
+        let result = parse_server("@server /v2", Span::call_site());
+        assert_eq!(result.unwrap(), ("/v2".to_string(), String::new()));
     }
 
     #[test]
-    fn parses_valid_security() {
-        let result = parse_security("@security bearer_auth", Span::call_site());
-        assert!(result.is_ok());
-        assert_eq!(result.unwrap(), "bearer_auth");
+    fn server_requires_url() {
+        for line in ["@server", "@server   "] {
+            let result = parse_server(line, Span::call_site());
+            assert!(
+                result.unwrap_err().to_string().contains("@server"),
+                "{line}"
+            );
+        }
     }
 
     #[test]
-    fn security_requires_value() {
-        let result = parse_security("@security", Span::call_site());
-        assert!(result.is_err());
+    fn parses_tag_description() {
+        let result = parse_tag_description(
+            "@tag-description users Endpoints for managing user accounts",
+            Span::call_site(),
+        );
+        assert_eq!(
+            result.unwrap(),
+            (
+                "users".to_string(),
+                "Endpoints for managing user accounts".to_string()
+            )
+        );
+
+        let result = parse_tag_description("@tag-description users", Span::call_site());
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("Missing description for tag 'users'"));
+
+        let result = parse_tag_description("@tag-description", Span::call_site());
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("Invalid @tag-description annotation format"));
+    }
+
+    #[test]
+    fn parses_external_docs() {
+        let result = parse_external_docs(
+            "@external-docs https://wiki.example.com/users - User guide",
+            Span::call_site(),
+        );
+        assert_eq!(
+            result.unwrap(),
+            (
+                "https://wiki.example.com/users".to_string(),
+                "User guide".to_string()
+            )
+        );
+
+        let result = parse_external_docs("@external-docs", Span::call_site());
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("Invalid @external-docs annotation format"));
     }
 
     #[test]
@@ -277,11 +1996,76 @@ mod tests {
         assert!(validate_status_code(600, Span::call_site()).is_err());
     }
 
+    #[test]
+    fn resolves_status_code_names() {
+        assert_eq!(
+            parse_status_code_name("NOT_FOUND", Span::call_site()).unwrap(),
+            404
+        );
+        assert_eq!(
+            parse_status_code_name("OK", Span::call_site()).unwrap(),
+            200
+        );
+
+        let err = parse_status_code_name("NOPE", Span::call_site()).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("Unknown status code constant 'StatusCode::NOPE'"));
+        assert!(err.to_string().contains("use the numeric status code"));
+    }
+
     // Tests for parse_response_from_parts
 
+    #[test]
+    fn parses_status_keys() {
+        assert_eq!(StatusKey::parse("200"), Some(StatusKey::Exact(200)));
+        assert_eq!(StatusKey::parse("2XX"), Some(StatusKey::Range(2)));
+        assert_eq!(StatusKey::parse("default"), Some(StatusKey::Default));
+        for status in ["", "2xx", "20X", "XX", "22XX", "Default", "+200"] {
+            assert_eq!(StatusKey::parse(status), None, "{status}");
+        }
+        assert_eq!(StatusKey::Range(4).to_string(), "4XX");
+    }
+
+    #[test]
+    fn accepts_range_and_default_responses() {
+        let info = parse_response_from_parts(
+            "Json<Error>",
+            StatusKey::Range(4),
+            "Client error",
+            Span::call_site(),
+        )
+        .unwrap();
+        assert_eq!(info.status_code, StatusKey::Range(4));
+
+        let info = parse_response_from_parts(
+            "Json<Error>",
+            StatusKey::Default,
+            "Fallback",
+            Span::call_site(),
+        )
+        .unwrap();
+        assert_eq!(info.status_code, StatusKey::Default);
+    }
+
+    #[test]
+    fn rejects_out_of_range_status_range() {
+        let result =
+            parse_response_from_parts("Json<Error>", StatusKey::Range(6), "Odd", Span::call_site());
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("Status code range 6XX is out of valid range"));
+    }
+
     #[test]
     fn response_from_parts_valid() {
-        let result = parse_response_from_parts("Json<User>", 200, "Success", Span::call_site());
+        let result = parse_response_from_parts(
+            "Json<User>",
+            StatusKey::Exact(200),
+            "Success",
+            Span::call_site(),
+        );
         assert!(result.is_ok());
         let info = result.unwrap();
         assert_eq!(info.status_code, 200);
@@ -290,7 +2074,8 @@ mod tests {
 
     #[test]
     fn response_from_parts_empty_description() {
-        let result = parse_response_from_parts("Json<User>", 200, "", Span::call_site());
+        let result =
+            parse_response_from_parts("Json<User>", StatusKey::Exact(200), "", Span::call_site());
         assert!(result.is_err());
         assert!(result
             .unwrap_err()
@@ -300,7 +2085,12 @@ mod tests {
 
     #[test]
     fn response_from_parts_whitespace_description() {
-        let result = parse_response_from_parts("Json<User>", 200, "   ", Span::call_site());
+        let result = parse_response_from_parts(
+            "Json<User>",
+            StatusKey::Exact(200),
+            "   ",
+            Span::call_site(),
+        );
         assert!(result.is_err());
         assert!(result
             .unwrap_err()
@@ -310,7 +2100,12 @@ mod tests {
 
     #[test]
     fn response_from_parts_invalid_status() {
-        let result = parse_response_from_parts("Json<User>", 999, "Success", Span::call_site());
+        let result = parse_response_from_parts(
+            "Json<User>",
+            StatusKey::Exact(999),
+            "Success",
+            Span::call_site(),
+        );
         assert!(result.is_err());
         assert!(result
             .unwrap_err()
@@ -320,15 +2115,107 @@ mod tests {
 
     #[test]
     fn response_from_parts_unit_type() {
-        let result = parse_response_from_parts("()", 204, "No content", Span::call_site());
+        let result =
+            parse_response_from_parts("()", StatusKey::Exact(204), "No content", Span::call_site());
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn response_from_parts_array_shorthand() {
+        let result = parse_response_from_parts(
+            "[User]",
+            StatusKey::Exact(200),
+            "List of users",
+            Span::call_site(),
+        );
         assert!(result.is_ok());
+        let info = result.unwrap();
+        assert_eq!(
+            info.response_type().to_string(),
+            quote::quote!(Json<Vec<User>>).to_string()
+        );
+    }
+
+    #[test]
+    fn response_from_parts_multiple_content_types() {
+        let info = parse_response_from_parts(
+            "Json<User> [application/json], String [text/csv]",
+            StatusKey::Exact(200),
+            "User data",
+            Span::call_site(),
+        )
+        .unwrap();
+        let content: Vec<_> = info
+            .content
+            .iter()
+            .map(|(content_type, ty)| (content_type.as_deref(), ty.to_string()))
+            .collect();
+        assert_eq!(
+            content,
+            vec![
+                (Some("application/json"), "Json < User >".to_string()),
+                (Some("text/csv"), "String".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn response_from_parts_keeps_single_type_and_tuples() {
+        let info = parse_response_from_parts(
+            "(StatusCode, Json<User>)",
+            StatusKey::Exact(200),
+            "User",
+            Span::call_site(),
+        )
+        .unwrap();
+        assert_eq!(info.content.len(), 1);
+        assert_eq!(info.content[0].0, None);
+
+        let info = parse_response_from_parts(
+            "[User] [text/csv]",
+            StatusKey::Exact(200),
+            "Users",
+            Span::call_site(),
+        )
+        .unwrap();
+        assert_eq!(info.content[0].0.as_deref(), Some("text/csv"));
+        assert_eq!(
+            info.response_type().to_string(),
+            quote::quote!(Json<Vec<User>>).to_string()
+        );
+    }
+
+    #[test]
+    fn response_from_parts_requires_content_type_for_each_type() {
+        let result = parse_response_from_parts(
+            "Json<User> [application/json], String",
+            StatusKey::Exact(200),
+            "User data",
+            Span::call_site(),
+        );
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("Missing content type for response type 'String'"));
+    }
+
+    #[test]
+    fn array_shorthand_expansion() {
+        assert_eq!(expand_array_shorthand("[User]"), "Json<Vec<User>>");
+        assert_eq!(
+            expand_array_shorthand(" [ models::User ] "),
+            "Json<Vec<models::User>>"
+        );
+        assert_eq!(expand_array_shorthand("Json<User>"), "Json<User>");
+        assert_eq!(expand_array_shorthand("[u8; 4]"), "[u8; 4]");
+        assert_eq!(expand_array_shorthand("[]"), "[]");
     }
 
     // Tests for parse_example_from_parts
 
     #[test]
     fn example_from_parts_valid() {
-        let result = parse_example_from_parts(200, "User::default()", Span::call_site());
+        let result = parse_example_from_parts(200, "User::default()", None, Span::call_site());
         assert!(result.is_ok());
         let info = result.unwrap();
         assert_eq!(info.status_code, 200);
@@ -336,7 +2223,7 @@ mod tests {
 
     #[test]
     fn example_from_parts_empty_code() {
-        let result = parse_example_from_parts(200, "", Span::call_site());
+        let result = parse_example_from_parts(200, "", None, Span::call_site());
         assert!(result.is_err());
         assert!(result
             .unwrap_err()
@@ -346,7 +2233,7 @@ mod tests {
 
     #[test]
     fn example_from_parts_whitespace_code() {
-        let result = parse_example_from_parts(200, "   ", Span::call_site());
+        let result = parse_example_from_parts(200, "   ", None, Span::call_site());
         assert!(result.is_err());
         assert!(result
             .unwrap_err()
@@ -356,7 +2243,7 @@ mod tests {
 
     #[test]
     fn example_from_parts_invalid_status() {
-        let result = parse_example_from_parts(999, "User::default()", Span::call_site());
+        let result = parse_example_from_parts(999, "User::default()", None, Span::call_site());
         assert!(result.is_err());
         assert!(result
             .unwrap_err()
@@ -366,7 +2253,7 @@ mod tests {
 
     #[test]
     fn example_from_parts_invalid_syntax() {
-        let result = parse_example_from_parts(200, "User{", Span::call_site());
+        let result = parse_example_from_parts(200, "User{", None, Span::call_site());
         assert!(result.is_err());
         assert!(result
             .unwrap_err()
@@ -379,6 +2266,7 @@ mod tests {
         let result = parse_example_from_parts(
             200,
             "User { id: 1, name: \"Test\".into() }",
+            None,
             Span::call_site(),
         );
         assert!(result.is_ok());
@@ -386,23 +2274,97 @@ mod tests {
 
     #[test]
     fn example_from_parts_escaped_quotes() {
-        let result =
-            parse_example_from_parts(200, "User { name: \\\"Test\\\".into() }", Span::call_site());
+        let result = parse_example_from_parts(
+            200,
+            "User { name: \\\"Test\\\".into() }",
+            None,
+            Span::call_site(),
+        );
         assert!(result.is_ok());
     }
 
     #[test]
     fn example_from_parts_vec_expression() {
-        let result = parse_example_from_parts(200, "vec![1, 2, 3]", Span::call_site());
+        let result = parse_example_from_parts(200, "vec![1, 2, 3]", None, Span::call_site());
         assert!(result.is_ok());
     }
 
     #[test]
     fn example_from_parts_method_chain() {
-        let result = parse_example_from_parts(200, "User::new().with_id(1)", Span::call_site());
+        let result =
+            parse_example_from_parts(200, "User::new().with_id(1)", None, Span::call_site());
         assert!(result.is_ok());
     }
 
+    // Tests for split_example_line
+    #[test]
+    fn split_example_line_unnamed() {
+        let (status, label, code) = split_example_line("200: User::default()", Span::call_site())
+            .unwrap()
+            .unwrap();
+        assert_eq!(status, 200);
+        assert!(label.is_none());
+        assert_eq!(code, "User::default()");
+    }
+
+    #[test]
+    fn split_example_line_named_with_summary() {
+        let (status, label, code) = split_example_line(
+            "200 \"empty\" (summary: \"No results: none\"): Vec::<User>::new()",
+            Span::call_site(),
+        )
+        .unwrap()
+        .unwrap();
+        assert_eq!(status, 200);
+        assert_eq!(
+            label,
+            Some(ExampleLabel {
+                name: "empty".to_string(),
+                summary: Some("No results: none".to_string()),
+            })
+        );
+        assert_eq!(code, "Vec::<User>::new()");
+    }
+
+    #[test]
+    fn split_example_line_named_without_summary() {
+        let (_, label, code) = split_example_line("404 \"missing\":", Span::call_site())
+            .unwrap()
+            .unwrap();
+        assert_eq!(label.unwrap().name, "missing");
+        assert!(code.is_empty());
+    }
+
+    #[test]
+    fn split_example_line_ignores_non_example_lines() {
+        assert!(split_example_line("Some prose: here", Span::call_site())
+            .unwrap()
+            .is_none());
+        assert!(split_example_line("200abc: x", Span::call_site())
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn split_example_line_rejects_malformed_label() {
+        for line in [
+            "200 \"empty: x",
+            "200 \"\": x",
+            "200 \"empty\" (title: \"x\"): x",
+            "200 \"empty\" (summary: \"x\": x",
+            "200 \"empty\" x",
+        ] {
+            let result = split_example_line(line, Span::call_site());
+            assert!(
+                result
+                    .unwrap_err()
+                    .to_string()
+                    .contains("Invalid example label"),
+                "{line}"
+            );
+        }
+    }
+
     // Additional edge case tests for simple annotations
 
     #[test]
@@ -416,7 +2378,7 @@ mod tests {
     fn security_with_extra_spaces() {
         let result = parse_security("@security   bearer", Span::call_site());
         assert!(result.is_ok());
-        assert_eq!(result.unwrap(), "bearer");
+        assert_eq!(result.unwrap().0, "bearer");
     }
 
     #[test]
@@ -27,6 +27,13 @@ impl ParseError {
     }
 }
 
+/// A non-fatal problem, emitted as a compiler warning
+#[derive(Debug, Clone)]
+pub struct ParseWarning {
+    pub message: String,
+    pub span: Span,
+}
+
 impl fmt::Display for ParseError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}", self.message)
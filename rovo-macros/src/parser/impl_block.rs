@@ -0,0 +1,296 @@
+use proc_macro2::{Delimiter, Group, TokenStream, TokenTree};
+use quote::quote;
+
+use super::{module, parse_rovo_method, DocInfo, FuncItem, ParseError};
+
+/// An inherent impl block annotated with `#[rovo]`
+pub struct ImplBlock {
+    /// The impl block, with the `#[rovo]` attributes removed from its methods
+    pub tokens: TokenStream,
+    /// The methods that were annotated with `#[rovo]`
+    pub handlers: Vec<(FuncItem, DocInfo)>,
+}
+
+/// Whether `#[rovo]` was applied to an impl block rather than a function
+pub fn is_impl_block(input: &TokenStream) -> bool {
+    input
+        .clone()
+        .into_iter()
+        .find_map(|token| match token {
+            TokenTree::Ident(ident) if ident == "impl" => Some(true),
+            TokenTree::Ident(ident) if ident == "fn" => Some(false),
+            _ => None,
+        })
+        .unwrap_or(false)
+}
+
+/// Parse an impl block annotated with `#[rovo]`
+///
+/// Each method inside marked with `#[rovo]` or `#[rovo(...)]` is parsed as a
+/// handler. Options given to the impl block's own attribute apply to every one
+/// of them.
+pub fn parse_rovo_impl(attr: &TokenStream, input: TokenStream) -> Result<ImplBlock, ParseError> {
+    let mut tokens: Vec<TokenTree> = input.into_iter().collect();
+
+    let impl_pos = tokens
+        .iter()
+        .position(|token| matches!(token, TokenTree::Ident(ident) if ident == "impl"))
+        .ok_or_else(|| {
+            ParseError::new("#[rovo] can only be applied to functions and impl blocks")
+        })?;
+    let body_pos = tokens
+        .iter()
+        .position(|token| matches!(token, TokenTree::Group(g) if g.delimiter() == Delimiter::Brace))
+        .ok_or_else(|| ParseError::new("Expected an impl block body"))?;
+
+    // The generated handler types live outside the impl block, so they can't
+    // refer to its generics or trait
+    let header = &tokens[impl_pos + 1..body_pos];
+    let unsupported = match header.first() {
+        Some(TokenTree::Punct(p)) if p.as_char() == '<' => Some("generic impl blocks"),
+        _ if header.iter().any(
+            |t| matches!(t, TokenTree::Ident(ident) if ident == "for" || ident == "where"),
+        ) =>
+        {
+            Some("trait impls and where clauses")
+        }
+        _ => None,
+    };
+    if let Some(unsupported) = unsupported {
+        return Err(ParseError::with_span(
+            format!(
+                "#[rovo] doesn't support {unsupported}\n\
+                 help: put the handlers in an inherent impl like `impl MyHandlers {{ ... }}`"
+            ),
+            tokens[impl_pos].span(),
+        ));
+    }
+    let self_ty: TokenStream = header.iter().cloned().collect();
+
+    let TokenTree::Group(body) = &tokens[body_pos] else {
+        unreachable!("found above");
+    };
+    let mut new_body = Vec::new();
+    let mut handlers = Vec::new();
+    for item in split_items(body.stream()) {
+        let Some((args, method)) = strip_rovo_attribute(&item)? else {
+            new_body.extend(item);
+            continue;
+        };
+
+        let args = match (args.is_empty(), attr.is_empty()) {
+            (_, true) => args,
+            (true, false) => attr.clone(),
+            (false, false) => quote! { #args, #attr },
+        };
+        handlers.push(parse_rovo_method(args, method.clone(), &self_ty)?);
+        new_body.extend(method);
+    }
+
+    let mut new_group = Group::new(Delimiter::Brace, new_body.into_iter().collect());
+    new_group.set_span(tokens[body_pos].span());
+    tokens[body_pos] = TokenTree::Group(new_group);
+
+    Ok(ImplBlock {
+        tokens: tokens.into_iter().collect(),
+        handlers,
+    })
+}
+
+/// Split an impl block's body into its items
+///
+/// An item ends after its body (`{ ... }`) or a `;`, whichever comes first.
+fn split_items(body: TokenStream) -> Vec<Vec<TokenTree>> {
+    let mut items = Vec::new();
+    let mut current = Vec::new();
+    for token in body {
+        let ends_item = match &token {
+            TokenTree::Group(group) => group.delimiter() == Delimiter::Brace,
+            TokenTree::Punct(p) => p.as_char() == ';',
+            _ => false,
+        };
+        current.push(token);
+        if ends_item {
+            items.push(std::mem::take(&mut current));
+        }
+    }
+    if !current.is_empty() {
+        items.push(current);
+    }
+    items
+}
+
+/// Remove the `#[rovo]` attribute from an item, returning its options and the
+/// rest of the item, or `None` if the item isn't marked
+fn strip_rovo_attribute(
+    item: &[TokenTree],
+) -> Result<Option<(TokenStream, TokenStream)>, ParseError> {
+    let mut args = None;
+    let mut rest = Vec::new();
+    let mut idx = 0;
+    while idx < item.len() {
+        if let [TokenTree::Punct(p), TokenTree::Group(group), ..] = &item[idx..] {
+            if p.as_char() == '#'
+                && group.delimiter() == Delimiter::Bracket
+                && module::is_rovo_attribute(&group.stream())
+            {
+                if args.is_some() {
+                    return Err(ParseError::with_span(
+                        "Duplicate #[rovo] attribute\n\
+                         help: combine the options into a single #[rovo(...)]",
+                        group.span(),
+                    ));
+                }
+                args = Some(match group.stream().into_iter().last() {
+                    Some(TokenTree::Group(options))
+                        if options.delimiter() == Delimiter::Parenthesis =>
+                    {
+                        options.stream()
+                    }
+                    _ => TokenStream::new(),
+                });
+                idx += 2;
+                continue;
+            }
+        }
+        rest.push(item[idx].clone());
+        idx += 1;
+    }
+
+    Ok(args.map(|args| (args, rest.into_iter().collect())))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(input: &str) -> Result<ImplBlock, ParseError> {
+        parse_rovo_impl(&TokenStream::new(), input.parse().unwrap())
+    }
+
+    #[test]
+    fn detects_impl_blocks() {
+        assert!(is_impl_block(&"impl Handlers {}".parse().unwrap()));
+        assert!(!is_impl_block(
+            &"/// impl\nasync fn handler() {}".parse().unwrap()
+        ));
+    }
+
+    #[test]
+    fn parses_marked_methods() {
+        let block = parse(
+            r"
+            impl UserHandlers {
+                /// List users.
+                ///
+                /// # Responses
+                ///
+                /// 200: Json<Vec<User>> - All users
+                #[rovo(code_samples)]
+                async fn list_users(&self, Query(page): Query<Page>) -> Json<Vec<User>> { }
+
+                /// Get a user.
+                #[rovo]
+                async fn get_user(State(app): State<AppState>, Path(id): Path<u64>) -> Json<Self> { }
+
+                fn helper(&self) -> u64 { 0 }
+
+                const LIMIT: usize = 10;
+            }
+            ",
+        )
+        .unwrap();
+
+        assert!(!block.tokens.to_string().contains("rovo"));
+        assert_eq!(block.handlers.len(), 2);
+
+        let (list_users, doc_info) = &block.handlers[0];
+        assert_eq!(list_users.name, "list_users");
+        assert!(doc_info.code_samples);
+        assert_eq!(
+            list_users.state_type.as_ref().unwrap().to_string(),
+            "UserHandlers"
+        );
+        let method = list_users.method.as_ref().unwrap();
+        assert!(method.has_receiver && method.is_async);
+        let param_types: Vec<String> = method.param_types.iter().map(ToString::to_string).collect();
+        assert_eq!(param_types, vec!["Query < Page >"]);
+
+        let (get_user, _) = &block.handlers[1];
+        assert_eq!(
+            get_user.state_type.as_ref().unwrap().to_string(),
+            "AppState"
+        );
+        assert!(!get_user.method.as_ref().unwrap().has_receiver);
+        assert_eq!(
+            get_user.return_type.as_ref().unwrap().to_string(),
+            "Json < UserHandlers >"
+        );
+    }
+
+    #[test]
+    fn impl_options_apply_to_every_method() {
+        let block = parse_rovo_impl(
+            &"code_samples".parse().unwrap(),
+            r"
+            impl Handlers {
+                #[rovo]
+                async fn a() {}
+
+                /// # Responses
+                ///
+                /// 200: Json<String> - The value
+                #[rovo(strict_responses)]
+                async fn b() -> Json<String> {}
+            }
+            "
+            .parse()
+            .unwrap(),
+        )
+        .unwrap();
+
+        assert!(block
+            .handlers
+            .iter()
+            .all(|(_, doc_info)| doc_info.code_samples));
+        assert!(block.handlers[1].1.strict_responses);
+    }
+
+    #[test]
+    fn rejects_state_with_receiver() {
+        let err =
+            parse("impl Handlers { #[rovo] async fn a(&self, State(s): State<AppState>) {} }")
+                .err()
+                .unwrap();
+        assert!(err
+            .to_string()
+            .contains("A method taking `self` can't also take `State<T>`"));
+    }
+
+    #[test]
+    fn rejects_trait_and_generic_impls() {
+        for input in [
+            "impl Handler for UserHandlers { #[rovo] async fn a() {} }",
+            "impl<T> Handlers<T> { #[rovo] async fn a() {} }",
+        ] {
+            let err = parse(input).err().unwrap();
+            assert!(
+                err.to_string().contains("#[rovo] doesn't support"),
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    fn method_without_impl_attribute_is_an_error() {
+        let err = crate::parser::parse_rovo_function(
+            TokenStream::new(),
+            "async fn list(&self) {}".parse().unwrap(),
+        )
+        .err()
+        .unwrap();
+        assert!(err
+            .to_string()
+            .contains("#[rovo] on a method needs #[rovo] on its impl block too"));
+    }
+}
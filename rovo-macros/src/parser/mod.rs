@@ -1,27 +1,58 @@
 mod annotations;
 mod error;
+mod impl_block;
+mod module;
 mod tokens;
 pub mod types;
 
-pub use error::ParseError;
-pub use types::{DocInfo, FuncItem, PathParamDoc, PathParamInfo};
+pub use error::{ParseError, ParseWarning};
+pub use impl_block::{is_impl_block, parse_rovo_impl};
+pub use module::parse_rovo_module;
+pub use types::{
+    DocInfo, ExampleInfo, ExampleLabel, FuncItem, HeaderDoc, LinkDoc, MethodInfo, ParamDoc,
+    ParamLocation, PathParamDoc, PathParamInfo, StatusKey,
+};
 
 use proc_macro2::{Span, TokenStream, TokenTree};
-use types::DocLine;
+use types::{DocLine, ResponseInfo};
 
-use crate::utils::find_closest_annotation;
+use crate::utils::{find_closest_annotation, ANNOTATIONS};
 
 /// Special depth value indicating code block mode for multi-line examples
 const CODE_BLOCK_MODE: usize = usize::MAX - 1;
 
 /// Parse a function annotated with #[rovo]
-pub fn parse_rovo_function(input: TokenStream) -> Result<(FuncItem, DocInfo), ParseError> {
+pub fn parse_rovo_function(
+    attr: TokenStream,
+    input: TokenStream,
+) -> Result<(FuncItem, DocInfo), ParseError> {
+    parse_handler(attr, input, None)
+}
+
+/// Parse a method annotated with `#[rovo]` in an impl block for `self_ty`
+///
+/// A method taking `self` gets it from the router state, so its state type is
+/// `self_ty`; otherwise the state comes from a `State<T>` parameter as usual.
+pub fn parse_rovo_method(
+    attr: TokenStream,
+    input: TokenStream,
+    self_ty: &TokenStream,
+) -> Result<(FuncItem, DocInfo), ParseError> {
+    parse_handler(attr, input, Some(self_ty))
+}
+
+fn parse_handler(
+    attr: TokenStream,
+    input: TokenStream,
+    self_ty: Option<&TokenStream>,
+) -> Result<(FuncItem, DocInfo), ParseError> {
     let tokens: Vec<TokenTree> = input.clone().into_iter().collect();
 
     // Extract doc comments, attributes, and function name
     let mut doc_lines = Vec::new();
     let mut func_name = None;
     let mut is_deprecated = false;
+    let mut deprecation_note = None;
     let mut i = 0;
 
     while i < tokens.len() {
@@ -33,15 +64,17 @@ pub fn parse_rovo_function(input: TokenStream) -> Result<(FuncItem, DocInfo), Pa
                         let attr_content = group.stream().to_string();
                         if attr_content.starts_with("doc") {
                             // Extract the doc comment text and preserve the span
+                            // A single attribute may hold several lines (`#[doc = "a\nb"]`)
                             let doc_text = tokens::extract_doc_text(&attr_content);
                             let span = group.span();
-                            doc_lines.push(DocLine {
-                                text: doc_text,
+                            doc_lines.extend(doc_text.split('\n').map(|line| DocLine {
+                                text: line.to_string(),
                                 span,
-                            });
+                            }));
                         } else if attr_content.starts_with("deprecated") {
-                            // Mark as deprecated
+                            // Mark as deprecated, keeping any note/since as the reason
                             is_deprecated = true;
+                            deprecation_note = tokens::extract_deprecation_note(group.stream());
                         }
                     }
                 }
@@ -63,60 +96,283 @@ pub fn parse_rovo_function(input: TokenStream) -> Result<(FuncItem, DocInfo), Pa
     let func_name = func_name.ok_or_else(|| ParseError::new("Could not find function name"))?;
 
     // Extract state type from function parameters
-    let state_type = tokens::extract_state_type(&input);
+    let mut state_type = tokens::extract_state_type(&input);
 
     // Extract path parameter info from function signature
     let path_params = tokens::extract_path_info(&input);
 
+    // Extract the declared return type (used by strict_responses)
+    let mut return_type = tokens::extract_return_type(&input);
+
+    let params = tokens::split_params(&input);
+    let receiver = params.first().filter(|param| tokens::is_receiver(param));
+    let method = if let Some(self_ty) = self_ty {
+        if receiver.is_some() {
+            if state_type.is_some() {
+                return Err(ParseError::with_span(
+                    "A method taking `self` can't also take `State<T>`\n\
+                         help: the router state is passed as `self`; remove the State parameter",
+                    func_name.span(),
+                ));
+            }
+            state_type = Some(quote::quote! { Self });
+        }
+        // The generated items live outside the impl block, where `Self` means nothing
+        state_type = state_type.map(|ty| tokens::replace_self(ty, self_ty));
+        return_type = return_type.map(|ty| tokens::replace_self(ty, self_ty));
+
+        let param_types = params
+            .iter()
+            .skip(usize::from(receiver.is_some()))
+            .map(|param| {
+                tokens::param_type(param)
+                    .map(|ty| tokens::replace_self(ty, self_ty))
+                    .ok_or_else(|| {
+                        ParseError::with_span(
+                            "Expected a `pattern: Type` parameter",
+                            func_name.span(),
+                        )
+                    })
+            })
+            .collect::<Result<_, _>>()?;
+        let is_async = tokens
+            .iter()
+            .take_while(|t| !matches!(t, TokenTree::Ident(ident) if ident == "fn"))
+            .any(|t| matches!(t, TokenTree::Ident(ident) if ident == "async"));
+
+        Some(MethodInfo {
+            self_ty: self_ty.clone(),
+            has_receiver: receiver.is_some(),
+            is_async,
+            param_types,
+        })
+    } else if let Some(receiver) = receiver {
+        return Err(ParseError::with_span(
+            "#[rovo] on a method needs #[rovo] on its impl block too\n\
+             help: add #[rovo] above `impl`",
+            receiver[0].span(),
+        ));
+    } else {
+        None
+    };
+
     // Parse doc comments
     let mut doc_info = parse_doc_comments(&doc_lines)?;
 
-    // Set deprecated flag from Rust attribute
-    doc_info.deprecated = is_deprecated;
+    // Set deprecated flag from Rust attribute (its reason wins over `@deprecated`'s)
+    doc_info.deprecated |= is_deprecated;
+    if deprecation_note.is_some() {
+        doc_info.deprecation_note = deprecation_note;
+    }
+    check_deprecated_message(&mut doc_info);
+
+    // Apply options from the #[rovo(...)] attribute itself
+    parse_rovo_options(attr, &mut doc_info)?;
+    if doc_info.strict_responses {
+        validate_strict_responses(&doc_info, return_type.as_ref())?;
+    }
 
     // Validate that documented path parameters match function signature bindings
-    if !doc_info.path_params.is_empty() {
-        if let Some(ref sig_params) = path_params {
-            // Only validate for primitive types (not struct patterns)
-            if !sig_params.is_struct_pattern {
-                for doc_param in &doc_info.path_params {
-                    if !sig_params.bindings.contains(&doc_param.name) {
-                        let bindings_list = sig_params.bindings.join(", ");
+    validate_path_param_docs(&mut doc_info, path_params.as_ref())?;
+
+    resolve_param_locations(&mut doc_info, path_params.as_ref(), &input)?;
+
+    let func_item = FuncItem {
+        name: func_name,
+        tokens: input,
+        state_type,
+        path_params,
+        return_type,
+        method,
+    };
+
+    Ok((func_item, doc_info))
+}
+
+/// Parse the options passed to `#[rovo(...)]` into `doc_info`
+fn parse_rovo_options(attr: TokenStream, doc_info: &mut DocInfo) -> Result<(), ParseError> {
+    let mut tokens = attr.into_iter();
+    while let Some(token) = tokens.next() {
+        match &token {
+            TokenTree::Ident(ident) if ident == "strict_responses" => {
+                doc_info.strict_responses = true;
+            }
+            TokenTree::Ident(ident) if ident == "code_samples" => doc_info.code_samples = true,
+            // Added by `#[rovo_module]` for each of the module's `@tag-description`s
+            TokenTree::Ident(ident) if ident == "__tag_description" => {
+                let args = match tokens.next() {
+                    Some(TokenTree::Group(group)) => group.stream(),
+                    _ => {
                         return Err(ParseError::with_span(
-                            format!(
-                                "Documented path parameter '{}' does not match any parameter in function signature\n\
-                                 help: found parameters: {}\n\
-                                 note: parameter names in # Path Parameters must match the binding names in Path(...)",
-                                doc_param.name,
-                                bindings_list
-                            ),
-                            doc_param.span,
-                        ));
+                            "Expected __tag_description(..)",
+                            token.span(),
+                        ))
                     }
-                }
+                };
+                let (tag, description) =
+                    module::parse_tag_description_args(args).ok_or_else(|| {
+                        ParseError::with_span("Malformed __tag_description option", token.span())
+                    })?;
+                doc_info.tag_descriptions.push((tag, description));
+            }
+            TokenTree::Punct(p) if p.as_char() == ',' => {}
+            _ => {
+                return Err(ParseError::with_span(
+                    format!(
+                        "Unknown #[rovo] option '{token}'\n\
+                         note: valid options are: strict_responses, code_samples"
+                    ),
+                    token.span(),
+                ));
             }
+        }
+    }
+
+    Ok(())
+}
+
+/// Use `@deprecated-message` as the deprecation note, warning if nothing marks the operation deprecated
+fn check_deprecated_message(doc_info: &mut DocInfo) {
+    let Some((message, span)) = &doc_info.deprecated_message else {
+        return;
+    };
+
+    if doc_info.deprecated {
+        if doc_info.deprecation_note.is_none() {
+            doc_info.deprecation_note = Some(format!("Deprecated: {message}"));
+        }
+    } else if !doc_info.hidden {
+        doc_info.warnings.push(ParseWarning {
+            message: "@deprecated-message is set but the operation is not deprecated\n\
+                      help: mark the function #[deprecated] (or add @deprecated) so clients see it"
+                .to_string(),
+            span: *span,
+        });
+    }
+}
+
+/// Check documented path parameters against the `Path(...)` bindings
+///
+/// Mismatched `name: description` entries are errors; mismatched `@path` entries
+/// become warnings so they don't break the build.
+fn validate_path_param_docs(
+    doc_info: &mut DocInfo,
+    path_params: Option<&PathParamInfo>,
+) -> Result<(), ParseError> {
+    // Struct patterns are validated by aide via JsonSchema
+    if path_params.is_some_and(|p| p.is_struct_pattern) {
+        return Ok(());
+    }
+
+    for doc_param in &doc_info.path_params {
+        let message = match path_params {
+            Some(sig_params) if sig_params.bindings.contains(&doc_param.name) => continue,
+            Some(sig_params) => format!(
+                "Documented path parameter '{}' does not match any parameter in function signature\n\
+                 help: found parameters: {}\n\
+                 note: parameter names in # Path Parameters must match the binding names in Path(...)",
+                doc_param.name,
+                sig_params.bindings.join(", ")
+            ),
+            None => format!(
+                "Documented path parameter '{}' but function has no Path<T> extractor\n\
+                 help: add a Path<T> parameter to your function signature",
+                doc_param.name
+            ),
+        };
+
+        if !doc_param.warn_on_mismatch {
+            return Err(ParseError::with_span(message, doc_param.span));
+        }
+        doc_info.warnings.push(ParseWarning {
+            message,
+            span: doc_param.span,
+        });
+    }
+
+    Ok(())
+}
+
+/// Match each `@param` to the `Path` binding or `Query` extractor it documents
+fn resolve_param_locations(
+    doc_info: &mut DocInfo,
+    path_params: Option<&PathParamInfo>,
+    input: &TokenStream,
+) -> Result<(), ParseError> {
+    let has_query = tokens::has_query_extractor(input);
+
+    for param in doc_info.params.iter_mut().filter(|p| p.location.is_none()) {
+        if path_params.is_some_and(|p| p.bindings.contains(&param.name)) {
+            param.location = Some(ParamLocation::Path);
+        } else if has_query {
+            param.location = Some(ParamLocation::Query);
         } else {
-            // Documented path params but no Path<T> in signature
-            let first_param = &doc_info.path_params[0];
+            let bindings = path_params.map_or_else(String::new, |p| p.bindings.join(", "));
             return Err(ParseError::with_span(
                 format!(
-                    "Documented path parameter '{}' but function has no Path<T> extractor\n\
-                     help: add a Path<T> parameter to your function signature",
-                    first_param.name
+                    "Documented parameter '{}' does not match any Path or Query extractor\n\
+                     help: path parameters found: {}\n\
+                     note: @param names must match a Path(...) binding, or the function must take a Query<T>",
+                    param.name,
+                    if bindings.is_empty() { "none" } else { &bindings }
                 ),
-                first_param.span,
+                param.span,
             ));
         }
     }
 
-    let func_item = FuncItem {
-        name: func_name,
-        tokens: input,
-        state_type,
-        path_params,
+    Ok(())
+}
+
+/// Whether `line` is the annotation `name`, followed by whitespace or nothing
+///
+/// Unlike `starts_with`, `@tag` doesn't match `@tag-description`.
+fn is_annotation(line: &str, name: &str) -> bool {
+    line.strip_prefix(name)
+        .is_some_and(|rest| rest.is_empty() || rest.starts_with(char::is_whitespace))
+}
+
+/// Strip a Markdown list bullet (`- ` or `* `) from the start of a section entry
+fn strip_list_bullet(line: &str) -> &str {
+    line.strip_prefix("- ")
+        .or_else(|| line.strip_prefix("* "))
+        .map_or(line, str::trim_start)
+}
+
+/// Position of the colon ending a response line's status, skipping the `::` in `StatusCode::NAME`
+fn response_status_end(line: &str) -> Option<usize> {
+    let skip = if line.starts_with("StatusCode::") {
+        "StatusCode::".len()
+    } else {
+        0
     };
+    line[skip..].find(':').map(|pos| pos + skip)
+}
 
-    Ok((func_item, doc_info))
+/// Check that `#[rovo(strict_responses)]` has something to link the return type to
+fn validate_strict_responses(
+    doc_info: &DocInfo,
+    return_type: Option<&TokenStream>,
+) -> Result<(), ParseError> {
+    if !doc_info.responses.iter().any(|r| r.status_code == 200) {
+        return Err(ParseError::new(
+            "#[rovo(strict_responses)] requires a documented 200 response\n\
+             help: add '200: <type> - <description>' to the # Responses section",
+        ));
+    }
+
+    if let Some(TokenTree::Ident(ident)) = return_type.and_then(|ty| ty.clone().into_iter().next())
+    {
+        if ident == "impl" {
+            return Err(ParseError::with_span(
+                "#[rovo(strict_responses)] can't check an `impl Trait` return type\n\
+                 help: return the documented 200 response type (e.g. `Json<User>`) instead",
+                ident.span(),
+            ));
+        }
+    }
+
+    Ok(())
 }
 
 /// Parse doc comments and extract documentation info
@@ -124,11 +380,16 @@ pub fn parse_rovo_function(input: TokenStream) -> Result<(FuncItem, DocInfo), Pa
 fn parse_doc_comments(lines: &[DocLine]) -> Result<DocInfo, ParseError> {
     let mut doc_info = DocInfo::default();
     let mut description_lines = Vec::new();
-    let mut in_description = false;
     let mut title_set = false;
+    let mut explicit_summary: Option<String> = None;
     let mut current_section: Option<&str> = None;
-    let mut pending_response: Option<(u16, String, String, Span)> = None; // (status, type, desc, span)
-    let mut pending_example: Option<(u16, String, Span, usize)> = None; // (status, code, span, depth)
+    let mut pending_response: Option<(StatusKey, String, String, Span)> = None; // (status, type, desc, span)
+    let mut error_sets: Vec<&[(u16, &str)]> = Vec::new();
+    let mut paginated_span = None;
+    let mut cacheable: Option<(String, Span)> = None;
+    let mut content_types: Vec<(u16, String, Span)> = Vec::new();
+    let mut max_version_span = None;
+    let mut pending_example: Option<(u16, String, Span, usize, Option<ExampleLabel>)> = None; // (status, code, span, depth, label)
 
     for doc_line in lines {
         let trimmed = doc_line.text.trim();
@@ -139,6 +400,19 @@ fn parse_doc_comments(lines: &[DocLine]) -> Result<DocInfo, ParseError> {
             break;
         }
 
+        // @summary may appear anywhere in the doc block
+        if trimmed.starts_with("@summary") {
+            if explicit_summary.is_some() {
+                return Err(ParseError::with_span(
+                    "Duplicate @summary annotation\n\
+                     help: an operation has a single summary; remove the extra @summary line",
+                    span,
+                ));
+            }
+            explicit_summary = Some(annotations::parse_summary(trimmed, span)?);
+            continue;
+        }
+
         // Check if we're starting a markdown section
         if trimmed.starts_with("# ") {
             // Finalize any pending multi-line content
@@ -147,8 +421,8 @@ fn parse_doc_comments(lines: &[DocLine]) -> Result<DocInfo, ParseError> {
                     annotations::parse_response_from_parts(&type_str, status, &desc, sp)?;
                 doc_info.responses.push(response_info);
             }
-            if let Some((status, code, sp, _)) = pending_example.take() {
-                let example_info = annotations::parse_example_from_parts(status, &code, sp)?;
+            if let Some((status, code, sp, _, label)) = pending_example.take() {
+                let example_info = annotations::parse_example_from_parts(status, &code, label, sp)?;
                 doc_info.examples.push(example_info);
             }
 
@@ -166,9 +440,22 @@ fn parse_doc_comments(lines: &[DocLine]) -> Result<DocInfo, ParseError> {
         match current_section {
             Some("responses") if !trimmed.is_empty() => {
                 // Check if this line starts a new response or continues the previous one
-                if let Some(colon_pos) = trimmed.find(':') {
-                    let before_colon = &trimmed[..colon_pos];
-                    if before_colon.chars().all(|c| c.is_ascii_digit()) {
+                if trimmed.starts_with("@header") {
+                    if let Some((status, type_str, desc, sp)) = pending_response.take() {
+                        let response_info =
+                            annotations::parse_response_from_parts(&type_str, status, &desc, sp)?;
+                        doc_info.responses.push(response_info);
+                    }
+                    doc_info
+                        .headers
+                        .push(annotations::parse_header(trimmed, span)?);
+                } else if let Some(colon_pos) = response_status_end(strip_list_bullet(trimmed)) {
+                    let entry = strip_list_bullet(trimmed);
+                    let before_colon = &entry[..colon_pos];
+                    if before_colon.chars().all(|c| c.is_ascii_digit())
+                        || before_colon.starts_with("StatusCode::")
+                        || StatusKey::parse(before_colon).is_some()
+                    {
                         // This is a new response line
                         // First, finalize any pending response
                         if let Some((status, type_str, desc, sp)) = pending_response.take() {
@@ -179,14 +466,34 @@ fn parse_doc_comments(lines: &[DocLine]) -> Result<DocInfo, ParseError> {
                         }
 
                         // Parse the new response line
-                        let status_code = before_colon.parse::<u16>().map_err(|_| {
-                            ParseError::with_span(
-                                format!("Invalid status code '{before_colon}'"),
+                        let status_code = match before_colon.strip_prefix("StatusCode::") {
+                            Some(name) => StatusKey::Exact(annotations::parse_status_code_name(
+                                name.trim(),
+                                span,
+                            )?),
+                            None => StatusKey::parse(before_colon).ok_or_else(|| {
+                                ParseError::with_span(
+                                    format!("Invalid status code '{before_colon}'"),
+                                    span,
+                                )
+                            })?,
+                        };
+                        if doc_info
+                            .responses
+                            .iter()
+                            .any(|r| r.status_code == status_code)
+                        {
+                            return Err(ParseError::with_span(
+                                format!(
+                                    "Duplicate response for status code {status_code}\n\
+                                     help: each status code can only be documented once; \
+                                     merge the descriptions or change the status code"
+                                ),
                                 span,
-                            )
-                        })?;
+                            ));
+                        }
 
-                        let after_colon = trimmed[colon_pos + 1..].trim();
+                        let after_colon = entry[colon_pos + 1..].trim();
                         if let Some(dash_pos) = after_colon.find(" - ") {
                             let type_str = after_colon[..dash_pos].trim().to_string();
                             let description = after_colon[dash_pos + 3..].trim().to_string();
@@ -210,14 +517,19 @@ fn parse_doc_comments(lines: &[DocLine]) -> Result<DocInfo, ParseError> {
             }
             Some("examples") if !trimmed.is_empty() => {
                 // Check if we have a pending example that needs more lines
-                if let Some((status, ref mut code, sp, ref mut depth)) = pending_example {
+                if let Some((status, ref mut code, sp, ref mut depth, ref label)) = pending_example
+                {
                     if *depth == CODE_BLOCK_MODE {
                         // In code block mode - looking for closing backticks
                         if trimmed == "```" && !code.is_empty() {
                             // Found closing backticks, finalize the example
                             let final_code = code.clone();
-                            let example_info =
-                                annotations::parse_example_from_parts(status, &final_code, sp)?;
+                            let example_info = annotations::parse_example_from_parts(
+                                status,
+                                &final_code,
+                                label.clone(),
+                                sp,
+                            )?;
                             doc_info.examples.push(example_info);
                             pending_example = None;
                         } else if code.is_empty()
@@ -258,93 +570,192 @@ fn parse_doc_comments(lines: &[DocLine]) -> Result<DocInfo, ParseError> {
                             // If depth is 0 and we have meaningful content, finalize it
                             if *depth == 0 && !code.trim().is_empty() {
                                 let final_code = code.clone();
-                                let example_info =
-                                    annotations::parse_example_from_parts(status, &final_code, sp)?;
+                                let example_info = annotations::parse_example_from_parts(
+                                    status,
+                                    &final_code,
+                                    label.clone(),
+                                    sp,
+                                )?;
                                 doc_info.examples.push(example_info);
                                 pending_example = None;
                             }
                         }
                     }
-                } else if let Some(colon_pos) = trimmed.find(':') {
-                    let before_colon = &trimmed[..colon_pos];
-                    if before_colon.chars().all(|c| c.is_ascii_digit()) {
-                        // This is a new example line
-                        let status_code = before_colon.parse::<u16>().map_err(|_| {
-                            ParseError::with_span(
-                                format!("Invalid status code '{before_colon}'"),
-                                span,
-                            )
-                        })?;
-
-                        let code = trimmed[colon_pos + 1..].trim().to_string();
-
-                        // Check if code starts with triple backticks (code block on same line)
-                        if code == "```" || code == "```rust" || code == "```rs" {
-                            // Start code block mode
-                            pending_example =
-                                Some((status_code, String::new(), span, CODE_BLOCK_MODE));
-                        } else if code.is_empty() {
-                            // Store pending example with empty code, depth 0 (will accumulate on next lines)
-                            pending_example = Some((status_code, String::new(), span, 0));
-                        } else {
-                            // Calculate bracket/brace depth
-                            let mut depth: usize = 0;
-                            for ch in code.chars() {
-                                match ch {
-                                    '{' | '[' | '(' => depth += 1,
-                                    '}' | ']' | ')' => depth = depth.saturating_sub(1),
-                                    _ => {}
-                                }
+                } else if let Some((status_code, label, code)) =
+                    annotations::split_example_line(strip_list_bullet(trimmed), span)?
+                {
+                    // This is a new example line
+                    let code = code.to_string();
+                    // Check if code starts with triple backticks (code block on same line)
+                    if code == "```" || code == "```rust" || code == "```rs" {
+                        // Start code block mode
+                        pending_example =
+                            Some((status_code, String::new(), span, CODE_BLOCK_MODE, label));
+                    } else if code.is_empty() {
+                        // Store pending example with empty code, depth 0 (will accumulate on next lines)
+                        pending_example = Some((status_code, String::new(), span, 0, label));
+                    } else {
+                        // Calculate bracket/brace depth
+                        let mut depth: usize = 0;
+                        for ch in code.chars() {
+                            match ch {
+                                '{' | '[' | '(' => depth += 1,
+                                '}' | ']' | ')' => depth = depth.saturating_sub(1),
+                                _ => {}
                             }
+                        }
 
-                            if depth == 0 {
-                                // Single-line example, process immediately
-                                let example_info = annotations::parse_example_from_parts(
-                                    status_code,
-                                    &code,
-                                    span,
-                                )?;
-                                doc_info.examples.push(example_info);
-                            } else {
-                                // Multi-line example, store for continuation
-                                pending_example = Some((status_code, code, span, depth));
-                            }
+                        if depth == 0 {
+                            // Single-line example, process immediately
+                            let example_info = annotations::parse_example_from_parts(
+                                status_code,
+                                &code,
+                                label,
+                                span,
+                            )?;
+                            doc_info.examples.push(example_info);
+                        } else {
+                            // Multi-line example, store for continuation
+                            pending_example = Some((status_code, code, span, depth, label));
                         }
                     }
                 }
             }
             Some("metadata") if !trimmed.is_empty() => {
                 // Parse annotations in metadata section
-                if trimmed.starts_with("@tag") {
+                if is_annotation(trimmed, "@tag-description") {
+                    return Err(ParseError::with_span(
+                        "@tag-description can't be used on a handler\n\
+                         help: describe the tag in a #[rovo_module] doc comment, e.g. '//! @tag-description users Endpoints for managing users'\n\
+                         note: tag the handler itself with '@tag <tag_name>'",
+                        span,
+                    ));
+                } else if is_annotation(trimmed, "@tag") {
                     let tag = annotations::parse_tag(trimmed, span)?;
-                    doc_info.tags.push(tag);
+                    // A repeated tag would list the operation under it twice; the
+                    // LSP warns about the extra line
+                    if !doc_info.tags.contains(&tag) {
+                        doc_info.tags.push(tag);
+                    }
+                } else if trimmed.starts_with("@security-and") {
+                    let schemes = annotations::parse_security_and(trimmed, span)?;
+                    doc_info.security_requirements.push(
+                        schemes
+                            .into_iter()
+                            .map(|scheme| (scheme, Vec::new()))
+                            .collect(),
+                    );
                 } else if trimmed.starts_with("@security") {
-                    let scheme = annotations::parse_security(trimmed, span)?;
-                    doc_info.security_requirements.push(scheme);
-                } else if trimmed.starts_with("@id") {
+                    let requirement = annotations::parse_security(trimmed, span)?;
+                    doc_info.security_requirements.push(vec![requirement]);
+                } else if trimmed == "@idempotent" {
+                    doc_info.idempotent = true;
+                } else if is_annotation(trimmed, "@id") {
                     let id = annotations::parse_id(trimmed, span)?;
                     doc_info.operation_id = Some(id);
                 } else if trimmed == "@hidden" {
                     doc_info.hidden = true;
+                } else if trimmed == "@i18n" {
+                    doc_info.i18n = true;
+                } else if trimmed == "@concurrency" {
+                    doc_info.concurrency = true;
+                } else if trimmed.starts_with("@cfg") {
+                    let feature = annotations::parse_cfg(trimmed, span)?;
+                    doc_info.cfg_feature = Some(feature);
+                } else if trimmed.starts_with("@min-version") {
+                    doc_info.min_version = Some(annotations::parse_min_version(trimmed, span)?);
+                } else if trimmed.starts_with("@max-version") {
+                    doc_info.max_version = Some(annotations::parse_max_version(trimmed, span)?);
+                    max_version_span = Some(span);
+                } else if trimmed.starts_with("@ratelimit") {
+                    doc_info.rate_limit = Some(annotations::parse_ratelimit(trimmed, span)?);
+                } else if trimmed.starts_with("@sunset") {
+                    let date = annotations::parse_sunset(trimmed, span)?;
+                    doc_info.sunset = Some(date);
+                } else if trimmed.starts_with("@server") {
+                    doc_info
+                        .servers
+                        .push(annotations::parse_server(trimmed, span)?);
+                } else if trimmed.starts_with("@external-docs") {
+                    // A single object, so the last occurrence wins
+                    doc_info.external_docs = Some(annotations::parse_external_docs(trimmed, span)?);
+                } else if trimmed.starts_with("@async-job") {
+                    let callback_id = annotations::parse_async_job(trimmed, span)?;
+                    doc_info.async_job_callback = Some(callback_id);
+                } else if trimmed.starts_with("@param") {
+                    let param = annotations::parse_param(trimmed, span)?;
+                    doc_info.params.push(param);
+                } else if trimmed.starts_with("@query") {
+                    let param = annotations::parse_query(trimmed, span)?;
+                    doc_info.params.push(param);
+                } else if trimmed.starts_with("@body") {
+                    if doc_info.body.is_some() {
+                        return Err(ParseError::with_span(
+                            "Duplicate @body annotation\n\
+                             help: an operation has a single request body; remove the extra @body line",
+                            span,
+                        ));
+                    }
+                    doc_info.body = Some(annotations::parse_body(trimmed, span)?);
+                } else if trimmed.starts_with("@content-type") {
+                    let (status_code, content_type) =
+                        annotations::parse_content_type(trimmed, span)?;
+                    content_types.push((status_code, content_type, span));
+                } else if trimmed.starts_with("@produces") {
+                    let content_type = annotations::parse_produces(trimmed, span)?;
+                    content_types.push((200, content_type, span));
+                } else if trimmed.starts_with("@deprecated-message") {
+                    let message = annotations::parse_deprecated_message(trimmed, span)?;
+                    doc_info.deprecated_message = Some((message, span));
+                } else if trimmed == "@deprecated" || trimmed.starts_with("@deprecated ") {
+                    doc_info.deprecated = true;
+                    doc_info.deprecation_note = annotations::parse_deprecated(trimmed);
+                } else if trimmed.starts_with("@header") {
+                    doc_info
+                        .headers
+                        .push(annotations::parse_header(trimmed, span)?);
+                } else if trimmed.starts_with("@link") {
+                    let link = annotations::parse_link(trimmed, span)?;
+                    // Links are keyed by operation ID, so a second one would replace the first
+                    if doc_info.links.iter().any(|existing| {
+                        existing.status_code == link.status_code
+                            && existing.operation_id == link.operation_id
+                    }) {
+                        return Err(ParseError::with_span(
+                            format!(
+                                "Duplicate @link to '{}' for status {}\n\
+                                 help: a response has one link per operation; put every parameter on a single @link line",
+                                link.operation_id, link.status_code
+                            ),
+                            span,
+                        ));
+                    }
+                    doc_info.links.push(link);
+                } else if trimmed.starts_with("@extension") {
+                    let extension = annotations::parse_extension(trimmed, span)?;
+                    doc_info.extensions.push(extension);
+                } else if trimmed.starts_with("@errors") {
+                    error_sets.push(annotations::parse_errors(trimmed, span)?);
+                } else if trimmed == "@paginated" {
+                    paginated_span = Some(span);
+                } else if trimmed.starts_with("@cacheable") {
+                    cacheable = Some((annotations::parse_cacheable(trimmed, span)?, span));
                 } else if trimmed.starts_with('@') {
                     // Unknown annotation in metadata section
                     let annotation = trimmed.split_whitespace().next().unwrap_or(trimmed);
                     let annotation_name = annotation.strip_prefix('@').unwrap_or(annotation);
 
-                    let error_msg = find_closest_annotation(annotation_name).map_or_else(
-                        || {
-                            format!(
-                                "Unknown annotation '{annotation}'\n\
-                             note: valid annotations are @tag, @security, @id, @hidden"
-                            )
-                        },
-                        |suggestion| {
-                            format!(
-                                "Unknown annotation '{annotation}'\n\
-                             help: did you mean '@{suggestion}'?\n\
-                             note: valid annotations are @tag, @security, @id, @hidden"
-                            )
-                        },
+                    let help = find_closest_annotation(annotation_name)
+                        .map(|suggestion| format!("help: did you mean '@{suggestion}'?\n"))
+                        .unwrap_or_default();
+                    let valid = ANNOTATIONS
+                        .iter()
+                        .filter(|&&name| name != "rovo-ignore")
+                        .map(|name| format!("@{name}"))
+                        .collect::<Vec<_>>()
+                        .join(", ");
+                    let error_msg = format!(
+                        "Unknown annotation '{annotation}'\n{help}note: valid annotations are {valid}"
                     );
 
                     return Err(ParseError::with_span(error_msg, span));
@@ -352,29 +763,32 @@ fn parse_doc_comments(lines: &[DocLine]) -> Result<DocInfo, ParseError> {
             }
             Some("path_parameters") if !trimmed.is_empty() => {
                 // Parse path parameter documentation
-                // Format: "name: description"
-                if let Some(colon_pos) = trimmed.find(':') {
+                // Format: "name: description" or "@path name description"
+                if trimmed.starts_with("@path") {
+                    let doc = annotations::parse_path_param(trimmed, span)?;
+                    doc_info.path_params.push(doc);
+                } else if let Some(colon_pos) = trimmed.find(':') {
                     let name = trimmed[..colon_pos].trim().to_string();
                     let description = trimmed[colon_pos + 1..].trim().to_string();
                     doc_info.path_params.push(PathParamDoc {
                         name,
                         description,
                         span,
+                        warn_on_mismatch: false,
                     });
                 }
             }
             None if !trimmed.is_empty() => {
                 // Not in a section - this is title or description
                 if title_set {
-                    in_description = true;
                     description_lines.push(trimmed.to_string());
                 } else {
                     doc_info.title = Some(trimmed.to_string());
                     title_set = true;
                 }
             }
-            None if trimmed.is_empty() && in_description => {
-                // Empty line in description
+            None if trimmed.is_empty() && title_set => {
+                // Empty line in description (leading ones are trimmed below)
                 description_lines.push(String::new());
             }
             _ => {
@@ -388,22 +802,76 @@ fn parse_doc_comments(lines: &[DocLine]) -> Result<DocInfo, ParseError> {
         let response_info = annotations::parse_response_from_parts(&type_str, status, &desc, sp)?;
         doc_info.responses.push(response_info);
     }
-    if let Some((status, code, sp, _)) = pending_example {
-        let example_info = annotations::parse_example_from_parts(status, &code, sp)?;
+    if let Some((status, code, sp, _, label)) = pending_example {
+        let example_info = annotations::parse_example_from_parts(status, &code, label, sp)?;
         doc_info.examples.push(example_info);
     }
 
-    if !description_lines.is_empty() {
-        doc_info.description = Some(description_lines.join("\n").trim().to_string());
+    // An explicit @summary replaces the first line, which then opens the description
+    if let Some(summary) = explicit_summary {
+        if let Some(first_line) = doc_info.title.replace(summary) {
+            description_lines.insert(0, first_line);
+        }
+    }
+
+    let description = description_lines.join("\n");
+    let description = description.trim();
+    if !description.is_empty() {
+        doc_info.description = Some(description.to_string());
+    }
+
+    // `@concurrency` documents the response to a stale `If-Match`, unless it's written out
+    if doc_info.concurrency && !doc_info.responses.iter().any(|r| r.status_code == 412) {
+        doc_info.responses.push(ResponseInfo {
+            status_code: StatusKey::Exact(412),
+            content: vec![(None, quote::quote! { () })],
+            description:
+                "Precondition failed: the resource changed since the If-Match ETag was read"
+                    .to_string(),
+        });
+    }
+
+    // Expand `@errors` sets, leaving explicitly documented status codes alone
+    for &(status_code, description) in error_sets.into_iter().flatten() {
+        if doc_info
+            .responses
+            .iter()
+            .any(|r| r.status_code == status_code)
+        {
+            continue;
+        }
+        doc_info.responses.push(ResponseInfo {
+            status_code: StatusKey::Exact(status_code),
+            content: vec![(None, quote::quote! { () })],
+            description: description.to_string(),
+        });
+        doc_info.standard_errors.push(status_code);
+    }
+
+    // Expand `@paginated`, leaving explicitly documented parameters alone
+    if let Some(span) = paginated_span {
+        for &(name, param_type, description) in annotations::PAGINATION_PARAMS {
+            if doc_info.params.iter().any(|p| p.name == name) {
+                continue;
+            }
+            doc_info.params.push(ParamDoc {
+                name: name.to_string(),
+                param_type: param_type.parse().expect("pagination types are valid"),
+                description: description.to_string(),
+                location: Some(ParamLocation::Query),
+                span,
+            });
+            doc_info.pagination_params.push(name.to_string());
+        }
     }
 
     // Validate that all example status codes are defined in responses
     if !doc_info.examples.is_empty() && !doc_info.responses.is_empty() {
-        let response_codes: std::collections::HashSet<u16> =
+        let response_codes: std::collections::HashSet<StatusKey> =
             doc_info.responses.iter().map(|r| r.status_code).collect();
 
         for example in &doc_info.examples {
-            if !response_codes.contains(&example.status_code) {
+            if !response_codes.contains(&StatusKey::Exact(example.status_code)) {
                 let available_codes: Vec<String> = doc_info
                     .responses
                     .iter()
@@ -423,5 +891,179 @@ fn parse_doc_comments(lines: &[DocLine]) -> Result<DocInfo, ParseError> {
         }
     }
 
+    // `@cacheable` documents `Cache-Control` on the success response, unless it's written out
+    if let Some((directive, span)) = cacheable {
+        let status_code = doc_info
+            .responses
+            .iter()
+            .find_map(|r| match r.status_code {
+                StatusKey::Exact(code) if (200..300).contains(&code) => Some(code),
+                _ => None,
+            })
+            .ok_or_else(|| {
+                ParseError::with_span(
+                    "@cacheable needs a documented success response\n\
+                     help: add an entry like '200: Json<T> - description' under '# Responses'",
+                    span,
+                )
+            })?;
+        if !doc_info
+            .headers
+            .iter()
+            .any(|h| h.status_code == status_code && h.name.eq_ignore_ascii_case("Cache-Control"))
+        {
+            doc_info.headers.push(HeaderDoc {
+                status_code,
+                name: "Cache-Control".to_string(),
+                header_type: quote::quote! { String },
+                description: format!("Caching policy for the response: {directive}"),
+                example: Some(directive),
+                span,
+            });
+        }
+    }
+
+    // Validate that @header status codes are defined in responses
+    for header in &doc_info.headers {
+        if !doc_info
+            .responses
+            .iter()
+            .any(|r| r.status_code == header.status_code)
+        {
+            let available_codes: Vec<String> = doc_info
+                .responses
+                .iter()
+                .map(|r| r.status_code.to_string())
+                .collect();
+
+            return Err(ParseError::with_span(
+                format!(
+                    "Header '{}' refers to status code {}, which is not defined in responses. Available status codes: {}",
+                    header.name,
+                    header.status_code,
+                    available_codes.join(", ")
+                ),
+                header.span,
+            ));
+        }
+    }
+
+    // Validate that @link status codes are defined in responses
+    for link in &doc_info.links {
+        if !doc_info
+            .responses
+            .iter()
+            .any(|r| r.status_code == link.status_code)
+        {
+            let available_codes: Vec<String> = doc_info
+                .responses
+                .iter()
+                .map(|r| r.status_code.to_string())
+                .collect();
+
+            return Err(ParseError::with_span(
+                format!(
+                    "Link to '{}' refers to status code {}, which is not defined in responses. Available status codes: {}",
+                    link.operation_id,
+                    link.status_code,
+                    available_codes.join(", ")
+                ),
+                link.span,
+            ));
+        }
+    }
+
+    // Validate that the version range isn't empty
+    if let (Some(min), Some(max), Some(span)) = (
+        &doc_info.min_version,
+        &doc_info.max_version,
+        max_version_span,
+    ) {
+        if annotations::version_key(min) > annotations::version_key(max) {
+            return Err(ParseError::with_span(
+                format!(
+                    "@max-version {max} is older than @min-version {min}\n\
+                     help: swap the versions or remove one of the annotations"
+                ),
+                span,
+            ));
+        }
+    }
+
+    // Apply @content-type overrides to the primary type of each response, unless
+    // it already has a different one
+    for (status_code, content_type, span) in content_types {
+        let Some(response) = doc_info
+            .responses
+            .iter_mut()
+            .find(|r| r.status_code == status_code)
+        else {
+            let available_codes: Vec<String> = doc_info
+                .responses
+                .iter()
+                .map(|r| r.status_code.to_string())
+                .collect();
+
+            return Err(ParseError::with_span(
+                format!(
+                    "Content type '{}' refers to status code {}, which is not defined in responses. Available status codes: {}",
+                    content_type,
+                    status_code,
+                    available_codes.join(", ")
+                ),
+                span,
+            ));
+        };
+        let primary = &mut response.content[0].0;
+        if let Some(existing) = primary
+            .as_ref()
+            .filter(|existing| **existing != content_type)
+        {
+            return Err(ParseError::with_span(
+                format!(
+                    "Conflicting content types for status code {status_code}: '{existing}' and '{content_type}'\n\
+                     help: set the content type once, with `[type]` on the response line, @content-type or @produces"
+                ),
+                span,
+            ));
+        }
+        *primary = Some(content_type);
+    }
+
+    // Validate that named examples are unique per status code, and that a
+    // response's examples are either all named or a single unnamed one
+    let mut seen_names = std::collections::HashSet::new();
+    let mut seen_named = std::collections::HashSet::new();
+    let mut seen_unnamed = std::collections::HashSet::new();
+    for example in &doc_info.examples {
+        if let Some(label) = &example.label {
+            if !seen_names.insert((example.status_code, label.name.as_str())) {
+                return Err(ParseError::with_span(
+                    format!(
+                        "Duplicate example name '{}' for status code {}\n\
+                         help: give each named example of a response a distinct name",
+                        label.name, example.status_code
+                    ),
+                    example.span,
+                ));
+            }
+            seen_named.insert(example.status_code);
+        } else {
+            seen_unnamed.insert(example.status_code);
+        }
+
+        if seen_named.contains(&example.status_code) && seen_unnamed.contains(&example.status_code)
+        {
+            return Err(ParseError::with_span(
+                format!(
+                    "Status code {} has both named and unnamed examples\n\
+                     help: name every example of this response, e.g. `{} \"default\": ...`",
+                    example.status_code, example.status_code
+                ),
+                example.span,
+            ));
+        }
+    }
+
     Ok(doc_info)
 }
@@ -0,0 +1,248 @@
+use proc_macro2::{Delimiter, Group, Span, TokenStream, TokenTree};
+use quote::quote;
+
+use super::{annotations, tokens, ParseError};
+
+/// Parse a module annotated with `#[rovo_module]`
+///
+/// Collects the `@tag-description` annotations from the module's doc comments
+/// and passes them to every `#[rovo]` handler directly inside the module, which
+/// marks its operation so the router can merge them into the spec's tags.
+pub fn parse_rovo_module(attr: TokenStream, input: TokenStream) -> Result<TokenStream, ParseError> {
+    if let Some(token) = attr.into_iter().next() {
+        return Err(ParseError::with_span(
+            "#[rovo_module] takes no options\n\
+             help: describe tags with '@tag-description <tag> <description>' in the module's doc comments",
+            token.span(),
+        ));
+    }
+
+    let mut tokens: Vec<TokenTree> = input.into_iter().collect();
+
+    // The module body is the brace group following `mod <name>`
+    let mod_pos = tokens
+        .iter()
+        .position(|token| matches!(token, TokenTree::Ident(ident) if ident == "mod"))
+        .ok_or_else(|| ParseError::new("#[rovo_module] can only be applied to a module"))?;
+    let body_pos = match tokens.get(mod_pos + 2) {
+        Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Brace => mod_pos + 2,
+        _ => {
+            return Err(ParseError::with_span(
+                "#[rovo_module] requires an inline module\n\
+                 help: use `mod name { ... }` instead of `mod name;`",
+                tokens[mod_pos].span(),
+            ))
+        }
+    };
+    let TokenTree::Group(body) = &tokens[body_pos] else {
+        unreachable!("checked above");
+    };
+    let body: Vec<TokenTree> = body.stream().into_iter().collect();
+
+    // Outer `///` docs come before `mod`, inner `//!` docs open the body
+    let mut descriptions: Vec<(String, String)> = Vec::new();
+    for (doc_text, span) in doc_attributes(&tokens[..mod_pos]).chain(doc_attributes(&body)) {
+        for line in doc_text.split('\n') {
+            let trimmed = line.trim();
+            if !trimmed.starts_with("@tag-description") {
+                continue;
+            }
+
+            let (tag, description) = annotations::parse_tag_description(trimmed, span)?;
+            if descriptions.iter().any(|(existing, _)| *existing == tag) {
+                return Err(ParseError::with_span(
+                    format!(
+                        "Duplicate @tag-description for tag '{tag}'\n\
+                         help: a tag has a single description; remove the extra line"
+                    ),
+                    span,
+                ));
+            }
+            descriptions.push((tag, description));
+        }
+    }
+
+    if descriptions.is_empty() {
+        return Ok(tokens.into_iter().collect());
+    }
+
+    let options: Vec<TokenStream> = descriptions
+        .iter()
+        .map(|(tag, description)| quote! { __tag_description(#tag, #description) })
+        .collect();
+
+    let mut new_body = TokenStream::new();
+    let mut after_pound = false;
+    for token in body {
+        let is_attribute = after_pound;
+        after_pound = matches!(&token, TokenTree::Punct(p) if p.as_char() == '#');
+
+        let TokenTree::Group(group) = &token else {
+            new_body.extend([token]);
+            continue;
+        };
+        if !is_attribute
+            || group.delimiter() != Delimiter::Bracket
+            || !is_rovo_attribute(&group.stream())
+        {
+            new_body.extend([token]);
+            continue;
+        }
+
+        // `#[rovo]` or `#[rovo(...)]`: append the options to any existing ones
+        let mut attr_tokens: Vec<TokenTree> = group.stream().into_iter().collect();
+        let existing = match attr_tokens.last() {
+            Some(TokenTree::Group(args)) if args.delimiter() == Delimiter::Parenthesis => {
+                let args = args.stream();
+                attr_tokens.pop();
+                args
+            }
+            _ => TokenStream::new(),
+        };
+        let path: TokenStream = attr_tokens.into_iter().collect();
+        let mut rewritten = Group::new(
+            Delimiter::Bracket,
+            quote! { #path(#existing, #(#options),*) },
+        );
+        rewritten.set_span(group.span());
+        new_body.extend([TokenTree::Group(rewritten)]);
+    }
+
+    let mut new_group = Group::new(Delimiter::Brace, new_body);
+    new_group.set_span(tokens[body_pos].span());
+    tokens[body_pos] = TokenTree::Group(new_group);
+
+    Ok(tokens.into_iter().collect())
+}
+
+/// Parse the `("tag", "description")` arguments of the `__tag_description` option
+pub fn parse_tag_description_args(args: TokenStream) -> Option<(String, String)> {
+    let mut strings = args.into_iter().filter_map(|token| match token {
+        TokenTree::Literal(literal) => syn::parse_str::<syn::LitStr>(&literal.to_string()).ok(),
+        _ => None,
+    });
+
+    let tag = strings.next()?.value();
+    let description = strings.next()?.value();
+    Some((tag, description))
+}
+
+/// The text and span of each `#[doc = ...]` or `#![doc = ...]` attribute in `tokens`
+fn doc_attributes(tokens: &[TokenTree]) -> impl Iterator<Item = (String, Span)> + '_ {
+    tokens.windows(2).filter_map(|pair| match pair {
+        [TokenTree::Punct(p), TokenTree::Group(group)]
+            if matches!(p.as_char(), '#' | '!') && group.delimiter() == Delimiter::Bracket =>
+        {
+            let attr_content = group.stream().to_string();
+            attr_content
+                .starts_with("doc")
+                .then(|| (tokens::extract_doc_text(&attr_content), group.span()))
+        }
+        _ => None,
+    })
+}
+
+/// Whether an attribute's contents are `rovo` or `rovo::rovo`, with or without options
+pub fn is_rovo_attribute(attr: &TokenStream) -> bool {
+    let idents: Vec<String> = attr
+        .clone()
+        .into_iter()
+        .take_while(|token| !matches!(token, TokenTree::Group(_)))
+        .filter_map(|token| match token {
+            TokenTree::Ident(ident) => Some(ident.to_string()),
+            _ => None,
+        })
+        .collect();
+
+    matches!(idents.as_slice(), [name] | [_, name] if name == "rovo")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::parse_rovo_function;
+
+    fn handler_attr_args(module: &TokenStream) -> TokenStream {
+        let handler: TokenStream = module
+            .clone()
+            .into_iter()
+            .find_map(|token| match token {
+                TokenTree::Group(group) if group.delimiter() == Delimiter::Brace => {
+                    Some(group.stream())
+                }
+                _ => None,
+            })
+            .unwrap();
+        handler
+            .into_iter()
+            .find_map(|token| match token {
+                TokenTree::Group(group) if group.delimiter() == Delimiter::Bracket => {
+                    group.stream().into_iter().find_map(|token| match token {
+                        TokenTree::Group(args) => Some(args.stream()),
+                        _ => None,
+                    })
+                }
+                _ => None,
+            })
+            .unwrap()
+    }
+
+    #[test]
+    fn passes_tag_descriptions_to_handlers() {
+        let module: TokenStream = r"
+            /// User management
+            ///
+            /// @tag-description users Endpoints for managing user accounts
+            mod users {
+                //! @tag-description admin Administrative endpoints
+                #[rovo(code_samples)]
+                async fn list_users() -> Json<String> { }
+            }
+        "
+        .parse()
+        .unwrap();
+
+        let module = parse_rovo_module(TokenStream::new(), module).unwrap();
+        let args = handler_attr_args(&module);
+        let handler: TokenStream = "async fn list_users() -> Json<String> { }".parse().unwrap();
+        let (_, doc_info) = parse_rovo_function(args, handler).unwrap();
+
+        assert!(doc_info.code_samples);
+        assert_eq!(
+            doc_info.tag_descriptions,
+            vec![
+                (
+                    "users".to_string(),
+                    "Endpoints for managing user accounts".to_string()
+                ),
+                ("admin".to_string(), "Administrative endpoints".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_duplicate_tag_descriptions() {
+        let module: TokenStream = r"
+            /// @tag-description users Endpoints for managing user accounts
+            /// @tag-description users Users again
+            mod users {}
+        "
+        .parse()
+        .unwrap();
+
+        let err = parse_rovo_module(TokenStream::new(), module).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("Duplicate @tag-description for tag 'users'"));
+    }
+
+    #[test]
+    fn requires_inline_module() {
+        let module: TokenStream = "/// @tag-description users Users\nmod users;"
+            .parse()
+            .unwrap();
+
+        let err = parse_rovo_module(TokenStream::new(), module).unwrap_err();
+        assert!(err.to_string().contains("requires an inline module"));
+    }
+}
@@ -1,4 +1,4 @@
-use proc_macro2::TokenStream;
+use proc_macro2::{Delimiter, Group, TokenStream, TokenTree};
 
 use super::types::PathParamInfo;
 
@@ -196,23 +196,249 @@ pub fn extract_state_type(tokens: &TokenStream) -> Option<TokenStream> {
     None
 }
 
-/// Extract doc comment text from an attribute string
-pub fn extract_doc_text(attr: &str) -> String {
-    // Parse doc = "text" format
-    if let Some(start) = attr.find('"') {
-        if let Some(end) = attr.rfind('"') {
-            if start < end {
-                return attr[start + 1..end].to_string();
+/// Check whether the function takes a `Query<T>` extractor
+pub fn has_query_extractor(tokens: &TokenStream) -> bool {
+    let tokens: Vec<TokenTree> = tokens.clone().into_iter().collect();
+
+    let Some(fn_pos) = tokens
+        .iter()
+        .position(|t| matches!(t, TokenTree::Ident(ident) if ident == "fn"))
+    else {
+        return false;
+    };
+    let Some(TokenTree::Group(params)) = tokens[fn_pos..]
+        .iter()
+        .find(|t| matches!(t, TokenTree::Group(g) if g.delimiter() == Delimiter::Parenthesis))
+    else {
+        return false;
+    };
+
+    let params: Vec<TokenTree> = params.stream().into_iter().collect();
+    params.windows(2).any(|pair| {
+        matches!(
+            pair,
+            [TokenTree::Ident(ident), TokenTree::Punct(p)] if ident == "Query" && p.as_char() == '<'
+        )
+    })
+}
+
+/// Split the function's parameter list into its comma-separated parameters
+pub fn split_params(tokens: &TokenStream) -> Vec<Vec<TokenTree>> {
+    let tokens: Vec<TokenTree> = tokens.clone().into_iter().collect();
+
+    let Some(fn_pos) = tokens
+        .iter()
+        .position(|t| matches!(t, TokenTree::Ident(ident) if ident == "fn"))
+    else {
+        return Vec::new();
+    };
+    let Some(TokenTree::Group(params)) = tokens[fn_pos..]
+        .iter()
+        .find(|t| matches!(t, TokenTree::Group(g) if g.delimiter() == Delimiter::Parenthesis))
+    else {
+        return Vec::new();
+    };
+
+    // Commas inside generics (`Path<(A, B)>` is a group, `HashMap<K, V>` isn't) don't split
+    let mut result = vec![Vec::new()];
+    let mut angle_depth = 0_usize;
+    let mut after_dash = false;
+    for token in params.stream() {
+        // The `>` of `->` doesn't close a generic
+        let is_arrow = after_dash;
+        after_dash = matches!(&token, TokenTree::Punct(p) if p.as_char() == '-');
+        match &token {
+            TokenTree::Punct(p) if p.as_char() == '<' => angle_depth += 1,
+            TokenTree::Punct(p) if p.as_char() == '>' && !is_arrow => {
+                angle_depth = angle_depth.saturating_sub(1);
+            }
+            TokenTree::Punct(p) if p.as_char() == ',' && angle_depth == 0 => {
+                result.push(Vec::new());
+                continue;
             }
+            _ => {}
+        }
+        if let Some(param) = result.last_mut() {
+            param.push(token);
+        }
+    }
+    result.retain(|param| !param.is_empty());
+    result
+}
+
+/// Whether a parameter is a method receiver: `self`, `&self`, `mut self`, `self: Box<Self>`...
+pub fn is_receiver(param: &[TokenTree]) -> bool {
+    param
+        .iter()
+        .take_while(|t| !matches!(t, TokenTree::Punct(p) if p.as_char() == ':'))
+        .any(|t| matches!(t, TokenTree::Ident(ident) if ident == "self"))
+}
+
+/// The type of a `pattern: Type` parameter
+pub fn param_type(param: &[TokenTree]) -> Option<TokenStream> {
+    // The first `:` that isn't half of a `::` path separator
+    let colon = param.iter().enumerate().position(|(idx, t)| {
+        let is_colon = |t: &TokenTree| matches!(t, TokenTree::Punct(p) if p.as_char() == ':');
+        is_colon(t)
+            && !param.get(idx + 1).is_some_and(is_colon)
+            && !idx
+                .checked_sub(1)
+                .is_some_and(|prev| is_colon(&param[prev]))
+    })?;
+    Some(param[colon + 1..].iter().cloned().collect())
+}
+
+/// Replace every `Self` in `tokens` with `self_ty`
+pub fn replace_self(tokens: TokenStream, self_ty: &TokenStream) -> TokenStream {
+    tokens
+        .into_iter()
+        .flat_map(|token| match token {
+            TokenTree::Ident(ident) if ident == "Self" => self_ty.clone(),
+            TokenTree::Group(group) => {
+                let mut replaced =
+                    Group::new(group.delimiter(), replace_self(group.stream(), self_ty));
+                replaced.set_span(group.span());
+                TokenStream::from(TokenTree::Group(replaced))
+            }
+            other => TokenStream::from(other),
+        })
+        .collect()
+}
+
+/// Extract the declared return type of the function, keeping the original spans
+/// Returns None if the function has no `-> Type` (meaning it returns `()`)
+pub fn extract_return_type(tokens: &TokenStream) -> Option<TokenStream> {
+    let tokens: Vec<TokenTree> = tokens.clone().into_iter().collect();
+
+    // The return type follows the parameter list of the `fn`
+    let fn_pos = tokens
+        .iter()
+        .position(|t| matches!(t, TokenTree::Ident(ident) if ident == "fn"))?;
+    let params_pos = fn_pos
+        + tokens[fn_pos..].iter().position(
+            |t| matches!(t, TokenTree::Group(g) if g.delimiter() == Delimiter::Parenthesis),
+        )?;
+
+    let rest = &tokens[params_pos + 1..];
+    match rest {
+        [TokenTree::Punct(dash), TokenTree::Punct(arrow), ..]
+            if dash.as_char() == '-' && arrow.as_char() == '>' => {}
+        _ => return None,
+    }
+
+    // Collect everything up to the body or a where clause
+    let return_type: TokenStream = rest[2..]
+        .iter()
+        .take_while(|t| match t {
+            TokenTree::Group(g) => g.delimiter() != Delimiter::Brace,
+            TokenTree::Ident(ident) => ident != "where",
+            _ => true,
+        })
+        .cloned()
+        .collect();
+
+    (!return_type.is_empty()).then_some(return_type)
+}
+
+/// Build the deprecation reason from a `#[deprecated]` attribute's `note` and `since`
+///
+/// Accepts the body of the attribute: `deprecated`, `deprecated = "..."` or
+/// `deprecated(since = "...", note = "...")`.
+pub fn extract_deprecation_note(attr: TokenStream) -> Option<String> {
+    let meta = syn::parse2::<syn::Meta>(attr).ok()?;
+    let string_value = |expr: &syn::Expr| match expr {
+        syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Str(lit),
+            ..
+        }) => Some(lit.value()),
+        _ => None,
+    };
+
+    let (mut note, mut since) = (None, None);
+    match &meta {
+        syn::Meta::Path(_) => {}
+        syn::Meta::NameValue(name_value) => note = string_value(&name_value.value),
+        syn::Meta::List(list) => {
+            let _ = list.parse_nested_meta(|nested| {
+                let value = nested.value()?.parse::<syn::LitStr>()?.value();
+                if nested.path.is_ident("note") {
+                    note = Some(value);
+                } else if nested.path.is_ident("since") {
+                    since = Some(value);
+                }
+                Ok(())
+            });
         }
     }
-    String::new()
+
+    match (since, note) {
+        (Some(since), Some(note)) => Some(format!("Deprecated since {since}: {note}")),
+        (Some(since), None) => Some(format!("Deprecated since {since}")),
+        (None, Some(note)) => Some(format!("Deprecated: {note}")),
+        (None, None) => None,
+    }
+}
+
+/// Extract doc comment text from an attribute string
+pub fn extract_doc_text(attr: &str) -> String {
+    // Parse doc = "text" format, unescaping the literal so `///` comments and
+    // explicit `#[doc = "..."]` attributes (including raw strings) read the same
+    attr.split_once('=')
+        .and_then(|(_, literal)| syn::parse_str::<syn::LitStr>(literal.trim()).ok())
+        .map(|literal| literal.value())
+        .unwrap_or_default()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    // Return type extraction tests
+
+    #[test]
+    fn extracts_return_type() {
+        let tokens: TokenStream =
+            "#[doc = \"x\"] async fn get_user(Path(id): Path<u64>) -> Result<Json<User>, AppError> { }"
+                .parse()
+                .unwrap();
+        let result = extract_return_type(&tokens).unwrap();
+        assert_eq!(result.to_string(), "Result < Json < User >, AppError >");
+    }
+
+    #[test]
+    fn extracts_return_type_before_where_clause() {
+        let tokens: TokenStream = "fn handler<T>() -> Json<T> where T: Default { }"
+            .parse()
+            .unwrap();
+        let result = extract_return_type(&tokens).unwrap();
+        assert_eq!(result.to_string(), "Json < T >");
+    }
+
+    #[test]
+    fn detects_query_extractor() {
+        let tokens: TokenStream =
+            "async fn list(Query(q): Query<ListParams>, State(s): State<AppState>) { }"
+                .parse()
+                .unwrap();
+        assert!(has_query_extractor(&tokens));
+    }
+
+    #[test]
+    fn no_query_extractor_in_body() {
+        let tokens: TokenStream = "async fn list(Path(id): Path<u64>) { let q: Query<u8>; }"
+            .parse()
+            .unwrap();
+        assert!(!has_query_extractor(&tokens));
+    }
+
+    #[test]
+    fn no_return_type_for_unit_function() {
+        let tokens: TokenStream = "async fn handler(State(s): State<AppState>) { }"
+            .parse()
+            .unwrap();
+        assert!(extract_return_type(&tokens).is_none());
+    }
+
     // Path parameter extraction tests
 
     #[test]
@@ -284,7 +510,7 @@ mod tests {
         );
         let tokens: TokenStream = code.parse().unwrap();
 
-        let result = parse_rovo_function(tokens);
+        let result = parse_rovo_function(TokenStream::new(), tokens);
         assert!(result.is_ok(), "Should parse successfully");
 
         let (func_item, doc_info) = result.unwrap();
@@ -305,6 +531,479 @@ mod tests {
         assert_eq!(doc_info.path_params[0].name, "id");
     }
 
+    #[test]
+    fn test_mismatched_path_annotation_is_a_warning() {
+        use crate::parser::parse_rovo_function;
+
+        let code = concat!(
+            "#[doc = \"# Path Parameters\"]",
+            "#[doc = \"\"]",
+            "#[doc = \"@path id The user id\"]",
+            "#[doc = \"@path slug The post slug\"]",
+            "#[doc = \"@path page The page\"]",
+            "async fn get_post(Path((id, slug)): Path<(u32, String)>) -> Json<String> { }"
+        );
+        let tokens: TokenStream = code.parse().unwrap();
+
+        let (_, doc_info) = parse_rovo_function(TokenStream::new(), tokens)
+            .expect("@path mismatches should not fail the build");
+
+        assert_eq!(doc_info.path_params.len(), 3);
+        assert_eq!(doc_info.warnings.len(), 1);
+        assert!(doc_info.warnings[0]
+            .message
+            .contains("Documented path parameter 'page' does not match"));
+    }
+
+    #[test]
+    fn deprecated_message_without_deprecation_is_a_warning() {
+        use crate::parser::parse_rovo_function;
+
+        let code = concat!(
+            "#[doc = \"# Metadata\"]",
+            "#[doc = \"\"]",
+            "#[doc = \"@deprecated-message Use /v2/users instead\"]",
+            "async fn list_users() -> Json<String> { }"
+        );
+        let tokens: TokenStream = code.parse().unwrap();
+        let (_, doc_info) = parse_rovo_function(TokenStream::new(), tokens).unwrap();
+
+        assert_eq!(doc_info.deprecation_note, None);
+        assert_eq!(doc_info.warnings.len(), 1);
+        assert!(doc_info.warnings[0]
+            .message
+            .contains("@deprecated-message is set but the operation is not deprecated"));
+
+        let deprecated: TokenStream = format!("#[deprecated] {code}").parse().unwrap();
+        let (_, doc_info) = parse_rovo_function(TokenStream::new(), deprecated).unwrap();
+
+        assert!(doc_info.warnings.is_empty());
+        assert_eq!(
+            doc_info.deprecation_note.as_deref(),
+            Some("Deprecated: Use /v2/users instead")
+        );
+    }
+
+    #[test]
+    fn summary_annotation_overrides_first_line() {
+        use crate::parser::parse_rovo_function;
+
+        let code = concat!(
+            "#[doc = \" Fetches a single user record by its numeric identifier.\"]",
+            "#[doc = \"\"]",
+            "#[doc = \" Returns 404 when missing.\"]",
+            "#[doc = \"\"]",
+            "#[doc = \" # Metadata\"]",
+            "#[doc = \"\"]",
+            "#[doc = \" @summary Get user by ID\"]",
+            "async fn get_user() -> Json<String> { }"
+        );
+        let tokens: TokenStream = code.parse().unwrap();
+        let (_, doc_info) = parse_rovo_function(TokenStream::new(), tokens).unwrap();
+
+        assert_eq!(doc_info.title.as_deref(), Some("Get user by ID"));
+        assert_eq!(
+            doc_info.description.as_deref(),
+            Some("Fetches a single user record by its numeric identifier.\n\nReturns 404 when missing.")
+        );
+
+        let duplicate: TokenStream = format!("#[doc = \" @summary Another\"] {code}")
+            .parse()
+            .unwrap();
+        let Err(err) = parse_rovo_function(TokenStream::new(), duplicate) else {
+            panic!("expected a duplicate @summary error");
+        };
+        assert!(err.to_string().contains("Duplicate @summary annotation"));
+    }
+
+    #[test]
+    fn duplicate_response_status_is_an_error() {
+        use crate::parser::parse_rovo_function;
+
+        for status in ["200", "4XX", "default"] {
+            let code = format!(
+                concat!(
+                    "#[doc = \" # Responses\"]",
+                    "#[doc = \"\"]",
+                    "#[doc = \" {}: Json<String> - First\"]",
+                    "#[doc = \" {}: Json<String> - Second\"]",
+                    "async fn get_user() -> Json<String> {{ }}"
+                ),
+                status, status
+            );
+            let tokens: TokenStream = code.parse().unwrap();
+            let Err(err) = parse_rovo_function(TokenStream::new(), tokens) else {
+                panic!("expected a duplicate status error for {status}");
+            };
+            assert!(err
+                .to_string()
+                .contains(&format!("Duplicate response for status code {status}")));
+        }
+    }
+
+    #[test]
+    fn concurrency_adds_if_match_and_412() {
+        use crate::parser::parse_rovo_function;
+
+        let tokens: TokenStream = concat!(
+            "#[doc = \" # Responses\"]",
+            "#[doc = \"\"]",
+            "#[doc = \" 200: Json<String> - The updated user\"]",
+            "#[doc = \"\"]",
+            "#[doc = \" # Metadata\"]",
+            "#[doc = \"\"]",
+            "#[doc = \" @concurrency\"]",
+            "async fn update_user() -> Json<String> { }"
+        )
+        .parse()
+        .unwrap();
+        let (_, doc_info) = parse_rovo_function(TokenStream::new(), tokens).unwrap();
+
+        assert!(doc_info.concurrency);
+        let codes: Vec<String> = doc_info
+            .responses
+            .iter()
+            .map(|r| r.status_code.to_string())
+            .collect();
+        assert_eq!(codes, vec!["200", "412"]);
+    }
+
+    #[test]
+    fn paginated_adds_query_parameters() {
+        use crate::parser::{parse_rovo_function, DocInfo, ParamLocation};
+
+        let parse = |metadata: &str| -> DocInfo {
+            let tokens: TokenStream = format!(
+                "#[doc = \" # Metadata\"] \
+                 #[doc = \"\"] \
+                 {metadata} \
+                 #[doc = \" @paginated\"] \
+                 async fn list_users() -> Json<String> {{ }}"
+            )
+            .parse()
+            .unwrap();
+            parse_rovo_function(TokenStream::new(), tokens).unwrap().1
+        };
+        let params = |doc_info: &DocInfo| -> Vec<(String, String)> {
+            doc_info
+                .params
+                .iter()
+                .map(|p| (p.name.clone(), p.param_type.to_string()))
+                .collect()
+        };
+
+        let doc_info = parse("");
+        assert_eq!(
+            params(&doc_info),
+            vec![
+                ("page".to_string(), "i32".to_string()),
+                ("per_page".to_string(), "i32".to_string()),
+                ("sort".to_string(), "String".to_string()),
+            ]
+        );
+        assert!(doc_info
+            .params
+            .iter()
+            .all(|p| p.location == Some(ParamLocation::Query)));
+        assert_eq!(doc_info.pagination_params, vec!["page", "per_page", "sort"]);
+
+        // An explicitly documented parameter is kept, and left out of the marker
+        let doc_info = parse("#[doc = \" @query sort SortField Column to sort by\"]");
+        assert_eq!(
+            params(&doc_info)[0],
+            ("sort".to_string(), "SortField".to_string())
+        );
+        assert_eq!(doc_info.pagination_params, vec!["page", "per_page"]);
+    }
+
+    #[test]
+    fn cacheable_documents_cache_control_header() {
+        use crate::parser::parse_rovo_function;
+
+        let tokens: TokenStream = concat!(
+            "#[doc = \" # Responses\"]",
+            "#[doc = \"\"]",
+            "#[doc = \" 200: Json<String> - The countries\"]",
+            "#[doc = \"\"]",
+            "#[doc = \" # Metadata\"]",
+            "#[doc = \"\"]",
+            "#[doc = \" @cacheable max-age=60\"]",
+            "async fn list_countries() -> Json<String> { }"
+        )
+        .parse()
+        .unwrap();
+        let (_, doc_info) = parse_rovo_function(TokenStream::new(), tokens).unwrap();
+
+        assert_eq!(doc_info.headers.len(), 1);
+        let header = &doc_info.headers[0];
+        assert_eq!(header.status_code, 200);
+        assert_eq!(header.name, "Cache-Control");
+        assert_eq!(header.header_type.to_string(), "String");
+        assert!(header.description.contains("max-age=60"));
+        assert_eq!(header.example.as_deref(), Some("max-age=60"));
+
+        let tokens: TokenStream = concat!(
+            "#[doc = \" # Metadata\"]",
+            "#[doc = \" @cacheable max-age=60\"]",
+            "async fn list_countries() -> Json<String> { }"
+        )
+        .parse()
+        .unwrap();
+        let Err(err) = parse_rovo_function(TokenStream::new(), tokens) else {
+            panic!("expected an error without a success response");
+        };
+        assert!(err
+            .to_string()
+            .contains("@cacheable needs a documented success response"));
+    }
+
+    #[test]
+    fn duplicate_tags_are_emitted_once() {
+        let tokens: TokenStream = concat!(
+            "#[doc = \" # Metadata\"]",
+            "#[doc = \"\"]",
+            "#[doc = \" @tag users\"]",
+            "#[doc = \" @tag admin\"]",
+            "#[doc = \" @tag users\"]",
+            "async fn list_users() -> Json<String> { }"
+        )
+        .parse()
+        .unwrap();
+        let (_, doc_info) = crate::parser::parse_rovo_function(TokenStream::new(), tokens).unwrap();
+
+        assert_eq!(doc_info.tags, vec!["users", "admin"]);
+    }
+
+    #[test]
+    fn unknown_annotations_list_the_valid_ones() {
+        let parse = |annotation: &str| {
+            let tokens: TokenStream = format!(
+                "#[doc = \" # Metadata\"] #[doc = \"\"] #[doc = \" {annotation}\"] \
+                 async fn list_users() -> Json<String> {{ }}"
+            )
+            .parse()
+            .unwrap();
+            match crate::parser::parse_rovo_function(TokenStream::new(), tokens) {
+                Ok(_) => panic!("{annotation} should be rejected"),
+                Err(err) => err.to_string(),
+            }
+        };
+        let note = "note: valid annotations are @tag, @security, @security-and, @id, @summary, @hidden, @i18n, @idempotent, @concurrency, @cfg, @min-version, @max-version, @ratelimit, @sunset, @server, @external-docs, @async-job, @param, @query, @paginated, @body, @errors, @header, @cacheable, @deprecated, @deprecated-message, @content-type, @produces, @link, @extension";
+
+        assert_eq!(
+            parse("@respons typo"),
+            format!("Unknown annotation '@respons'\n{note}")
+        );
+        assert_eq!(
+            parse("@tga users"),
+            format!("Unknown annotation '@tga'\nhelp: did you mean '@tag'?\n{note}")
+        );
+    }
+
+    #[test]
+    fn named_and_unnamed_examples_of_one_status_are_rejected() {
+        let parse = |examples: &str| {
+            let tokens: TokenStream = format!(
+                "#[doc = \" # Responses\"] #[doc = \"\"] \
+                 #[doc = \" 200: Json<Vec<User>> - Users\"] #[doc = \"\"] \
+                 #[doc = \" # Examples\"] #[doc = \"\"] {examples} \
+                 async fn list_users() -> Json<Vec<User>> {{ }}"
+            )
+            .parse()
+            .unwrap();
+            crate::parser::parse_rovo_function(TokenStream::new(), tokens)
+        };
+
+        let mixed = parse(
+            r#"#[doc = " 200: Vec::<User>::new()"] #[doc = " 200 \"empty\": Vec::<User>::new()"]"#,
+        );
+        match mixed {
+            Ok(_) => panic!("mixed examples should be rejected"),
+            Err(err) => assert!(err
+                .to_string()
+                .starts_with("Status code 200 has both named and unnamed examples")),
+        }
+
+        let named = parse(
+            r#"#[doc = " 200 \"default\": Vec::<User>::new()"] #[doc = " 200 \"empty\": Vec::<User>::new()"]"#,
+        );
+        assert!(named.is_ok());
+    }
+
+    #[test]
+    fn conflicting_content_types_are_rejected() {
+        let parse = |response: &str, metadata: &str| {
+            let tokens: TokenStream = format!(
+                "#[doc = \" # Responses\"] #[doc = \"\"] #[doc = \" {response}\"] #[doc = \"\"] \
+                 #[doc = \" # Metadata\"] #[doc = \"\"] {metadata} \
+                 async fn export() -> String {{ }}"
+            )
+            .parse()
+            .unwrap();
+            crate::parser::parse_rovo_function(TokenStream::new(), tokens)
+        };
+        let conflict = |result: Result<_, crate::parser::ParseError>| match result {
+            Ok(_) => panic!("conflicting content types should be rejected"),
+            Err(err) => err.to_string(),
+        };
+
+        let message = conflict(parse(
+            "200: String - Export",
+            r#"#[doc = " @produces text/csv"] #[doc = " @content-type 200 text/plain"]"#,
+        ));
+        assert!(message.starts_with(
+            "Conflicting content types for status code 200: 'text/csv' and 'text/plain'"
+        ));
+
+        let message = conflict(parse(
+            "200: String [text/csv] - Export",
+            r#"#[doc = " @produces text/plain"]"#,
+        ));
+        assert!(message.contains("'text/csv' and 'text/plain'"));
+
+        // Repeating the same content type is fine
+        let (_, doc_info) = parse(
+            "200: String [text/csv] - Export",
+            r#"#[doc = " @produces text/csv"]"#,
+        )
+        .unwrap_or_else(|err| panic!("{err}"));
+        assert_eq!(
+            doc_info.responses[0].content[0].0.as_deref(),
+            Some("text/csv")
+        );
+    }
+
+    #[test]
+    fn status_code_constants_resolve_to_numbers() {
+        let tokens: TokenStream = "#[doc = \" # Responses\"] \
+             #[doc = \"\"] \
+             #[doc = \" StatusCode::CREATED: Json<User> - ok\"] \
+             async fn create_user() -> Json<User> { }"
+            .parse()
+            .unwrap();
+        let (_, doc_info) = crate::parser::parse_rovo_function(TokenStream::new(), tokens).unwrap();
+
+        assert_eq!(doc_info.responses.len(), 1);
+        assert_eq!(doc_info.responses[0].status_code.to_string(), "201");
+        assert_eq!(doc_info.responses[0].description, "ok");
+    }
+
+    #[test]
+    fn bulleted_section_entries_parse_like_plain_ones() {
+        use crate::parser::parse_rovo_function;
+
+        let parse = |bullet: &str| {
+            let tokens: TokenStream = format!(
+                "#[doc = \" # Responses\"] \
+                 #[doc = \"\"] \
+                 #[doc = \" {bullet}200: Json<String> - The user\"] \
+                 #[doc = \" {bullet}404: () - No user\"] \
+                 #[doc = \" with that ID\"] \
+                 #[doc = \"\"] \
+                 #[doc = \" # Examples\"] \
+                 #[doc = \"\"] \
+                 #[doc = \" {bullet}200: String::new()\"] \
+                 #[doc = \" {bullet}404 \\\"missing\\\": ()\"] \
+                 async fn get_user() -> Json<String> {{ }}"
+            )
+            .parse()
+            .unwrap();
+            let (_, doc_info) = parse_rovo_function(TokenStream::new(), tokens).unwrap();
+
+            let responses: Vec<(String, String)> = doc_info
+                .responses
+                .iter()
+                .map(|r| (r.status_code.to_string(), r.description.clone()))
+                .collect();
+            let examples: Vec<(u16, String, Option<crate::parser::ExampleLabel>)> = doc_info
+                .examples
+                .iter()
+                .map(|e| (e.status_code, e.example_code.to_string(), e.label.clone()))
+                .collect();
+            (responses, examples)
+        };
+
+        let plain = parse("");
+        assert_eq!(
+            plain.0,
+            vec![
+                ("200".to_string(), "The user".to_string()),
+                ("404".to_string(), "No user with that ID".to_string()),
+            ]
+        );
+        assert_eq!(plain.1.len(), 2);
+        assert_eq!(parse("- "), plain);
+        assert_eq!(parse("* "), plain);
+    }
+
+    #[test]
+    fn max_version_older_than_min_version_is_an_error() {
+        use crate::parser::parse_rovo_function;
+
+        let tokens: TokenStream = concat!(
+            "#[doc = \" # Metadata\"]",
+            "#[doc = \"\"]",
+            "#[doc = \" @min-version 3.0\"]",
+            "#[doc = \" @max-version 2.5\"]",
+            "async fn legacy_search() -> Json<String> { }"
+        )
+        .parse()
+        .unwrap();
+        let Err(err) = parse_rovo_function(TokenStream::new(), tokens) else {
+            panic!("expected an empty version range error");
+        };
+        assert!(err
+            .to_string()
+            .contains("@max-version 2.5 is older than @min-version 3.0"));
+    }
+
+    #[test]
+    fn explicit_doc_attributes_parse_like_doc_comments() {
+        use crate::parser::parse_rovo_function;
+
+        let sugared: TokenStream = r#"
+            /// Get a user.
+            ///
+            /// # Responses
+            ///
+            /// 200: Json<User> - The "current" user
+            /// 404: () - Not found
+            async fn get_user() -> Json<User> { }
+        "#
+        .parse()
+        .unwrap();
+        let explicit: TokenStream = r##"
+            #[doc = "Get a user."]
+            #[doc = ""]
+            #[doc = "# Responses\n"]
+            #[doc = r#"200: Json<User> - The "current" user"#]
+            #[doc = "404: () - Not found"]
+            async fn get_user() -> Json<User> { }
+        "##
+        .parse()
+        .unwrap();
+
+        let (_, sugared) = parse_rovo_function(TokenStream::new(), sugared).unwrap();
+        let (_, explicit) = parse_rovo_function(TokenStream::new(), explicit).unwrap();
+
+        assert_eq!(explicit.title, sugared.title);
+        let summary = |doc_info: &crate::parser::DocInfo| {
+            doc_info
+                .responses
+                .iter()
+                .map(|r| (r.status_code.to_string(), r.description.clone()))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(summary(&explicit), summary(&sugared));
+        assert_eq!(
+            summary(&explicit),
+            vec![
+                ("200".to_string(), "The \"current\" user".to_string()),
+                ("404".to_string(), "Not found".to_string())
+            ]
+        );
+    }
+
     #[test]
     fn extracts_string_path_binding() {
         let tokens: TokenStream = "Path(username): Path<String>".parse().unwrap();
@@ -446,7 +1145,7 @@ mod tests {
     fn extracts_doc_text_with_special_chars() {
         let attr = r#"doc = "Contains 'quotes' and \"escapes\"""#;
         let result = extract_doc_text(attr);
-        assert_eq!(result, r#"Contains 'quotes' and \"escapes\""#);
+        assert_eq!(result, r#"Contains 'quotes' and "escapes""#);
     }
 
     #[test]
@@ -474,7 +1173,32 @@ mod tests {
     fn extracts_multiline_doc_text() {
         let attr = r#"doc = "Line 1\nLine 2\nLine 3""#;
         let result = extract_doc_text(attr);
-        assert_eq!(result, r"Line 1\nLine 2\nLine 3");
+        assert_eq!(result, "Line 1\nLine 2\nLine 3");
+    }
+
+    #[test]
+    fn extracts_deprecation_note() {
+        let note = |attr: &str| extract_deprecation_note(attr.parse().unwrap());
+        assert_eq!(note("deprecated"), None);
+        assert_eq!(
+            note(r#"deprecated = "use v2""#).as_deref(),
+            Some("Deprecated: use v2")
+        );
+        assert_eq!(
+            note(r#"deprecated(note = "use v2")"#).as_deref(),
+            Some("Deprecated: use v2")
+        );
+        assert_eq!(
+            note(r#"deprecated(since = "1.4.0", note = "use v2")"#).as_deref(),
+            Some("Deprecated since 1.4.0: use v2")
+        );
+    }
+
+    #[test]
+    fn extracts_raw_string_doc_text() {
+        let attr = "doc = r#\"200: Json<User> - \"Found\"\"#";
+        let result = extract_doc_text(attr);
+        assert_eq!(result, r#"200: Json<User> - "Found""#);
     }
 
     // Additional edge case tests for coverage
@@ -1,20 +1,86 @@
 use proc_macro2::{Ident, Span, TokenStream, TokenTree};
 use quote::ToTokens;
 
+use super::error::ParseWarning;
+
+/// Status of a documented response: a code, a range (`2XX`) or `default`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StatusKey {
+    Exact(u16),
+    /// The leading digit of an `NXX` range
+    Range(u16),
+    Default,
+}
+
+impl StatusKey {
+    /// Parse `200`, `2XX` or `default`; range digits are checked by the caller
+    pub fn parse(status: &str) -> Option<Self> {
+        if status == "default" {
+            return Some(Self::Default);
+        }
+        if let Some(digit) = status.strip_suffix("XX") {
+            return digit
+                .parse()
+                .ok()
+                .filter(|_| digit.len() == 1)
+                .map(Self::Range);
+        }
+        status
+            .chars()
+            .all(|c| c.is_ascii_digit())
+            .then(|| status.parse().ok().map(Self::Exact))
+            .flatten()
+    }
+}
+
+impl PartialEq<u16> for StatusKey {
+    fn eq(&self, other: &u16) -> bool {
+        *self == Self::Exact(*other)
+    }
+}
+
+impl std::fmt::Display for StatusKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Exact(code) => write!(f, "{code}"),
+            Self::Range(digit) => write!(f, "{digit}XX"),
+            Self::Default => f.write_str("default"),
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct ResponseInfo {
-    pub status_code: u16,
-    pub response_type: TokenStream,
+    pub status_code: StatusKey,
+    /// Response types with their content type, if given (`String [text/csv]`);
+    /// never empty, and the first entry is the primary type
+    pub content: Vec<(Option<String>, TokenStream)>,
     pub description: String,
 }
 
+impl ResponseInfo {
+    /// The primary response type, used for examples and `strict_responses`
+    pub fn response_type(&self) -> &TokenStream {
+        &self.content[0].1
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct ExampleInfo {
     pub status_code: u16,
     pub example_code: TokenStream,
+    /// Name and summary for a named example (`200 "empty" (summary: "..."): ...`)
+    pub label: Option<ExampleLabel>,
     pub span: Span,
 }
 
+/// Name and optional summary of a named example
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExampleLabel {
+    pub name: String,
+    pub summary: Option<String>,
+}
+
 /// Information about a path parameter from the `# Path Parameters` doc section
 #[derive(Debug, Clone)]
 pub struct PathParamDoc {
@@ -24,9 +90,61 @@ pub struct PathParamDoc {
     pub description: String,
     /// Span for error reporting
     pub span: Span,
+    /// Documented with `@path`: a name matching no binding is a warning, not an error
+    pub warn_on_mismatch: bool,
+}
+
+/// A request body documented with `@body`
+#[derive(Debug, Clone)]
+pub struct BodyDoc {
+    pub body_type: TokenStream,
+    /// Overrides the content type inferred from the extractor (`application/json` for `Json<T>`)
+    pub content_type: Option<String>,
+    pub description: String,
+}
+
+/// A response header documented with `@header` or `@cacheable`
+#[derive(Debug, Clone)]
+pub struct HeaderDoc {
+    pub status_code: u16,
+    pub name: String,
+    pub header_type: TokenStream,
+    pub description: String,
+    /// Example value (the directive of `@cacheable`)
+    pub example: Option<String>,
+    pub span: Span,
+}
+
+/// An `OpenAPI` link documented with `@link`
+#[derive(Debug, Clone)]
+pub struct LinkDoc {
+    pub status_code: u16,
+    pub operation_id: String,
+    /// Parameter name and runtime expression pairs, e.g. `id` and `$response.body#/id`
+    pub parameters: Vec<(String, String)>,
+    pub span: Span,
+}
+
+/// Where a documented parameter is sent
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParamLocation {
+    Path,
+    Query,
+}
+
+/// A parameter documented with `@param` or `@query`
+#[derive(Debug, Clone)]
+pub struct ParamDoc {
+    pub name: String,
+    pub param_type: TokenStream,
+    pub description: String,
+    /// Set by `@query`; for `@param` resolved against the signature's extractors
+    pub location: Option<ParamLocation>,
+    pub span: Span,
 }
 
 #[derive(Debug, Clone, Default)]
+#[allow(clippy::struct_excessive_bools)]
 pub struct DocInfo {
     pub title: Option<String>,
     pub description: Option<String>,
@@ -34,11 +152,63 @@ pub struct DocInfo {
     pub examples: Vec<ExampleInfo>,
     pub tags: Vec<String>,
     pub deprecated: bool,
-    pub security_requirements: Vec<String>,
+    /// Reason from `#[deprecated(note/since)]` or `@deprecated <reason>`, appended to the description
+    pub deprecation_note: Option<String>,
+    /// Message from `@deprecated-message`, emitted as `x-deprecated-message`
+    pub deprecated_message: Option<(String, Span)>,
+    /// Security requirements as (scheme, scopes) pairs; the schemes within one entry
+    /// must all be satisfied (`@security-and`)
+    pub security_requirements: Vec<Vec<(String, Vec<String>)>>,
     pub operation_id: Option<String>,
     pub hidden: bool,
+    /// Whether `@i18n` was set (documents `Accept-Language`/`Content-Language`)
+    pub i18n: bool,
+    /// Whether `@idempotent` was set (documents a required `Idempotency-Key` header)
+    pub idempotent: bool,
+    /// Whether `@concurrency` was set (documents an `If-Match` header and a `412` response)
+    pub concurrency: bool,
+    /// Cargo feature the operation is conditional on (`@cfg`), emitted as `x-cfg`
+    pub cfg_feature: Option<String>,
+    /// First API version with the operation (`@min-version`), emitted as `x-min-version`
+    pub min_version: Option<String>,
+    /// Last API version with the operation (`@max-version`), emitted as `x-max-version`
+    pub max_version: Option<String>,
+    /// Request count and window (`@ratelimit`), emitted as `x-ratelimit`
+    pub rate_limit: Option<(u64, String)>,
+    /// Removal date (`@sunset`), emitted as `x-sunset` and a `Sunset` response header
+    pub sunset: Option<String>,
+    /// Callback operation ID (`@async-job`), emitted as a `202` response and a `callbacks` entry
+    pub async_job_callback: Option<String>,
     /// Path parameter documentation from `# Path Parameters` section
     pub path_params: Vec<PathParamDoc>,
+    /// Path and query parameters documented with `@param` and `@query`
+    pub params: Vec<ParamDoc>,
+    /// Status codes added by `@errors`, emitted as `x-rovo-errors` so the router can
+    /// swap in its configured set
+    pub standard_errors: Vec<u16>,
+    /// Query parameters added by `@paginated`, emitted as `x-rovo-paginated` so the
+    /// router can apply its pagination style
+    pub pagination_params: Vec<String>,
+    /// Operation-level servers from `@server`, as (URL, description)
+    pub servers: Vec<(String, String)>,
+    /// Link to fuller documentation from `@external-docs`, as (URL, description)
+    pub external_docs: Option<(String, String)>,
+    /// Operation-level `x-` extensions from `@extension`, as (key, JSON text)
+    pub extensions: Vec<(String, String)>,
+    /// Response headers documented with `@header`
+    pub headers: Vec<HeaderDoc>,
+    /// Response links documented with `@link`
+    pub links: Vec<LinkDoc>,
+    /// Request body type and description (`@body`)
+    pub body: Option<BodyDoc>,
+    /// Non-fatal problems to report as compiler warnings
+    pub warnings: Vec<ParseWarning>,
+    /// Whether `#[rovo(strict_responses)]` was set
+    pub strict_responses: bool,
+    /// Whether `#[rovo(code_samples)]` was set (the router adds `x-codeSamples`)
+    pub code_samples: bool,
+    /// Tag descriptions passed down by `#[rovo_module]`, as `(tag, description)`
+    pub tag_descriptions: Vec<(String, String)>,
 }
 
 /// Information about path parameters extracted from function signature
@@ -59,6 +229,22 @@ pub struct FuncItem {
     pub state_type: Option<TokenStream>,
     /// Path parameter info extracted from function signature
     pub path_params: Option<PathParamInfo>,
+    /// Declared return type (None when the function returns `()`)
+    pub return_type: Option<TokenStream>,
+    /// Set when the handler is a method in a `#[rovo]` impl block
+    pub method: Option<MethodInfo>,
+}
+
+/// A handler defined as a method in a `#[rovo]` impl block
+#[derive(Clone)]
+pub struct MethodInfo {
+    /// The type the impl block is for
+    pub self_ty: TokenStream,
+    /// Whether the method takes `self`, which is then extracted from the router state
+    pub has_receiver: bool,
+    pub is_async: bool,
+    /// Types of the parameters after the receiver, with `Self` replaced by `self_ty`
+    pub param_types: Vec<TokenStream>,
 }
 
 impl FuncItem {
@@ -23,10 +23,45 @@ pub fn levenshtein_distance(s1: &str, s2: &str) -> usize {
     matrix[len1][len2]
 }
 
+/// Every metadata annotation, without the `@`
+///
+/// Keep in sync with `rovo_lsp::utils::ANNOTATIONS`.
+pub const ANNOTATIONS: &[&str] = &[
+    "tag",
+    "security",
+    "security-and",
+    "id",
+    "summary",
+    "hidden",
+    "i18n",
+    "idempotent",
+    "concurrency",
+    "cfg",
+    "min-version",
+    "max-version",
+    "ratelimit",
+    "sunset",
+    "server",
+    "external-docs",
+    "async-job",
+    "param",
+    "query",
+    "paginated",
+    "body",
+    "errors",
+    "header",
+    "cacheable",
+    "deprecated",
+    "deprecated-message",
+    "content-type",
+    "produces",
+    "link",
+    "extension",
+    "rovo-ignore",
+];
+
 /// Find the closest matching annotation
 pub fn find_closest_annotation(input: &str) -> Option<&'static str> {
-    const ANNOTATIONS: &[&str] = &["tag", "security", "id", "hidden", "rovo-ignore"];
-
     let input_lower = input.to_lowercase();
     let mut best_match = None;
     let mut best_distance = usize::MAX;
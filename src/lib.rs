@@ -93,14 +93,42 @@
 //!
 //! **Available metadata annotations:**
 //! - `@tag <name>` - Group endpoints by tags
-//! - `@security <scheme>` - Specify security requirements
+//! - `@security <scheme> [scopes...]` - Specify security requirements
+//! - `@security-and <scheme> <scheme>...` - Require several schemes together
 //! - `@id <operation_id>` - Set custom operation ID
+//! - `@summary <text>` - Set the summary instead of using the first doc line
 //! - `@hidden` - Hide endpoint from documentation
+//! - `@i18n` - Document `Accept-Language` and `Content-Language` headers
+//! - `@idempotent` - Document a required `Idempotency-Key` header
+//! - `@concurrency` - Document a required `If-Match` header and a `412` response
+//! - `@cfg <feature>` - Mark the endpoint as feature-gated via an `x-cfg` extension
+//! - `@min-version <version>` / `@max-version <version>` - Record the API versions with the endpoint
+//! - `@ratelimit <count> per-<window>` - Document the rate limit via an `x-ratelimit` extension
+//! - `@sunset <date>` - Announce a removal date (`x-sunset` and a `Sunset` response header)
+//! - `@server <url> [description]` - Add an operation-level server
+//! - `@external-docs <url> [description]` - Link the operation to fuller documentation
+//! - `@async-job <callbackOperationId>` - Document a `202 Accepted` response and a callback
+//! - `@param <name> <type> <description>` - Document a path or query parameter
+//! - `@query <name> <type> <description>` - Document a query parameter
+//! - `@paginated` - Document `page`, `per_page` and `sort` query parameters (see [`Router::with_pagination_style`])
+//! - `@body <type> [<content-type>] - <description>` - Document the request body
+//! - `@header <status> <name> <type> <description>` - Document a response header
+//! - `@cacheable <directive>` - Document a `Cache-Control` header on the success response
+//! - `@content-type <status> <content-type>` - Override the content type of a response
+//! - `@produces <content-type>` - Override the content type of the `200` response
+//! - `@link <status> <operationId> <name>=<expression>` - Link a response to another operation
+//! - `@deprecated [reason]` - Mark the operation as deprecated with an optional reason
+//! - `@deprecated-message <message>` - Explain a deprecation via `x-deprecated-message`
+//! - `@extension <x-key> <json_value>` - Add an `x-` extension field to the operation
+//! - `@errors standard` - Add the standard `400`/`401`/`403`/`500` error responses
 //!
 //! **Special directives:**
 //! - `@rovo-ignore` - Stop processing annotations after this point
+//!
+//! **Module-level annotations** (in the doc comments of a `#[rovo_module]` module):
+//! - `@tag-description <tag> <description>` - Describe a tag in the spec's `tags` array
 
-pub use rovo_macros::rovo;
+pub use rovo_macros::{rovo, rovo_module};
 
 // Re-export aide for convenience
 pub use aide;
@@ -109,6 +137,44 @@ pub use aide;
 #[doc(hidden)]
 pub use ::schemars as __schemars;
 
+/// `serde_json` re-export, used internally by `#[rovo]` to build spec extensions.
+#[doc(hidden)]
+pub use ::serde_json as __serde_json;
+
+/// `inventory` re-export, used internally by `#[rovo]` to register handlers.
+#[doc(hidden)]
+pub use ::inventory as __inventory;
+
+/// A `#[rovo]` handler, registered by the macro for [`Router::unrouted_handlers`].
+#[doc(hidden)]
+pub struct __RegisteredHandler {
+    /// The handler's path, e.g. `my_app::users::list_users`
+    pub name: &'static str,
+}
+
+inventory::collect!(__RegisteredHandler);
+
+/// Marks handler return types that plausibly produce the documented response `D`.
+///
+/// Used by `#[rovo(strict_responses)]` to link the documented `200` response type
+/// to the handler's return type at compile time.
+#[doc(hidden)]
+#[diagnostic::on_unimplemented(
+    message = "handler returns `{Self}`, which doesn't match the documented 200 response `{D}`",
+    label = "expected the documented response type `{D}` here",
+    note = "`#[rovo(strict_responses)]` requires the handler to return the documented 200 response type, optionally wrapped in `Result<_, E>` or `(StatusCode, _)`"
+)]
+pub trait __DocumentedResponse<D> {}
+
+impl<T> __DocumentedResponse<T> for T {}
+impl<T, E> __DocumentedResponse<T> for Result<T, E> {}
+impl<T> __DocumentedResponse<T> for (http::StatusCode, T) {}
+impl<T, E> __DocumentedResponse<T> for Result<(http::StatusCode, T), E> {}
+
+/// Compile-time assertion emitted by `#[rovo(strict_responses)]`.
+#[doc(hidden)]
+pub const fn __assert_documented_response<R: __DocumentedResponse<D>, D>() {}
+
 /// Re-export of the [`schemars`](::schemars) crate with rovo's `JsonSchema` derive.
 ///
 /// The `JsonSchema` derive exported here automatically resolves rovo's crate path,
@@ -162,11 +228,22 @@ pub use ::axum::response;
 /// ```
 pub use ::axum::http;
 
+use ::axum::body::Bytes;
+use ::axum::http::{header, HeaderMap, HeaderValue, Method, Uri};
+use ::axum::response::{IntoResponse, Redirect, Response};
+use ::axum::routing::MethodFilter;
 use ::axum::Extension;
 use aide::axum::ApiRouter as AideApiRouter;
 use aide::openapi::OpenApi;
+use indexmap::IndexMap;
+use post_process::{
+    apply_openapi_version, check_documented_responses, check_operation_ids,
+    duplicate_operation_ids, openapi_minor_version, post_process, PostProcess,
+};
 use std::sync::Arc;
 
+mod post_process;
+
 /// Trait for types that can be nested into a [`Router`].
 ///
 /// Implemented for [`Router<S>`] (same state type, preserves `OpenAPI` docs)
@@ -187,6 +264,11 @@ where
 pub trait IntoRouteHandler<S> {
     /// Register this handler on the router at the given path.
     fn register(self, router: AideApiRouter<S>, path: &str) -> AideApiRouter<S>;
+
+    /// The paths of the `#[rovo]` handlers this registers, see [`Router::unrouted_handlers`].
+    fn documented_handlers(&self) -> Vec<&'static str> {
+        Vec::new()
+    }
 }
 
 impl<S> IntoRouteHandler<S> for ApiMethodRouter<S>
@@ -196,6 +278,10 @@ where
     fn register(self, router: AideApiRouter<S>, path: &str) -> AideApiRouter<S> {
         router.api_route(path, self.inner)
     }
+
+    fn documented_handlers(&self) -> Vec<&'static str> {
+        self.handlers.clone()
+    }
 }
 
 impl<S> IntoRouteHandler<S> for ::axum::routing::MethodRouter<S>
@@ -213,10 +299,7 @@ where
 {
     fn nest_into(self, mut parent: Self, path: &str) -> Self {
         parent.inner = parent.inner.nest(path, self.inner);
-        if parent.oas_spec.is_none() && self.oas_spec.is_some() {
-            parent.oas_spec = self.oas_spec;
-            parent.oas_route = self.oas_route;
-        }
+        self.oas.merge_into_parent(&mut parent.oas, path);
         parent
     }
 }
@@ -227,10 +310,7 @@ where
 {
     fn nest_into(self, mut parent: Router<S>, path: &str) -> Router<S> {
         parent.inner = parent.inner.nest_api_service(path, self.inner);
-        if parent.oas_spec.is_none() && self.oas_spec.is_some() {
-            parent.oas_spec = self.oas_spec;
-            parent.oas_route = self.oas_route;
-        }
+        self.oas.merge_into_parent(&mut parent.oas, path);
         parent
     }
 }
@@ -245,6 +325,196 @@ where
     }
 }
 
+/// A spec transform registered with [`Router::transform`]
+type OpenApiTransform = Box<dyn FnOnce(&mut OpenApi) + Send + Sync>;
+
+/// The router with the spec routes wired in, or the inner router if there's no spec
+type WiredRouters<S> = (Option<::axum::Router<S>>, Option<AideApiRouter<S>>);
+
+/// The `OpenAPI` settings a [`Router`] collects before it's finished
+struct OasConfig {
+    spec: Option<OpenApi>,
+    route: String,
+    cache_control: Option<HeaderValue>,
+    filename: Option<String>,
+    basic_auth: Option<String>,
+    version: Option<String>,
+    route_methods: Option<MethodFilter>,
+    ui_paths: Vec<String>,
+    standard_errors: Option<Vec<(u16, String)>>,
+    pagination_style: Option<PaginationStyle>,
+    transforms: Vec<OpenApiTransform>,
+    routed_handlers: Vec<&'static str>,
+    strict_operation_ids: bool,
+    short_schema_names: bool,
+    require_documented_responses: bool,
+}
+
+impl Default for OasConfig {
+    fn default() -> Self {
+        Self {
+            spec: None,
+            route: "/api.json".to_string(),
+            cache_control: None,
+            filename: None,
+            basic_auth: None,
+            version: None,
+            route_methods: None,
+            ui_paths: Vec::new(),
+            standard_errors: None,
+            pagination_style: None,
+            transforms: Vec::new(),
+            routed_handlers: Vec::new(),
+            strict_operation_ids: false,
+            short_schema_names: false,
+            require_documented_responses: false,
+        }
+    }
+}
+
+impl OasConfig {
+    /// Merge the settings of a router nested at `prefix` into its parent's
+    ///
+    /// Settings the parent already has win, lists are concatenated and
+    /// strictness flags are combined. The child's docs UI paths are relative
+    /// to `prefix`, so they're stored with it to match where they're served.
+    fn merge_into_parent(self, parent: &mut Self, prefix: &str) {
+        if parent.spec.is_none() && self.spec.is_some() {
+            parent.spec = self.spec;
+            parent.route = self.route;
+            parent.cache_control = self.cache_control;
+            parent.filename = self.filename;
+            parent.basic_auth = self.basic_auth;
+            parent.version = self.version;
+            parent.route_methods = self.route_methods;
+        }
+        if parent.standard_errors.is_none() {
+            parent.standard_errors = self.standard_errors;
+        }
+        if parent.pagination_style.is_none() {
+            parent.pagination_style = self.pagination_style;
+        }
+        let prefix = prefix.trim_end_matches('/');
+        parent.ui_paths.extend(
+            self.ui_paths
+                .into_iter()
+                .map(|ui_path| match ui_path.as_str() {
+                    "/" if !prefix.is_empty() => prefix.to_string(),
+                    _ => format!("{prefix}{ui_path}"),
+                }),
+        );
+        parent.transforms.extend(self.transforms);
+        parent.routed_handlers.extend(self.routed_handlers);
+        parent.strict_operation_ids |= self.strict_operation_ids;
+        parent.short_schema_names |= self.short_schema_names;
+        parent.require_documented_responses |= self.require_documented_responses;
+    }
+}
+
+/// Error returned by fallible [`Router`] configuration methods
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum RouterError {
+    /// The route given to [`Router::try_with_oas_route`] has no path once its
+    /// extension is removed (e.g. `""`, `"/"` or `".json"`)
+    EmptyOasRoute(String),
+    /// [`Router::require_documented_responses`] is set and these operations,
+    /// listed as `METHOD /path (operation ID)`, document no responses
+    UndocumentedResponses(Vec<String>),
+    /// [`Router::strict`] is set and these operation IDs are shared by more
+    /// than one operation
+    DuplicateOperationIds(Vec<String>),
+    /// [`Router::with_short_schema_names`] is set and these component schemas
+    /// would all be renamed to `short`
+    SchemaNameCollision {
+        /// The name the schemas shorten to
+        short: String,
+        /// The full names of the colliding schemas
+        names: Vec<String>,
+    },
+    /// The version given to [`Router::try_with_openapi_version`] isn't a
+    /// `3.0.x` or `3.1.x` version
+    UnsupportedOpenApiVersion(String),
+    /// The spec breaks the structural rules of the version set with
+    /// [`Router::with_openapi_version`]
+    InvalidSpec {
+        /// The `openapi` version the spec was checked against
+        version: String,
+        /// What's wrong with the spec
+        problems: Vec<String>,
+    },
+}
+
+impl std::fmt::Display for RouterError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::EmptyOasRoute(route) => write!(
+                f,
+                "invalid OpenAPI route {route:?}: it needs a path, like \"/openapi\""
+            ),
+            Self::UndocumentedResponses(operations) => write!(
+                f,
+                "operations without documented responses: {}; add a `# Responses` section to their handlers",
+                operations.join(", ")
+            ),
+            Self::DuplicateOperationIds(ids) => write!(
+                f,
+                "duplicate OpenAPI operation IDs: {}; give the handlers distinct `@id`s",
+                ids.join(", ")
+            ),
+            Self::SchemaNameCollision { short, names } => write!(
+                f,
+                "component schemas {} would all be named `{short}`; rename one of the types or remove `with_short_schema_names`",
+                names
+                    .iter()
+                    .map(|name| format!("`{name}`"))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            Self::UnsupportedOpenApiVersion(version) => write!(
+                f,
+                "unsupported OpenAPI version {version:?}: use a 3.0.x or 3.1.x version, like \"3.1.0\""
+            ),
+            Self::InvalidSpec { version, problems } => write!(
+                f,
+                "the spec isn't a valid OpenAPI {version} document: {}",
+                problems.join("; ")
+            ),
+        }
+    }
+}
+
+impl std::error::Error for RouterError {}
+
+/// Query parameters documented by `@paginated`, see [`Router::with_pagination_style`]
+///
+/// Every style also documents a `sort` parameter. The parameters are optional.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum PaginationStyle {
+    /// `page` and `per_page` integers
+    #[default]
+    Page,
+    /// `offset` and `limit` integers
+    Offset,
+    /// An opaque `cursor` string and a `limit` integer
+    Cursor,
+}
+
+impl PaginationStyle {
+    /// The name and description replacing a `page`/`per_page` parameter in this style
+    fn replacement(self, name: &str) -> Option<(&'static str, &'static str)> {
+        match (self, name) {
+            (Self::Offset, "page") => Some(("offset", "Number of items to skip")),
+            (Self::Cursor, "page") => Some(("cursor", "Cursor from the previous page")),
+            (Self::Offset | Self::Cursor, "per_page") => {
+                Some(("limit", "Maximum number of items to return"))
+            }
+            _ => None,
+        }
+    }
+}
+
 /// A drop-in replacement for `axum::Router` that adds `OpenAPI` documentation support.
 ///
 /// This Router works seamlessly with handlers decorated with `#[rovo]` and provides
@@ -273,8 +543,7 @@ where
 /// ```
 pub struct Router<S = ()> {
     inner: AideApiRouter<S>,
-    oas_spec: Option<OpenApi>,
-    oas_route: String,
+    oas: OasConfig,
 }
 
 impl<S> Router<S>
@@ -286,8 +555,7 @@ where
     pub fn new() -> Self {
         Self {
             inner: AideApiRouter::new(),
-            oas_spec: None,
-            oas_route: "/api.json".to_string(),
+            oas: OasConfig::default(),
         }
     }
 
@@ -300,6 +568,9 @@ where
     where
         M: IntoRouteHandler<S>,
     {
+        self.oas
+            .routed_handlers
+            .extend(method_router.documented_handlers());
         self.inner = method_router.register(self.inner, path);
         self
     }
@@ -339,6 +610,10 @@ where
     ///
     /// This automatically sets up endpoints for both JSON and YAML formats.
     ///
+    /// When a documentation UI is mounted (e.g. [`with_swagger`](Self::with_swagger)),
+    /// requests to the JSON route that prefer `text/html` (such as a browser
+    /// navigating to it) are redirected to that UI instead.
+    ///
     /// # Memory Efficiency
     ///
     /// The `OpenAPI` spec is serialized to JSON and YAML at startup, then the
@@ -347,10 +622,19 @@ where
     ///
     /// If you need runtime access to the `OpenApi` struct (e.g., in handlers via
     /// `Extension<Arc<OpenApi>>`), use [`finish_api_with_extension`](Self::finish_api_with_extension) instead.
+    ///
+    /// # Call Order
+    ///
+    /// The spec is only generated when the router is finished, so `with_oas` can
+    /// be called before or after [`route`](Self::route) and [`nest`](Self::nest):
+    /// every documented route ends up in the spec either way. Documentation UIs
+    /// like [`with_swagger`](Self::with_swagger) do point at the spec route set
+    /// when they're added, so call `with_oas` or
+    /// [`with_oas_route`](Self::with_oas_route) before them.
     #[must_use]
     pub fn with_oas(mut self, api: OpenApi) -> Self {
-        self.oas_spec = Some(api);
-        self.oas_route = "/api.json".to_string();
+        self.oas.spec = Some(api);
+        self.oas.route = "/api.json".to_string();
         self
     }
 
@@ -360,6 +644,11 @@ where
     /// For example, "/openapi" creates:
     /// - /openapi.json
     /// - /openapi.yaml
+    /// - /openapi.yml
+    ///
+    /// A `.json`, `.yaml` or `.yml` extension on `route` is ignored, so
+    /// "/openapi.yml" serves the same three endpoints. A missing leading `/` is
+    /// added.
     ///
     /// # Memory Efficiency
     ///
@@ -369,31 +658,467 @@ where
     ///
     /// If you need runtime access to the `OpenApi` struct (e.g., in handlers via
     /// `Extension<Arc<OpenApi>>`), use [`finish_api_with_extension`](Self::finish_api_with_extension) instead.
-    pub fn with_oas_route(mut self, api: OpenApi, route: impl Into<String>) -> Self {
-        self.oas_spec = Some(api);
-        let route_str = route.into();
-        // Remove extension if provided
-        let base_route = route_str
+    ///
+    /// # Panics
+    ///
+    /// Panics if `route` has no path, like `""` or `"/"`; use
+    /// [`try_with_oas_route`](Self::try_with_oas_route) to handle that instead.
+    #[must_use]
+    #[track_caller]
+    pub fn with_oas_route(self, api: OpenApi, route: impl Into<String>) -> Self {
+        self.try_with_oas_route(api, route)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Configure `OpenAPI` spec with custom base route, rejecting invalid routes
+    ///
+    /// Like [`with_oas_route`](Self::with_oas_route), but returns an error
+    /// instead of panicking.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RouterError::EmptyOasRoute`] if `route` has no path once its
+    /// extension is removed.
+    ///
+    /// ```no_run
+    /// # use rovo::Router;
+    /// # use rovo::aide::openapi::OpenApi;
+    /// let app = Router::<()>::new()
+    ///     .try_with_oas_route(OpenApi::default(), "openapi.yml")
+    ///     .expect("valid route"); // serves /openapi.json, /openapi.yaml and /openapi.yml
+    /// ```
+    pub fn try_with_oas_route(
+        mut self,
+        api: OpenApi,
+        route: impl Into<String>,
+    ) -> Result<Self, RouterError> {
+        self.oas.route = normalize_oas_route(&route.into())?;
+        self.oas.spec = Some(api);
+        Ok(self)
+    }
+
+    /// Also serve the JSON spec for `method` requests, in addition to `GET`
+    ///
+    /// Some security setups fetch the spec with `POST` so it is not cached or
+    /// logged like a `GET`. Call this once per extra method.
+    ///
+    /// ```no_run
+    /// # use rovo::Router;
+    /// # use rovo::aide::openapi::OpenApi;
+    /// # use rovo::http::Method;
+    /// let app = Router::<()>::new()
+    ///     .with_oas(OpenApi::default())
+    ///     .with_oas_route_method(Method::POST);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `method` is not a standard HTTP method.
+    #[must_use]
+    #[track_caller]
+    pub fn with_oas_route_method(mut self, method: Method) -> Self {
+        let filter = MethodFilter::try_from(method).unwrap_or_else(|err| {
+            panic!("Unsupported method for the OpenAPI route: {}", err.method())
+        });
+        self.oas.route_methods = Some(
+            self.oas
+                .route_methods
+                .map_or(filter, |methods| methods.or(filter)),
+        );
+        self
+    }
+
+    /// Set a `Cache-Control` header on the `OpenAPI` spec endpoints
+    ///
+    /// Useful for CDN-fronted deployments where the spec only changes on deploy.
+    /// By default no `Cache-Control` header is sent.
+    ///
+    /// ```no_run
+    /// # use rovo::Router;
+    /// # use rovo::aide::openapi::OpenApi;
+    /// let app = Router::<()>::new()
+    ///     .with_oas(OpenApi::default())
+    ///     .with_oas_cache_control("public, max-age=3600");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `value` is not a valid HTTP header value.
+    #[must_use]
+    #[track_caller]
+    pub fn with_oas_cache_control(mut self, value: &str) -> Self {
+        let value = HeaderValue::from_str(value)
+            .unwrap_or_else(|_| panic!("Invalid Cache-Control header value: {value:?}"));
+        self.oas.cache_control = Some(value);
+        self
+    }
+
+    /// Let the spec endpoints be downloaded as a file named `name`
+    ///
+    /// Requests with a `download` query parameter (e.g. `/api.json?download=1`)
+    /// get a `Content-Disposition: attachment` header, so a "Download spec" link
+    /// saves `name.json` (or `name.yaml` / `name.yml` from the YAML endpoints)
+    /// instead of opening it. Any extension on `name` is ignored.
+    ///
+    /// ```no_run
+    /// # use rovo::Router;
+    /// # use rovo::aide::openapi::OpenApi;
+    /// let app = Router::<()>::new()
+    ///     .with_oas(OpenApi::default())
+    ///     .with_oas_filename("my-api");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `name` is empty or contains characters that can't appear in a
+    /// quoted filename (`"`, `\`, `/` or non-printable ASCII).
+    #[must_use]
+    #[track_caller]
+    pub fn with_oas_filename(mut self, name: &str) -> Self {
+        let base_name = name
             .strip_suffix(".json")
-            .or_else(|| route_str.strip_suffix(".yaml"))
-            .or_else(|| route_str.strip_suffix(".yml"))
-            .unwrap_or(&route_str);
-        self.oas_route = format!("{base_route}.json");
+            .or_else(|| name.strip_suffix(".yaml"))
+            .or_else(|| name.strip_suffix(".yml"))
+            .unwrap_or(name);
+        let is_valid = !base_name.trim().is_empty()
+            && base_name
+                .chars()
+                .all(|ch| (ch.is_ascii_graphic() || ch == ' ') && !matches!(ch, '"' | '\\' | '/'));
+        assert!(is_valid, "Invalid OpenAPI download filename: {name:?}");
+        self.oas.filename = Some(base_name.to_string());
+        self
+    }
+
+    /// Protect the `OpenAPI` spec and documentation UI routes with HTTP basic auth
+    ///
+    /// Requests to those routes without matching credentials get a `401` with a
+    /// `WWW-Authenticate: Basic` challenge. API routes are unaffected.
+    ///
+    /// ```no_run
+    /// # use rovo::Router;
+    /// # use rovo::aide::openapi::OpenApi;
+    /// let app = Router::<()>::new()
+    ///     .with_oas(OpenApi::default())
+    ///     .with_oas_basic_auth("docs", "s3cret");
+    /// ```
+    #[must_use]
+    pub fn with_oas_basic_auth(mut self, user: &str, pass: &str) -> Self {
+        self.oas.basic_auth = Some(base64_encode(format!("{user}:{pass}").as_bytes()));
+        self
+    }
+
+    /// Set the `openapi` version of the served spec
+    ///
+    /// aide generates `3.1` documents, which are converted when the router is
+    /// finished:
+    ///
+    /// - For `3.1.x`, schemas still using the `3.0` form `nullable: true` (e.g.
+    ///   from hand-written `JsonSchema` impls) are rewritten to a `type` array
+    ///   containing `"null"`. Webhooks are kept.
+    /// - For `3.0.x`, `type` arrays containing `"null"` become `nullable: true`,
+    ///   and other `3.1`-only schema keywords are rewritten to their `3.0` form.
+    ///
+    /// Finishing fails with [`RouterError::InvalidSpec`] if the document breaks
+    /// the version's structural rules, like webhooks in a `3.0` spec.
+    ///
+    /// ```no_run
+    /// # use rovo::Router;
+    /// let app = Router::<()>::new().with_openapi_version("3.1.0");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `version` isn't a `3.0.x` or `3.1.x` version; use
+    /// [`try_with_openapi_version`](Self::try_with_openapi_version) to handle
+    /// that instead.
+    #[must_use]
+    #[track_caller]
+    pub fn with_openapi_version(self, version: &str) -> Self {
+        self.try_with_openapi_version(version)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Set the `openapi` version of the served spec, rejecting unsupported versions
+    ///
+    /// Like [`with_openapi_version`](Self::with_openapi_version), but returns an
+    /// error instead of panicking.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RouterError::UnsupportedOpenApiVersion`] if `version` isn't a
+    /// `3.0.x` or `3.1.x` version.
+    ///
+    /// ```no_run
+    /// # use rovo::Router;
+    /// let app = Router::<()>::new()
+    ///     .try_with_openapi_version("3.0.3")
+    ///     .expect("supported version");
+    /// ```
+    pub fn try_with_openapi_version(mut self, version: &str) -> Result<Self, RouterError> {
+        if openapi_minor_version(version).is_none() {
+            return Err(RouterError::UnsupportedOpenApiVersion(version.to_string()));
+        }
+        self.oas.version = Some(version.to_string());
+        Ok(self)
+    }
+
+    /// Refuse to finish the API while operations share an operation ID
+    ///
+    /// Duplicate IDs (e.g. from same-named handlers in two modules, which both
+    /// default to the function name) break generated clients. When set, they're
+    /// checked when the API is finished: [`try_finish`](Self::try_finish) returns
+    /// [`RouterError::DuplicateOperationIds`] and [`finish`](Self::finish)
+    /// panics with the same message. See also
+    /// [`validate_operation_ids`](Self::validate_operation_ids).
+    ///
+    /// ```no_run
+    /// # use rovo::Router;
+    /// let app = Router::<()>::new().strict();
+    /// ```
+    #[must_use]
+    pub const fn strict(mut self) -> Self {
+        self.oas.strict_operation_ids = true;
+        self
+    }
+
+    /// Check that no two operations share an operation ID
+    ///
+    /// # Errors
+    ///
+    /// Returns the duplicated operation IDs, sorted.
+    ///
+    /// ```no_run
+    /// # use rovo::Router;
+    /// let app = Router::<()>::new()
+    ///     .validate_operation_ids()
+    ///     .unwrap_or_else(|ids| panic!("duplicate operation IDs: {ids:?}"));
+    /// ```
+    pub fn validate_operation_ids(self) -> Result<Self, Vec<String>> {
+        let mut api = OpenApi::default();
+        let _ = self.inner.clone().finish_api(&mut api);
+
+        let duplicates = duplicate_operation_ids(&api);
+        if duplicates.is_empty() {
+            Ok(self)
+        } else {
+            Err(duplicates)
+        }
+    }
+
+    /// Name component schemas by their last path segment
+    ///
+    /// Types with a module-qualified schema name (e.g. `crate::models::User`)
+    /// are listed as `User` in `components.schemas`, with every `$ref` updated.
+    /// If two schemas shorten to the same name, [`try_finish`](Self::try_finish)
+    /// returns [`RouterError::SchemaNameCollision`] and [`finish`](Self::finish)
+    /// panics with the same message.
+    ///
+    /// ```no_run
+    /// # use rovo::Router;
+    /// let app = Router::<()>::new().with_short_schema_names();
+    /// ```
+    #[must_use]
+    pub const fn with_short_schema_names(mut self) -> Self {
+        self.oas.short_schema_names = true;
+        self
+    }
+
+    /// Refuse to finish the API while any operation documents no responses
+    ///
+    /// For enforcing documentation completeness: [`try_finish`](Self::try_finish)
+    /// returns [`RouterError::UndocumentedResponses`] naming the offending
+    /// operations, and [`finish`](Self::finish) panics with the same message.
+    /// Responses added by [`with_standard_errors`](Self::with_standard_errors)
+    /// don't count.
+    ///
+    /// ```no_run
+    /// # use rovo::Router;
+    /// let app = Router::<()>::new()
+    ///     .require_documented_responses()
+    ///     .try_finish()
+    ///     .unwrap_or_else(|err| panic!("{err}"));
+    /// ```
+    #[must_use]
+    pub const fn require_documented_responses(mut self) -> Self {
+        self.oas.require_documented_responses = true;
+        self
+    }
+
+    /// Modify the generated `OpenAPI` spec before it's served
+    ///
+    /// `f` runs after the routes' operations have been added to the spec and
+    /// rovo's own passes (standard errors, pagination, short schema names, ...)
+    /// have run, just before it's serialized, so it sees the spec as served. Use
+    /// it for anything the doc comments can't express, like registering the
+    /// security schemes that `@security` refers to or setting `info.version`.
+    /// Transforms run in the order they were added.
+    ///
+    /// ```no_run
+    /// # use rovo::Router;
+    /// # use rovo::aide::openapi::{OpenApi, ReferenceOr, SecurityScheme};
+    /// let app = Router::<()>::new()
+    ///     .with_oas(OpenApi::default())
+    ///     .transform(|api| {
+    ///         api.info.version = "1.2.0".to_string();
+    ///         let components = api.components.get_or_insert_with(Default::default);
+    ///         components.security_schemes.insert(
+    ///             "bearer".to_string(),
+    ///             ReferenceOr::Item(SecurityScheme::Http {
+    ///                 scheme: "bearer".to_string(),
+    ///                 bearer_format: Some("JWT".to_string()),
+    ///                 description: None,
+    ///                 extensions: Default::default(),
+    ///             }),
+    ///         );
+    ///     });
+    /// ```
+    #[must_use]
+    pub fn transform<F>(mut self, f: F) -> Self
+    where
+        F: FnOnce(&mut OpenApi) + Send + Sync + 'static,
+    {
+        self.oas.transforms.push(Box::new(f));
+        self
+    }
+
+    /// Define a security scheme in the spec's `components.securitySchemes`
+    ///
+    /// `@security <name>` only references a scheme by name; this defines it so the
+    /// spec is valid and documentation UIs can offer to authorize requests. A
+    /// scheme with the same name already on your `OpenApi` is replaced.
+    ///
+    /// ```no_run
+    /// # use rovo::Router;
+    /// # use rovo::aide::openapi::{OpenApi, SecurityScheme};
+    /// let app = Router::<()>::new()
+    ///     .with_oas(OpenApi::default())
+    ///     .with_security_scheme(
+    ///         "basic",
+    ///         SecurityScheme::Http {
+    ///             scheme: "basic".to_string(),
+    ///             bearer_format: None,
+    ///             description: None,
+    ///             extensions: Default::default(),
+    ///         },
+    ///     );
+    /// ```
+    #[must_use]
+    pub fn with_security_scheme(self, name: &str, scheme: aide::openapi::SecurityScheme) -> Self {
+        let name = name.to_string();
+        self.transform(move |api| {
+            api.components
+                .get_or_insert_with(Default::default)
+                .security_schemes
+                .insert(name, aide::openapi::ReferenceOr::Item(scheme));
+        })
+    }
+
+    /// Define an HTTP bearer token security scheme named `name`
+    ///
+    /// ```no_run
+    /// # use rovo::Router;
+    /// # use rovo::aide::openapi::OpenApi;
+    /// // Handlers reference it with `@security bearer`
+    /// let app = Router::<()>::new()
+    ///     .with_oas(OpenApi::default())
+    ///     .with_bearer_auth("bearer");
+    /// ```
+    #[must_use]
+    pub fn with_bearer_auth(self, name: &str) -> Self {
+        self.with_security_scheme(
+            name,
+            aide::openapi::SecurityScheme::Http {
+                scheme: "bearer".to_string(),
+                bearer_format: None,
+                description: None,
+                extensions: IndexMap::new(),
+            },
+        )
+    }
+
+    /// Define an API key security scheme named `name`, read from `key_name` at `location`
+    ///
+    /// ```no_run
+    /// # use rovo::Router;
+    /// # use rovo::aide::openapi::{ApiKeyLocation, OpenApi};
+    /// // Handlers reference it with `@security apiKey`
+    /// let app = Router::<()>::new()
+    ///     .with_oas(OpenApi::default())
+    ///     .with_api_key("apiKey", ApiKeyLocation::Header, "X-API-Key");
+    /// ```
+    #[must_use]
+    pub fn with_api_key(
+        self,
+        name: &str,
+        location: aide::openapi::ApiKeyLocation,
+        key_name: &str,
+    ) -> Self {
+        self.with_security_scheme(
+            name,
+            aide::openapi::SecurityScheme::ApiKey {
+                location,
+                name: key_name.to_string(),
+                description: None,
+                extensions: IndexMap::new(),
+            },
+        )
+    }
+
+    /// Replace the error responses added by `@errors standard`
+    ///
+    /// By default `@errors standard` documents `400`, `401`, `403` and `500`.
+    /// Status codes a handler documents explicitly are left untouched.
+    ///
+    /// ```no_run
+    /// # use rovo::Router;
+    /// let app = Router::<()>::new()
+    ///     .with_standard_errors([(400, "Invalid input"), (429, "Too many requests")]);
+    /// ```
+    #[must_use]
+    pub fn with_standard_errors<D: Into<String>>(
+        mut self,
+        errors: impl IntoIterator<Item = (u16, D)>,
+    ) -> Self {
+        self.oas.standard_errors = Some(
+            errors
+                .into_iter()
+                .map(|(code, description)| (code, description.into()))
+                .collect(),
+        );
+        self
+    }
+
+    /// Choose the query parameters documented by `@paginated`
+    ///
+    /// Defaults to [`PaginationStyle::Page`]. Parameters a handler documents
+    /// explicitly with `@query` are left untouched.
+    ///
+    /// ```no_run
+    /// # use rovo::{PaginationStyle, Router};
+    /// let app = Router::<()>::new().with_pagination_style(PaginationStyle::Cursor);
+    /// ```
+    #[must_use]
+    pub const fn with_pagination_style(mut self, style: PaginationStyle) -> Self {
+        self.oas.pagination_style = Some(style);
         self
     }
 
     /// Add Swagger UI route at the specified path
+    ///
+    /// The first documentation UI mounted also becomes the target for browsers
+    /// opening the JSON spec route directly (see [`with_oas`](Self::with_oas)).
     #[cfg(feature = "swagger")]
     #[must_use]
     pub fn with_swagger(mut self, swagger_path: &str) -> Self
     where
         S: Clone + Send + Sync + 'static,
     {
-        let api_route = self.oas_route.clone();
+        let api_route = self.oas.route.clone();
         self.inner = self.inner.route(
             swagger_path,
             aide::swagger::Swagger::new(&api_route).axum_route(),
         );
+        self.oas.ui_paths.push(swagger_path.to_string());
         self
     }
 
@@ -404,10 +1129,11 @@ where
     where
         S: Clone + Send + Sync + 'static,
     {
-        let api_route = self.oas_route.clone();
+        let api_route = self.oas.route.clone();
         self.inner = self
             .inner
             .route(redoc_path, aide::redoc::Redoc::new(&api_route).axum_route());
+        self.oas.ui_paths.push(redoc_path.to_string());
         self
     }
 
@@ -418,91 +1144,236 @@ where
     where
         S: Clone + Send + Sync + 'static,
     {
-        let api_route = self.oas_route.clone();
+        let api_route = self.oas.route.clone();
         self.inner = self.inner.route(
             scalar_path,
             aide::scalar::Scalar::new(&api_route).axum_route(),
         );
+        self.oas.ui_paths.push(scalar_path.to_string());
+        self
+    }
+
+    /// Add Scalar UI route at the specified path with custom configuration
+    ///
+    /// Unlike [`with_scalar`](Self::with_scalar), the page loads the Scalar
+    /// bundle from the jsDelivr CDN so the configuration can be embedded.
+    ///
+    /// ```no_run
+    /// # use rovo::{Router, ScalarConfig, ScalarLayout};
+    /// # use rovo::aide::openapi::OpenApi;
+    /// let app = Router::<()>::new()
+    ///     .with_oas(OpenApi::default())
+    ///     .with_scalar_config(
+    ///         "/scalar",
+    ///         ScalarConfig::new()
+    ///             .theme("purple")
+    ///             .layout(ScalarLayout::Classic)
+    ///             .show_sidebar(false),
+    ///     );
+    /// ```
+    #[cfg(feature = "scalar")]
+    #[must_use]
+    pub fn with_scalar_config(mut self, scalar_path: &str, config: ScalarConfig) -> Self
+    where
+        S: Clone + Send + Sync + 'static,
+    {
+        let html = config.into_html(&self.oas.route);
+        self.inner = self.inner.route(
+            scalar_path,
+            ::axum::routing::get(move || {
+                let html = html.clone();
+                async move { ::axum::response::Html(html) }
+            }),
+        );
+        self.oas.ui_paths.push(scalar_path.to_string());
+        self
+    }
+
+    /// Add `RapiDoc` UI route at the specified path
+    ///
+    /// The page loads the `RapiDoc` bundle from the jsDelivr CDN.
+    #[cfg(feature = "rapidoc")]
+    #[must_use]
+    pub fn with_rapidoc(mut self, rapidoc_path: &str) -> Self
+    where
+        S: Clone + Send + Sync + 'static,
+    {
+        let html = rapidoc_html(&self.oas.route);
+        self.inner = self.inner.route(
+            rapidoc_path,
+            ::axum::routing::get(move || {
+                let html = html.clone();
+                async move { ::axum::response::Html(html) }
+            }),
+        );
+        self.oas.ui_paths.push(rapidoc_path.to_string());
         self
     }
 
     /// Internal helper to wire up `OpenAPI` endpoints and extension
-    fn wire_openapi_routes(self) -> (Option<::axum::Router<S>>, Option<AideApiRouter<S>>)
+    #[allow(clippy::too_many_lines)]
+    fn wire_openapi_routes(mut self) -> Result<WiredRouters<S>, RouterError>
     where
         S: Clone + Send + Sync + 'static,
     {
-        if let Some(api) = self.oas_spec {
-            let oas_route = self.oas_route.clone();
+        let post_process_config = self.post_process_config();
+        if let Some(api) = self.oas.spec {
+            let oas_route = self.oas.route.clone();
 
             // Finish API first to populate it with routes
             let mut api_mut = api;
             let axum_router = self.inner.finish_api(&mut api_mut);
+            post_process(&mut api_mut, post_process_config)?;
 
             // Pre-serialize once at startup to avoid cloning on each request
-            let json_bytes: ::axum::body::Bytes = serde_json::to_vec(&api_mut)
-                .expect("Failed to serialize OpenAPI spec to JSON")
-                .into();
-            let yaml_bytes: ::axum::body::Bytes = serde_yaml::to_string(&api_mut)
-                .expect("Failed to serialize OpenAPI spec to YAML")
-                .into();
+            let (json_bytes, yaml_bytes): (Bytes, Bytes) = match &self.oas.version {
+                Some(version) => {
+                    let mut spec = serde_json::to_value(&api_mut)
+                        .expect("Failed to serialize OpenAPI spec to JSON");
+                    apply_openapi_version(&mut spec, version)?;
+                    (
+                        serde_json::to_vec(&spec)
+                            .expect("Failed to serialize OpenAPI spec to JSON")
+                            .into(),
+                        serde_yaml::to_string(&spec)
+                            .expect("Failed to serialize OpenAPI spec to YAML")
+                            .into(),
+                    )
+                }
+                None => (
+                    serde_json::to_vec(&api_mut)
+                        .expect("Failed to serialize OpenAPI spec to JSON")
+                        .into(),
+                    serde_yaml::to_string(&api_mut)
+                        .expect("Failed to serialize OpenAPI spec to YAML")
+                        .into(),
+                ),
+            };
+
+            // Compress once at startup too, for clients that accept gzip
+            let json_body = SpecBody::new(json_bytes);
+            let yaml_body = SpecBody::new(yaml_bytes);
 
             // Determine base route (without extension)
             let base_route = oas_route.strip_suffix(".json").unwrap_or(&oas_route);
 
-            // Add JSON endpoint - returns pre-serialized bytes
-            let json_for_handler = json_bytes;
-            let router_with_json = axum_router.route(
-                &oas_route,
-                ::axum::routing::get(move || {
-                    let json = json_for_handler.clone();
-                    async move {
-                        (
-                            [(::axum::http::header::CONTENT_TYPE, "application/json")],
-                            json,
+            let cache_control = self.oas.cache_control;
+            let ui_path = self.oas.ui_paths.first().cloned();
+            let disposition = |extension: &str| {
+                self.oas.filename.as_ref().map(|name| {
+                    HeaderValue::from_str(&format!("attachment; filename=\"{name}.{extension}\""))
+                        .expect("filename was validated by with_oas_filename")
+                })
+            };
+
+            // Add JSON endpoint - returns pre-serialized bytes, or redirects
+            // browsers to the documentation UI when one is mounted
+            let json_for_handler = json_body;
+            let json_cache_control = cache_control.clone();
+            let json_disposition = disposition("json");
+            let json_handler = move |uri: Uri, headers: HeaderMap| {
+                let json = json_for_handler.clone();
+                let cache_control = json_cache_control.clone();
+                let disposition = json_disposition.clone().filter(|_| wants_download(&uri));
+                let ui_path = ui_path.clone();
+                async move {
+                    // Caches must keep browsers' redirects apart from the JSON
+                    let vary_accept = ui_path.is_some();
+                    match ui_path {
+                        Some(ui_path) if prefers_html(&headers) && disposition.is_none() => (
+                            [(header::VARY, HeaderValue::from_static("accept"))],
+                            Redirect::temporary(&ui_path),
                         )
+                            .into_response(),
+                        _ => spec_response(
+                            "application/json",
+                            &json,
+                            cache_control,
+                            disposition,
+                            vary_accept,
+                            &headers,
+                        ),
                     }
-                }),
-            );
+                }
+            };
+            // Serve the same JSON for any extra methods from `with_oas_route_method`
+            let json_route = match self.oas.route_methods {
+                Some(methods) => {
+                    ::axum::routing::get(json_handler.clone()).on(methods, json_handler)
+                }
+                None => ::axum::routing::get(json_handler),
+            };
+            let router_with_json = axum_router.route(&oas_route, json_route);
 
             // Add YAML endpoint - returns pre-serialized bytes
             let yaml_route = format!("{base_route}.yaml");
-            let yaml_for_handler = yaml_bytes.clone();
-            let router_with_yaml = router_with_json.route(
-                &yaml_route,
-                ::axum::routing::get(move || {
-                    let yaml = yaml_for_handler.clone();
-                    async move {
-                        (
-                            [(::axum::http::header::CONTENT_TYPE, "application/x-yaml")],
-                            yaml,
-                        )
-                    }
-                }),
-            );
+            let yaml_for_handler = yaml_body.clone();
+            let yaml_cache_control = cache_control.clone();
+            let yaml_disposition = disposition("yaml");
+            let yaml_handler = move |uri: Uri, headers: HeaderMap| {
+                let yaml = yaml_for_handler.clone();
+                let cache_control = yaml_cache_control.clone();
+                let disposition = yaml_disposition.clone().filter(|_| wants_download(&uri));
+                async move {
+                    spec_response(
+                        "application/x-yaml",
+                        &yaml,
+                        cache_control,
+                        disposition,
+                        false,
+                        &headers,
+                    )
+                }
+            };
+            let router_with_yaml =
+                router_with_json.route(&yaml_route, ::axum::routing::get(yaml_handler));
 
             // Add YML endpoint (alias for YAML) - reuses pre-serialized bytes
             let yml_route = format!("{base_route}.yml");
-            let yml_for_handler = yaml_bytes;
-            let router_with_yml = router_with_yaml.route(
-                &yml_route,
-                ::axum::routing::get(move || {
-                    let yaml = yml_for_handler.clone();
-                    async move {
-                        (
-                            [(::axum::http::header::CONTENT_TYPE, "application/x-yaml")],
-                            yaml,
-                        )
-                    }
-                }),
-            );
+            let yml_for_handler = yaml_body;
+            let yml_disposition = disposition("yml");
+            let yml_handler = move |uri: Uri, headers: HeaderMap| {
+                let yaml = yml_for_handler.clone();
+                let cache_control = cache_control.clone();
+                let disposition = yml_disposition.clone().filter(|_| wants_download(&uri));
+                async move {
+                    spec_response(
+                        "application/x-yaml",
+                        &yaml,
+                        cache_control,
+                        disposition,
+                        false,
+                        &headers,
+                    )
+                }
+            };
+            let router_with_yml =
+                router_with_yaml.route(&yml_route, ::axum::routing::get(yml_handler));
+
+            // Require basic auth on the spec and UI routes if configured
+            let router_with_yml = match self.oas.basic_auth {
+                Some(credentials) => {
+                    let mut protected = vec![oas_route, yaml_route, yml_route];
+                    protected.extend(self.oas.ui_paths);
+                    require_basic_auth(router_with_yml, protected, credentials)
+                }
+                None => router_with_yml,
+            };
 
             // No Extension layer - the OpenApi struct is dropped after serialization
             // to minimize memory usage. Use finish_api_with_extension() if you need
             // runtime access to the spec.
-            (Some(router_with_yml), None)
+            Ok((Some(router_with_yml), None))
+        } else if self.oas.strict_operation_ids || self.oas.require_documented_responses {
+            // No OAS spec to serve, but the operations still need checking
+            let mut api = OpenApi::default();
+            let router = self.inner.finish_api(&mut api);
+            check_operation_ids(&api, self.oas.strict_operation_ids)?;
+            check_documented_responses(&api, self.oas.require_documented_responses)?;
+            Ok((Some(router), None))
         } else {
             // No OAS spec, return the inner router
-            (None, Some(self.inner))
+            Ok((None, Some(self.inner)))
         }
     }
 
@@ -534,29 +1405,60 @@ where
     pub fn with_state(self, state: S) -> StatefulRouter {
         StatefulRouter {
             inner: self.inner.with_state(state),
-            oas_spec: self.oas_spec,
-            oas_route: self.oas_route,
+            oas: self.oas,
         }
     }
 
     /// Finalize the API without state
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`try_finish`](Self::try_finish) would return an error.
     pub fn finish(self) -> ::axum::Router<S>
     where
         S: Clone + Send + Sync + 'static,
     {
-        let (with_oas, without_oas) = self.wire_openapi_routes();
+        self.try_finish().unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Finalize the API without state, returning an error instead of panicking
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RouterError::UndocumentedResponses`] if the router
+    /// [requires documented responses](Self::require_documented_responses) and
+    /// some operations have none, [`RouterError::DuplicateOperationIds`] if
+    /// it's [`strict`](Self::strict) and operation IDs are shared, and
+    /// [`RouterError::SchemaNameCollision`] if it uses
+    /// [short schema names](Self::with_short_schema_names) and two schemas
+    /// shorten to the same name.
+    pub fn try_finish(self) -> Result<::axum::Router<S>, RouterError>
+    where
+        S: Clone + Send + Sync + 'static,
+    {
+        let (with_oas, without_oas) = self.wire_openapi_routes()?;
 
-        with_oas.unwrap_or_else(|| {
+        Ok(with_oas.unwrap_or_else(|| {
             without_oas.map_or_else(
                 || unreachable!("Either with_oas or without_oas must be Some"),
                 std::convert::Into::into,
             )
-        })
+        }))
     }
 
     /// Finish building the API and return an axum Router for further configuration
-    pub fn finish_api(self, api: &mut aide::openapi::OpenApi) -> ::axum::Router<S> {
-        self.inner.finish_api(api)
+    ///
+    /// # Panics
+    ///
+    /// Panics if the finished spec fails the checks that make
+    /// [`try_finish`](Self::try_finish) return an error.
+    pub fn finish_api(mut self, api: &mut aide::openapi::OpenApi) -> ::axum::Router<S> {
+        let post_process_config = self.post_process_config();
+        let router = self.inner.finish_api(api);
+        if let Err(err) = post_process(api, post_process_config) {
+            panic!("{err}");
+        }
+        router
     }
 
     /// Finish the API with `OpenAPI` spec embedded via Extension layer
@@ -582,19 +1484,358 @@ where
     /// This keeps the full `OpenApi` struct in memory. For large APIs where you don't
     /// need runtime access, prefer using [`with_oas`](Self::with_oas) which only keeps
     /// pre-serialized strings in memory.
-    pub fn finish_api_with_extension(self, api: aide::openapi::OpenApi) -> ::axum::Router<S>
+    ///
+    /// # Panics
+    ///
+    /// Panics if the finished spec fails the checks that make
+    /// [`try_finish`](Self::try_finish) return an error.
+    pub fn finish_api_with_extension(mut self, api: aide::openapi::OpenApi) -> ::axum::Router<S>
     where
         S: Clone + Send + Sync + 'static,
     {
         let mut api_mut = api;
+        let post_process_config = self.post_process_config();
         let router = self.inner.finish_api(&mut api_mut);
+        if let Err(err) = post_process(&mut api_mut, post_process_config) {
+            panic!("{err}");
+        }
         router.layer(Extension(Arc::new(api_mut)))
     }
 
+    /// Take the settings the spec post-processing passes need
+    fn post_process_config(&mut self) -> PostProcess {
+        PostProcess {
+            transforms: std::mem::take(&mut self.oas.transforms),
+            strict_operation_ids: self.oas.strict_operation_ids,
+            require_documented_responses: self.oas.require_documented_responses,
+            standard_errors: self.oas.standard_errors.take(),
+            pagination_style: self.oas.pagination_style.unwrap_or_default(),
+            short_schema_names: self.oas.short_schema_names,
+        }
+    }
+
+    /// List the `#[rovo]` handlers that were never passed to [`route`](Self::route)
+    ///
+    /// A development helper for catching documented handlers that were never
+    /// mounted. Every `#[rovo]` handler linked into the binary is checked, so
+    /// call this on the top-level router once all routes are nested. Handlers
+    /// are reported by path (e.g. `my_app::users::list_users`), sorted.
+    ///
+    /// ```no_run
+    /// # let app = rovo::Router::<()>::new();
+    /// for handler in app.unrouted_handlers() {
+    ///     eprintln!("warning: {handler} is documented but never routed");
+    /// }
+    /// ```
+    #[must_use]
+    pub fn unrouted_handlers(&self) -> Vec<&'static str> {
+        let mut unrouted: Vec<&'static str> = inventory::iter::<__RegisteredHandler>
+            .into_iter()
+            .map(|handler| handler.name)
+            .filter(|name| !self.oas.routed_handlers.contains(name))
+            .collect();
+        unrouted.sort_unstable();
+        unrouted.dedup();
+        unrouted
+    }
+
     /// Convert into the underlying aide `ApiRouter`
+    ///
+    /// `#[rovo]` handlers leave `x-rovo-*` marker extensions in the spec that
+    /// are replaced when this router is finished, using its settings (e.g.
+    /// [`with_pagination_style`](Self::with_pagination_style)). A spec from
+    /// calling aide's `finish_api` on the returned router keeps the markers; use
+    /// [`into_parts`](Self::into_parts) to post-process it instead.
     pub fn into_inner(self) -> AideApiRouter<S> {
         self.inner
     }
+
+    /// Split into the underlying aide `ApiRouter` and the spec post-processing
+    /// this router runs when finished
+    ///
+    /// Apply the [`SpecPostProcess`] to the spec once aide's `finish_api` has
+    /// filled it in. The spec routes, docs UIs and
+    /// [`with_openapi_version`](Self::with_openapi_version) aren't part of it.
+    ///
+    /// ```no_run
+    /// # use rovo::Router;
+    /// # use rovo::aide::openapi::OpenApi;
+    /// let (api_router, spec_post_process) = Router::<()>::new().into_parts();
+    /// let mut api = OpenApi::default();
+    /// let app = api_router.finish_api(&mut api);
+    /// spec_post_process.apply(&mut api).expect("valid spec");
+    /// ```
+    pub fn into_parts(mut self) -> (AideApiRouter<S>, SpecPostProcess) {
+        let post_process_config = self.post_process_config();
+        (self.inner, SpecPostProcess(post_process_config))
+    }
+}
+
+/// The spec post-processing of a [`Router`], see [`Router::into_parts`]
+pub struct SpecPostProcess(PostProcess);
+
+impl SpecPostProcess {
+    /// Replace the `x-rovo-*` markers in `api` and run the router's checks and
+    /// transforms
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors [`Router::try_finish`] would for the same spec.
+    pub fn apply(self, api: &mut OpenApi) -> Result<(), RouterError> {
+        post_process(api, self.0)
+    }
+}
+
+/// Turn a user-supplied spec route into the JSON route, e.g. `openapi.yml` into `/openapi.json`
+fn normalize_oas_route(route: &str) -> Result<String, RouterError> {
+    // Remove extension if provided
+    let base_route = route
+        .strip_suffix(".json")
+        .or_else(|| route.strip_suffix(".yaml"))
+        .or_else(|| route.strip_suffix(".yml"))
+        .unwrap_or(route);
+
+    let path = base_route.trim_start_matches('/').trim_end_matches('/');
+    if path.trim().is_empty() {
+        return Err(RouterError::EmptyOasRoute(route.to_string()));
+    }
+
+    Ok(format!("/{path}.json"))
+}
+
+/// Build a response for a pre-serialized `OpenAPI` spec
+///
+/// `vary_accept` marks routes that redirect browsers to a documentation UI, so
+/// the response depends on the `Accept` header.
+fn spec_response(
+    content_type: &'static str,
+    body: &SpecBody,
+    cache_control: Option<HeaderValue>,
+    content_disposition: Option<HeaderValue>,
+    vary_accept: bool,
+    request_headers: &HeaderMap,
+) -> Response {
+    let (body, content_encoding) = body.for_request(request_headers);
+    let mut response = ([(header::CONTENT_TYPE, content_type)], body).into_response();
+    if let Some(cache_control) = cache_control {
+        response
+            .headers_mut()
+            .insert(header::CACHE_CONTROL, cache_control);
+    }
+    if let Some(content_disposition) = content_disposition {
+        response
+            .headers_mut()
+            .insert(header::CONTENT_DISPOSITION, content_disposition);
+    }
+    let vary = match (vary_accept, cfg!(feature = "compression")) {
+        (true, true) => Some("accept, accept-encoding"),
+        (true, false) => Some("accept"),
+        (false, true) => Some("accept-encoding"),
+        (false, false) => None,
+    };
+    if let Some(vary) = vary {
+        response
+            .headers_mut()
+            .insert(header::VARY, HeaderValue::from_static(vary));
+    }
+    if let Some(content_encoding) = content_encoding {
+        response
+            .headers_mut()
+            .insert(header::CONTENT_ENCODING, content_encoding);
+    }
+    response
+}
+
+/// Whether a spec request asks to be downloaded (`?download`, unless `=0` or `=false`)
+fn wants_download(uri: &Uri) -> bool {
+    uri.query().is_some_and(|query| {
+        query.split('&').any(|pair| {
+            let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+            key == "download" && !matches!(value, "0" | "false")
+        })
+    })
+}
+
+/// A serialized spec, plus a gzipped copy with the `compression` feature
+#[derive(Clone)]
+struct SpecBody {
+    plain: Bytes,
+    #[cfg(feature = "compression")]
+    gzip: Bytes,
+}
+
+impl SpecBody {
+    #[cfg(feature = "compression")]
+    fn new(plain: Bytes) -> Self {
+        Self {
+            gzip: gzip(&plain),
+            plain,
+        }
+    }
+
+    #[cfg(not(feature = "compression"))]
+    const fn new(plain: Bytes) -> Self {
+        Self { plain }
+    }
+
+    /// The body to send for a request, and its `Content-Encoding` if compressed
+    fn for_request(&self, request_headers: &HeaderMap) -> (Bytes, Option<HeaderValue>) {
+        #[cfg(feature = "compression")]
+        if accepts_gzip(request_headers) {
+            return (self.gzip.clone(), Some(HeaderValue::from_static("gzip")));
+        }
+        #[cfg(not(feature = "compression"))]
+        let _ = request_headers;
+
+        (self.plain.clone(), None)
+    }
+}
+
+#[cfg(feature = "compression")]
+fn gzip(body: &[u8]) -> Bytes {
+    use std::io::Write;
+
+    let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+    encoder
+        .write_all(body)
+        .expect("Failed to gzip OpenAPI spec");
+    encoder
+        .finish()
+        .expect("Failed to gzip OpenAPI spec")
+        .into()
+}
+
+/// Whether `Accept-Encoding` lists `gzip` without ruling it out with `q=0`
+#[cfg(feature = "compression")]
+fn accepts_gzip(headers: &HeaderMap) -> bool {
+    headers
+        .get_all(header::ACCEPT_ENCODING)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .any(|coding| {
+            let mut params = coding.split(';').map(str::trim);
+            let name = params.next().unwrap_or_default();
+            let rejected = params.any(|param| {
+                param
+                    .strip_prefix("q=")
+                    .and_then(|q| q.parse::<f32>().ok())
+                    .is_some_and(|q| q <= 0.0)
+            });
+            name.eq_ignore_ascii_case("gzip") && !rejected
+        })
+}
+
+/// Wrap `router` so requests to the `protected` paths require basic auth credentials
+fn require_basic_auth<S>(
+    router: ::axum::Router<S>,
+    protected: Vec<String>,
+    credentials: String,
+) -> ::axum::Router<S>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    let protected: Arc<[String]> = protected.into();
+    let credentials: Arc<str> = credentials.into();
+
+    router.layer(::axum::middleware::from_fn(
+        move |request: ::axum::extract::Request, next: ::axum::middleware::Next| {
+            let protected = Arc::clone(&protected);
+            let credentials = Arc::clone(&credentials);
+            async move {
+                let path = request.uri().path();
+                if protected.iter().any(|route| route == path)
+                    && !has_basic_auth(request.headers(), &credentials)
+                {
+                    return (
+                        ::axum::http::StatusCode::UNAUTHORIZED,
+                        [(
+                            header::WWW_AUTHENTICATE,
+                            "Basic realm=\"API documentation\", charset=\"UTF-8\"",
+                        )],
+                    )
+                        .into_response();
+                }
+                next.run(request).await
+            }
+        },
+    ))
+}
+
+/// Check a request's `Authorization` header against the expected basic auth token
+///
+/// The token is compared in constant time so the password can't be guessed
+/// byte by byte from response timings.
+fn has_basic_auth(headers: &HeaderMap, expected: &str) -> bool {
+    headers
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.trim().split_once(' '))
+        .is_some_and(|(scheme, token)| {
+            scheme.eq_ignore_ascii_case("basic")
+                && constant_time_eq(token.trim().as_bytes(), expected.as_bytes())
+        })
+}
+
+/// Compare two byte strings without short-circuiting on the first difference
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+/// Standard base64 with padding, as used by basic auth credentials (RFC 7617)
+fn base64_encode(input: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    let mut encoded = String::with_capacity(input.len().div_ceil(3) * 4);
+    for chunk in input.chunks(3) {
+        let bytes = [
+            chunk[0],
+            chunk.get(1).copied().unwrap_or(0),
+            chunk.get(2).copied().unwrap_or(0),
+        ];
+        let n = (u32::from(bytes[0]) << 16) | (u32::from(bytes[1]) << 8) | u32::from(bytes[2]);
+        for i in 0..4 {
+            if i <= chunk.len() {
+                encoded.push(char::from(ALPHABET[((n >> (18 - 6 * i)) & 63) as usize]));
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+    encoded
+}
+
+/// Check whether a request's `Accept` header prefers HTML over JSON
+///
+/// Only explicit media types count: a browser's `*/*` fallback doesn't make
+/// JSON preferred, and API clients that don't mention `text/html` never match.
+fn prefers_html(headers: &HeaderMap) -> bool {
+    let Some(accept) = headers
+        .get(header::ACCEPT)
+        .and_then(|value| value.to_str().ok())
+    else {
+        return false;
+    };
+
+    let quality = |media_type: &str| -> f32 {
+        accept
+            .split(',')
+            .filter_map(|entry| {
+                let mut parts = entry.split(';').map(str::trim);
+                if !parts.next()?.eq_ignore_ascii_case(media_type) {
+                    return None;
+                }
+                let q = parts
+                    .find_map(|param| param.strip_prefix("q="))
+                    .and_then(|q| q.parse().ok())
+                    .unwrap_or(1.0);
+                Some(q)
+            })
+            .fold(0.0, f32::max)
+    };
+
+    let html = quality("text/html").max(quality("application/xhtml+xml"));
+    html > 0.0 && html > quality("application/json")
 }
 
 /// A router whose state has been provided via [`Router::with_state`].
@@ -627,8 +1868,7 @@ where
 /// ```
 pub struct StatefulRouter {
     inner: AideApiRouter<()>,
-    oas_spec: Option<OpenApi>,
-    oas_route: String,
+    oas: OasConfig,
 }
 
 impl StatefulRouter {
@@ -636,14 +1876,165 @@ impl StatefulRouter {
     ///
     /// This wires up `OpenAPI` spec endpoints (JSON/YAML) if configured,
     /// then returns a ready-to-serve `axum::Router`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`try_finish`](Self::try_finish) would return an error.
     pub fn finish(self) -> ::axum::Router {
-        let router: Router<()> = Router {
+        self.into_router().finish()
+    }
+
+    /// Finalize into an `axum::Router`, returning an error instead of panicking
+    ///
+    /// # Errors
+    ///
+    /// See [`Router::try_finish`].
+    pub fn try_finish(self) -> Result<::axum::Router, RouterError> {
+        self.into_router().try_finish()
+    }
+
+    /// Turn back into a [`Router`] so it can be finished
+    fn into_router(self) -> Router<()> {
+        Router {
             inner: self.inner,
-            oas_spec: self.oas_spec,
-            oas_route: self.oas_route,
-        };
-        router.finish()
+            oas: self.oas,
+        }
+    }
+}
+
+/// Scalar UI layout, see [`ScalarConfig::layout`]
+#[cfg(feature = "scalar")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScalarLayout {
+    /// Three-column layout with the request examples beside each operation
+    Modern,
+    /// Single-column layout with collapsible operations
+    Classic,
+}
+
+#[cfg(feature = "scalar")]
+impl ScalarLayout {
+    const fn as_str(self) -> &'static str {
+        match self {
+            Self::Modern => "modern",
+            Self::Classic => "classic",
+        }
+    }
+}
+
+/// Configuration for the Scalar UI mounted by [`Router::with_scalar_config`]
+///
+/// Options left unset fall back to Scalar's own defaults.
+#[cfg(feature = "scalar")]
+#[derive(Debug, Clone, Default)]
+pub struct ScalarConfig {
+    theme: Option<String>,
+    layout: Option<ScalarLayout>,
+    show_sidebar: Option<bool>,
+}
+
+#[cfg(feature = "scalar")]
+impl ScalarConfig {
+    /// Create a configuration using Scalar's defaults
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the color theme (e.g. `"default"`, `"moon"`, `"purple"`, `"solarized"`)
+    #[must_use]
+    pub fn theme(mut self, theme: impl Into<String>) -> Self {
+        self.theme = Some(theme.into());
+        self
+    }
+
+    /// Set the page layout
+    #[must_use]
+    pub const fn layout(mut self, layout: ScalarLayout) -> Self {
+        self.layout = Some(layout);
+        self
     }
+
+    /// Show or hide the operation sidebar
+    #[must_use]
+    pub const fn show_sidebar(mut self, show_sidebar: bool) -> Self {
+        self.show_sidebar = Some(show_sidebar);
+        self
+    }
+
+    /// Scalar configuration object, as passed to `data-configuration`
+    fn into_json(self) -> serde_json::Value {
+        let mut config = serde_json::Map::new();
+        if let Some(theme) = self.theme {
+            config.insert("theme".to_string(), theme.into());
+        }
+        if let Some(layout) = self.layout {
+            config.insert("layout".to_string(), layout.as_str().into());
+        }
+        if let Some(show_sidebar) = self.show_sidebar {
+            config.insert("showSidebar".to_string(), show_sidebar.into());
+        }
+        serde_json::Value::Object(config)
+    }
+
+    /// Render the Scalar page for the spec served at `spec_url`
+    fn into_html(self, spec_url: &str) -> String {
+        format!(
+            r#"<!doctype html>
+<html>
+  <head>
+    <title>API Reference</title>
+    <meta charset="utf-8" />
+    <meta name="viewport" content="width=device-width, initial-scale=1" />
+  </head>
+  <body>
+    <script id="api-reference" data-url="{}" data-configuration="{}"></script>
+    <script src="https://cdn.jsdelivr.net/npm/@scalar/api-reference"></script>
+  </body>
+</html>
+"#,
+            escape_html_attribute(spec_url),
+            escape_html_attribute(&self.into_json().to_string()),
+        )
+    }
+}
+
+/// Render the `RapiDoc` page for the spec served at `spec_url`
+#[cfg(feature = "rapidoc")]
+fn rapidoc_html(spec_url: &str) -> String {
+    format!(
+        r#"<!doctype html>
+<html>
+  <head>
+    <title>API Reference</title>
+    <meta charset="utf-8" />
+    <meta name="viewport" content="width=device-width, initial-scale=1" />
+    <script type="module" src="https://cdn.jsdelivr.net/npm/rapidoc/dist/rapidoc-min.js"></script>
+  </head>
+  <body>
+    <rapi-doc spec-url="{}"></rapi-doc>
+  </body>
+</html>
+"#,
+        escape_html_attribute(spec_url),
+    )
+}
+
+/// Escape a value for use inside a double-quoted HTML attribute
+#[cfg(any(feature = "scalar", feature = "rapidoc"))]
+fn escape_html_attribute(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
 }
 
 impl<S> Default for Router<S>
@@ -673,6 +2064,15 @@ pub trait IntoApiMethodRouter<S = ()> {
     fn into_delete_route(self) -> aide::axum::routing::ApiMethodRouter<S>;
     /// Convert into a PUT route with documentation
     fn into_put_route(self) -> aide::axum::routing::ApiMethodRouter<S>;
+    /// Convert into a HEAD route with documentation
+    fn into_head_route(self) -> aide::axum::routing::ApiMethodRouter<S>;
+    /// Convert into an OPTIONS route with documentation
+    fn into_options_route(self) -> aide::axum::routing::ApiMethodRouter<S>;
+
+    /// The handler's path, used by [`Router::unrouted_handlers`]
+    fn handler_name(&self) -> Option<&'static str> {
+        None
+    }
 }
 
 /// Wrapper around `ApiMethodRouter` that provides method chaining for documented handlers.
@@ -706,6 +2106,7 @@ pub trait IntoApiMethodRouter<S = ()> {
 /// ```
 pub struct ApiMethodRouter<S = ()> {
     inner: aide::axum::routing::ApiMethodRouter<S>,
+    handlers: Vec<&'static str>,
 }
 
 impl<S> ApiMethodRouter<S>
@@ -715,7 +2116,21 @@ where
     /// Create a new `ApiMethodRouter` from aide's `ApiMethodRouter`
     #[must_use]
     pub const fn new(inner: aide::axum::routing::ApiMethodRouter<S>) -> Self {
-        Self { inner }
+        Self {
+            inner,
+            handlers: Vec::new(),
+        }
+    }
+
+    /// Chain another route, recording its handler for [`Router::unrouted_handlers`]
+    fn merge(
+        mut self,
+        route: aide::axum::routing::ApiMethodRouter<S>,
+        handler: Option<&'static str>,
+    ) -> Self {
+        self.inner = self.inner.merge(route);
+        self.handlers.extend(handler);
+        self
     }
 
     /// Chain a POST handler
@@ -723,9 +2138,8 @@ where
     where
         H: IntoApiMethodRouter<S>,
     {
-        Self {
-            inner: self.inner.merge(handler.into_post_route()),
-        }
+        let name = handler.handler_name();
+        self.merge(handler.into_post_route(), name)
     }
 
     /// Chain a GET handler
@@ -733,9 +2147,8 @@ where
     where
         H: IntoApiMethodRouter<S>,
     {
-        Self {
-            inner: self.inner.merge(handler.into_get_route()),
-        }
+        let name = handler.handler_name();
+        self.merge(handler.into_get_route(), name)
     }
 
     /// Chain a PATCH handler
@@ -743,9 +2156,8 @@ where
     where
         H: IntoApiMethodRouter<S>,
     {
-        Self {
-            inner: self.inner.merge(handler.into_patch_route()),
-        }
+        let name = handler.handler_name();
+        self.merge(handler.into_patch_route(), name)
     }
 
     /// Chain a DELETE handler
@@ -753,9 +2165,8 @@ where
     where
         H: IntoApiMethodRouter<S>,
     {
-        Self {
-            inner: self.inner.merge(handler.into_delete_route()),
-        }
+        let name = handler.handler_name();
+        self.merge(handler.into_delete_route(), name)
     }
 
     /// Chain a PUT handler
@@ -763,9 +2174,26 @@ where
     where
         H: IntoApiMethodRouter<S>,
     {
-        Self {
-            inner: self.inner.merge(handler.into_put_route()),
-        }
+        let name = handler.handler_name();
+        self.merge(handler.into_put_route(), name)
+    }
+
+    /// Chain a HEAD handler
+    pub fn head<H>(self, handler: H) -> Self
+    where
+        H: IntoApiMethodRouter<S>,
+    {
+        let name = handler.handler_name();
+        self.merge(handler.into_head_route(), name)
+    }
+
+    /// Chain an OPTIONS handler
+    pub fn options<H>(self, handler: H) -> Self
+    where
+        H: IntoApiMethodRouter<S>,
+    {
+        let name = handler.handler_name();
+        self.merge(handler.into_options_route(), name)
     }
 }
 
@@ -839,7 +2267,10 @@ pub mod routing {
         H: IntoApiMethodRouter<S>,
         S: Clone + Send + Sync + 'static,
     {
-        ApiMethodRouter::new(handler.into_get_route())
+        let name = handler.handler_name();
+        let mut router = ApiMethodRouter::new(handler.into_get_route());
+        router.handlers.extend(name);
+        router
     }
 
     /// Create a POST route with documentation from a `#[rovo]` decorated handler.
@@ -848,7 +2279,10 @@ pub mod routing {
         H: IntoApiMethodRouter<S>,
         S: Clone + Send + Sync + 'static,
     {
-        ApiMethodRouter::new(handler.into_post_route())
+        let name = handler.handler_name();
+        let mut router = ApiMethodRouter::new(handler.into_post_route());
+        router.handlers.extend(name);
+        router
     }
 
     /// Create a PATCH route with documentation from a `#[rovo]` decorated handler.
@@ -857,7 +2291,10 @@ pub mod routing {
         H: IntoApiMethodRouter<S>,
         S: Clone + Send + Sync + 'static,
     {
-        ApiMethodRouter::new(handler.into_patch_route())
+        let name = handler.handler_name();
+        let mut router = ApiMethodRouter::new(handler.into_patch_route());
+        router.handlers.extend(name);
+        router
     }
 
     /// Create a DELETE route with documentation from a `#[rovo]` decorated handler.
@@ -866,7 +2303,10 @@ pub mod routing {
         H: IntoApiMethodRouter<S>,
         S: Clone + Send + Sync + 'static,
     {
-        ApiMethodRouter::new(handler.into_delete_route())
+        let name = handler.handler_name();
+        let mut router = ApiMethodRouter::new(handler.into_delete_route());
+        router.handlers.extend(name);
+        router
     }
 
     /// Create a PUT route with documentation from a `#[rovo]` decorated handler.
@@ -875,7 +2315,34 @@ pub mod routing {
         H: IntoApiMethodRouter<S>,
         S: Clone + Send + Sync + 'static,
     {
-        ApiMethodRouter::new(handler.into_put_route())
+        let name = handler.handler_name();
+        let mut router = ApiMethodRouter::new(handler.into_put_route());
+        router.handlers.extend(name);
+        router
+    }
+
+    /// Create a HEAD route with documentation from a `#[rovo]` decorated handler.
+    pub fn head<S, H>(handler: H) -> ApiMethodRouter<S>
+    where
+        H: IntoApiMethodRouter<S>,
+        S: Clone + Send + Sync + 'static,
+    {
+        let name = handler.handler_name();
+        let mut router = ApiMethodRouter::new(handler.into_head_route());
+        router.handlers.extend(name);
+        router
+    }
+
+    /// Create an OPTIONS route with documentation from a `#[rovo]` decorated handler.
+    pub fn options<S, H>(handler: H) -> ApiMethodRouter<S>
+    where
+        H: IntoApiMethodRouter<S>,
+        S: Clone + Send + Sync + 'static,
+    {
+        let name = handler.handler_name();
+        let mut router = ApiMethodRouter::new(handler.into_options_route());
+        router.handlers.extend(name);
+        router
     }
 }
 
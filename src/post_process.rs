@@ -0,0 +1,689 @@
+//! Passes over the generated `OpenAPI` spec that run when a [`Router`](crate::Router)
+//! is finished
+//!
+//! The `#[rovo]` macro can't see where a handler is routed or how the router is
+//! configured, so it leaves `x-rovo-*` marker extensions on operations. These
+//! passes replace the markers once the whole spec is known.
+
+use std::collections::BTreeMap;
+
+use aide::openapi::{OpenApi, Operation, ReferenceOr};
+
+use crate::{OpenApiTransform, PaginationStyle, RouterError};
+
+/// Every operation in the spec, as `(path, method, operation)`
+fn operations(api: &OpenApi) -> impl Iterator<Item = (&str, &'static str, &Operation)> {
+    api.paths
+        .iter()
+        .flat_map(|paths| paths.paths.iter())
+        .filter_map(|(path, item)| {
+            let ReferenceOr::Item(item) = item else {
+                return None;
+            };
+            Some((path.as_str(), item))
+        })
+        .flat_map(|(path, item)| {
+            [
+                ("GET", item.get.as_ref()),
+                ("PUT", item.put.as_ref()),
+                ("POST", item.post.as_ref()),
+                ("DELETE", item.delete.as_ref()),
+                ("OPTIONS", item.options.as_ref()),
+                ("HEAD", item.head.as_ref()),
+                ("PATCH", item.patch.as_ref()),
+                ("TRACE", item.trace.as_ref()),
+            ]
+            .into_iter()
+            .filter_map(move |(method, operation)| Some((path, method, operation?)))
+        })
+}
+
+/// Every operation in the spec, mutably, as `(path, method, operation)`
+fn operations_mut(api: &mut OpenApi) -> impl Iterator<Item = (&str, &'static str, &mut Operation)> {
+    api.paths
+        .iter_mut()
+        .flat_map(|paths| paths.paths.iter_mut())
+        .filter_map(|(path, item)| {
+            let ReferenceOr::Item(item) = item else {
+                return None;
+            };
+            Some((path.as_str(), item))
+        })
+        .flat_map(|(path, item)| {
+            [
+                ("GET", item.get.as_mut()),
+                ("PUT", item.put.as_mut()),
+                ("POST", item.post.as_mut()),
+                ("DELETE", item.delete.as_mut()),
+                ("OPTIONS", item.options.as_mut()),
+                ("HEAD", item.head.as_mut()),
+                ("PATCH", item.patch.as_mut()),
+                ("TRACE", item.trace.as_mut()),
+            ]
+            .into_iter()
+            .filter_map(move |(method, operation)| Some((path, method, operation?)))
+        })
+}
+
+/// The [`Router`](crate::Router) settings the passes depend on
+pub struct PostProcess {
+    pub transforms: Vec<OpenApiTransform>,
+    pub strict_operation_ids: bool,
+    pub require_documented_responses: bool,
+    pub standard_errors: Option<Vec<(u16, String)>>,
+    pub pagination_style: PaginationStyle,
+    pub short_schema_names: bool,
+}
+
+/// Run every pass over a finished spec
+///
+/// The checks run first, so responses added by the later passes (like the
+/// standard errors) don't count as documented. Transforms run last and see the
+/// spec as it will be served, schema names included.
+pub fn post_process(api: &mut OpenApi, config: PostProcess) -> Result<(), RouterError> {
+    check_operation_ids(api, config.strict_operation_ids)?;
+    check_documented_responses(api, config.require_documented_responses)?;
+    add_code_samples(api);
+    apply_tag_descriptions(api);
+    apply_standard_errors(api, config.standard_errors.as_deref());
+    apply_pagination_style(api, config.pagination_style);
+    if config.short_schema_names {
+        shorten_schema_names(api)?;
+    }
+    for transform in config.transforms {
+        transform(api);
+    }
+    Ok(())
+}
+
+/// Extension `#[rovo(code_samples)]` marks operations with
+const CODE_SAMPLES_MARKER: &str = "x-rovo-code-samples";
+
+/// Replace `#[rovo(code_samples)]` markers with `x-codeSamples` curl samples
+///
+/// The macro can't see where a handler is routed, so the samples are built
+/// here once every operation's method and path are known.
+fn add_code_samples(api: &mut OpenApi) {
+    let base_url = api
+        .servers
+        .first()
+        .map_or("http://localhost", |server| {
+            server.url.trim_end_matches('/')
+        })
+        .to_string();
+
+    for (path, method, operation) in operations_mut(api) {
+        if operation
+            .extensions
+            .shift_remove(CODE_SAMPLES_MARKER)
+            .is_none()
+        {
+            continue;
+        }
+
+        let source = curl_sample(method, &format!("{base_url}{path}"), operation);
+        operation.extensions.insert(
+            "x-codeSamples".to_string(),
+            serde_json::json!([{ "lang": "curl", "label": "cURL", "source": source }]),
+        );
+    }
+}
+
+/// Operation IDs used by more than one operation, sorted
+pub fn duplicate_operation_ids(api: &OpenApi) -> Vec<String> {
+    let mut seen = std::collections::HashSet::new();
+    let mut duplicates = std::collections::BTreeSet::new();
+
+    for (_, _, operation) in operations(api) {
+        if let Some(id) = &operation.operation_id {
+            if !seen.insert(id) {
+                duplicates.insert(id.clone());
+            }
+        }
+    }
+
+    duplicates.into_iter().collect()
+}
+
+/// Fail on duplicate operation IDs if the router is [`strict`](crate::Router::strict)
+pub fn check_operation_ids(api: &OpenApi, strict: bool) -> Result<(), RouterError> {
+    if !strict {
+        return Ok(());
+    }
+
+    let duplicates = duplicate_operation_ids(api);
+    if duplicates.is_empty() {
+        Ok(())
+    } else {
+        Err(RouterError::DuplicateOperationIds(duplicates))
+    }
+}
+
+/// Operations that document no responses, as `METHOD /path (operation ID)`
+fn undocumented_operations(api: &OpenApi) -> Vec<String> {
+    let mut undocumented = Vec::new();
+
+    for (path, method, operation) in operations(api) {
+        let documented = operation.responses.as_ref().is_some_and(|responses| {
+            responses.default.is_some() || !responses.responses.is_empty()
+        });
+        if documented {
+            continue;
+        }
+
+        undocumented.push(operation.operation_id.as_ref().map_or_else(
+            || format!("{method} {path}"),
+            |id| format!("{method} {path} ({id})"),
+        ));
+    }
+
+    undocumented
+}
+
+/// Fail if the router [requires documented responses](crate::Router::require_documented_responses)
+/// and some operations have none
+pub fn check_documented_responses(api: &OpenApi, required: bool) -> Result<(), RouterError> {
+    if !required {
+        return Ok(());
+    }
+
+    let undocumented = undocumented_operations(api);
+    if undocumented.is_empty() {
+        Ok(())
+    } else {
+        Err(RouterError::UndocumentedResponses(undocumented))
+    }
+}
+
+/// Extension `#[rovo_module]` handlers carry their module's tag descriptions in
+const TAG_DESCRIPTIONS_MARKER: &str = "x-rovo-tag-descriptions";
+
+/// Remove `@tag-description` markers, merging the descriptions into the spec's tags
+///
+/// Tags already described on the `OpenApi` keep their description.
+fn apply_tag_descriptions(api: &mut OpenApi) {
+    let mut descriptions: Vec<(String, String)> = Vec::new();
+    for (_, _, operation) in operations_mut(api) {
+        let Some(serde_json::Value::Object(marker)) =
+            operation.extensions.shift_remove(TAG_DESCRIPTIONS_MARKER)
+        else {
+            continue;
+        };
+        for (tag, description) in marker {
+            if let serde_json::Value::String(description) = description {
+                if !descriptions.iter().any(|(existing, _)| *existing == tag) {
+                    descriptions.push((tag, description));
+                }
+            }
+        }
+    }
+
+    for (name, description) in descriptions {
+        match api.tags.iter_mut().find(|tag| tag.name == name) {
+            Some(tag) => {
+                tag.description.get_or_insert(description);
+            }
+            None => api.tags.push(aide::openapi::Tag {
+                name,
+                description: Some(description),
+                ..Default::default()
+            }),
+        }
+    }
+}
+
+/// The minor version of a supported `openapi` version string, `0` or `1`
+///
+/// Only full `3.0.x` and `3.1.x` versions are supported.
+pub fn openapi_minor_version(version: &str) -> Option<u8> {
+    let mut parts = version.split('.');
+    let (Some("3"), Some(minor), Some(patch), None) =
+        (parts.next(), parts.next(), parts.next(), parts.next())
+    else {
+        return None;
+    };
+    if patch.is_empty() || !patch.bytes().all(|byte| byte.is_ascii_digit()) {
+        return None;
+    }
+    match minor {
+        "0" => Some(0),
+        "1" => Some(1),
+        _ => None,
+    }
+}
+
+/// Convert the serialized spec to `version` and check it's a valid document for it
+///
+/// aide generates `3.1` documents. For `3.1.x`, schemas still using the `3.0`
+/// keyword `nullable: true` are rewritten to the `3.1` form; for `3.0.x`, the
+/// `3.1` schema keywords are rewritten to their `3.0` equivalents.
+///
+/// # Errors
+///
+/// Returns [`RouterError::UnsupportedOpenApiVersion`] if `version` isn't a
+/// `3.0.x` or `3.1.x` version, or [`RouterError::InvalidSpec`] if the document
+/// breaks that version's structural rules, like webhooks in a `3.0` spec.
+pub fn apply_openapi_version(
+    spec: &mut serde_json::Value,
+    version: &str,
+) -> Result<(), RouterError> {
+    match openapi_minor_version(version) {
+        Some(1) => nullable_to_type_arrays(spec),
+        Some(_) => {
+            type_arrays_to_nullable(spec);
+            if let Some(root) = spec.as_object_mut() {
+                root.remove("jsonSchemaDialect");
+            }
+        }
+        None => return Err(RouterError::UnsupportedOpenApiVersion(version.to_string())),
+    }
+    spec["openapi"] = version.into();
+
+    let problems = structural_problems(spec, version.starts_with("3.1"));
+    if problems.is_empty() {
+        Ok(())
+    } else {
+        Err(RouterError::InvalidSpec {
+            version: version.to_string(),
+            problems,
+        })
+    }
+}
+
+/// The ways the document breaks the structural rules of `3.1`, or of `3.0` if
+/// `v3_1` is false
+fn structural_problems(spec: &serde_json::Value, v3_1: bool) -> Vec<String> {
+    let mut problems = Vec::new();
+    for field in ["title", "version"] {
+        if !spec["info"][field].is_string() {
+            problems.push(format!("`info.{field}` is missing"));
+        }
+    }
+    let has_webhooks = spec
+        .get("webhooks")
+        .and_then(serde_json::Value::as_object)
+        .is_some_and(|webhooks| !webhooks.is_empty());
+    if v3_1 {
+        if ["paths", "components"]
+            .iter()
+            .all(|field| spec.get(field).is_none())
+            && !has_webhooks
+        {
+            problems.push("it needs `paths`, `components` or `webhooks`".to_string());
+        }
+    } else {
+        if spec.get("paths").is_none() {
+            problems.push("`paths` is missing".to_string());
+        }
+        if has_webhooks {
+            problems.push("`webhooks` need OpenAPI 3.1".to_string());
+        }
+        if spec["info"].get("summary").is_some() {
+            problems.push("`info.summary` needs OpenAPI 3.1".to_string());
+        }
+        if spec["info"]["license"].get("identifier").is_some() {
+            problems.push("`info.license.identifier` needs OpenAPI 3.1".to_string());
+        }
+    }
+    if let Some(paths) = spec.get("paths").and_then(serde_json::Value::as_object) {
+        for path in paths.keys().filter(|path| !path.starts_with('/')) {
+            problems.push(format!("path `{path}` doesn't start with `/`"));
+        }
+    }
+    problems
+}
+
+/// Rewrite `nullable: true` schemas into the `3.1` form, `type: [T, "null"]`
+///
+/// Schemas without a `type` (e.g. a `$ref`) become `anyOf` the schema and `null`.
+fn nullable_to_type_arrays(value: &mut serde_json::Value) {
+    use serde_json::Value;
+
+    match value {
+        Value::Object(map) => {
+            for child in map.values_mut() {
+                nullable_to_type_arrays(child);
+            }
+            match map.get("nullable") {
+                Some(Value::Bool(true)) => {}
+                Some(Value::Bool(false)) => {
+                    map.remove("nullable");
+                    return;
+                }
+                _ => return,
+            }
+            map.remove("nullable");
+
+            if let Some(Value::Array(values)) = map.get_mut("enum") {
+                if !values.contains(&Value::Null) {
+                    values.push(Value::Null);
+                }
+            }
+            match map.get_mut("type") {
+                Some(Value::String(ty)) => {
+                    let ty = std::mem::take(ty);
+                    map.insert("type".to_string(), serde_json::json!([ty, "null"]));
+                }
+                Some(Value::Array(types)) => {
+                    if !types.iter().any(|ty| ty == "null") {
+                        types.push("null".into());
+                    }
+                }
+                _ => {
+                    let schema = Value::Object(std::mem::take(map));
+                    map.insert(
+                        "anyOf".to_string(),
+                        serde_json::json!([schema, { "type": "null" }]),
+                    );
+                }
+            }
+        }
+        Value::Array(values) => {
+            for child in values {
+                nullable_to_type_arrays(child);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Rewrite `3.1` schemas into their `3.0` form
+///
+/// A `null` in a `type` array, or a `{ "type": "null" }` alternative in an
+/// `anyOf`/`oneOf`, becomes `nullable: true`. `const` becomes a single-value
+/// `enum`, an `examples` array becomes `example`, and numeric
+/// `exclusiveMinimum`/`exclusiveMaximum` become the boolean `3.0` form.
+fn type_arrays_to_nullable(value: &mut serde_json::Value) {
+    use serde_json::Value;
+
+    match value {
+        Value::Object(map) => {
+            for (key, child) in map.iter_mut() {
+                match (key.as_str(), child) {
+                    // Keys here are names, not keywords, so only their schemas are rewritten
+                    (
+                        "properties" | "patternProperties" | "$defs" | "schemas",
+                        Value::Object(named),
+                    ) => {
+                        named.values_mut().for_each(type_arrays_to_nullable);
+                    }
+                    (_, child) => type_arrays_to_nullable(child),
+                }
+            }
+
+            let mut nullable = false;
+            match map.get_mut("type") {
+                Some(Value::Array(types)) => {
+                    let len = types.len();
+                    types.retain(|ty| ty != "null");
+                    nullable = types.len() != len;
+                    match types.len() {
+                        0 => {
+                            map.remove("type");
+                        }
+                        1 => {
+                            let ty = types.remove(0);
+                            map.insert("type".to_string(), ty);
+                        }
+                        _ => {
+                            let alternatives = types
+                                .drain(..)
+                                .map(|ty| serde_json::json!({ "type": ty }))
+                                .collect();
+                            map.remove("type");
+                            map.insert("anyOf".to_string(), Value::Array(alternatives));
+                        }
+                    }
+                }
+                Some(Value::String(ty)) if ty == "null" => {
+                    map.remove("type");
+                    nullable = true;
+                }
+                _ => {}
+            }
+            for key in ["anyOf", "oneOf"] {
+                if let Some(Value::Array(schemas)) = map.get_mut(key) {
+                    let len = schemas.len();
+                    schemas.retain(|schema| *schema != serde_json::json!({ "type": "null" }));
+                    if schemas.len() != len {
+                        nullable = true;
+                        if schemas.len() == 1 {
+                            // `nullable` has no effect next to a bare `$ref`
+                            let schemas = map.remove(key).unwrap_or_default();
+                            map.insert("allOf".to_string(), schemas);
+                        }
+                    }
+                }
+            }
+            if nullable {
+                map.insert("nullable".to_string(), Value::Bool(true));
+                if let Some(Value::Array(values)) = map.get_mut("enum") {
+                    if !values.contains(&Value::Null) {
+                        values.push(Value::Null);
+                    }
+                }
+            }
+
+            if let Some(value) = map.remove("const") {
+                map.insert("enum".to_string(), Value::Array(vec![value]));
+            }
+            if let Some(Value::Array(examples)) = map.get("examples") {
+                let example = examples.first().cloned();
+                map.remove("examples");
+                if let Some(example) = example {
+                    map.entry("example").or_insert(example);
+                }
+            }
+            for (exclusive, inclusive) in [
+                ("exclusiveMinimum", "minimum"),
+                ("exclusiveMaximum", "maximum"),
+            ] {
+                if let Some(bound @ Value::Number(_)) = map.get(exclusive).cloned() {
+                    map.insert(inclusive.to_string(), bound);
+                    map.insert(exclusive.to_string(), Value::Bool(true));
+                }
+            }
+        }
+        Value::Array(values) => {
+            for child in values {
+                type_arrays_to_nullable(child);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Extension listing the status codes `@errors` added to an operation
+const STANDARD_ERRORS_MARKER: &str = "x-rovo-errors";
+
+/// Remove `@errors` markers, swapping the marked responses for `standard_errors` if set
+fn apply_standard_errors(api: &mut OpenApi, standard_errors: Option<&[(u16, String)]>) {
+    for (_, _, operation) in operations_mut(api) {
+        let Some(marker) = operation.extensions.shift_remove(STANDARD_ERRORS_MARKER) else {
+            continue;
+        };
+        let (Some(standard_errors), Some(responses)) =
+            (standard_errors, operation.responses.as_mut())
+        else {
+            continue;
+        };
+
+        let added: Vec<u16> = serde_json::from_value(marker).unwrap_or_default();
+        for code in added {
+            responses
+                .responses
+                .shift_remove(&aide::openapi::StatusCode::Code(code));
+        }
+        for (code, description) in standard_errors {
+            responses
+                .responses
+                .entry(aide::openapi::StatusCode::Code(*code))
+                .or_insert_with(|| {
+                    ReferenceOr::Item(aide::openapi::Response {
+                        description: description.clone(),
+                        ..Default::default()
+                    })
+                });
+        }
+    }
+}
+
+/// Extension listing the query parameters `@paginated` added to an operation
+const PAGINATION_MARKER: &str = "x-rovo-paginated";
+
+/// Remove `@paginated` markers, making the marked parameters optional and
+/// renaming them to match `style`
+fn apply_pagination_style(api: &mut OpenApi, style: PaginationStyle) {
+    for (_, _, operation) in operations_mut(api) {
+        let Some(marker) = operation.extensions.shift_remove(PAGINATION_MARKER) else {
+            continue;
+        };
+
+        let added: Vec<String> = serde_json::from_value(marker).unwrap_or_default();
+        for parameter in &mut operation.parameters {
+            let ReferenceOr::Item(aide::openapi::Parameter::Query { parameter_data, .. }) =
+                parameter
+            else {
+                continue;
+            };
+            if !added.contains(&parameter_data.name) {
+                continue;
+            }
+
+            parameter_data.required = false;
+            let Some((name, description)) = style.replacement(&parameter_data.name) else {
+                continue;
+            };
+            parameter_data.name = name.to_string();
+            parameter_data.description = Some(description.to_string());
+            if name == "cursor" {
+                parameter_data.format =
+                    aide::openapi::ParameterSchemaOrContent::Schema(aide::openapi::SchemaObject {
+                        json_schema: <String as ::schemars::JsonSchema>::json_schema(
+                            &mut ::schemars::SchemaGenerator::default(),
+                        ),
+                        example: None,
+                        external_docs: None,
+                    });
+            }
+        }
+    }
+}
+
+/// Where `$ref`s to component schemas point
+const SCHEMA_REF_PREFIX: &str = "#/components/schemas/";
+
+/// Rename component schemas to their last path segment, updating every `$ref`
+///
+/// Fails without renaming anything if two schemas shorten to the same name.
+fn shorten_schema_names(api: &mut OpenApi) -> Result<(), RouterError> {
+    let Some(components) = api.components.as_ref() else {
+        return Ok(());
+    };
+
+    let mut renames = BTreeMap::new();
+    let mut shortened: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    for name in components.schemas.keys() {
+        let short = short_schema_name(name);
+        shortened
+            .entry(short.clone())
+            .or_default()
+            .push(name.clone());
+        if short != *name {
+            renames.insert(name.clone(), short);
+        }
+    }
+    if let Some((short, mut names)) = shortened.into_iter().find(|(_, names)| names.len() > 1) {
+        names.sort();
+        return Err(RouterError::SchemaNameCollision { short, names });
+    }
+    if renames.is_empty() {
+        return Ok(());
+    }
+
+    let mut spec = serde_json::to_value(&*api).expect("Failed to serialize OpenAPI spec to JSON");
+    if let Some(serde_json::Value::Object(schemas)) = spec.pointer_mut("/components/schemas") {
+        *schemas = std::mem::take(schemas)
+            .into_iter()
+            .map(|(name, schema)| match renames.get(&name) {
+                Some(short) => (short.clone(), schema),
+                None => (name, schema),
+            })
+            .collect();
+    }
+    rename_schema_refs(&mut spec, &renames);
+    // `OpenApi` borrows its version string while deserializing, so go through a string
+    let spec = serde_json::to_string(&spec).expect("Failed to serialize OpenAPI spec to JSON");
+    *api = serde_json::from_str(&spec).expect("Failed to deserialize OpenAPI spec");
+    Ok(())
+}
+
+/// Strip the module path from every type in a schema name
+///
+/// `crate::models::User` becomes `User`, and `Page<crate::models::User>` becomes `Page<User>`.
+fn short_schema_name(name: &str) -> String {
+    let mut segments: Vec<&str> = name.split("::").collect();
+    let last = segments.pop().unwrap_or_default();
+
+    let mut short: String = segments
+        .into_iter()
+        .map(|segment| segment.trim_end_matches(|c: char| c.is_alphanumeric() || c == '_'))
+        .collect();
+    short.push_str(last);
+    short
+}
+
+/// Point `$ref`s at renamed component schemas
+fn rename_schema_refs(value: &mut serde_json::Value, renames: &BTreeMap<String, String>) {
+    use serde_json::Value;
+
+    match value {
+        Value::Object(map) => {
+            for (key, child) in map.iter_mut() {
+                if key == "$ref" {
+                    if let Value::String(reference) = child {
+                        let renamed = reference
+                            .strip_prefix(SCHEMA_REF_PREFIX)
+                            .and_then(|name| renames.get(name));
+                        if let Some(short) = renamed {
+                            *reference = format!("{SCHEMA_REF_PREFIX}{short}");
+                        }
+                    }
+                } else {
+                    rename_schema_refs(child, renames);
+                }
+            }
+        }
+        Value::Array(values) => {
+            for child in values {
+                rename_schema_refs(child, renames);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Build a curl command for an operation, sending its JSON request body example if any
+fn curl_sample(method: &str, url: &str, operation: &aide::openapi::Operation) -> String {
+    let mut source = format!("curl -X {method} '{url}'");
+
+    let json_body = operation.request_body.as_ref().and_then(|body| {
+        if let ReferenceOr::Item(body) = body {
+            body.content.get("application/json")
+        } else {
+            None
+        }
+    });
+    if let Some(media) = json_body {
+        let example = media
+            .example
+            .as_ref()
+            .map_or_else(|| "{}".to_string(), ToString::to_string);
+        source.push_str(" \\\n  -H 'Content-Type: application/json'");
+        source.push_str(" \\\n  -d '");
+        source.push_str(&example.replace('\'', r"'\''"));
+        source.push('\'');
+    }
+
+    source
+}
@@ -0,0 +1,92 @@
+use axum::body::Body;
+use axum::http::Request;
+use rovo::aide::openapi::OpenApi;
+use rovo::extract::{Path, State};
+use rovo::response::Json;
+use rovo::routing::get;
+use rovo::{rovo, Router};
+use tower::ServiceExt;
+
+#[derive(Clone)]
+struct UserHandlers {
+    prefix: String,
+}
+
+#[rovo]
+impl UserHandlers {
+    /// List users.
+    ///
+    /// # Responses
+    ///
+    /// 200: Json<Vec<String>> - All users
+    ///
+    /// # Metadata
+    ///
+    /// @tag users
+    #[rovo]
+    async fn list_users(&self) -> Json<Vec<String>> {
+        Json(vec![self.name("alice")])
+    }
+
+    /// Get a user.
+    ///
+    /// # Path Parameters
+    ///
+    /// id: The user ID
+    ///
+    /// # Responses
+    ///
+    /// 200: Json<String> - The user's name
+    #[rovo]
+    async fn get_user(State(handlers): State<Self>, Path(id): Path<u64>) -> Json<String> {
+        Json(handlers.name(&id.to_string()))
+    }
+
+    fn name(&self, name: &str) -> String {
+        format!("{}{name}", self.prefix)
+    }
+}
+
+fn app() -> axum::Router {
+    Router::new()
+        .route("/users", get(UserHandlers::LIST_USERS))
+        .route("/users/{id}", get(UserHandlers::GET_USER))
+        .with_oas(OpenApi::default())
+        .with_state(UserHandlers {
+            prefix: "user-".to_string(),
+        })
+        .finish()
+}
+
+async fn get_body(app: axum::Router, uri: &str) -> String {
+    let response = app
+        .oneshot(Request::builder().uri(uri).body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    String::from_utf8(body.to_vec()).unwrap()
+}
+
+#[tokio::test]
+async fn method_handlers_receive_state() {
+    assert_eq!(get_body(app(), "/users").await, r#"["user-alice"]"#);
+    assert_eq!(get_body(app(), "/users/7").await, r#""user-7""#);
+}
+
+#[tokio::test]
+async fn method_handlers_are_documented() {
+    let spec: serde_json::Value =
+        serde_json::from_str(&get_body(app(), "/api.json").await).unwrap();
+
+    let list_users = &spec["paths"]["/users"]["get"];
+    assert_eq!(list_users["operationId"], "list_users");
+    assert_eq!(list_users["tags"][0], "users");
+    assert_eq!(list_users["responses"]["200"]["description"], "All users");
+
+    let get_user = &spec["paths"]["/users/{id}"]["get"];
+    assert_eq!(get_user["operationId"], "get_user");
+    assert_eq!(get_user["parameters"][0]["name"], "id");
+    assert_eq!(get_user["parameters"][0]["description"], "The user ID");
+}
@@ -88,6 +88,49 @@ fn test_router_into_inner() {
     let _inner = router.into_inner();
 }
 
+#[test]
+fn test_router_into_parts_post_processes_spec() {
+    /// List items
+    ///
+    /// # Responses
+    ///
+    /// 200: Json<Vec<Item>> - A page of items
+    ///
+    /// # Metadata
+    ///
+    /// @paginated
+    #[rovo]
+    async fn list_items(State(_state): State<AppState>) -> Json<Vec<Item>> {
+        Json(Vec::new())
+    }
+
+    let (api_router, spec_post_process) = Router::new()
+        .route("/items", get(list_items))
+        .with_pagination_style(rovo::PaginationStyle::Cursor)
+        .into_parts();
+
+    let mut api = OpenApi::default();
+    api.info.title = "Test API".to_string();
+    let _app: axum::Router = api_router.finish_api(&mut api).with_state(AppState);
+    spec_post_process.apply(&mut api).unwrap();
+
+    let paths = &api.paths.as_ref().unwrap().paths;
+    let aide::openapi::ReferenceOr::Item(item) = paths.get("/items").unwrap() else {
+        panic!("Expected PathItem, got Reference");
+    };
+    let op = item.get.as_ref().unwrap();
+    assert!(op.extensions.keys().all(|key| !key.starts_with("x-rovo-")));
+    let names: Vec<&str> = op
+        .parameters
+        .iter()
+        .filter_map(|p| match p {
+            aide::openapi::ReferenceOr::Item(p) => Some(p.parameter_data_ref().name.as_str()),
+            aide::openapi::ReferenceOr::Reference { .. } => None,
+        })
+        .collect();
+    assert_eq!(names, vec!["cursor", "limit", "sort"]);
+}
+
 #[test]
 fn test_router_finish_api() {
     let router = Router::new().route("/items", get(get_item));
@@ -290,6 +333,54 @@ fn test_with_oas_route_strips_yml_extension() {
     });
 }
 
+#[test]
+fn test_with_oas_route_adds_missing_leading_slash() {
+    let app = Router::new()
+        .route("/items", get(get_item))
+        .with_oas_route(OpenApi::default(), "docs/spec.yml")
+        .with_state(AppState)
+        .finish();
+
+    let rt = tokio::runtime::Runtime::new().unwrap();
+
+    rt.block_on(async {
+        use axum::body::Body;
+        use axum::http::Request;
+        use tower::util::ServiceExt;
+
+        for uri in ["/docs/spec.json", "/docs/spec.yaml", "/docs/spec.yml"] {
+            let response = app
+                .clone()
+                .oneshot(Request::builder().uri(uri).body(Body::empty()).unwrap())
+                .await
+                .unwrap();
+
+            assert_eq!(response.status(), StatusCode::OK, "{uri}");
+        }
+    });
+}
+
+#[test]
+fn test_try_with_oas_route_rejects_empty_routes() {
+    for route in ["", "/", ".json", "/.yaml"] {
+        let err = Router::<AppState>::new()
+            .try_with_oas_route(OpenApi::default(), route)
+            .err()
+            .unwrap();
+        assert_eq!(err, rovo::RouterError::EmptyOasRoute(route.to_string()));
+    }
+
+    assert!(Router::<AppState>::new()
+        .try_with_oas_route(OpenApi::default(), "/openapi")
+        .is_ok());
+}
+
+#[test]
+#[should_panic(expected = "invalid OpenAPI route \"\": it needs a path")]
+fn test_with_oas_route_panics_on_empty_route() {
+    let _ = Router::<AppState>::new().with_oas_route(OpenApi::default(), "");
+}
+
 #[test]
 fn test_nest_with_child_having_oas() {
     let state = AppState;
@@ -371,6 +462,49 @@ fn test_router_without_oas() {
     });
 }
 
+/// Health check without a `# Responses` section
+#[rovo]
+async fn health_check(State(_state): State<AppState>) -> StatusCode {
+    StatusCode::OK
+}
+
+#[test]
+fn test_require_documented_responses_names_undocumented_handlers() {
+    let err = Router::new()
+        .route("/items", get(get_item))
+        .route("/health", get(health_check))
+        .with_oas(OpenApi::default())
+        .require_documented_responses()
+        .with_state(AppState)
+        .try_finish()
+        .err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        rovo::RouterError::UndocumentedResponses(vec!["GET /health (health_check)".to_string()])
+    );
+    assert!(err.to_string().contains("GET /health (health_check)"));
+
+    assert!(Router::new()
+        .route("/items", get(get_item))
+        .with_oas(OpenApi::default())
+        .require_documented_responses()
+        .with_state(AppState)
+        .try_finish()
+        .is_ok());
+}
+
+#[test]
+#[should_panic(expected = "operations without documented responses: GET /health (health_check)")]
+fn test_finish_panics_on_undocumented_responses() {
+    let _ = Router::new()
+        .route("/health", get(health_check))
+        .require_documented_responses()
+        .with_state(AppState)
+        .finish();
+}
+
 /// GET handler
 #[rovo]
 async fn handler_get() -> StatusCode {
@@ -430,6 +564,54 @@ fn test_all_http_methods() {
     assert!(path.delete.is_some(), "Should have DELETE");
 }
 
+/// HEAD handler
+#[rovo]
+async fn handler_head() -> StatusCode {
+    StatusCode::OK
+}
+
+/// OPTIONS handler
+#[rovo]
+async fn handler_options() -> StatusCode {
+    StatusCode::NO_CONTENT
+}
+
+#[test]
+fn test_head_and_options_routing() {
+    let mut api = OpenApi::default();
+    api.info.title = "Test API".to_string();
+
+    let app = Router::new()
+        .route("/head", rovo::routing::head(handler_head))
+        .route(
+            "/items",
+            rovo::routing::options(handler_options)
+                .get(handler_get)
+                .head(handler_head),
+        )
+        .route("/options", get(handler_get).options(handler_options))
+        .with_oas(api)
+        .with_state(())
+        .finish();
+
+    let spec = extract_openapi_from_router(app);
+    let paths = &spec.paths.as_ref().unwrap().paths;
+
+    let head = get_path_item(paths.get("/head").unwrap());
+    assert!(head.head.is_some(), "Should have HEAD");
+
+    let items = get_path_item(paths.get("/items").unwrap());
+    assert!(items.options.is_some(), "Should have OPTIONS");
+    assert!(items.get.is_some(), "Should have GET");
+    assert!(items.head.is_some(), "Should have HEAD from chaining");
+
+    let options = get_path_item(paths.get("/options").unwrap());
+    assert!(
+        options.options.is_some(),
+        "Should have OPTIONS from chaining"
+    );
+}
+
 // Helper functions
 fn get_path_item(
     path: &aide::openapi::ReferenceOr<aide::openapi::PathItem>,
@@ -223,6 +223,150 @@ fn test_nested_routers() {
     );
 }
 
+#[test]
+fn test_with_oas_before_routes() {
+    let state = AppState;
+    let mut api = OpenApi::default();
+    api.info.title = "Test API".to_string();
+
+    // Routes added after `with_oas` are still collected when the router is finished
+    let app = Router::new()
+        .with_oas(api)
+        .route("/items", get(list_items).post(create_item))
+        .nest(
+            "/api",
+            Router::new().route("/items/{id}", delete(delete_item)),
+        )
+        .with_state(state)
+        .finish();
+
+    let rt = tokio::runtime::Runtime::new().unwrap();
+
+    rt.block_on(async {
+        use axum::body::Body;
+        use axum::http::Request;
+        use tower::util::ServiceExt;
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/api.json")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let spec: serde_json::Value = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(spec["info"]["title"], "Test API");
+        assert!(spec["paths"]["/items"]["get"].is_object());
+        assert!(spec["paths"]["/items"]["post"].is_object());
+        assert!(spec["paths"]["/api/items/{id}"]["delete"].is_object());
+    });
+}
+
+#[test]
+fn test_unrouted_handlers() {
+    /// Archive an item
+    ///
+    /// # Responses
+    ///
+    /// 204: () - Archived
+    #[rovo]
+    async fn archive_item(State(_state): State<AppState>) -> StatusCode {
+        StatusCode::NO_CONTENT
+    }
+
+    let router = Router::<AppState>::new().nest(
+        "/api",
+        Router::new().route("/items", get(list_items).post(create_item)),
+    );
+    let unrouted = router.unrouted_handlers();
+
+    assert!(unrouted.contains(&"router_functionality::archive_item"));
+    assert!(unrouted.contains(&"router_functionality::delete_item"));
+    assert!(!unrouted.contains(&"router_functionality::list_items"));
+    assert!(!unrouted.contains(&"router_functionality::create_item"));
+}
+
+mod admin {
+    use super::{AppState, Item};
+    use rovo::extract::State;
+    use rovo::response::Json;
+    use rovo::rovo;
+
+    /// List items for administrators
+    ///
+    /// # Responses
+    ///
+    /// 200: Json<Vec<Item>> - List of items
+    #[rovo]
+    pub async fn list_items(State(_state): State<AppState>) -> Json<Vec<Item>> {
+        Json(vec![])
+    }
+}
+
+fn router_with_duplicate_ids() -> Router<AppState> {
+    Router::new()
+        .route("/items", get(list_items).post(create_item))
+        .route("/admin/items", get(admin::list_items))
+}
+
+#[test]
+fn test_validate_operation_ids() {
+    let duplicates = router_with_duplicate_ids()
+        .validate_operation_ids()
+        .err()
+        .expect("list_items is used twice");
+    assert_eq!(duplicates, vec!["list_items".to_string()]);
+
+    let router = Router::<AppState>::new()
+        .route("/items", get(list_items).post(create_item))
+        .validate_operation_ids()
+        .unwrap_or_else(|ids| panic!("unexpected duplicates: {ids:?}"));
+    let mut api = OpenApi::default();
+    let _ = router.finish_api(&mut api);
+    assert_eq!(api.paths.unwrap().paths.len(), 1);
+}
+
+#[test]
+#[should_panic(expected = "duplicate OpenAPI operation IDs: list_items")]
+fn test_strict_panics_on_duplicate_operation_ids() {
+    let mut api = OpenApi::default();
+    let _ = router_with_duplicate_ids().strict().finish_api(&mut api);
+}
+
+#[test]
+fn test_strict_try_finish_returns_duplicate_operation_ids() {
+    let err = router_with_duplicate_ids()
+        .with_oas(OpenApi::default())
+        .strict()
+        .with_state(AppState)
+        .try_finish()
+        .expect_err("list_items is used twice");
+    assert_eq!(
+        err,
+        rovo::RouterError::DuplicateOperationIds(vec!["list_items".to_string()])
+    );
+}
+
+#[test]
+fn test_strict_try_finish_checks_operation_ids_without_oas() {
+    let err = router_with_duplicate_ids()
+        .strict()
+        .try_finish()
+        .expect_err("list_items is used twice");
+    assert_eq!(
+        err,
+        rovo::RouterError::DuplicateOperationIds(vec!["list_items".to_string()])
+    );
+}
+
 #[test]
 fn test_custom_oas_route() {
     let state = AppState;
@@ -278,22 +422,719 @@ fn test_custom_oas_route() {
             "Should be able to fetch /openapi.yaml"
         );
 
-        // Verify /openapi.yml works
-        let yml_response = app
+        // Verify /openapi.yml works
+        let yml_response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/openapi.yml")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            yml_response.status(),
+            StatusCode::OK,
+            "Should be able to fetch /openapi.yml"
+        );
+    });
+}
+
+#[test]
+fn test_oas_cache_control() {
+    let state = AppState;
+    let mut api = OpenApi::default();
+    api.info.title = "Test API".to_string();
+
+    let app = Router::new()
+        .route("/items", get(list_items))
+        .with_oas(api)
+        .with_oas_cache_control("public, max-age=3600")
+        .with_state(state)
+        .finish();
+
+    let rt = tokio::runtime::Runtime::new().unwrap();
+
+    rt.block_on(async {
+        use axum::body::Body;
+        use axum::http::{header, Request};
+        use tower::util::ServiceExt;
+
+        for uri in ["/api.json", "/api.yaml", "/api.yml"] {
+            let response = app
+                .clone()
+                .oneshot(Request::builder().uri(uri).body(Body::empty()).unwrap())
+                .await
+                .unwrap();
+
+            assert_eq!(response.status(), StatusCode::OK);
+            assert_eq!(
+                response.headers().get(header::CACHE_CONTROL).unwrap(),
+                "public, max-age=3600",
+                "{uri} should have the configured Cache-Control header"
+            );
+        }
+    });
+}
+
+#[test]
+fn test_oas_filename_sets_content_disposition_on_download() {
+    let mut api = OpenApi::default();
+    api.info.title = "Test API".to_string();
+
+    let app = Router::new()
+        .route("/items", get(list_items))
+        .with_oas(api)
+        .with_oas_filename("my-api.json")
+        .with_state(AppState)
+        .finish();
+
+    let rt = tokio::runtime::Runtime::new().unwrap();
+
+    rt.block_on(async {
+        use axum::body::Body;
+        use axum::http::{header, Request};
+        use tower::util::ServiceExt;
+
+        for (uri, filename) in [
+            ("/api.json?download=1", "my-api.json"),
+            ("/api.yaml?download=1", "my-api.yaml"),
+            ("/api.yml?download", "my-api.yml"),
+        ] {
+            let response = app
+                .clone()
+                .oneshot(Request::builder().uri(uri).body(Body::empty()).unwrap())
+                .await
+                .unwrap();
+
+            assert_eq!(response.status(), StatusCode::OK, "{uri}");
+            assert_eq!(
+                response.headers().get(header::CONTENT_DISPOSITION).unwrap(),
+                &format!("attachment; filename=\"{filename}\""),
+                "{uri}"
+            );
+        }
+
+        for uri in ["/api.json", "/api.json?download=0"] {
+            let response = app
+                .clone()
+                .oneshot(Request::builder().uri(uri).body(Body::empty()).unwrap())
+                .await
+                .unwrap();
+
+            assert!(
+                response
+                    .headers()
+                    .get(header::CONTENT_DISPOSITION)
+                    .is_none(),
+                "{uri} should not be a download"
+            );
+        }
+    });
+}
+
+#[cfg(feature = "swagger")]
+#[test]
+fn test_oas_download_is_not_redirected_to_docs_ui() {
+    let app = Router::new()
+        .route("/items", get(list_items))
+        .with_oas(OpenApi::default())
+        .with_oas_filename("my-api")
+        .with_swagger("/swagger")
+        .with_state(AppState)
+        .finish();
+
+    let rt = tokio::runtime::Runtime::new().unwrap();
+
+    rt.block_on(async {
+        use axum::body::Body;
+        use axum::http::{header, Request};
+        use tower::util::ServiceExt;
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/api.json?download=1")
+                    .header(header::ACCEPT, "text/html")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response.headers().get(header::CONTENT_DISPOSITION).unwrap(),
+            "attachment; filename=\"my-api.json\""
+        );
+    });
+}
+
+#[test]
+fn test_oas_route_method_post() {
+    let build = |post: bool| {
+        let mut api = OpenApi::default();
+        api.info.title = "Test API".to_string();
+        let router = Router::new().route("/items", get(list_items)).with_oas(api);
+        let router = if post {
+            router.with_oas_route_method(rovo::http::Method::POST)
+        } else {
+            router
+        };
+        router.with_state(AppState).finish()
+    };
+
+    let rt = tokio::runtime::Runtime::new().unwrap();
+
+    rt.block_on(async {
+        use axum::body::Body;
+        use axum::http::{Method, Request};
+        use tower::util::ServiceExt;
+
+        let post = || {
+            Request::builder()
+                .method(Method::POST)
+                .uri("/api.json")
+                .body(Body::empty())
+                .unwrap()
+        };
+
+        let response = build(true).oneshot(post()).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let spec: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(spec["info"]["title"], "Test API");
+
+        // GET keeps working alongside the extra method
+        let response = build(true)
+            .oneshot(
+                Request::builder()
+                    .uri("/api.json")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let response = build(false).oneshot(post()).await.unwrap();
+        assert_eq!(response.status(), StatusCode::METHOD_NOT_ALLOWED);
+    });
+}
+
+#[test]
+fn test_oas_basic_auth() {
+    let state = AppState;
+    let mut api = OpenApi::default();
+    api.info.title = "Test API".to_string();
+
+    let app = Router::new()
+        .route("/items", get(list_items))
+        .with_oas(api)
+        .with_oas_basic_auth("admin", "secret")
+        .with_state(state)
+        .finish();
+
+    let rt = tokio::runtime::Runtime::new().unwrap();
+
+    rt.block_on(async {
+        use axum::body::Body;
+        use axum::http::{header, Request};
+        use tower::util::ServiceExt;
+
+        let request = |uri: &str, authorization: Option<&str>| {
+            let mut builder = Request::builder().uri(uri);
+            if let Some(authorization) = authorization {
+                builder = builder.header(header::AUTHORIZATION, authorization);
+            }
+            builder.body(Body::empty()).unwrap()
+        };
+
+        // Correct credentials ("admin:secret")
+        for uri in ["/api.json", "/api.yaml", "/api.yml"] {
+            let response = app
+                .clone()
+                .oneshot(request(uri, Some("Basic YWRtaW46c2VjcmV0")))
+                .await
+                .unwrap();
+            assert_eq!(response.status(), StatusCode::OK, "{uri}");
+        }
+
+        // Wrong password ("admin:wrong")
+        let response = app
+            .clone()
+            .oneshot(request("/api.json", Some("Basic YWRtaW46d3Jvbmc=")))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+        // Missing credentials get a challenge
+        let response = app
+            .clone()
+            .oneshot(request("/api.json", None))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+        let challenge = response
+            .headers()
+            .get(header::WWW_AUTHENTICATE)
+            .expect("Should have a WWW-Authenticate header")
+            .to_str()
+            .unwrap();
+        assert!(challenge.starts_with("Basic"), "{challenge}");
+
+        // API routes stay public
+        let response = app.oneshot(request("/items", None)).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+    });
+}
+
+#[cfg(feature = "scalar")]
+#[test]
+fn test_scalar_config_embeds_theme() {
+    use rovo::{ScalarConfig, ScalarLayout};
+
+    let state = AppState;
+    let mut api = OpenApi::default();
+    api.info.title = "Test API".to_string();
+
+    let app = Router::new()
+        .route("/items", get(list_items))
+        .with_oas_route(api, "/api-docs/openapi.json")
+        .with_scalar_config(
+            "/scalar",
+            ScalarConfig::new()
+                .theme("solarized")
+                .layout(ScalarLayout::Classic)
+                .show_sidebar(false),
+        )
+        .with_state(state)
+        .finish();
+
+    let rt = tokio::runtime::Runtime::new().unwrap();
+
+    rt.block_on(async {
+        use axum::body::Body;
+        use axum::http::Request;
+        use tower::util::ServiceExt;
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/scalar")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let html = String::from_utf8(body.to_vec()).unwrap();
+        assert!(html.contains("&quot;theme&quot;:&quot;solarized&quot;"));
+        assert!(html.contains("&quot;layout&quot;:&quot;classic&quot;"));
+        assert!(html.contains("&quot;showSidebar&quot;:false"));
+        assert!(html.contains(r#"data-url="/api-docs/openapi.json""#));
+    });
+}
+
+#[cfg(feature = "rapidoc")]
+#[test]
+fn test_rapidoc_points_at_spec_route() {
+    let state = AppState;
+    let mut api = OpenApi::default();
+    api.info.title = "Test API".to_string();
+
+    let app = Router::new()
+        .route("/items", get(list_items))
+        .with_oas_route(api, "/api-docs/openapi.json")
+        .with_rapidoc("/rapidoc")
+        .with_state(state)
+        .finish();
+
+    let rt = tokio::runtime::Runtime::new().unwrap();
+
+    rt.block_on(async {
+        use axum::body::Body;
+        use axum::http::Request;
+        use tower::util::ServiceExt;
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/rapidoc")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let html = String::from_utf8(body.to_vec()).unwrap();
+        assert!(html.contains("rapidoc-min.js"));
+        assert!(html.contains(r#"<rapi-doc spec-url="/api-docs/openapi.json">"#));
+    });
+}
+
+#[cfg(feature = "compression")]
+#[test]
+fn test_spec_is_gzipped_when_accepted() {
+    let state = AppState;
+    let mut api = OpenApi::default();
+    api.info.title = "Test API".to_string();
+
+    let app = Router::new()
+        .route("/items", get(list_items))
+        .with_oas(api)
+        .with_state(state)
+        .finish();
+
+    let rt = tokio::runtime::Runtime::new().unwrap();
+
+    rt.block_on(async {
+        use axum::body::Body;
+        use axum::http::{header, Request};
+        use std::io::Read;
+        use tower::util::ServiceExt;
+
+        for route in ["/api.json", "/api.yaml"] {
+            let response = app
+                .clone()
+                .oneshot(
+                    Request::builder()
+                        .uri(route)
+                        .header(header::ACCEPT_ENCODING, "br;q=1.0, gzip;q=0.8")
+                        .body(Body::empty())
+                        .unwrap(),
+                )
+                .await
+                .unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+            assert_eq!(response.headers()[header::CONTENT_ENCODING], "gzip");
+
+            let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+                .await
+                .unwrap();
+            let mut spec = String::new();
+            flate2::read::GzDecoder::new(&body[..])
+                .read_to_string(&mut spec)
+                .unwrap();
+            assert!(spec.contains("Test API"), "{route}");
+        }
+
+        // Uncompressed without the header, or when gzip is refused
+        for accept_encoding in [None, Some("gzip;q=0")] {
+            let mut request = Request::builder().uri("/api.json");
+            if let Some(accept_encoding) = accept_encoding {
+                request = request.header(header::ACCEPT_ENCODING, accept_encoding);
+            }
+            let response = app
+                .clone()
+                .oneshot(request.body(Body::empty()).unwrap())
+                .await
+                .unwrap();
+            assert!(response.headers().get(header::CONTENT_ENCODING).is_none());
+
+            let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+                .await
+                .unwrap();
+            let spec: serde_json::Value = serde_json::from_slice(&body).unwrap();
+            assert_eq!(spec["info"]["title"], "Test API");
+        }
+    });
+}
+
+#[cfg(feature = "swagger")]
+#[test]
+fn test_oas_basic_auth_protects_docs_ui() {
+    let state = AppState;
+    let mut api = OpenApi::default();
+    api.info.title = "Test API".to_string();
+
+    let app = Router::new()
+        .route("/items", get(list_items))
+        .with_oas(api)
+        .with_swagger("/swagger")
+        .with_oas_basic_auth("admin", "secret")
+        .with_state(state)
+        .finish();
+
+    let rt = tokio::runtime::Runtime::new().unwrap();
+
+    rt.block_on(async {
+        use axum::body::Body;
+        use axum::http::{header, Request};
+        use tower::util::ServiceExt;
+
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .uri("/swagger")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/swagger")
+                    .header(header::AUTHORIZATION, "Basic YWRtaW46c2VjcmV0")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+    });
+}
+
+#[cfg(feature = "swagger")]
+#[test]
+fn test_oas_basic_auth_protects_nested_docs_ui() {
+    let state = AppState;
+    let mut api = OpenApi::default();
+    api.info.title = "Test API".to_string();
+
+    let api_router = Router::new()
+        .route("/items", get(list_items))
+        .with_swagger("/docs");
+
+    let app = Router::new()
+        .nest("/v1", api_router)
+        .with_oas(api)
+        .with_oas_basic_auth("admin", "secret")
+        .with_state(state)
+        .finish();
+
+    let rt = tokio::runtime::Runtime::new().unwrap();
+
+    rt.block_on(async {
+        use axum::body::Body;
+        use axum::http::{header, Request};
+        use tower::util::ServiceExt;
+
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .uri("/v1/docs")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/v1/docs")
+                    .header(header::AUTHORIZATION, "Basic YWRtaW46c2VjcmV0")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+    });
+}
+
+#[test]
+fn test_oas_no_cache_control_by_default() {
+    let state = AppState;
+    let mut api = OpenApi::default();
+    api.info.title = "Test API".to_string();
+
+    let app = Router::new()
+        .route("/items", get(list_items))
+        .with_oas(api)
+        .with_state(state)
+        .finish();
+
+    let rt = tokio::runtime::Runtime::new().unwrap();
+
+    rt.block_on(async {
+        use axum::body::Body;
+        use axum::http::{header, Request};
+        use tower::util::ServiceExt;
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/api.json")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert!(response.headers().get(header::CACHE_CONTROL).is_none());
+    });
+}
+
+#[cfg(feature = "swagger")]
+#[test]
+fn test_html_request_to_spec_redirects_to_nested_swagger() {
+    let state = AppState;
+    let mut api = OpenApi::default();
+    api.info.title = "Test API".to_string();
+
+    let api_router = Router::new()
+        .route("/items", get(list_items))
+        .with_swagger("/docs");
+
+    let app = Router::new()
+        .nest("/v1", api_router)
+        .with_oas(api)
+        .with_state(state)
+        .finish();
+
+    let rt = tokio::runtime::Runtime::new().unwrap();
+
+    rt.block_on(async {
+        use axum::body::Body;
+        use axum::http::{header, Request};
+        use tower::util::ServiceExt;
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/api.json")
+                    .header(header::ACCEPT, "text/html")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert!(response.status().is_redirection());
+        assert_eq!(
+            response.headers().get(header::LOCATION).unwrap(),
+            "/v1/docs"
+        );
+    });
+}
+
+#[cfg(feature = "swagger")]
+#[test]
+fn test_html_request_to_spec_redirects_to_swagger() {
+    let state = AppState;
+    let mut api = OpenApi::default();
+    api.info.title = "Test API".to_string();
+
+    let app = Router::new()
+        .route("/items", get(list_items))
+        .with_oas(api)
+        .with_swagger("/swagger")
+        .with_state(state)
+        .finish();
+
+    let rt = tokio::runtime::Runtime::new().unwrap();
+
+    rt.block_on(async {
+        use axum::body::Body;
+        use axum::http::{header, Request};
+        use tower::util::ServiceExt;
+
+        // A browser navigating to the spec is sent to the UI
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .uri("/api.json")
+                    .header(
+                        header::ACCEPT,
+                        "text/html,application/xhtml+xml,application/xml;q=0.9,*/*;q=0.8",
+                    )
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert!(response.status().is_redirection());
+        assert_eq!(
+            response.headers().get(header::LOCATION).unwrap(),
+            "/swagger"
+        );
+        assert!(varies_on_accept(&response));
+
+        // API clients (including Swagger UI itself) still get JSON
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/api.json")
+                    .header(header::ACCEPT, "application/json,*/*")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response.headers().get(header::CONTENT_TYPE).unwrap(),
+            "application/json"
+        );
+        // Caches must not hand the JSON to browsers, or the redirect to clients
+        assert!(varies_on_accept(&response));
+    });
+}
+
+/// Whether a response's `Vary` header lists `Accept`
+fn varies_on_accept(response: &axum::response::Response) -> bool {
+    response
+        .headers()
+        .get(axum::http::header::VARY)
+        .and_then(|vary| vary.to_str().ok())
+        .is_some_and(|vary| vary.split(',').any(|name| name.trim() == "accept"))
+}
+
+#[test]
+fn test_html_request_to_spec_without_ui_returns_json() {
+    let state = AppState;
+    let mut api = OpenApi::default();
+    api.info.title = "Test API".to_string();
+
+    let app = Router::new()
+        .route("/items", get(list_items))
+        .with_oas(api)
+        .with_state(state)
+        .finish();
+
+    let rt = tokio::runtime::Runtime::new().unwrap();
+
+    rt.block_on(async {
+        use axum::body::Body;
+        use axum::http::{header, Request};
+        use tower::util::ServiceExt;
+
+        let response = app
             .oneshot(
                 Request::builder()
-                    .uri("/openapi.yml")
+                    .uri("/api.json")
+                    .header(header::ACCEPT, "text/html")
                     .body(Body::empty())
                     .unwrap(),
             )
             .await
             .unwrap();
 
-        assert_eq!(
-            yml_response.status(),
-            StatusCode::OK,
-            "Should be able to fetch /openapi.yml"
-        );
+        assert_eq!(response.status(), StatusCode::OK);
+        assert!(!varies_on_accept(&response));
     });
 }
 
@@ -336,6 +1177,115 @@ fn test_deprecated_endpoint() {
     );
 }
 
+#[test]
+#[allow(deprecated)]
+fn test_deprecated_note_appended_to_description() {
+    /// Old endpoint
+    ///
+    /// Lists items the old way.
+    ///
+    /// # Responses
+    ///
+    /// 200: Json<Vec<Item>> - Old response
+    #[deprecated(since = "0.3.0", note = "use /v2/items")]
+    #[rovo]
+    async fn old_list_items(State(_state): State<AppState>) -> Json<Vec<Item>> {
+        Json(vec![])
+    }
+
+    /// Legacy endpoint
+    ///
+    /// # Responses
+    ///
+    /// 200: Json<Vec<Item>> - Legacy response
+    ///
+    /// # Metadata
+    ///
+    /// @deprecated Replaced by /v2/items
+    #[rovo]
+    async fn legacy_list_items(State(_state): State<AppState>) -> Json<Vec<Item>> {
+        Json(vec![])
+    }
+
+    let mut api = OpenApi::default();
+    api.info.title = "Test API".to_string();
+
+    let app = Router::new()
+        .route("/old-items", get(old_list_items))
+        .route("/legacy-items", get(legacy_list_items))
+        .with_oas(api)
+        .with_state(AppState)
+        .finish();
+
+    let spec = extract_openapi_from_router(app);
+    let paths = &spec.paths.as_ref().unwrap().paths;
+
+    let old_op = get_path_item(paths.get("/old-items").unwrap())
+        .get
+        .as_ref()
+        .unwrap();
+    assert!(old_op.deprecated);
+    assert_eq!(
+        old_op.description.as_deref(),
+        Some("Lists items the old way.\n\nDeprecated since 0.3.0: use /v2/items")
+    );
+
+    let legacy_op = get_path_item(paths.get("/legacy-items").unwrap())
+        .get
+        .as_ref()
+        .unwrap();
+    assert!(legacy_op.deprecated);
+    assert_eq!(
+        legacy_op.description.as_deref(),
+        Some("Deprecated: Replaced by /v2/items")
+    );
+}
+
+#[test]
+#[allow(deprecated)]
+fn test_deprecated_message_extension() {
+    /// Old endpoint
+    ///
+    /// # Responses
+    ///
+    /// 200: Json<Vec<Item>> - Old response
+    ///
+    /// # Metadata
+    ///
+    /// @deprecated-message Use /v2/items instead
+    #[deprecated]
+    #[rovo]
+    async fn old_list_items(State(_state): State<AppState>) -> Json<Vec<Item>> {
+        Json(vec![])
+    }
+
+    let mut api = OpenApi::default();
+    api.info.title = "Test API".to_string();
+
+    let app = Router::new()
+        .route("/old-items", get(old_list_items))
+        .with_oas(api)
+        .with_state(AppState)
+        .finish();
+
+    let spec = extract_openapi_from_router(app);
+    let paths = &spec.paths.as_ref().unwrap().paths;
+    let get_op = get_path_item(paths.get("/old-items").unwrap())
+        .get
+        .as_ref()
+        .unwrap();
+
+    assert!(get_op.deprecated);
+    assert_eq!(
+        get_op.extensions.get("x-deprecated-message"),
+        Some(&serde_json::json!("Use /v2/items instead"))
+    );
+    assert_eq!(
+        get_op.description.as_deref(),
+        Some("Deprecated: Use /v2/items instead")
+    );
+}
+
 #[test]
 fn test_security_annotation() {
     /// Protected endpoint
@@ -384,6 +1334,85 @@ fn test_security_annotation() {
     );
 }
 
+#[test]
+fn test_security_and_annotation() {
+    /// Internal endpoint
+    ///
+    /// # Responses
+    ///
+    /// 200: Json<Vec<Item>> - Internal response
+    ///
+    /// # Metadata
+    ///
+    /// @security-and bearer_auth api_key
+    /// @security mtls
+    #[rovo]
+    async fn internal_items(State(_state): State<AppState>) -> Json<Vec<Item>> {
+        Json(vec![])
+    }
+
+    let mut api = OpenApi::default();
+    api.info.title = "Test API".to_string();
+
+    let app = Router::new()
+        .route("/internal", get(internal_items))
+        .with_oas(api)
+        .with_state(AppState)
+        .finish();
+
+    let spec = extract_openapi_from_router(app);
+    let paths = &spec.paths.as_ref().unwrap().paths;
+    let get_op = get_path_item(paths.get("/internal").unwrap())
+        .get
+        .as_ref()
+        .unwrap();
+
+    assert_eq!(get_op.security.len(), 2, "Should have two alternatives");
+    let combined = &get_op.security[0];
+    assert!(combined.contains_key("bearer_auth") && combined.contains_key("api_key"));
+    assert_eq!(combined.len(), 2);
+    assert_eq!(get_op.security[1].keys().collect::<Vec<_>>(), vec!["mtls"]);
+}
+
+#[test]
+fn test_security_scopes_annotation() {
+    /// Update items
+    ///
+    /// # Responses
+    ///
+    /// 200: Json<Vec<Item>> - Updated items
+    ///
+    /// # Metadata
+    ///
+    /// @security oauth2 read:items write:items
+    #[rovo]
+    async fn update_items(State(_state): State<AppState>) -> Json<Vec<Item>> {
+        Json(vec![])
+    }
+
+    let mut api = OpenApi::default();
+    api.info.title = "Test API".to_string();
+
+    let app = Router::new()
+        .route("/items", get(update_items))
+        .with_oas(api)
+        .with_state(AppState)
+        .finish();
+
+    let spec = extract_openapi_from_router(app);
+    let paths = &spec.paths.as_ref().unwrap().paths;
+    let get_op = get_path_item(paths.get("/items").unwrap())
+        .get
+        .as_ref()
+        .unwrap();
+
+    assert_eq!(get_op.security.len(), 1);
+    assert_eq!(
+        get_op.security[0].get("oauth2").unwrap(),
+        &vec!["read:items".to_string(), "write:items".to_string()]
+    );
+}
+
 #[test]
 fn test_custom_operation_id() {
     /// Get items
@@ -499,6 +1528,213 @@ fn test_multiple_tags() {
     assert!(get_op.tags.contains(&"deprecated".to_string()));
 }
 
+#[test]
+fn test_openapi_3_1_nullable_uses_type_array() {
+    use rovo::schemars::{json_schema, Schema, SchemaGenerator};
+    use std::borrow::Cow;
+
+    /// A type whose schema still uses the OpenAPI 3.0 `nullable` keyword
+    #[derive(Serialize)]
+    struct LegacyNote(Option<String>);
+
+    impl JsonSchema for LegacyNote {
+        fn schema_name() -> Cow<'static, str> {
+            "LegacyNote".into()
+        }
+
+        fn json_schema(_generator: &mut SchemaGenerator) -> Schema {
+            json_schema!({ "type": "string", "nullable": true })
+        }
+    }
+
+    #[derive(Serialize, JsonSchema)]
+    struct Record {
+        note: LegacyNote,
+        nickname: Option<String>,
+    }
+
+    /// Get a record
+    ///
+    /// # Responses
+    ///
+    /// 200: Json<Record> - The record
+    #[rovo]
+    async fn get_record(State(_state): State<AppState>) -> Json<Record> {
+        Json(Record {
+            note: LegacyNote(None),
+            nickname: None,
+        })
+    }
+
+    let mut api = OpenApi::default();
+    api.info.title = "Test API".to_string();
+
+    let app = Router::new()
+        .route("/record", get(get_record))
+        .with_oas(api)
+        .with_openapi_version("3.1.0")
+        .with_state(AppState)
+        .finish();
+
+    let rt = tokio::runtime::Runtime::new().unwrap();
+
+    rt.block_on(async {
+        use axum::body::Body;
+        use axum::http::Request;
+        use tower::util::ServiceExt;
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/api.json")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let spec: serde_json::Value = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(spec["openapi"], "3.1.0");
+        let text = spec.to_string();
+        assert!(
+            !text.contains("\"nullable\""),
+            "3.1 specs should not use the nullable keyword: {text}"
+        );
+        assert!(
+            text.contains(r#""type":["string","null"]"#),
+            "Nullable fields should use a type array: {text}"
+        );
+    });
+}
+
+#[derive(Serialize, JsonSchema)]
+struct Profile {
+    nickname: Option<String>,
+    age: Option<u32>,
+}
+
+/// Get a profile
+///
+/// # Responses
+///
+/// 200: Json<Profile> - The profile
+#[rovo]
+async fn get_profile(State(_state): State<AppState>) -> Json<Profile> {
+    Json(Profile {
+        nickname: None,
+        age: None,
+    })
+}
+
+#[test]
+fn test_openapi_3_0_downgrades_nullable_schemas() {
+    let mut api = OpenApi::default();
+    api.info.title = "Test API".to_string();
+
+    let app = Router::new()
+        .route("/profile", get(get_profile))
+        .with_oas(api)
+        .with_openapi_version("3.0.3")
+        .with_state(AppState)
+        .finish();
+
+    let spec = extract_spec_json(app);
+    assert_eq!(spec["openapi"], "3.0.3");
+    let profile = &spec["components"]["schemas"]["Profile"]["properties"];
+    assert_eq!(
+        profile["nickname"],
+        serde_json::json!({ "type": "string", "nullable": true })
+    );
+    assert_eq!(profile["age"]["type"], "integer");
+    assert_eq!(profile["age"]["nullable"], true);
+    let text = spec.to_string();
+    assert!(
+        !text.contains(r#""null""#),
+        "3.0 specs should not use the null type: {text}"
+    );
+}
+
+#[test]
+fn test_openapi_3_1_serializes_webhooks() {
+    let mut api = OpenApi::default();
+    api.info.title = "Test API".to_string();
+    api.webhooks.insert(
+        "itemCreated".to_string(),
+        aide::openapi::ReferenceOr::Item(aide::openapi::PathItem {
+            post: Some(aide::openapi::Operation {
+                summary: Some("An item was created".to_string()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }),
+    );
+
+    let app = Router::new()
+        .route("/profile", get(get_profile))
+        .with_oas(api)
+        .with_openapi_version("3.1.0")
+        .with_state(AppState)
+        .finish();
+
+    let spec = extract_spec_json(app);
+    assert_eq!(spec["openapi"], "3.1.0");
+    assert_eq!(
+        spec["webhooks"]["itemCreated"]["post"]["summary"],
+        "An item was created"
+    );
+}
+
+#[test]
+fn test_openapi_3_0_rejects_webhooks() {
+    let mut api = OpenApi::default();
+    api.info.title = "Test API".to_string();
+    api.webhooks.insert(
+        "itemCreated".to_string(),
+        aide::openapi::ReferenceOr::Item(aide::openapi::PathItem::default()),
+    );
+
+    let err = Router::new()
+        .route("/profile", get(get_profile))
+        .with_oas(api)
+        .with_openapi_version("3.0.3")
+        .with_state(AppState)
+        .try_finish()
+        .expect_err("webhooks need OpenAPI 3.1");
+
+    match err {
+        rovo::RouterError::InvalidSpec { version, problems } => {
+            assert_eq!(version, "3.0.3");
+            assert_eq!(problems, vec!["`webhooks` need OpenAPI 3.1".to_string()]);
+        }
+        other => panic!("expected InvalidSpec, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_unsupported_openapi_versions_are_rejected() {
+    for version in ["2.0", "3.2.0", "3.1", "3.0.x", "foo", ""] {
+        let result = Router::<()>::new().try_with_openapi_version(version);
+        assert!(
+            matches!(result, Err(rovo::RouterError::UnsupportedOpenApiVersion(ref v)) if v == version),
+            "{version:?} should be rejected"
+        );
+    }
+    for version in ["3.0.0", "3.0.3", "3.1.0", "3.1.1"] {
+        assert!(Router::<()>::new()
+            .try_with_openapi_version(version)
+            .is_ok());
+    }
+}
+
+#[test]
+#[should_panic(expected = "unsupported OpenAPI version \"foo\"")]
+fn test_with_openapi_version_panics_on_unsupported_version() {
+    let _ = Router::<()>::new().with_openapi_version("foo");
+}
+
 // Helper functions
 fn get_path_item(
     path: &aide::openapi::ReferenceOr<aide::openapi::PathItem>,
@@ -534,3 +1770,29 @@ fn extract_openapi_from_router(app: ::axum::Router) -> OpenApi {
         serde_json::from_slice(&body).unwrap()
     })
 }
+
+fn extract_spec_json(app: ::axum::Router) -> serde_json::Value {
+    use axum::body::Body;
+    use axum::http::Request;
+    use tower::util::ServiceExt;
+
+    let rt = tokio::runtime::Runtime::new().unwrap();
+
+    rt.block_on(async {
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/api.json")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+
+        serde_json::from_slice(&body).unwrap()
+    })
+}
@@ -632,3 +632,30 @@ fn test_code_block_same_line() {
         .with_state(_state)
         .finish();
 }
+
+// Test: Explicit #[doc = "..."] attributes instead of ///
+#[doc = "Get todo documented with explicit doc attributes."]
+#[doc = ""]
+#[doc = "# Responses\n"]
+#[doc = r#"200: Json<TodoItem> - The "current" todo item"#]
+#[doc = "404: () - Todo item was not found"]
+#[doc = ""]
+#[doc = "# Examples"]
+#[doc = ""]
+#[doc = "200: TodoItem::default()"]
+#[rovo]
+async fn get_todo_explicit_doc_attributes(
+    State(_app): State<AppState>,
+    Path(_id): Path<Uuid>,
+) -> impl IntoApiResponse {
+    Json(TodoItem::default())
+}
+
+#[test]
+fn test_explicit_doc_attributes() {
+    let _state = AppState {};
+    let _router: ::axum::Router = Router::<AppState>::new()
+        .route("/todos/{id}", get(get_todo_explicit_doc_attributes))
+        .with_state(_state)
+        .finish();
+}
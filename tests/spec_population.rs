@@ -423,6 +423,1865 @@ fn test_spec_contains_request_body() {
     assert!(post_op.request_body.is_some(), "Should have request body");
 }
 
+#[test]
+fn test_spec_contains_named_example_with_summary() {
+    /// Search users.
+    ///
+    /// # Responses
+    ///
+    /// 200: Json<Vec<User>> - Matching users
+    ///
+    /// # Examples
+    ///
+    /// 200 "empty" (summary: "No results"): Vec::<User>::new()
+    /// 200 "single": vec![User::default()]
+    #[rovo]
+    async fn search_users(State(_state): State<AppState>) -> Json<Vec<User>> {
+        Json(vec![])
+    }
+
+    let state = AppState;
+    let mut api = OpenApi::default();
+    api.info.title = "Test API".to_string();
+
+    let app = Router::new()
+        .route("/users/search", get(search_users))
+        .with_oas(api.clone())
+        .with_state(state)
+        .finish();
+
+    let spec = extract_openapi_from_router(app);
+
+    let paths = &spec.paths.as_ref().unwrap().paths;
+    let search_path = get_path_item(paths.get("/users/search").unwrap());
+    let get_op = search_path.get.as_ref().unwrap();
+    let responses = get_op.responses.as_ref().unwrap();
+
+    let status_200 = aide::openapi::StatusCode::Code(200);
+    if let aide::openapi::ReferenceOr::Item(response_200) =
+        responses.responses.get(&status_200).unwrap()
+    {
+        let json_content = response_200
+            .content
+            .get("application/json")
+            .expect("Should have JSON content");
+
+        // Named examples go into `examples`, not the single `example`
+        assert!(json_content.example.is_none());
+
+        let Some(aide::openapi::ReferenceOr::Item(empty)) = json_content.examples.get("empty")
+        else {
+            panic!("Should have an 'empty' example");
+        };
+        assert_eq!(empty.summary.as_deref(), Some("No results"));
+        assert_eq!(empty.value, Some(serde_json::json!([])));
+
+        let Some(aide::openapi::ReferenceOr::Item(single)) = json_content.examples.get("single")
+        else {
+            panic!("Should have a 'single' example");
+        };
+        assert!(single.summary.is_none());
+        assert_eq!(
+            single.value,
+            Some(serde_json::json!([{ "id": 0, "name": "" }]))
+        );
+    } else {
+        panic!("200 response should be an Item, not a Reference");
+    }
+}
+
+#[test]
+fn test_strict_responses_accepts_wrapped_return_type() {
+    /// Create a user.
+    ///
+    /// # Responses
+    ///
+    /// 200: Json<User> - User created
+    #[rovo(strict_responses)]
+    async fn create_user_strict(State(_state): State<AppState>) -> (StatusCode, Json<User>) {
+        (StatusCode::OK, Json(User::default()))
+    }
+
+    let state = AppState;
+    let mut api = OpenApi::default();
+    api.info.title = "Test API".to_string();
+
+    let app = Router::new()
+        .route("/users", rovo::routing::post(create_user_strict))
+        .with_oas(api.clone())
+        .with_state(state)
+        .finish();
+
+    let spec = extract_openapi_from_router(app);
+
+    let paths = &spec.paths.as_ref().unwrap().paths;
+    let users_path = get_path_item(paths.get("/users").unwrap());
+    let post_op = users_path.post.as_ref().unwrap();
+    let responses = post_op.responses.as_ref().unwrap();
+    assert!(responses
+        .responses
+        .contains_key(&aide::openapi::StatusCode::Code(200)));
+}
+
+#[test]
+fn test_spec_array_shorthand_response() {
+    /// List users.
+    ///
+    /// # Responses
+    ///
+    /// 200: [User] - List of users
+    #[rovo]
+    async fn list_users_shorthand(State(_state): State<AppState>) -> Json<Vec<User>> {
+        Json(vec![User::default()])
+    }
+
+    let state = AppState;
+    let mut api = OpenApi::default();
+    api.info.title = "Test API".to_string();
+
+    let app = Router::new()
+        .route("/users", get(list_users_shorthand))
+        .with_oas(api.clone())
+        .with_state(state)
+        .finish();
+
+    let spec = extract_openapi_from_router(app);
+
+    let paths = &spec.paths.as_ref().unwrap().paths;
+    let users_path = get_path_item(paths.get("/users").unwrap());
+    let get_op = users_path.get.as_ref().unwrap();
+    let responses = get_op.responses.as_ref().unwrap();
+
+    let status_200 = aide::openapi::StatusCode::Code(200);
+    if let aide::openapi::ReferenceOr::Item(response_200) =
+        responses.responses.get(&status_200).unwrap()
+    {
+        let json_content = response_200
+            .content
+            .get("application/json")
+            .expect("Should have JSON content");
+        let schema = &json_content.schema.as_ref().unwrap().json_schema;
+
+        // `[User]` expands to `Json<Vec<User>>`: an array of User
+        assert_eq!(schema.get("type"), Some(&serde_json::json!("array")));
+        let items = serde_json::to_string(schema.get("items").unwrap()).unwrap();
+        assert!(
+            items.contains("User"),
+            "Items should reference User: {items}"
+        );
+    } else {
+        panic!("200 response should be an Item, not a Reference");
+    }
+}
+
+#[test]
+fn test_spec_contains_version_extensions() {
+    /// Legacy search.
+    ///
+    /// # Responses
+    ///
+    /// 200: Json<String> - Results
+    ///
+    /// # Metadata
+    ///
+    /// @min-version 2.0
+    /// @max-version 3.5
+    /// @deprecated
+    #[rovo]
+    async fn legacy_search(State(_state): State<AppState>) -> Json<String> {
+        Json(String::new())
+    }
+
+    let mut api = OpenApi::default();
+    api.info.title = "Test API".to_string();
+
+    let app = Router::new()
+        .route("/search", get(legacy_search))
+        .with_oas(api)
+        .with_state(AppState)
+        .finish();
+
+    let spec = extract_openapi_from_router(app);
+    let paths = &spec.paths.as_ref().unwrap().paths;
+    let op = get_path_item(paths.get("/search").unwrap())
+        .get
+        .as_ref()
+        .unwrap();
+
+    assert_eq!(
+        op.extensions.get("x-min-version"),
+        Some(&serde_json::json!("2.0"))
+    );
+    assert_eq!(
+        op.extensions.get("x-max-version"),
+        Some(&serde_json::json!("3.5"))
+    );
+}
+
+#[test]
+fn test_spec_contains_ratelimit_extension() {
+    /// Search items.
+    ///
+    /// # Responses
+    ///
+    /// 200: Json<String> - Results
+    ///
+    /// # Metadata
+    ///
+    /// @ratelimit 100 per-minute
+    #[rovo]
+    async fn search(State(_state): State<AppState>) -> Json<String> {
+        Json(String::new())
+    }
+
+    let mut api = OpenApi::default();
+    api.info.title = "Test API".to_string();
+
+    let app = Router::new()
+        .route("/search", get(search))
+        .with_oas(api)
+        .with_state(AppState)
+        .finish();
+
+    let spec = extract_openapi_from_router(app);
+    let paths = &spec.paths.as_ref().unwrap().paths;
+    let op = get_path_item(paths.get("/search").unwrap())
+        .get
+        .as_ref()
+        .unwrap();
+
+    assert_eq!(
+        op.extensions.get("x-ratelimit"),
+        Some(&serde_json::json!({ "limit": 100, "window": "minute" }))
+    );
+}
+
+mod models {
+    use rovo::schemars::JsonSchema;
+    use serde::Serialize;
+
+    #[derive(Serialize, JsonSchema)]
+    #[schemars(rename = "crate::models::User")]
+    pub struct User {
+        pub id: u64,
+    }
+
+    #[derive(Serialize, JsonSchema)]
+    #[schemars(rename = "crate::models::Page")]
+    pub struct Page {
+        pub users: Vec<User>,
+    }
+
+    #[derive(Serialize, JsonSchema)]
+    #[schemars(rename = "crate::admin::User")]
+    pub struct AdminUser {
+        pub id: u64,
+    }
+}
+
+#[test]
+fn test_spec_short_schema_names() {
+    /// List users.
+    ///
+    /// # Responses
+    ///
+    /// 200: Json<models::Page> - A page of users
+    #[rovo]
+    async fn list_users(State(_state): State<AppState>) -> Json<models::Page> {
+        Json(models::Page { users: Vec::new() })
+    }
+
+    let mut api = OpenApi::default();
+    api.info.title = "Test API".to_string();
+
+    let app = Router::new()
+        .route("/users", get(list_users))
+        .with_oas(api)
+        .with_short_schema_names()
+        .with_state(AppState)
+        .finish();
+
+    let spec = serde_json::to_value(extract_openapi_from_router(app)).unwrap();
+    let schemas = spec["components"]["schemas"].as_object().unwrap();
+    let mut names: Vec<_> = schemas.keys().map(String::as_str).collect();
+    names.sort_unstable();
+    assert_eq!(names, vec!["Page", "User"]);
+
+    assert_eq!(
+        spec["paths"]["/users"]["get"]["responses"]["200"]["content"]["application/json"]["schema"]
+            ["$ref"],
+        "#/components/schemas/Page"
+    );
+    assert_eq!(
+        schemas["Page"]["properties"]["users"]["items"]["$ref"],
+        "#/components/schemas/User"
+    );
+}
+
+#[test]
+fn test_short_schema_names_reject_collisions() {
+    /// Get a user.
+    ///
+    /// # Responses
+    ///
+    /// 200: Json<models::User> - The user
+    #[rovo]
+    async fn get_user(State(_state): State<AppState>) -> Json<models::User> {
+        Json(models::User { id: 1 })
+    }
+
+    /// Get an admin.
+    ///
+    /// # Responses
+    ///
+    /// 200: Json<models::AdminUser> - The admin
+    #[rovo]
+    async fn get_admin(State(_state): State<AppState>) -> Json<models::AdminUser> {
+        Json(models::AdminUser { id: 1 })
+    }
+
+    let err = Router::new()
+        .route("/user", get(get_user))
+        .route("/admin", get(get_admin))
+        .with_oas(OpenApi::default())
+        .with_short_schema_names()
+        .with_state(AppState)
+        .try_finish()
+        .expect_err("both schemas shorten to `User`");
+    assert_eq!(
+        err,
+        rovo::RouterError::SchemaNameCollision {
+            short: "User".to_string(),
+            names: vec![
+                "crate::admin::User".to_string(),
+                "crate::models::User".to_string(),
+            ],
+        }
+    );
+}
+
+#[test]
+fn test_spec_contains_cfg_extension() {
+    /// Premium-only report.
+    ///
+    /// # Responses
+    ///
+    /// 200: Json<String> - The report
+    ///
+    /// # Metadata
+    ///
+    /// @cfg premium
+    #[rovo]
+    async fn premium_report(State(_state): State<AppState>) -> Json<String> {
+        Json("report".to_string())
+    }
+
+    let state = AppState;
+    let mut api = OpenApi::default();
+    api.info.title = "Test API".to_string();
+
+    let app = Router::new()
+        .route("/report", get(premium_report))
+        .with_oas(api.clone())
+        .with_state(state)
+        .finish();
+
+    let spec = extract_openapi_from_router(app);
+
+    let paths = &spec.paths.as_ref().unwrap().paths;
+    let report_path = get_path_item(paths.get("/report").unwrap());
+    let get_op = report_path.get.as_ref().unwrap();
+
+    assert_eq!(
+        get_op.extensions.get("x-cfg"),
+        Some(&serde_json::json!("premium")),
+        "Should record the feature as an x-cfg extension"
+    );
+}
+
+#[test]
+fn test_spec_contains_i18n_headers() {
+    /// Get a localized greeting.
+    ///
+    /// # Responses
+    ///
+    /// 200: Json<String> - Greeting in the negotiated language
+    /// 404: () - No translation available
+    ///
+    /// # Metadata
+    ///
+    /// @i18n
+    #[rovo]
+    async fn get_greeting(State(_state): State<AppState>) -> Json<String> {
+        Json("Hello".to_string())
+    }
+
+    let state = AppState;
+    let mut api = OpenApi::default();
+    api.info.title = "Test API".to_string();
+
+    let app = Router::new()
+        .route("/greeting", get(get_greeting))
+        .with_oas(api.clone())
+        .with_state(state)
+        .finish();
+
+    let spec = extract_openapi_from_router(app);
+
+    let paths = &spec.paths.as_ref().unwrap().paths;
+    let greeting_path = get_path_item(paths.get("/greeting").unwrap());
+    let get_op = greeting_path.get.as_ref().unwrap();
+
+    // Verify the Accept-Language header parameter
+    let accept_language = get_op.parameters.iter().find(|p| {
+        matches!(p,
+            rovo::aide::openapi::ReferenceOr::Item(
+                rovo::aide::openapi::Parameter::Header { parameter_data, .. }
+            ) if parameter_data.name == "Accept-Language"
+        )
+    });
+    assert!(
+        accept_language.is_some(),
+        "Should have 'Accept-Language' header parameter"
+    );
+
+    // Verify every response documents Content-Language
+    let responses = get_op.responses.as_ref().unwrap();
+    for status in [200, 404] {
+        let status_code = aide::openapi::StatusCode::Code(status);
+        if let aide::openapi::ReferenceOr::Item(response) =
+            responses.responses.get(&status_code).unwrap()
+        {
+            assert!(
+                response.headers.contains_key("Content-Language"),
+                "{status} response should have Content-Language header"
+            );
+        } else {
+            panic!("{status} response should be an Item, not a Reference");
+        }
+    }
+}
+
+#[test]
+fn test_spec_contains_if_match_header_and_412() {
+    /// Update a user.
+    ///
+    /// # Responses
+    ///
+    /// 200: Json<User> - The updated user
+    ///
+    /// # Metadata
+    ///
+    /// @concurrency
+    #[rovo]
+    async fn update_user(State(_state): State<AppState>) -> Json<User> {
+        Json(User::default())
+    }
+
+    let mut api = OpenApi::default();
+    api.info.title = "Test API".to_string();
+
+    let app = Router::new()
+        .route("/users/me", rovo::routing::put(update_user))
+        .with_oas(api)
+        .with_state(AppState)
+        .finish();
+
+    let spec = extract_openapi_from_router(app);
+    let paths = &spec.paths.as_ref().unwrap().paths;
+    let put_op = get_path_item(paths.get("/users/me").unwrap())
+        .put
+        .as_ref()
+        .unwrap();
+
+    let header = put_op
+        .parameters
+        .iter()
+        .find(|p| {
+            matches!(p,
+                rovo::aide::openapi::ReferenceOr::Item(
+                    rovo::aide::openapi::Parameter::Header { parameter_data, .. }
+                ) if parameter_data.name == "If-Match"
+            )
+        })
+        .expect("Should have an 'If-Match' header parameter");
+    let header = serde_json::to_value(header).unwrap();
+    assert_eq!(header["required"], true);
+
+    let responses = put_op.responses.as_ref().unwrap();
+    assert!(responses
+        .responses
+        .contains_key(&rovo::aide::openapi::StatusCode::Code(200)));
+    assert!(responses
+        .responses
+        .contains_key(&rovo::aide::openapi::StatusCode::Code(412)));
+}
+
+#[test]
+fn test_spec_contains_idempotency_key_header() {
+    /// Create a payment.
+    ///
+    /// # Responses
+    ///
+    /// 201: Json<String> - Payment created
+    ///
+    /// # Metadata
+    ///
+    /// @idempotent
+    #[rovo]
+    async fn create_payment(State(_state): State<AppState>) -> Json<String> {
+        Json("paid".to_string())
+    }
+
+    let mut api = OpenApi::default();
+    api.info.title = "Test API".to_string();
+
+    let app = Router::new()
+        .route("/payments", rovo::routing::post(create_payment))
+        .with_oas(api)
+        .with_state(AppState)
+        .finish();
+
+    let spec = extract_openapi_from_router(app);
+    let paths = &spec.paths.as_ref().unwrap().paths;
+    let post_op = get_path_item(paths.get("/payments").unwrap())
+        .post
+        .as_ref()
+        .unwrap();
+
+    let header = post_op
+        .parameters
+        .iter()
+        .find(|p| {
+            matches!(p,
+                rovo::aide::openapi::ReferenceOr::Item(
+                    rovo::aide::openapi::Parameter::Header { parameter_data, .. }
+                ) if parameter_data.name == "Idempotency-Key"
+            )
+        })
+        .expect("Should have an 'Idempotency-Key' header parameter");
+
+    let header = serde_json::to_value(header).unwrap();
+    assert_eq!(header["in"], "header");
+    assert_eq!(header["required"], true);
+    assert_eq!(header["schema"]["type"], "string");
+}
+
+#[test]
+fn test_spec_contains_sunset_extension_and_header() {
+    /// Legacy user listing.
+    ///
+    /// # Responses
+    ///
+    /// 200: Json<Vec<User>> - List of users
+    ///
+    /// # Metadata
+    ///
+    /// @sunset 2025-12-31
+    #[rovo]
+    async fn legacy_users(State(_state): State<AppState>) -> Json<Vec<User>> {
+        Json(vec![])
+    }
+
+    let state = AppState;
+    let mut api = OpenApi::default();
+    api.info.title = "Test API".to_string();
+
+    let app = Router::new()
+        .route("/legacy/users", get(legacy_users))
+        .with_oas(api.clone())
+        .with_state(state)
+        .finish();
+
+    let spec = extract_openapi_from_router(app);
+
+    let paths = &spec.paths.as_ref().unwrap().paths;
+    let legacy_path = get_path_item(paths.get("/legacy/users").unwrap());
+    let get_op = legacy_path.get.as_ref().unwrap();
+
+    assert_eq!(
+        get_op.extensions.get("x-sunset"),
+        Some(&serde_json::json!("2025-12-31")),
+        "Should record the removal date as an x-sunset extension"
+    );
+
+    let responses = get_op.responses.as_ref().unwrap();
+    let status_200 = aide::openapi::StatusCode::Code(200);
+    if let aide::openapi::ReferenceOr::Item(response) =
+        responses.responses.get(&status_200).unwrap()
+    {
+        assert!(
+            response.headers.contains_key("Sunset"),
+            "200 response should document the Sunset header"
+        );
+    } else {
+        panic!("200 response should be an Item, not a Reference");
+    }
+}
+
+#[test]
+fn test_spec_contains_async_job_response_and_callback() {
+    /// Start a user export.
+    ///
+    /// # Responses
+    ///
+    /// 200: Json<Vec<User>> - Export finished synchronously
+    ///
+    /// # Metadata
+    ///
+    /// @async-job exportCompleted
+    #[rovo]
+    async fn start_export(State(_state): State<AppState>) -> Json<Vec<User>> {
+        Json(vec![])
+    }
+
+    let state = AppState;
+    let mut api = OpenApi::default();
+    api.info.title = "Test API".to_string();
+
+    let app = Router::new()
+        .route("/exports", rovo::routing::post(start_export))
+        .with_oas(api.clone())
+        .with_state(state)
+        .finish();
+
+    let spec = extract_openapi_from_router(app);
+
+    let paths = &spec.paths.as_ref().unwrap().paths;
+    let exports_path = get_path_item(paths.get("/exports").unwrap());
+    let post_op = exports_path.post.as_ref().unwrap();
+
+    let responses = post_op.responses.as_ref().unwrap();
+    assert!(
+        responses
+            .responses
+            .contains_key(&aide::openapi::StatusCode::Code(202)),
+        "Should document a 202 Accepted response"
+    );
+    assert!(
+        responses
+            .responses
+            .contains_key(&aide::openapi::StatusCode::Code(200)),
+        "Documented responses should be kept"
+    );
+
+    let callback = post_op
+        .callbacks
+        .get("exportCompleted")
+        .expect("Should have a callbacks entry named after the callback operation");
+    let callback = serde_json::to_value(callback).unwrap();
+    assert_eq!(
+        callback["{$request.body#/callbackUrl}"]["post"]["operationId"],
+        serde_json::json!("exportCompleted"),
+        "Callback operation should reference the callback operation ID"
+    );
+}
+
+#[test]
+fn test_spec_contains_curl_code_sample() {
+    #[derive(Deserialize, JsonSchema)]
+    struct CreateUserRequest {
+        name: String,
+    }
+
+    /// Create a new user.
+    ///
+    /// # Responses
+    ///
+    /// 201: Json<User> - User created successfully
+    ///
+    /// # Examples
+    ///
+    /// 201: User { id: 1, name: "Alice".into() }
+    #[rovo(code_samples)]
+    async fn create_user(
+        State(_state): State<AppState>,
+        Json(req): Json<CreateUserRequest>,
+    ) -> (StatusCode, Json<User>) {
+        (
+            StatusCode::CREATED,
+            Json(User {
+                id: 1,
+                name: req.name,
+            }),
+        )
+    }
+
+    let state = AppState;
+    let mut api = OpenApi::default();
+    api.info.title = "Test API".to_string();
+    api.servers = vec![aide::openapi::Server {
+        url: "https://api.example.com/".to_string(),
+        ..Default::default()
+    }];
+
+    let app = Router::new()
+        .route("/users", rovo::routing::post(create_user))
+        .with_oas(api.clone())
+        .with_state(state)
+        .finish();
+
+    let spec = extract_openapi_from_router(app);
+
+    let paths = &spec.paths.as_ref().unwrap().paths;
+    let users_path = get_path_item(paths.get("/users").unwrap());
+    let post_op = users_path.post.as_ref().unwrap();
+
+    assert!(
+        !post_op.extensions.contains_key("x-rovo-code-samples"),
+        "Internal marker should be replaced"
+    );
+
+    let samples = post_op
+        .extensions
+        .get("x-codeSamples")
+        .and_then(|samples| samples.as_array())
+        .expect("Should have an x-codeSamples array");
+    assert_eq!(samples.len(), 1);
+    assert_eq!(samples[0]["lang"], "curl");
+
+    let source = samples[0]["source"].as_str().unwrap();
+    assert!(
+        source.starts_with("curl -X POST 'https://api.example.com/users'"),
+        "Unexpected sample: {source}"
+    );
+    assert!(source.contains("-H 'Content-Type: application/json'"));
+}
+
+#[test]
+fn test_spec_omits_code_samples_by_default() {
+    /// List users.
+    ///
+    /// # Responses
+    ///
+    /// 200: Json<Vec<User>> - All users
+    #[rovo]
+    async fn list_users(State(_state): State<AppState>) -> Json<Vec<User>> {
+        Json(vec![])
+    }
+
+    let state = AppState;
+    let mut api = OpenApi::default();
+    api.info.title = "Test API".to_string();
+
+    let app = Router::new()
+        .route("/users", get(list_users))
+        .with_oas(api.clone())
+        .with_state(state)
+        .finish();
+
+    let spec = extract_openapi_from_router(app);
+
+    let paths = &spec.paths.as_ref().unwrap().paths;
+    let get_op = get_path_item(paths.get("/users").unwrap())
+        .get
+        .as_ref()
+        .unwrap();
+    assert!(!get_op.extensions.contains_key("x-codeSamples"));
+}
+
+#[test]
+fn test_spec_contains_param_descriptions() {
+    use rovo::extract::Query;
+
+    #[derive(Deserialize, JsonSchema)]
+    struct ListParams {
+        limit: Option<u32>,
+    }
+
+    /// List a user's posts.
+    ///
+    /// # Responses
+    ///
+    /// 200: Json<Vec<User>> - Posts by the user
+    ///
+    /// # Metadata
+    ///
+    /// @param id u64 The user's unique identifier
+    /// @param limit Option<u32> Maximum number of posts to return
+    #[rovo]
+    async fn list_posts(
+        State(_state): State<AppState>,
+        Path(id): Path<u64>,
+        Query(params): Query<ListParams>,
+    ) -> Json<Vec<User>> {
+        let _ = (id, params.limit);
+        Json(vec![])
+    }
+
+    let state = AppState;
+    let mut api = OpenApi::default();
+    api.info.title = "Test API".to_string();
+
+    let app = Router::new()
+        .route("/users/{id}/posts", get(list_posts))
+        .with_oas(api.clone())
+        .with_state(state)
+        .finish();
+
+    let spec = extract_openapi_from_router(app);
+
+    let paths = &spec.paths.as_ref().unwrap().paths;
+    let get_op = get_path_item(paths.get("/users/{id}/posts").unwrap())
+        .get
+        .as_ref()
+        .unwrap();
+
+    let id_params: Vec<_> = get_op
+        .parameters
+        .iter()
+        .filter_map(|p| match p {
+            rovo::aide::openapi::ReferenceOr::Item(rovo::aide::openapi::Parameter::Path {
+                parameter_data,
+                ..
+            }) if parameter_data.name == "id" => Some(parameter_data),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(
+        id_params.len(),
+        1,
+        "Should not duplicate the 'id' parameter"
+    );
+    assert_eq!(
+        id_params[0].description.as_deref(),
+        Some("The user's unique identifier")
+    );
+
+    let limit_param = get_op
+        .parameters
+        .iter()
+        .find_map(|p| match p {
+            rovo::aide::openapi::ReferenceOr::Item(rovo::aide::openapi::Parameter::Query {
+                parameter_data,
+                ..
+            }) if parameter_data.name == "limit" => Some(parameter_data),
+            _ => None,
+        })
+        .expect("Should have 'limit' query parameter");
+    assert_eq!(
+        limit_param.description.as_deref(),
+        Some("Maximum number of posts to return")
+    );
+}
+
+#[test]
+fn test_spec_contains_query_params() {
+    use rovo::extract::Query;
+    use std::collections::HashMap;
+
+    type OptionSet = u32;
+
+    /// Search users.
+    ///
+    /// # Responses
+    ///
+    /// 200: Json<Vec<User>> - Matching users
+    ///
+    /// # Metadata
+    ///
+    /// @query q String Text to search for
+    /// @query page Option<u32> Page number to return
+    /// @query limit std::option::Option<u32> Maximum number of users
+    /// @query fields OptionSet Fields to include
+    #[rovo]
+    async fn search_users(
+        State(_state): State<AppState>,
+        Query(_params): Query<HashMap<String, String>>,
+    ) -> Json<Vec<User>> {
+        Json(vec![])
+    }
+
+    let state = AppState;
+    let mut api = OpenApi::default();
+    api.info.title = "Test API".to_string();
+
+    let app = Router::new()
+        .route("/users/search", get(search_users))
+        .with_oas(api.clone())
+        .with_state(state)
+        .finish();
+
+    let spec = extract_openapi_from_router(app);
+
+    let paths = &spec.paths.as_ref().unwrap().paths;
+    let get_op = get_path_item(paths.get("/users/search").unwrap())
+        .get
+        .as_ref()
+        .unwrap();
+
+    let query_params: Vec<_> = get_op
+        .parameters
+        .iter()
+        .filter_map(|p| match p {
+            rovo::aide::openapi::ReferenceOr::Item(rovo::aide::openapi::Parameter::Query {
+                parameter_data,
+                ..
+            }) => Some(parameter_data),
+            _ => None,
+        })
+        .collect();
+
+    let q = query_params
+        .iter()
+        .find(|p| p.name == "q")
+        .expect("Should have 'q' query parameter");
+    assert_eq!(q.description.as_deref(), Some("Text to search for"));
+    assert!(q.required);
+
+    let page = query_params
+        .iter()
+        .find(|p| p.name == "page")
+        .expect("Should have 'page' query parameter");
+    assert_eq!(page.description.as_deref(), Some("Page number to return"));
+    assert!(!page.required, "Option<T> query parameters are optional");
+
+    let limit = query_params
+        .iter()
+        .find(|p| p.name == "limit")
+        .expect("Should have 'limit' query parameter");
+    assert!(!limit.required, "path-qualified Option<T> is optional too");
+
+    let fields = query_params
+        .iter()
+        .find(|p| p.name == "fields")
+        .expect("Should have 'fields' query parameter");
+    assert!(fields.required, "OptionSet is not an Option<T>");
+}
+
+#[test]
+fn test_spec_contains_request_body_description() {
+    #[derive(Deserialize, JsonSchema)]
+    struct CreateUserRequest {
+        name: String,
+    }
+
+    /// Create a new user.
+    ///
+    /// # Responses
+    ///
+    /// 201: Json<User> - User created successfully
+    ///
+    /// # Metadata
+    ///
+    /// @body Json<CreateUserRequest> - The user to create
+    #[rovo]
+    async fn create_user(
+        State(_state): State<AppState>,
+        Json(req): Json<CreateUserRequest>,
+    ) -> (StatusCode, Json<User>) {
+        (
+            StatusCode::CREATED,
+            Json(User {
+                id: 1,
+                name: req.name,
+            }),
+        )
+    }
+
+    let state = AppState;
+    let mut api = OpenApi::default();
+    api.info.title = "Test API".to_string();
+
+    let app = Router::new()
+        .route("/users", rovo::routing::post(create_user))
+        .with_oas(api.clone())
+        .with_state(state)
+        .finish();
+
+    let spec = extract_openapi_from_router(app);
+
+    let paths = &spec.paths.as_ref().unwrap().paths;
+    let post_op = get_path_item(paths.get("/users").unwrap())
+        .post
+        .as_ref()
+        .unwrap();
+
+    let Some(rovo::aide::openapi::ReferenceOr::Item(request_body)) = &post_op.request_body else {
+        panic!("Should have an inline request body");
+    };
+    assert_eq!(
+        request_body.description.as_deref(),
+        Some("The user to create")
+    );
+    assert!(
+        request_body.content.contains_key("application/json"),
+        "Should keep the JSON body schema"
+    );
+}
+
+#[test]
+fn test_spec_contains_request_body_content_type() {
+    /// Upload a document.
+    ///
+    /// # Responses
+    ///
+    /// 204: () - Document stored
+    ///
+    /// # Metadata
+    ///
+    /// @body String text/plain - The raw document
+    #[rovo]
+    async fn upload_document(State(_state): State<AppState>, _body: String) -> StatusCode {
+        StatusCode::NO_CONTENT
+    }
+
+    let state = AppState;
+    let mut api = OpenApi::default();
+    api.info.title = "Test API".to_string();
+
+    let app = Router::new()
+        .route("/documents", rovo::routing::post(upload_document))
+        .with_oas(api.clone())
+        .with_state(state)
+        .finish();
+
+    let spec = extract_openapi_from_router(app);
+
+    let paths = &spec.paths.as_ref().unwrap().paths;
+    let post_op = get_path_item(paths.get("/documents").unwrap())
+        .post
+        .as_ref()
+        .unwrap();
+
+    let Some(rovo::aide::openapi::ReferenceOr::Item(request_body)) = &post_op.request_body else {
+        panic!("Should have an inline request body");
+    };
+    assert_eq!(
+        request_body.content.keys().collect::<Vec<_>>(),
+        vec!["text/plain"]
+    );
+}
+
+#[test]
+fn test_spec_contains_path_annotation_descriptions() {
+    /// Get a post.
+    ///
+    /// # Path Parameters
+    ///
+    /// @path id The post's author
+    /// @path slug URL-friendly post title
+    ///
+    /// # Responses
+    ///
+    /// 200: Json<User> - Post author
+    #[rovo]
+    async fn get_post(
+        State(_state): State<AppState>,
+        Path((id, slug)): Path<(u32, String)>,
+    ) -> Json<User> {
+        Json(User {
+            id: id.into(),
+            name: slug,
+        })
+    }
+
+    let state = AppState;
+    let mut api = OpenApi::default();
+    api.info.title = "Test API".to_string();
+
+    let app = Router::new()
+        .route("/posts/{id}/{slug}", get(get_post))
+        .with_oas(api.clone())
+        .with_state(state)
+        .finish();
+
+    let spec = extract_openapi_from_router(app);
+
+    let paths = &spec.paths.as_ref().unwrap().paths;
+    let get_op = get_path_item(paths.get("/posts/{id}/{slug}").unwrap())
+        .get
+        .as_ref()
+        .unwrap();
+
+    let description_of = |name: &str| {
+        get_op.parameters.iter().find_map(|p| match p {
+            rovo::aide::openapi::ReferenceOr::Item(rovo::aide::openapi::Parameter::Path {
+                parameter_data,
+                ..
+            }) if parameter_data.name == name => parameter_data.description.clone(),
+            _ => None,
+        })
+    };
+    assert_eq!(description_of("id").as_deref(), Some("The post's author"));
+    assert_eq!(
+        description_of("slug").as_deref(),
+        Some("URL-friendly post title")
+    );
+}
+
+#[test]
+fn test_spec_contains_standard_error_responses() {
+    /// Delete a user.
+    ///
+    /// # Responses
+    ///
+    /// 200: Json<User> - Deleted user
+    /// 400: Json<User> - Malformed user ID
+    ///
+    /// # Metadata
+    ///
+    /// @errors standard
+    #[rovo]
+    async fn delete_user(State(_state): State<AppState>) -> Json<User> {
+        Json(User {
+            id: 1,
+            name: "Alice".to_string(),
+        })
+    }
+
+    let response_descriptions = |app: axum::Router| {
+        let spec = extract_openapi_from_router(app);
+        let paths = &spec.paths.as_ref().unwrap().paths;
+        let op = get_path_item(paths.get("/users").unwrap())
+            .delete
+            .as_ref()
+            .unwrap();
+        assert!(!op.extensions.contains_key("x-rovo-errors"));
+        op.responses
+            .as_ref()
+            .unwrap()
+            .responses
+            .iter()
+            .map(|(code, response)| {
+                let (
+                    rovo::aide::openapi::StatusCode::Code(code),
+                    rovo::aide::openapi::ReferenceOr::Item(response),
+                ) = (code, response)
+                else {
+                    panic!("expected an inline response for a concrete status code");
+                };
+                (*code, response.description.clone())
+            })
+            .collect::<Vec<_>>()
+    };
+
+    let mut api = OpenApi::default();
+    api.info.title = "Test API".to_string();
+
+    // Default set; the explicitly documented 400 wins
+    let app = Router::new()
+        .route("/users", rovo::routing::delete(delete_user))
+        .with_oas(api.clone())
+        .with_state(AppState)
+        .finish();
+    let responses = response_descriptions(app);
+    assert!(responses.contains(&(400, "Malformed user ID".to_string())));
+    assert!(responses.contains(&(401, "Unauthorized".to_string())));
+    assert!(responses.contains(&(403, "Forbidden".to_string())));
+    assert!(responses.contains(&(500, "Internal server error".to_string())));
+
+    // Configured set replaces the defaults
+    let app = Router::new()
+        .route("/users", rovo::routing::delete(delete_user))
+        .with_oas(api)
+        .with_standard_errors([(400, "Invalid input"), (429, "Too many requests")])
+        .with_state(AppState)
+        .finish();
+    let responses = response_descriptions(app);
+    assert!(responses.contains(&(400, "Malformed user ID".to_string())));
+    assert!(responses.contains(&(429, "Too many requests".to_string())));
+    assert!(!responses
+        .iter()
+        .any(|(code, _)| *code == 401 || *code == 500));
+}
+
+#[test]
+fn test_spec_contains_pagination_parameters() {
+    /// List users.
+    ///
+    /// # Responses
+    ///
+    /// 200: Json<Vec<User>> - A page of users
+    ///
+    /// # Metadata
+    ///
+    /// @paginated
+    #[rovo]
+    async fn list_users(State(_state): State<AppState>) -> Json<Vec<User>> {
+        Json(Vec::new())
+    }
+
+    let query_parameters = |app: axum::Router| {
+        let spec = extract_openapi_from_router(app);
+        let paths = &spec.paths.as_ref().unwrap().paths;
+        let op = get_path_item(paths.get("/users").unwrap())
+            .get
+            .as_ref()
+            .unwrap();
+        assert!(!op.extensions.contains_key("x-rovo-paginated"));
+        op.parameters
+            .iter()
+            .filter_map(|p| match p {
+                rovo::aide::openapi::ReferenceOr::Item(rovo::aide::openapi::Parameter::Query {
+                    parameter_data,
+                    ..
+                }) => Some((parameter_data.name.clone(), parameter_data.required)),
+                _ => None,
+            })
+            .collect::<Vec<_>>()
+    };
+
+    let mut api = OpenApi::default();
+    api.info.title = "Test API".to_string();
+
+    let app = Router::new()
+        .route("/users", get(list_users))
+        .with_oas(api.clone())
+        .with_state(AppState)
+        .finish();
+    assert_eq!(
+        query_parameters(app),
+        vec![
+            ("page".to_string(), false),
+            ("per_page".to_string(), false),
+            ("sort".to_string(), false),
+        ]
+    );
+
+    let app = Router::new()
+        .route("/users", get(list_users))
+        .with_oas(api)
+        .with_pagination_style(rovo::PaginationStyle::Offset)
+        .with_state(AppState)
+        .finish();
+    assert_eq!(
+        query_parameters(app),
+        vec![
+            ("offset".to_string(), false),
+            ("limit".to_string(), false),
+            ("sort".to_string(), false),
+        ]
+    );
+}
+
+#[test]
+fn test_spec_contains_documented_response_headers() {
+    /// Create a user.
+    ///
+    /// # Responses
+    ///
+    /// 201: Json<User> - User created
+    /// @header 201 Location string The URL of the created user
+    /// 400: () - Invalid user
+    ///
+    /// # Metadata
+    ///
+    /// @header 201 X-Request-Id integer - Request identifier
+    #[rovo]
+    async fn create_user(State(_state): State<AppState>) -> Json<User> {
+        Json(User {
+            id: 1,
+            name: "Alice".to_string(),
+        })
+    }
+
+    let mut api = OpenApi::default();
+    api.info.title = "Test API".to_string();
+
+    let app = Router::new()
+        .route("/users", rovo::routing::post(create_user))
+        .with_oas(api)
+        .with_state(AppState)
+        .finish();
+
+    let spec = extract_openapi_from_router(app);
+    let paths = &spec.paths.as_ref().unwrap().paths;
+    let op = get_path_item(paths.get("/users").unwrap())
+        .post
+        .as_ref()
+        .unwrap();
+    let responses = &op.responses.as_ref().unwrap().responses;
+
+    let response = |code: u16| match responses
+        .get(&rovo::aide::openapi::StatusCode::Code(code))
+        .unwrap()
+    {
+        rovo::aide::openapi::ReferenceOr::Item(response) => response,
+        rovo::aide::openapi::ReferenceOr::Reference { .. } => panic!("expected inline response"),
+    };
+    let header_description = |code: u16, name: &str| match response(code).headers.get(name)? {
+        rovo::aide::openapi::ReferenceOr::Item(header) => header.description.clone(),
+        rovo::aide::openapi::ReferenceOr::Reference { .. } => None,
+    };
+
+    assert_eq!(
+        header_description(201, "Location").as_deref(),
+        Some("The URL of the created user")
+    );
+    assert_eq!(
+        header_description(201, "X-Request-Id").as_deref(),
+        Some("Request identifier")
+    );
+    assert!(response(400).headers.is_empty());
+}
+
+#[test]
+fn test_spec_contains_operation_extensions() {
+    /// List users.
+    ///
+    /// # Responses
+    ///
+    /// 200: Json<Vec<User>> - Users
+    ///
+    /// # Metadata
+    ///
+    /// @extension x-internal true
+    /// @extension x-rate-limit 100
+    /// @extension x-owner {"team": "identity"}
+    #[rovo]
+    async fn list_users(State(_state): State<AppState>) -> Json<Vec<User>> {
+        Json(vec![])
+    }
+
+    let mut api = OpenApi::default();
+    api.info.title = "Test API".to_string();
+
+    let app = Router::new()
+        .route("/users", get(list_users))
+        .with_oas(api)
+        .with_state(AppState)
+        .finish();
+
+    let spec = extract_openapi_from_router(app);
+    let paths = &spec.paths.as_ref().unwrap().paths;
+    let op = get_path_item(paths.get("/users").unwrap())
+        .get
+        .as_ref()
+        .unwrap();
+
+    assert_eq!(
+        op.extensions.get("x-internal"),
+        Some(&serde_json::json!(true))
+    );
+    assert_eq!(
+        op.extensions.get("x-rate-limit"),
+        Some(&serde_json::json!(100))
+    );
+    assert_eq!(
+        op.extensions.get("x-owner"),
+        Some(&serde_json::json!({ "team": "identity" }))
+    );
+}
+
+#[test]
+fn test_spec_contains_multiple_content_types() {
+    /// Export a user.
+    ///
+    /// # Responses
+    ///
+    /// 200: Json<User> [application/json], String [text/csv] - User data
+    /// 404: () - User not found
+    #[rovo]
+    async fn export_user(State(_state): State<AppState>) -> Json<User> {
+        Json(User {
+            id: 1,
+            name: "Alice".to_string(),
+        })
+    }
+
+    let mut api = OpenApi::default();
+    api.info.title = "Test API".to_string();
+
+    let app = Router::new()
+        .route("/users/export", get(export_user))
+        .with_oas(api)
+        .with_state(AppState)
+        .finish();
+
+    let spec = extract_openapi_from_router(app);
+    let paths = &spec.paths.as_ref().unwrap().paths;
+    let op = get_path_item(paths.get("/users/export").unwrap())
+        .get
+        .as_ref()
+        .unwrap();
+    let responses = &op.responses.as_ref().unwrap().responses;
+
+    let Some(rovo::aide::openapi::ReferenceOr::Item(response)) =
+        responses.get(&rovo::aide::openapi::StatusCode::Code(200))
+    else {
+        panic!("expected an inline 200 response");
+    };
+    assert_eq!(response.description, "User data");
+    let content_types: Vec<&str> = response.content.keys().map(String::as_str).collect();
+    assert_eq!(content_types, vec!["application/json", "text/csv"]);
+
+    let csv_schema = response.content["text/csv"].schema.as_ref().unwrap();
+    assert_eq!(
+        csv_schema.json_schema.get("type"),
+        Some(&serde_json::json!("string"))
+    );
+}
+
+#[test]
+fn test_spec_contains_content_type_override() {
+    /// Export the report.
+    ///
+    /// # Responses
+    ///
+    /// 200: String - The report as CSV
+    ///
+    /// # Metadata
+    ///
+    /// @content-type 200 text/csv
+    #[rovo]
+    async fn export_report(State(_state): State<AppState>) -> String {
+        String::new()
+    }
+
+    let mut api = OpenApi::default();
+    api.info.title = "Test API".to_string();
+
+    let app = Router::new()
+        .route("/report", get(export_report))
+        .with_oas(api)
+        .with_state(AppState)
+        .finish();
+
+    let spec = extract_openapi_from_router(app);
+    let paths = &spec.paths.as_ref().unwrap().paths;
+    let op = get_path_item(paths.get("/report").unwrap())
+        .get
+        .as_ref()
+        .unwrap();
+    let responses = &op.responses.as_ref().unwrap().responses;
+
+    let Some(rovo::aide::openapi::ReferenceOr::Item(response)) =
+        responses.get(&rovo::aide::openapi::StatusCode::Code(200))
+    else {
+        panic!("expected an inline 200 response");
+    };
+    let content_types: Vec<&str> = response.content.keys().map(String::as_str).collect();
+    assert_eq!(content_types, vec!["text/csv"]);
+}
+
+#[test]
+fn test_spec_contains_produces_override() {
+    /// Download the archive.
+    ///
+    /// # Responses
+    ///
+    /// 200: String - The archive
+    /// 404: Json<User> - No archive yet
+    ///
+    /// # Metadata
+    ///
+    /// @produces application/octet-stream
+    #[rovo]
+    async fn download_archive(State(_state): State<AppState>) -> String {
+        String::new()
+    }
+
+    let mut api = OpenApi::default();
+    api.info.title = "Test API".to_string();
+
+    let app = Router::new()
+        .route("/archive", get(download_archive))
+        .with_oas(api)
+        .with_state(AppState)
+        .finish();
+
+    let spec = extract_openapi_from_router(app);
+    let paths = &spec.paths.as_ref().unwrap().paths;
+    let op = get_path_item(paths.get("/archive").unwrap())
+        .get
+        .as_ref()
+        .unwrap();
+    let responses = &op.responses.as_ref().unwrap().responses;
+
+    let content_types = |code| {
+        let Some(rovo::aide::openapi::ReferenceOr::Item(response)) =
+            responses.get(&rovo::aide::openapi::StatusCode::Code(code))
+        else {
+            panic!("expected an inline {code} response");
+        };
+        response.content.keys().cloned().collect::<Vec<_>>()
+    };
+    assert_eq!(content_types(200), vec!["application/octet-stream"]);
+    assert_eq!(content_types(404), vec!["application/json"]);
+}
+
+#[test]
+fn test_spec_contains_range_and_default_responses() {
+    #[derive(Serialize, JsonSchema)]
+    struct ApiError {
+        message: String,
+    }
+
+    /// Get a user.
+    ///
+    /// # Responses
+    ///
+    /// 200: Json<User> - User found
+    /// 4XX: Json<ApiError> - The request was rejected
+    /// default: Json<ApiError> - Unexpected failure
+    #[rovo]
+    async fn get_user(State(_state): State<AppState>) -> Json<User> {
+        Json(User {
+            id: 1,
+            name: "Alice".to_string(),
+        })
+    }
+
+    let mut api = OpenApi::default();
+    api.info.title = "Test API".to_string();
+
+    let app = Router::new()
+        .route("/user", get(get_user))
+        .with_oas(api)
+        .with_state(AppState)
+        .finish();
+
+    let spec = extract_openapi_from_router(app);
+    let paths = &spec.paths.as_ref().unwrap().paths;
+    let op = get_path_item(paths.get("/user").unwrap())
+        .get
+        .as_ref()
+        .unwrap();
+    let responses = op.responses.as_ref().unwrap();
+
+    let Some(rovo::aide::openapi::ReferenceOr::Item(client_error)) = responses
+        .responses
+        .get(&rovo::aide::openapi::StatusCode::Range(4))
+    else {
+        panic!("expected an inline 4XX response");
+    };
+    assert_eq!(client_error.description, "The request was rejected");
+
+    let Some(rovo::aide::openapi::ReferenceOr::Item(fallback)) = &responses.default else {
+        panic!("expected an inline default response");
+    };
+    assert_eq!(fallback.description, "Unexpected failure");
+}
+
+#[test]
+fn test_spec_accepts_status_code_constants() {
+    /// Get a user.
+    ///
+    /// # Responses
+    ///
+    /// StatusCode::OK: Json<User> - User found
+    /// StatusCode::NOT_FOUND: () - User does not exist
+    #[rovo]
+    async fn get_user(State(_state): State<AppState>) -> Json<User> {
+        Json(User {
+            id: 1,
+            name: "Alice".to_string(),
+        })
+    }
+
+    let mut api = OpenApi::default();
+    api.info.title = "Test API".to_string();
+
+    let app = Router::new()
+        .route("/user", get(get_user))
+        .with_oas(api)
+        .with_state(AppState)
+        .finish();
+
+    let spec = extract_openapi_from_router(app);
+    let paths = &spec.paths.as_ref().unwrap().paths;
+    let op = get_path_item(paths.get("/user").unwrap())
+        .get
+        .as_ref()
+        .unwrap();
+    let responses = &op.responses.as_ref().unwrap().responses;
+
+    for (code, description) in [(200, "User found"), (404, "User does not exist")] {
+        let Some(rovo::aide::openapi::ReferenceOr::Item(response)) =
+            responses.get(&rovo::aide::openapi::StatusCode::Code(code))
+        else {
+            panic!("expected an inline {code} response");
+        };
+        assert_eq!(response.description, description);
+    }
+}
+
+#[test]
+fn test_spec_contains_response_link() {
+    /// Create a user.
+    ///
+    /// # Responses
+    ///
+    /// 201: Json<User> - User created
+    ///
+    /// # Metadata
+    ///
+    /// @link 201 getUser id=$response.body#/id
+    #[rovo]
+    async fn create_user(State(_state): State<AppState>) -> Json<User> {
+        Json(User::default())
+    }
+
+    let mut api = OpenApi::default();
+    api.info.title = "Test API".to_string();
+
+    let app = Router::new()
+        .route("/users", rovo::routing::post(create_user))
+        .with_oas(api)
+        .with_state(AppState)
+        .finish();
+
+    let spec = extract_openapi_from_router(app);
+    let paths = &spec.paths.as_ref().unwrap().paths;
+    let op = get_path_item(paths.get("/users").unwrap())
+        .post
+        .as_ref()
+        .unwrap();
+    let responses = &op.responses.as_ref().unwrap().responses;
+
+    let Some(rovo::aide::openapi::ReferenceOr::Item(response)) =
+        responses.get(&rovo::aide::openapi::StatusCode::Code(201))
+    else {
+        panic!("expected an inline 201 response");
+    };
+    let link = serde_json::to_value(response.links.get("getUser").unwrap()).unwrap();
+    assert_eq!(
+        link,
+        serde_json::json!({
+            "operationId": "getUser",
+            "parameters": { "id": "$response.body#/id" }
+        })
+    );
+}
+
+#[test]
+fn test_spec_contains_operation_servers() {
+    /// Upload a file.
+    ///
+    /// # Responses
+    ///
+    /// 200: Json<User> - Uploaded
+    ///
+    /// # Metadata
+    ///
+    /// @server https://eu.api.example.com EU region
+    /// @server https://us.api.example.com
+    #[rovo]
+    async fn upload_file(State(_state): State<AppState>) -> Json<User> {
+        Json(User::default())
+    }
+
+    let mut api = OpenApi::default();
+    api.info.title = "Test API".to_string();
+
+    let app = Router::new()
+        .route("/upload", get(upload_file))
+        .with_oas(api)
+        .with_state(AppState)
+        .finish();
+
+    let spec = extract_openapi_from_router(app);
+    let paths = &spec.paths.as_ref().unwrap().paths;
+    let op = get_path_item(paths.get("/upload").unwrap())
+        .get
+        .as_ref()
+        .unwrap();
+
+    let servers: Vec<(&str, Option<&str>)> = op
+        .servers
+        .iter()
+        .map(|server| (server.url.as_str(), server.description.as_deref()))
+        .collect();
+    assert_eq!(
+        servers,
+        vec![
+            ("https://eu.api.example.com", Some("EU region")),
+            ("https://us.api.example.com", None),
+        ]
+    );
+    assert!(spec.servers.is_empty());
+}
+
+#[test]
+fn test_spec_contains_external_docs() {
+    /// List users.
+    ///
+    /// # Responses
+    ///
+    /// 200: Json<User> - Users
+    ///
+    /// # Metadata
+    ///
+    /// @external-docs https://wiki.example.com/old Outdated guide
+    /// @external-docs https://wiki.example.com/users User management guide
+    #[rovo]
+    async fn list_users(State(_state): State<AppState>) -> Json<User> {
+        Json(User::default())
+    }
+
+    let mut api = OpenApi::default();
+    api.info.title = "Test API".to_string();
+
+    let app = Router::new()
+        .route("/users", get(list_users))
+        .with_oas(api)
+        .with_state(AppState)
+        .finish();
+
+    let spec = extract_openapi_from_router(app);
+    let paths = &spec.paths.as_ref().unwrap().paths;
+    let op = get_path_item(paths.get("/users").unwrap())
+        .get
+        .as_ref()
+        .unwrap();
+
+    let external_docs = op.external_docs.as_ref().unwrap();
+    assert_eq!(external_docs.url, "https://wiki.example.com/users");
+    assert_eq!(
+        external_docs.description.as_deref(),
+        Some("User management guide")
+    );
+}
+
+#[test]
+fn test_spec_contains_module_tag_descriptions() {
+    /// User management.
+    ///
+    /// @tag-description users Endpoints for managing user accounts
+    /// @tag-description admin Administrative endpoints
+    #[rovo::rovo_module]
+    mod users {
+        use super::{AppState, User};
+        use rovo::extract::State;
+        use rovo::response::Json;
+        use rovo::rovo;
+
+        /// List users.
+        ///
+        /// # Responses
+        ///
+        /// 200: Json<User> - The users
+        ///
+        /// # Metadata
+        ///
+        /// @tag users
+        #[rovo]
+        pub async fn list_users(State(_state): State<AppState>) -> Json<User> {
+            Json(User::default())
+        }
+    }
+
+    let mut api = OpenApi::default();
+    api.info.title = "Test API".to_string();
+    api.tags.push(rovo::aide::openapi::Tag {
+        name: "admin".to_string(),
+        description: Some("Set on the OpenApi".to_string()),
+        ..Default::default()
+    });
+
+    let app = Router::new()
+        .route("/users", get(users::list_users))
+        .with_oas(api)
+        .with_state(AppState)
+        .finish();
+
+    let spec = extract_openapi_from_router(app);
+    let tags: Vec<(&str, Option<&str>)> = spec
+        .tags
+        .iter()
+        .map(|tag| (tag.name.as_str(), tag.description.as_deref()))
+        .collect();
+    assert_eq!(
+        tags,
+        vec![
+            ("admin", Some("Set on the OpenApi")),
+            ("users", Some("Endpoints for managing user accounts")),
+        ]
+    );
+
+    let op = get_path_item(spec.paths.as_ref().unwrap().paths.get("/users").unwrap())
+        .get
+        .as_ref()
+        .unwrap();
+    assert!(!op.extensions.contains_key("x-rovo-tag-descriptions"));
+}
+
+#[test]
+fn test_transform_modifies_spec() {
+    let app = Router::new()
+        .route("/users", get(list_users))
+        .with_oas(OpenApi::default())
+        .transform(|api| {
+            // The routes' operations are already in the spec
+            assert!(api.paths.as_ref().unwrap().paths.contains_key("/users"));
+            api.info.version = "1.2.0".to_string();
+            api.components
+                .get_or_insert_with(Default::default)
+                .security_schemes
+                .insert(
+                    "bearer".to_string(),
+                    aide::openapi::ReferenceOr::Item(aide::openapi::SecurityScheme::Http {
+                        scheme: "bearer".to_string(),
+                        bearer_format: Some("JWT".to_string()),
+                        description: None,
+                        extensions: Default::default(),
+                    }),
+                );
+        })
+        .transform(|api| api.info.version.push_str("-beta"))
+        .with_state(AppState)
+        .finish();
+
+    let spec = extract_openapi_from_router(app);
+    assert_eq!(spec.info.version, "1.2.0-beta");
+    assert!(spec
+        .components
+        .unwrap()
+        .security_schemes
+        .contains_key("bearer"));
+}
+
+#[test]
+fn test_transform_runs_after_rovo_passes() {
+    /// List users.
+    ///
+    /// # Responses
+    ///
+    /// 200: Json<models::Page> - A page of users
+    #[rovo]
+    async fn list_users(State(_state): State<AppState>) -> Json<models::Page> {
+        Json(models::Page { users: Vec::new() })
+    }
+
+    let app = Router::new()
+        .route("/users", get(list_users))
+        .with_oas(OpenApi::default())
+        .transform(|api| {
+            // Schema names are already shortened
+            let schemas = &api.components.as_ref().unwrap().schemas;
+            assert!(schemas.contains_key("Page"));
+            api.info.description = Some("Transformed".to_string());
+        })
+        .with_short_schema_names()
+        .with_state(AppState)
+        .finish();
+
+    let spec = extract_openapi_from_router(app);
+    assert_eq!(spec.info.description.as_deref(), Some("Transformed"));
+}
+
+#[test]
+fn test_security_scheme_helpers_define_schemes() {
+    let app = Router::new()
+        .route("/users", get(list_users))
+        .with_oas(OpenApi::default())
+        .with_bearer_auth("bearer")
+        .with_api_key("apiKey", aide::openapi::ApiKeyLocation::Header, "X-API-Key")
+        .with_state(AppState)
+        .finish();
+
+    let spec = extract_openapi_from_router(app);
+    let schemes = serde_json::to_value(&spec.components.unwrap().security_schemes).unwrap();
+    assert_eq!(
+        schemes,
+        serde_json::json!({
+            "bearer": { "type": "http", "scheme": "bearer" },
+            "apiKey": { "type": "apiKey", "in": "header", "name": "X-API-Key" },
+        })
+    );
+}
+
 // Helper function to extract PathItem from ReferenceOr
 fn get_path_item(
     path: &aide::openapi::ReferenceOr<aide::openapi::PathItem>,
@@ -0,0 +1,21 @@
+#![allow(unused_imports)]
+use rovo::aide::axum::IntoApiResponse;
+use rovo::response::Json;
+use rovo::rovo;
+
+/// Test handler with two links to the same operation
+///
+/// # Responses
+///
+/// 201: Json<String> - Created
+///
+/// # Metadata
+///
+/// @link 201 getUser id=$response.body#/id
+/// @link 201 getUser name=$response.body#/name
+#[rovo]
+async fn test_handler() -> impl IntoApiResponse {
+    Json("test".to_string())
+}
+
+fn main() {}
@@ -0,0 +1,27 @@
+#![allow(unused_imports)]
+use rovo::response::Json;
+use rovo::rovo;
+use serde::Serialize;
+
+#[derive(Serialize)]
+struct User {
+    id: u64,
+    name: String,
+}
+
+/// Get user by ID
+///
+/// # Responses
+///
+/// 200: Json<User> - User found
+/// 404: () - User not found
+/// 200: Json<User> - User found in cache
+#[rovo]
+async fn get_user() -> Json<User> {
+    Json(User {
+        id: 1,
+        name: "Alice".to_string(),
+    })
+}
+
+fn main() {}
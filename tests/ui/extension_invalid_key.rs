@@ -0,0 +1,20 @@
+#![allow(unused_imports)]
+use rovo::aide::axum::IntoApiResponse;
+use rovo::response::Json;
+use rovo::rovo;
+
+/// Get all items.
+///
+/// # Responses
+///
+/// 200: Json<String> - Items found
+///
+/// # Metadata
+///
+/// @extension internal true
+#[rovo]
+async fn get_items() -> impl IntoApiResponse {
+    Json("items".to_string())
+}
+
+fn main() {}
@@ -0,0 +1,17 @@
+#![allow(unused_imports)]
+use rovo::aide::axum::IntoApiResponse;
+use rovo::response::Json;
+use rovo::rovo;
+
+/// Create an item.
+///
+/// # Responses
+///
+/// 200: Json<String> - Item created
+/// @header 201 Location string The URL of the created item
+#[rovo]
+async fn create_item() -> impl IntoApiResponse {
+    Json("item".to_string())
+}
+
+fn main() {}
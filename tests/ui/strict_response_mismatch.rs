@@ -0,0 +1,28 @@
+#![allow(unused_imports)]
+use rovo::aide::operation::OperationOutput;
+use rovo::response::{IntoResponse, Response};
+use rovo::rovo;
+
+struct Plain;
+
+impl IntoResponse for Plain {
+    fn into_response(self) -> Response {
+        ().into_response()
+    }
+}
+
+impl OperationOutput for Plain {
+    type Inner = ();
+}
+
+/// Handler whose return type doesn't match the documented response
+///
+/// # Responses
+///
+/// 200: () - Nothing to return
+#[rovo(strict_responses)]
+async fn test_handler() -> Plain {
+    Plain
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+#![allow(unused_imports)]
+use rovo::aide::axum::IntoApiResponse;
+use rovo::response::Json;
+use rovo::rovo;
+
+/// Test handler with a tag description
+///
+/// # Metadata
+///
+/// @tag-description users Endpoints for managing users
+#[rovo]
+async fn test_handler() -> impl IntoApiResponse {
+    Json("test".to_string())
+}
+
+fn main() {}
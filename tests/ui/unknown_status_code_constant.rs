@@ -0,0 +1,17 @@
+#![allow(unused_imports)]
+use rovo::aide::axum::IntoApiResponse;
+use rovo::response::Json;
+use rovo::rovo;
+
+/// Get an item.
+///
+/// # Responses
+///
+/// 200: Json<String> - Item found
+/// StatusCode::MISSING: () - Item does not exist
+#[rovo]
+async fn get_item() -> impl IntoApiResponse {
+    Json("item".to_string())
+}
+
+fn main() {}